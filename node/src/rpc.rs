@@ -5,7 +5,9 @@
 
 use std::sync::Arc;
 
-use imbue_kusama_runtime::{opaque::Block, AccountId, Balance, Nonce};
+use imbue_kusama_runtime::{
+    opaque::Block, AccountId, Balance, BlockNumber, DisputeId, MilestoneKey, Nonce,
+};
 
 use sc_client_api::AuxStore;
 pub use sc_rpc::{DenyUnsafe, SubscriptionTaskExecutor};
@@ -42,9 +44,19 @@ where
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
     C::Api: BlockBuilder<Block>,
+    C::Api: pallet_briefs_rpc::BriefsRuntimeApi<Block, AccountId, Balance, BlockNumber>,
     C::Api: pallet_proposals_rpc::ProposalsRuntimeApi<Block, AccountId, Balance>,
+    C::Api: pallet_disputes_rpc::DisputesRuntimeApi<
+        Block,
+        AccountId,
+        DisputeId,
+        MilestoneKey,
+        BlockNumber,
+    >,
     P: TransactionPool + Sync + Send + 'static,
 {
+    use pallet_briefs_rpc::{Briefs, BriefsApiServer};
+    use pallet_disputes_rpc::{Disputes, DisputesApiServer};
     use pallet_proposals_rpc::{Proposals, ProposalsApiServer};
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
     use substrate_frame_rpc_system::{System, SystemApiServer};
@@ -58,6 +70,8 @@ where
 
     module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
     module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
-    module.merge(Proposals::new(client).into_rpc())?;
+    module.merge(Proposals::new(client.clone()).into_rpc())?;
+    module.merge(Disputes::new(client.clone()).into_rpc())?;
+    module.merge(Briefs::new(client).into_rpc())?;
     Ok(module)
 }
@@ -0,0 +1,62 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode};
+use common_types::CurrencyId;
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_core::H256;
+use sp_std::vec::Vec;
+
+/// A decoded brief as returned by the runtime api.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct BriefInfo<AccountId, Balance, BlockNumber> {
+    pub brief_id: H256,
+    pub brief_owners: Vec<AccountId>,
+    pub applicant: AccountId,
+    pub budget: Balance,
+    /// The budget minus the sum of the contributions made so far.
+    pub remaining_bounty: Balance,
+    pub currency_id: CurrencyId,
+    pub created_at: BlockNumber,
+    pub milestones: Vec<BriefMilestoneInfo<BlockNumber>>,
+}
+
+/// A milestone proposed in a brief.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct BriefMilestoneInfo<BlockNumber> {
+    /// The percentage of the budget unlocked by this milestone.
+    pub percentage_to_unlock: u8,
+    pub content_hash: Vec<u8>,
+    pub due_block: Option<BlockNumber>,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct BriefContributionInfo<AccountId, Balance, BlockNumber> {
+    pub who: AccountId,
+    pub value: Balance,
+    pub timestamp: BlockNumber,
+}
+
+sp_api::decl_runtime_apis! {
+    pub trait BriefsApi<AccountId, Balance, BlockNumber>
+    where
+        AccountId: codec::Codec,
+        Balance: codec::Codec,
+        BlockNumber: codec::Codec,
+    {
+        /// Get the budget of a brief minus the contributions made so far.
+        fn get_remaining_bounty(brief_id: H256) -> Balance;
+        /// Get a decoded brief by its hash.
+        fn get_brief(brief_id: H256) -> Option<BriefInfo<AccountId, Balance, BlockNumber>>;
+        /// Get the contributions made to a brief.
+        fn get_brief_contributions(brief_id: H256) -> Vec<BriefContributionInfo<AccountId, Balance, BlockNumber>>;
+        /// Get the hashes of the briefs where `owner` is one of the brief owners.
+        fn get_briefs_by_owner(owner: AccountId) -> Vec<H256>;
+        /// Get the hashes of the briefs where `applicant` is the applicant.
+        fn get_briefs_by_applicant(applicant: AccountId) -> Vec<H256>;
+    }
+}
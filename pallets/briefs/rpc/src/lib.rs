@@ -0,0 +1,135 @@
+use codec::Codec;
+
+use jsonrpsee::{
+    core::{Error as JsonRpseeError, RpcResult},
+    proc_macros::rpc,
+    types::error::{CallError, ErrorObject},
+};
+pub use pallet_briefs_rpc_runtime_api::{
+    BriefContributionInfo, BriefInfo, BriefMilestoneInfo, BriefsApi as BriefsRuntimeApi,
+};
+
+use sp_blockchain::HeaderBackend;
+use sp_core::H256;
+use sp_runtime::traits::Block as BlockT;
+use sp_std::vec::Vec;
+
+use std::sync::Arc;
+
+#[rpc(client, server)]
+pub trait BriefsApi<BlockHash, AccountId, Balance, BlockNumber> {
+    #[method(name = "briefs_getRemainingBounty")]
+    fn remaining_bounty(&self, brief_id: H256) -> RpcResult<Balance>;
+    #[method(name = "briefs_getBrief")]
+    fn brief(
+        &self,
+        brief_id: H256,
+    ) -> RpcResult<Option<BriefInfo<AccountId, Balance, BlockNumber>>>;
+    #[method(name = "briefs_getBriefContributions")]
+    fn brief_contributions(
+        &self,
+        brief_id: H256,
+    ) -> RpcResult<Vec<BriefContributionInfo<AccountId, Balance, BlockNumber>>>;
+    #[method(name = "briefs_getBriefsByOwner")]
+    fn briefs_by_owner(&self, owner: AccountId) -> RpcResult<Vec<H256>>;
+    #[method(name = "briefs_getBriefsByApplicant")]
+    fn briefs_by_applicant(&self, applicant: AccountId) -> RpcResult<Vec<H256>>;
+}
+
+pub struct Briefs<C, B> {
+    /// Shared reference to the client.
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<B>,
+}
+
+impl<C, P> Briefs<C, P> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+    /// The call to runtime failed.
+    RuntimeError,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+        }
+    }
+}
+
+impl<C, B, AccountId, Balance, BlockNumber>
+    BriefsApiServer<<B as BlockT>::Hash, AccountId, Balance, BlockNumber> for Briefs<C, B>
+where
+    C: sp_api::ProvideRuntimeApi<B>,
+    C: HeaderBackend<B>,
+    C: Send + Sync + 'static,
+    C::Api: BriefsRuntimeApi<B, AccountId, Balance, BlockNumber>,
+    B: BlockT,
+    AccountId: Codec + Send + Sync + 'static,
+    Balance: Codec + Send + Sync + 'static,
+    BlockNumber: Codec + Send + Sync + 'static,
+{
+    fn remaining_bounty(&self, brief_id: H256) -> RpcResult<Balance> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        api.get_remaining_bounty(at, brief_id)
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn brief(
+        &self,
+        brief_id: H256,
+    ) -> RpcResult<Option<BriefInfo<AccountId, Balance, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        api.get_brief(at, brief_id)
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn brief_contributions(
+        &self,
+        brief_id: H256,
+    ) -> RpcResult<Vec<BriefContributionInfo<AccountId, Balance, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        api.get_brief_contributions(at, brief_id)
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn briefs_by_owner(&self, owner: AccountId) -> RpcResult<Vec<H256>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        api.get_briefs_by_owner(at, owner)
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn briefs_by_applicant(&self, applicant: AccountId) -> RpcResult<Vec<H256>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        api.get_briefs_by_applicant(at, applicant)
+            .map_err(runtime_error_into_rpc_err)
+    }
+}
+
+/// Converts a runtime trap into an RPC error.
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> JsonRpseeError {
+    CallError::Custom(ErrorObject::owned(
+        Error::RuntimeError.into(),
+        "Could not query the briefs runtime api",
+        Some(format!("{err:?}")),
+    ))
+    .into()
+}
@@ -7,8 +7,9 @@ use crate::{BoundedBriefOwners, BoundedProposedMilestones};
 use common_types::{CurrencyId, ForeignOwnedAccount};
 use frame_benchmarking::v2::*;
 use frame_support::{assert_ok, traits::Get};
-use frame_system::{EventRecord, RawOrigin};
+use frame_system::{pallet_prelude::BlockNumberFor, EventRecord, RawOrigin};
 use orml_traits::MultiCurrency;
+use pallet_fellowship::traits::SelectJury;
 use pallet_proposals::ProposedMilestone;
 use sp_arithmetic::per_things::Percent;
 use sp_runtime::SaturatedConversion;
@@ -30,7 +31,7 @@ mod benchmarks {
         let initial_contribution = 5_000u32.into();
         let brief_id = gen_hash(1);
         let milestones = get_max_milestones::<T>();
-        // (origin, brief_owners, applicant, budget, initial_contribution, brief_id, currency_id, milestones, Option<eoa>)
+        // (origin, brief_owners, applicant, budget, initial_contribution, brief_id, currency_id, milestones, Option<eoa>, require_fellowship, jury_size)
 
         #[extrinsic_call]
         create_brief(
@@ -44,6 +45,7 @@ mod benchmarks {
             milestones,
             Some(eoa),
             false,
+            <T::JurySelector as SelectJury<AccountIdOf<T>>>::JurySize::get(),
         );
         assert_last_event::<T>(Event::<T>::BriefSubmitted(caller, brief_id).into());
     }
@@ -70,6 +72,7 @@ mod benchmarks {
             milestones,
             None,
             false,
+            <T::JurySelector as SelectJury<AccountIdOf<T>>>::JurySize::get(),
         ));
         let brief_owner: T::AccountId = brief_owners[0].clone();
         // (brief_owner, brief_id, contribution)
@@ -104,6 +107,7 @@ mod benchmarks {
             milestones,
             None,
             false,
+            <T::JurySelector as SelectJury<AccountIdOf<T>>>::JurySize::get(),
         ));
         // (origin, brief_id)
         #[extrinsic_call]
@@ -132,6 +136,7 @@ mod benchmarks {
             milestones,
             None,
             false,
+            <T::JurySelector as SelectJury<AccountIdOf<T>>>::JurySize::get(),
         ));
         // (origin, brief_id)
         #[extrinsic_call]
@@ -195,8 +200,10 @@ fn get_milestones<T: Config>(mut n: u32) -> BoundedProposedMilestones<T> {
     (0..n)
         .map(|_| ProposedMilestone {
             percentage_to_unlock: Percent::from_percent((100 / n) as u8),
+            content_hash: Default::default(),
+            due_block: None,
         })
-        .collect::<Vec<ProposedMilestone>>()
+        .collect::<Vec<ProposedMilestone<BlockNumberFor<T>>>>()
         .try_into()
         .expect("qed")
 }
@@ -24,6 +24,7 @@ fn create_proposal_from_brief() {
             get_milestones(10),
             None,
             false,
+            MaxJuryMembers::get(),
         );
 
         assert_ok!(BriefsMod::commence_work(
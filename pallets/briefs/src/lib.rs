@@ -7,6 +7,8 @@ pub use weights::*;
 
 pub mod migrations;
 
+pub mod runtime_api;
+
 #[cfg(test)]
 mod mock;
 
@@ -52,7 +54,7 @@ pub mod pallet {
         <T as Config>::MaxBriefOwners,
     >;
     pub(crate) type BoundedProposedMilestones<T> =
-        BoundedVec<ProposedMilestone, <T as Config>::MaxMilestonesPerBrief>;
+        BoundedVec<ProposedMilestone<BlockNumberFor<T>>, <T as Config>::MaxMilestonesPerBrief>;
 
     pub(crate) type BoundedBriefOwners<T> =
         BoundedVec<AccountIdOf<T>, <T as Config>::MaxBriefOwners>;
@@ -65,7 +67,7 @@ pub mod pallet {
 
     pub type BriefHash = H256;
 
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(6);
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -110,6 +112,32 @@ pub mod pallet {
     pub type BriefContributions<T> =
         StorageMap<_, Blake2_128Concat, BriefHash, BoundedBriefContributions<T>, ValueQuery>;
 
+    /// The briefs each account is a brief owner of.
+    /// A helper for the runtime api so we dont have to iterate over every brief.
+    #[pallet::storage]
+    pub type OwnerBriefs<T> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AccountIdOf<T>,
+        Blake2_128Concat,
+        BriefHash,
+        (),
+        ValueQuery,
+    >;
+
+    /// The briefs each account has been selected as the applicant of.
+    /// A helper for the runtime api so we dont have to iterate over every brief.
+    #[pallet::storage]
+    pub type ApplicantBriefs<T> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AccountIdOf<T>,
+        Blake2_128Concat,
+        BriefHash,
+        (),
+        ValueQuery,
+    >;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -162,15 +190,20 @@ pub mod pallet {
         EoaRequiredForForeignCurrencies,
         /// Currency is not supported for this external address.
         CurrencyAccountComboNotSupported,
+        /// The jury size must be at least one and at most the JurySize of the JurySelector.
+        InvalidJurySize,
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Create a brief to be funded or amended.
         /// In the current state the applicant must be approved.
+        /// The jury_size is the number of jurors selected to hear disputes on the project once work commences.
         #[allow(clippy::too_many_arguments)]
         #[pallet::call_index(2)]
-        #[pallet::weight(<T as Config>::WeightInfo::create_brief())]
+        #[pallet::weight(<T as Config>::WeightInfo::create_brief().saturating_add(
+            T::DbWeight::get().writes(T::MaxBriefOwners::get().saturating_add(1) as u64)
+        ))]
         pub fn create_brief(
             origin: OriginFor<T>,
             mut brief_owners: BoundedBriefOwners<T>,
@@ -182,8 +215,15 @@ pub mod pallet {
             milestones: BoundedProposedMilestones<T>,
             external_owned_address: Option<common_types::ForeignOwnedAccount>,
             require_fellowship: bool,
+            jury_size: u32,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
+            ensure!(
+                jury_size > 0
+                    && jury_size
+                        <= <T::JurySelector as SelectJury<AccountIdOf<T>>>::JurySize::get(),
+                Error::<T>::InvalidJurySize
+            );
 
             if require_fellowship {
                 T::EnsureRole::ensure_role(&applicant, pallet_fellowship::Role::Freelancer, None)?;
@@ -207,11 +247,12 @@ pub mod pallet {
                 );
             }
 
-            let total_percentage = milestones
-                .iter()
-                .fold(Percent::zero(), |acc: Percent, ms: &ProposedMilestone| {
+            let total_percentage = milestones.iter().fold(
+                Percent::zero(),
+                |acc: Percent, ms: &ProposedMilestone<BlockNumberFor<T>>| {
                     acc.saturating_add(ms.percentage_to_unlock)
-                });
+                },
+            );
 
             ensure!(
                 total_percentage.is_one(),
@@ -260,8 +301,10 @@ pub mod pallet {
                 milestones,
                 deposit_id,
                 external_owned_address,
+                jury_size,
             );
 
+            Self::index_brief(brief_id, &brief);
             Briefs::<T>::insert(brief_id, brief);
 
             Self::deposit_event(Event::<T>::BriefSubmitted(who, brief_id));
@@ -316,7 +359,13 @@ pub mod pallet {
 
         /// Once the freelancer is happy with both the milestones and the offering this can be called.
         #[pallet::call_index(4)]
-        #[pallet::weight(<T as Config>::WeightInfo::commence_work())]
+        #[pallet::weight(<T as Config>::WeightInfo::commence_work().saturating_add(
+            <T::JurySelector as SelectJury<AccountIdOf<T>>>::select_jury_weight(
+                T::MaxBriefOwners::get().saturating_mul(2).saturating_add(1)
+            )
+        ).saturating_add(
+            T::DbWeight::get().writes(T::MaxBriefOwners::get().saturating_add(1) as u64)
+        ))]
         pub fn commence_work(origin: OriginFor<T>, brief_id: BriefHash) -> DispatchResult {
             let who = ensure_signed(origin)?;
             let brief = Briefs::<T>::get(brief_id).ok_or(Error::<T>::BriefNotFound)?;
@@ -325,6 +374,15 @@ pub mod pallet {
 
             let contributions = BriefContributions::<T>::get(brief_id);
 
+            // The brief owners, contributors and applicant cannot sit on the jury.
+            let mut excluded = brief.brief_owners.to_vec();
+            excluded.extend(contributions.keys().cloned());
+            excluded.push(brief.applicant.clone());
+            let jury = <T::JurySelector as SelectJury<AccountIdOf<T>>>::select_jury_excluding(
+                brief.jury_size,
+                &excluded,
+            );
+
             <T as Config>::DepositHandler::return_deposit(brief.deposit_id)?;
 
             let refund_locations =
@@ -343,15 +401,14 @@ pub mod pallet {
                     .try_into()
                     .map_err(|_| Error::<T>::TooManyBriefOwners)?,
                 brief_id,
-                brief.applicant,
+                brief.applicant.clone(),
                 brief
                     .milestones
                     .to_vec()
                     .try_into()
                     .map_err(|_| Error::<T>::TooManyMilestones)?,
                 refund_locations,
-                <T::JurySelector as SelectJury<AccountIdOf<T>>>::select_jury()
-                    .to_vec()
+                jury.to_vec()
                     .try_into()
                     .map_err(|_| Error::<T>::TooManyMilestones)?,
                 FundingPath::TakeFromReserved,
@@ -359,6 +416,7 @@ pub mod pallet {
             )?;
 
             BriefContributions::<T>::remove(brief_id);
+            Self::unindex_brief(brief_id, &brief);
             Briefs::<T>::remove(brief_id);
 
             Self::deposit_event(Event::<T>::BriefEvolution(brief_id));
@@ -367,7 +425,9 @@ pub mod pallet {
 
         /// Extrinsic to cancel a brief
         #[pallet::call_index(5)]
-        #[pallet::weight(<T as Config>::WeightInfo::cancel_brief())]
+        #[pallet::weight(<T as Config>::WeightInfo::cancel_brief().saturating_add(
+            T::DbWeight::get().writes(T::MaxBriefOwners::get().saturating_add(1) as u64)
+        ))]
         pub fn cancel_brief(origin: OriginFor<T>, brief_id: BriefHash) -> DispatchResult {
             let who = ensure_signed(origin)?;
             let brief = Briefs::<T>::get(brief_id).ok_or(Error::<T>::BriefNotFound)?;
@@ -384,6 +444,7 @@ pub mod pallet {
             }
 
             BriefContributions::<T>::remove(brief_id);
+            Self::unindex_brief(brief_id, &brief);
             Briefs::<T>::remove(brief_id);
 
             Self::deposit_event(Event::<T>::BriefCanceled(brief_id));
@@ -403,6 +464,8 @@ pub mod pallet {
         pub milestones: BoundedProposedMilestones<T>,
         pub deposit_id: DepositIdOf<T>,
         pub eoa: Option<common_types::ForeignOwnedAccount>,
+        /// The number of jurors selected for the project once work commences.
+        pub jury_size: u32,
     }
 
     impl<T: Config> Pallet<T> {
@@ -418,9 +481,26 @@ pub mod pallet {
                 Default::default()
             }
         }
+
+        /// Index a brief by its owners and applicant for the runtime api.
+        fn index_brief(brief_id: BriefHash, brief: &BriefData<T>) {
+            for owner in brief.brief_owners.iter() {
+                OwnerBriefs::<T>::insert(owner, brief_id, ());
+            }
+            ApplicantBriefs::<T>::insert(&brief.applicant, brief_id, ());
+        }
+
+        /// Remove a brief from the indexes once it has been cancelled or converted to a project.
+        fn unindex_brief(brief_id: BriefHash, brief: &BriefData<T>) {
+            for owner in brief.brief_owners.iter() {
+                OwnerBriefs::<T>::remove(owner, brief_id);
+            }
+            ApplicantBriefs::<T>::remove(&brief.applicant, brief_id);
+        }
     }
 
     impl<T: Config> BriefData<T> {
+        #[allow(clippy::too_many_arguments)]
         pub fn new(
             brief_owners: BoundedBriefOwners<T>,
             budget: BalanceOf<T>,
@@ -430,6 +510,7 @@ pub mod pallet {
             milestones: BoundedProposedMilestones<T>,
             deposit_id: DepositIdOf<T>,
             eoa: Option<common_types::ForeignOwnedAccount>,
+            jury_size: u32,
         ) -> Self {
             Self {
                 created_at,
@@ -440,6 +521,7 @@ pub mod pallet {
                 milestones,
                 deposit_id,
                 eoa,
+                jury_size,
             }
         }
     }
@@ -50,20 +50,20 @@ pub(crate) mod v1 {
         if v2::StorageVersion::<T>::get() == v2::Release::V0 {
             v0::BriefsV0::<T>::drain().for_each(|(key, brief)| {
                 *weight += T::DbWeight::get().reads_writes(2, 1);
-                let maybe_milestones: Result<BoundedProposedMilestones<T>, _> = brief
+                let maybe_milestones: Result<v2::BoundedProposedMilestonesV2<T>, _> = brief
                     .milestones
                     .iter()
                     .filter_map(|ms| {
                         let convert: Result<u8, _> = ms.percentage_to_unlock.try_into();
                         if let Ok(n) = convert {
-                            Some(ProposedMilestone {
+                            Some(v2::ProposedMilestoneV2 {
                                 percentage_to_unlock: Percent::from_percent(n),
                             })
                         } else {
                             None
                         }
                     })
-                    .collect::<Vec<ProposedMilestone>>()
+                    .collect::<Vec<v2::ProposedMilestoneV2>>()
                     .try_into();
 
                 if let Ok(milestones) = maybe_milestones {
@@ -96,6 +96,14 @@ pub mod v2 {
     pub type BriefsV2<T: Config> =
         CountedStorageMap<Pallet<T>, Blake2_128Concat, BriefHash, BriefDataV2<T>, OptionQuery>;
 
+    #[derive(Encode, Decode, PartialEq, Eq, Clone, Debug, MaxEncodedLen, TypeInfo)]
+    pub struct ProposedMilestoneV2 {
+        pub percentage_to_unlock: Percent,
+    }
+
+    pub type BoundedProposedMilestonesV2<T> =
+        BoundedVec<ProposedMilestoneV2, <T as Config>::MaxMilestonesPerBrief>;
+
     #[derive(Encode, Decode, PartialEq, Eq, Clone, Debug, MaxEncodedLen, TypeInfo)]
     #[scale_info(skip_type_params(T))]
     pub struct BriefDataV2<T: Config> {
@@ -104,7 +112,7 @@ pub mod v2 {
         pub currency_id: CurrencyId,
         pub created_at: BlockNumberFor<T>,
         pub applicant: AccountIdOf<T>,
-        pub milestones: BoundedProposedMilestones<T>,
+        pub milestones: BoundedProposedMilestonesV2<T>,
         pub deposit_id: crate::DepositIdOf<T>,
     }
 
@@ -167,6 +175,23 @@ pub mod v2 {
 pub mod v3 {
     use super::*;
 
+    #[storage_alias]
+    pub type BriefsV3<T: Config> =
+        CountedStorageMap<Pallet<T>, Blake2_128Concat, BriefHash, BriefDataV3<T>, OptionQuery>;
+
+    #[derive(Encode, Decode, PartialEq, Eq, Clone, Debug, MaxEncodedLen, TypeInfo)]
+    #[scale_info(skip_type_params(T))]
+    pub struct BriefDataV3<T: Config> {
+        pub brief_owners: BoundedBriefOwners<T>,
+        pub budget: BalanceOf<T>,
+        pub currency_id: CurrencyId,
+        pub created_at: BlockNumberFor<T>,
+        pub applicant: AccountIdOf<T>,
+        pub milestones: v2::BoundedProposedMilestonesV2<T>,
+        pub deposit_id: crate::DepositIdOf<T>,
+        pub eoa: Option<common_types::ForeignOwnedAccount>,
+    }
+
     pub struct MigrateToV3<T: Config>(T);
     impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
         #[cfg(feature = "try-runtime")]
@@ -185,7 +210,7 @@ pub mod v3 {
             let mut weight: Weight = Default::default();
             if current == 3 && onchain == 2 {
                 v2::BriefsV2::<T>::drain().for_each(|(key, brief)| {
-                    let migrated_brief = BriefData {
+                    let migrated_brief = BriefDataV3::<T> {
                         created_at: brief.created_at,
                         brief_owners: brief.brief_owners,
                         budget: brief.budget,
@@ -197,7 +222,7 @@ pub mod v3 {
                     };
 
                     T::DbWeight::get().reads_writes(2, 2);
-                    crate::Briefs::<T>::insert(key, migrated_brief);
+                    BriefsV3::<T>::insert(key, migrated_brief);
                 });
 
                 current.put::<Pallet<T>>();
@@ -223,10 +248,241 @@ pub mod v3 {
     }
 }
 
+// Add a content hash and an optional due block to each proposed milestone.
+pub mod v4 {
+    use super::*;
+
+    #[storage_alias]
+    pub type BriefsV4<T: Config> =
+        CountedStorageMap<Pallet<T>, Blake2_128Concat, BriefHash, BriefDataV4<T>, OptionQuery>;
+
+    #[derive(Encode, Decode, PartialEq, Eq, Clone, Debug, MaxEncodedLen, TypeInfo)]
+    #[scale_info(skip_type_params(T))]
+    pub struct BriefDataV4<T: Config> {
+        pub brief_owners: BoundedBriefOwners<T>,
+        pub budget: BalanceOf<T>,
+        pub currency_id: CurrencyId,
+        pub created_at: BlockNumberFor<T>,
+        pub applicant: AccountIdOf<T>,
+        pub milestones: BoundedProposedMilestones<T>,
+        pub deposit_id: crate::DepositIdOf<T>,
+        pub eoa: Option<common_types::ForeignOwnedAccount>,
+    }
+
+    pub struct MigrateToV4<T: Config>(T);
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV4<T> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            let onchain = Pallet::<T>::on_chain_storage_version();
+            ensure!(
+                onchain == 3,
+                "onchain must be version 3 to run the migration."
+            );
+            Ok(crate::Briefs::<T>::count().encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let current = Pallet::<T>::current_storage_version();
+            let onchain = Pallet::<T>::on_chain_storage_version();
+            let mut weight: Weight = Default::default();
+            if current == 4 && onchain == 3 {
+                migrate_milestone_metadata::<T>(&mut weight);
+                current.put::<Pallet<T>>();
+
+                log::warn!("v4 has been successfully applied");
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 1));
+            } else {
+                log::warn!("Skipping v4, should be removed");
+                weight = weight.saturating_add(T::DbWeight::get().reads(1));
+            }
+            weight
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            frame_support::ensure!(
+                Pallet::<T>::on_chain_storage_version() == 4,
+                "v4 has not been applied"
+            );
+            let count_before: u32 =
+                Decode::decode(&mut state.as_slice()).map_err(|_| "failed to decode count")?;
+            ensure!(
+                crate::Briefs::<T>::count() == count_before,
+                "briefs have been lost in the migration"
+            );
+
+            Ok(())
+        }
+    }
+
+    pub(super) fn migrate_milestone_metadata<T: Config>(weight: &mut Weight) {
+        BriefsV4::<T>::translate(|_key, brief: v3::BriefDataV3<T>| {
+            *weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+            let milestones: BoundedProposedMilestones<T> = brief
+                .milestones
+                .iter()
+                .map(|ms| ProposedMilestone {
+                    percentage_to_unlock: ms.percentage_to_unlock,
+                    content_hash: Default::default(),
+                    due_block: None,
+                })
+                .collect::<Vec<ProposedMilestone<BlockNumberFor<T>>>>()
+                .try_into()
+                .ok()?;
+
+            Some(BriefDataV4 {
+                brief_owners: brief.brief_owners,
+                budget: brief.budget,
+                currency_id: brief.currency_id,
+                created_at: brief.created_at,
+                applicant: brief.applicant,
+                milestones,
+                deposit_id: brief.deposit_id,
+                eoa: brief.eoa,
+            })
+        });
+    }
+}
+
+// Add the number of jurors selected for each brief once work commences.
+pub mod v5 {
+    use super::*;
+    use pallet_fellowship::traits::SelectJury;
+
+    pub struct MigrateToV5<T: Config>(T);
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV5<T> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            let onchain = Pallet::<T>::on_chain_storage_version();
+            ensure!(
+                onchain == 4,
+                "onchain must be version 4 to run the migration."
+            );
+            Ok(crate::Briefs::<T>::count().encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let current = Pallet::<T>::current_storage_version();
+            let onchain = Pallet::<T>::on_chain_storage_version();
+            let mut weight: Weight = Default::default();
+            if current == 5 && onchain == 4 {
+                migrate_jury_size::<T>(&mut weight);
+                current.put::<Pallet<T>>();
+
+                log::warn!("v5 has been successfully applied");
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 1));
+            } else {
+                log::warn!("Skipping v5, should be removed");
+                weight = weight.saturating_add(T::DbWeight::get().reads(1));
+            }
+            weight
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            frame_support::ensure!(
+                Pallet::<T>::on_chain_storage_version() == 5,
+                "v5 has not been applied"
+            );
+            let count_before: u32 =
+                Decode::decode(&mut state.as_slice()).map_err(|_| "failed to decode count")?;
+            ensure!(
+                crate::Briefs::<T>::count() == count_before,
+                "briefs have been lost in the migration"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Existing briefs keep the full jury they would have been given before.
+    pub(super) fn migrate_jury_size<T: Config>(weight: &mut Weight) {
+        let jury_size = <T::JurySelector as SelectJury<AccountIdOf<T>>>::JurySize::get();
+        crate::Briefs::<T>::translate(|_key, brief: v4::BriefDataV4<T>| {
+            *weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+            Some(BriefData {
+                brief_owners: brief.brief_owners,
+                budget: brief.budget,
+                currency_id: brief.currency_id,
+                created_at: brief.created_at,
+                applicant: brief.applicant,
+                milestones: brief.milestones,
+                deposit_id: brief.deposit_id,
+                eoa: brief.eoa,
+                jury_size,
+            })
+        });
+    }
+}
+
+pub mod v6 {
+    use super::*;
+
+    pub struct MigrateToV6<T: Config>(T);
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV6<T> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            let onchain = Pallet::<T>::on_chain_storage_version();
+            ensure!(
+                onchain == 5,
+                "onchain must be version 5 to run the migration."
+            );
+            Ok(crate::Briefs::<T>::count().encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let current = Pallet::<T>::current_storage_version();
+            let onchain = Pallet::<T>::on_chain_storage_version();
+            let mut weight: Weight = Default::default();
+            if current == 6 && onchain == 5 {
+                migrate_brief_indexes::<T>(&mut weight);
+                current.put::<Pallet<T>>();
+
+                log::warn!("v6 has been successfully applied");
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 1));
+            } else {
+                log::warn!("Skipping v6, should be removed");
+                weight = weight.saturating_add(T::DbWeight::get().reads(1));
+            }
+            weight
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            frame_support::ensure!(
+                Pallet::<T>::on_chain_storage_version() == 6,
+                "v6 has not been applied"
+            );
+            let count_before: u32 =
+                Decode::decode(&mut state.as_slice()).map_err(|_| "failed to decode count")?;
+            ensure!(
+                crate::ApplicantBriefs::<T>::iter().count() as u32 == count_before,
+                "briefs have not been indexed by applicant"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Index the existing briefs by their owners and applicant.
+    pub(super) fn migrate_brief_indexes<T: Config>(weight: &mut Weight) {
+        crate::Briefs::<T>::iter().for_each(|(brief_id, brief)| {
+            *weight = weight.saturating_add(
+                T::DbWeight::get()
+                    .reads_writes(1, brief.brief_owners.len().saturating_add(1) as u64),
+            );
+            for owner in brief.brief_owners.iter() {
+                crate::OwnerBriefs::<T>::insert(owner, brief_id, ());
+            }
+            crate::ApplicantBriefs::<T>::insert(brief.applicant, brief_id, ());
+        });
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::mock::{build_test_externality, Test, ALICE, BOB, CHARLIE};
+    use crate::mock::{build_test_externality, MaxJuryMembers, Test, ALICE, BOB, CHARLIE};
     use sp_arithmetic::Percent;
     use sp_core::H256;
 
@@ -275,4 +531,107 @@ mod test {
             );
         })
     }
+
+    #[test]
+    fn migrate_v3_to_v4() {
+        build_test_externality().execute_with(|| {
+            let milestones: v2::BoundedProposedMilestonesV2<Test> = vec![
+                v2::ProposedMilestoneV2 {
+                    percentage_to_unlock: Percent::from_percent(80u8),
+                },
+                v2::ProposedMilestoneV2 {
+                    percentage_to_unlock: Percent::from_percent(20u8),
+                },
+            ]
+            .try_into()
+            .expect("2 should be lower than bound");
+
+            let old_brief = v3::BriefDataV3::<Test> {
+                brief_owners: vec![ALICE, BOB]
+                    .try_into()
+                    .expect("2 should be lower than bound"),
+                budget: 100_000u64,
+                currency_id: CurrencyId::Native,
+                created_at: frame_system::Pallet::<Test>::block_number(),
+                applicant: CHARLIE,
+                milestones,
+                deposit_id: 0u64,
+                eoa: None,
+            };
+            let key: H256 = [1; 32].into();
+            v3::BriefsV3::<Test>::insert(key, &old_brief);
+            let mut weight: Weight = Default::default();
+            v4::migrate_milestone_metadata::<Test>(&mut weight);
+
+            let new_brief = v4::BriefsV4::<Test>::get(key).expect("should exist.");
+            assert_eq!(v4::BriefsV4::<Test>::count(), 1);
+            assert_eq!(new_brief.applicant, CHARLIE);
+            assert_eq!(new_brief.milestones.len(), 2);
+            new_brief
+                .milestones
+                .iter()
+                .zip(old_brief.milestones.iter())
+                .for_each(|(new, old)| {
+                    assert_eq!(new.percentage_to_unlock, old.percentage_to_unlock);
+                    assert!(new.content_hash.is_empty());
+                    assert_eq!(new.due_block, None);
+                });
+        })
+    }
+
+    #[test]
+    fn migrate_v4_to_v5() {
+        build_test_externality().execute_with(|| {
+            let old_brief = v4::BriefDataV4::<Test> {
+                brief_owners: vec![ALICE, BOB]
+                    .try_into()
+                    .expect("2 should be lower than bound"),
+                budget: 100_000u64,
+                currency_id: CurrencyId::Native,
+                created_at: frame_system::Pallet::<Test>::block_number(),
+                applicant: CHARLIE,
+                milestones: Default::default(),
+                deposit_id: 0u64,
+                eoa: None,
+            };
+            let key: H256 = [1; 32].into();
+            v4::BriefsV4::<Test>::insert(key, &old_brief);
+            let mut weight: Weight = Default::default();
+            v5::migrate_jury_size::<Test>(&mut weight);
+
+            let new_brief = crate::Briefs::<Test>::get(key).expect("should exist.");
+            assert_eq!(crate::Briefs::<Test>::count(), 1);
+            assert_eq!(new_brief.applicant, CHARLIE);
+            assert_eq!(new_brief.deposit_id, 0u64);
+            assert_eq!(new_brief.jury_size, MaxJuryMembers::get());
+        })
+    }
+
+    #[test]
+    fn migrate_v5_to_v6() {
+        build_test_externality().execute_with(|| {
+            let brief = BriefData::<Test>::new(
+                vec![ALICE, BOB]
+                    .try_into()
+                    .expect("2 should be lower than bound"),
+                100_000u64,
+                CurrencyId::Native,
+                frame_system::Pallet::<Test>::block_number(),
+                CHARLIE,
+                Default::default(),
+                0u64,
+                None,
+                MaxJuryMembers::get(),
+            );
+            let key: H256 = [1; 32].into();
+            crate::Briefs::<Test>::insert(key, &brief);
+            let mut weight: Weight = Default::default();
+            v6::migrate_brief_indexes::<Test>(&mut weight);
+
+            assert!(crate::OwnerBriefs::<Test>::contains_key(ALICE, key));
+            assert!(crate::OwnerBriefs::<Test>::contains_key(BOB, key));
+            assert!(crate::ApplicantBriefs::<Test>::contains_key(CHARLIE, key));
+            assert!(!crate::ApplicantBriefs::<Test>::contains_key(ALICE, key));
+        })
+    }
 }
@@ -174,6 +174,7 @@ pub enum StorageItem {
     Brief,
     Grant,
     Project,
+    Dispute,
 }
 
 pub struct MockDepositHandler;
@@ -222,14 +223,25 @@ parameter_types! {
     pub RefundsPerBlock: u8 = 2;
     pub IsIdentityRequired: bool = false;
     pub MilestoneVotingWindow: BlockNumber  =  100800u64;
+    pub NoConfidenceVotingWindow: BlockNumber = 100800u64;
+    pub PercentRequiredForVoteNoConfidenceToPass: Percent = Percent::from_percent(75u8);
     pub MaxMilestonesPerProject: u32 = 10;
     pub ProjectStorageDeposit: Balance = 100;
     pub ImbueFee: Percent = Percent::from_percent(5u8);
     pub ExpiringProjectRoundsPerBlock: u32 = 10;
     pub ProjectStorageItem: StorageItem = StorageItem::Project;
+    pub DisputeStorageItem: StorageItem = StorageItem::Dispute;
     pub MaxProjectsPerAccount: u16 = 100;
     pub ImbueFeeAccount: AccountId = TREASURY;
     pub MaxJuryMembers: u32 = 100;
+    pub MaxDisputesPerJuror: u32 = 50;
+    pub MaxJuriesPerAccount: u32 = 50;
+    pub MinimumJurySize: u32 = 2;
+    pub ArbitrationWindow: BlockNumber = 100;
+    pub DefaultResubmissionPolicy: pallet_proposals::ResubmissionPolicy<BlockNumber> =
+        pallet_proposals::ResubmissionPolicy { cooldown: 0, max_attempts: 3 };
+    pub DefaultVotingExpiryPolicy: pallet_proposals::VotingExpiryPolicy =
+        pallet_proposals::VotingExpiryPolicy::Reject;
 }
 
 impl pallet_proposals::Config for Test {
@@ -240,6 +252,8 @@ impl pallet_proposals::Config for Test {
     type PercentRequiredForVoteToPass = PercentRequiredForVoteToPass;
     type MaximumContributorsPerProject = MaximumContributorsPerProject;
     type MilestoneVotingWindow = MilestoneVotingWindow;
+    type NoConfidenceVotingWindow = NoConfidenceVotingWindow;
+    type PercentRequiredForVoteNoConfidenceToPass = PercentRequiredForVoteNoConfidenceToPass;
     type ExternalRefundHandler = pallet_proposals::traits::MockRefundHandler<Test>;
     type MaxMilestonesPerProject = MaxMilestonesPerProject;
     type ImbueFee = ImbueFee;
@@ -247,10 +261,18 @@ impl pallet_proposals::Config for Test {
     type ExpiringProjectRoundsPerBlock = ExpiringProjectRoundsPerBlock;
     type DepositHandler = MockDepositHandler;
     type ProjectStorageItem = ProjectStorageItem;
+    type DisputeStorageItem = DisputeStorageItem;
     type MaxProjectsPerAccount = MaxProjectsPerAccount;
     type DisputeRaiser = MockDisputeRaiser;
     type JurySelector = MockJurySelector;
+    type MaxJuriesPerAccount = MaxJuriesPerAccount;
     type AssetSignerOrigin = EnsureRoot<AccountId>;
+    type MinimumJurySize = MinimumJurySize;
+    type ArbitrationOrigin = EnsureRoot<AccountId>;
+    type ArbitrationWindow = ArbitrationWindow;
+    type DefaultResubmissionPolicy = DefaultResubmissionPolicy;
+    type DefaultVotingExpiryPolicy = DefaultVotingExpiryPolicy;
+    type ProjectPolicyOrigin = EnsureRoot<AccountId>;
 }
 
 parameter_types! {
@@ -283,6 +305,8 @@ parameter_types! {
     pub MembershipDeposit: Balance = 50_000_000;
     pub SlashAccount: AccountId = 1;
     pub DepositCurrencyId: CurrencyId = CurrencyId::Native;
+    pub JuryRoles: Vec<pallet_fellowship::Role> = vec![pallet_fellowship::Role::Vetter, pallet_fellowship::Role::Freelancer];
+    pub MinJuryRank: u16 = 0;
 }
 
 impl pallet_fellowship::Config for Test {
@@ -295,6 +319,9 @@ impl pallet_fellowship::Config for Test {
     type DepositCurrencyId = DepositCurrencyId;
     type SlashAccount = SlashAccount;
     type Permissions = pallet_fellowship::impls::VetterAndFreelancerAllPermissions;
+    type FellowshipHooks = ();
+    type JuryRoles = JuryRoles;
+    type MinJuryRank = MinJuryRank;
     type WeightInfo = pallet_fellowship::weights::WeightInfo<Test>;
 }
 
@@ -341,10 +368,13 @@ pub(crate) fn build_test_externality() -> sp_io::TestExternalities {
 pub struct MockJurySelector;
 impl pallet_fellowship::traits::SelectJury<AccountId> for MockJurySelector {
     type JurySize = MaxJuryMembers;
-    fn select_jury() -> BoundedVec<AccountId, Self::JurySize> {
-        vec![JURY_1, JURY_2]
-            .try_into()
-            .expect("should be below bound.")
+    fn select_jury(amount: u32) -> BoundedVec<AccountId, Self::JurySize> {
+        BoundedVec::truncate_from(
+            vec![JURY_1, JURY_2]
+                .into_iter()
+                .take(amount as usize)
+                .collect(),
+        )
     }
 }
 
@@ -353,12 +383,25 @@ impl pallet_disputes::traits::DisputeRaiser<AccountId> for MockDisputeRaiser {
     type DisputeKey = u32;
     type SpecificId = u32;
     type MaxJurySize = MaxJuryMembers;
+    type MaxDisputesPerJuror = MaxDisputesPerJuror;
     type MaxSpecifics = MaxMilestonesPerProject;
+    type BondId = u64;
     fn raise_dispute(
         _dispute_key: Self::DisputeKey,
         _raised_by: AccountId,
         _jury: BoundedVec<AccountId, Self::MaxJurySize>,
         _specific_ids: BoundedVec<Self::SpecificId, Self::MaxSpecifics>,
+        _bond: Option<Self::BondId>,
+    ) -> Result<(), DispatchError> {
+        Ok(())
+    }
+    fn disputes_of_juror(_juror: &AccountId) -> Vec<Self::DisputeKey> {
+        Vec::new()
+    }
+    fn replace_juror(
+        _dispute_key: Self::DisputeKey,
+        _juror: &AccountId,
+        _replacement: Option<AccountId>,
     ) -> Result<(), DispatchError> {
         Ok(())
     }
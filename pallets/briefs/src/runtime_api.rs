@@ -1,14 +1,58 @@
-use crate::pallet::*;
+use crate::*;
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_briefs_rpc_runtime_api::{BriefContributionInfo, BriefInfo, BriefMilestoneInfo};
+use sp_std::vec::Vec;
 
-sp_api::decl_runtime_apis! {
-    pub trait GetRemainingBounty<Balance> {
-        fn get_remaining_bounty() -> Balance;
+pub type BriefInfoOf<T> = BriefInfo<AccountIdOf<T>, BalanceOf<T>, BlockNumberFor<T>>;
+pub type BriefContributionInfoOf<T> =
+    BriefContributionInfo<AccountIdOf<T>, BalanceOf<T>, BlockNumberFor<T>>;
+
+impl<T: Config> Pallet<T> {
+    /// Get a decoded view of a brief, used by the runtime api.
+    pub fn brief_info(brief_id: BriefHash) -> Option<BriefInfoOf<T>> {
+        let brief = Briefs::<T>::get(brief_id)?;
+        let remaining_bounty = Self::get_remaining_bounty(brief_id);
+        let milestones = brief
+            .milestones
+            .into_iter()
+            .map(|ms| BriefMilestoneInfo {
+                percentage_to_unlock: ms.percentage_to_unlock.deconstruct(),
+                content_hash: ms.content_hash.into_inner(),
+                due_block: ms.due_block,
+            })
+            .collect();
+
+        Some(BriefInfo {
+            brief_id,
+            brief_owners: brief.brief_owners.into_inner(),
+            applicant: brief.applicant,
+            budget: brief.budget,
+            remaining_bounty,
+            currency_id: brief.currency_id,
+            created_at: brief.created_at,
+            milestones,
+        })
+    }
+
+    /// Get the contributions made to a brief, used by the runtime api.
+    pub fn brief_contributions_info(brief_id: BriefHash) -> Vec<BriefContributionInfoOf<T>> {
+        BriefContributions::<T>::get(brief_id)
+            .into_iter()
+            .map(|(who, c)| BriefContributionInfo {
+                who,
+                value: c.value,
+                timestamp: c.timestamp,
+            })
+            .collect()
     }
-}
 
-// TODO IMPL into RUNTIME
-//impl sum_storage_rpc_runtime_api::GetRemainingBounty<Balance> for Runtime {
-//    fn get_remaining_bounty(brief_id: BriefId) -> Balance {
-//        BriefsMod::get_remaining_bounty(brief_id)
-//    }
-//}
\ No newline at end of file
+    /// Get the hashes of the briefs `owner` is a brief owner of, used by the runtime api.
+    pub fn briefs_by_owner(owner: &AccountIdOf<T>) -> Vec<BriefHash> {
+        OwnerBriefs::<T>::iter_key_prefix(owner).collect()
+    }
+
+    /// Get the hashes of the briefs `applicant` has been selected for, used by the runtime api.
+    pub fn briefs_by_applicant(applicant: &AccountIdOf<T>) -> Vec<BriefHash> {
+        ApplicantBriefs::<T>::iter_key_prefix(applicant).collect()
+    }
+}
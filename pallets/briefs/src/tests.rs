@@ -28,6 +28,7 @@ fn create_brief_not_approved_applicant() {
                 get_milestones(10),
                 None,
                 true,
+                MaxJuryMembers::get(),
             ),
             BadOrigin
         );
@@ -54,6 +55,7 @@ fn create_brief_approved_applicant() {
             get_milestones(10),
             None,
             true,
+            MaxJuryMembers::get(),
         ));
     });
 }
@@ -73,6 +75,7 @@ fn create_brief_brief_owner_overflow() {
                 get_milestones(10),
                 None,
                 false,
+                MaxJuryMembers::get(),
             ),
             Error::<Test>::TooManyBriefOwners
         );
@@ -93,6 +96,7 @@ fn create_brief_with_no_contribution_ok() {
             get_milestones(10),
             None,
             false,
+            MaxJuryMembers::get(),
         ));
     });
 }
@@ -115,6 +119,7 @@ fn create_brief_no_contribution_and_contribute() {
             get_milestones(10),
             None,
             false,
+            MaxJuryMembers::get(),
         ));
 
         (0..5).for_each(|_| {
@@ -158,6 +163,7 @@ fn contribute_to_brief_not_brief_owner() {
             get_milestones(10),
             None,
             false,
+            MaxJuryMembers::get(),
         ));
 
         assert_noop!(
@@ -188,6 +194,7 @@ fn contribute_to_brief_more_than_total_ok() {
             get_milestones(10),
             None,
             false,
+            MaxJuryMembers::get(),
         ));
         assert_ok!(BriefsMod::contribute_to_brief(
             RuntimeOrigin::signed(BOB),
@@ -214,6 +221,7 @@ fn create_brief_already_exists() {
             get_milestones(10),
             None,
             false,
+            MaxJuryMembers::get(),
         ));
 
         assert_noop!(
@@ -228,6 +236,7 @@ fn create_brief_already_exists() {
                 get_milestones(10),
                 None,
                 false,
+                MaxJuryMembers::get(),
             ),
             Error::<Test>::BriefAlreadyExists
         );
@@ -251,6 +260,7 @@ fn only_applicant_can_start_work() {
             get_milestones(10),
             None,
             false,
+            MaxJuryMembers::get(),
         ));
 
         assert_noop!(
@@ -282,6 +292,7 @@ fn initial_contribution_and_extra_contribution_aggregates() {
             get_milestones(10),
             None,
             false,
+            MaxJuryMembers::get(),
         ));
 
         assert_ok!(BriefsMod::contribute_to_brief(
@@ -303,6 +314,57 @@ fn initial_contribution_and_extra_contribution_aggregates() {
     });
 }
 
+#[test]
+fn create_brief_invalid_jury_size() {
+    build_test_externality().execute_with(|| {
+        for jury_size in [0, MaxJuryMembers::get() + 1] {
+            assert_noop!(
+                BriefsMod::create_brief(
+                    RuntimeOrigin::signed(BOB),
+                    get_brief_owners(1),
+                    ALICE,
+                    100000,
+                    10000,
+                    gen_hash(1),
+                    CurrencyId::Native,
+                    get_milestones(10),
+                    None,
+                    false,
+                    jury_size,
+                ),
+                Error::<Test>::InvalidJurySize
+            );
+        }
+    });
+}
+
+#[test]
+fn commence_work_selects_the_jury_size_of_the_brief() {
+    build_test_externality().execute_with(|| {
+        let brief_id = gen_hash(100);
+        assert_ok!(BriefsMod::create_brief(
+            RuntimeOrigin::signed(BOB),
+            get_brief_owners(1),
+            ALICE,
+            100000,
+            100000,
+            brief_id,
+            CurrencyId::Native,
+            get_milestones(10),
+            None,
+            false,
+            1,
+        ));
+        assert_ok!(BriefsMod::commence_work(
+            RuntimeOrigin::signed(ALICE),
+            brief_id,
+        ));
+
+        let created_project = Projects::<Test>::get(1).unwrap();
+        assert_eq!(created_project.jury.len(), 1);
+    });
+}
+
 #[test]
 fn reserved_funds_are_transferred_to_project_kitty() {
     build_test_externality().execute_with(|| {
@@ -320,6 +382,7 @@ fn reserved_funds_are_transferred_to_project_kitty() {
             get_milestones(10),
             None,
             false,
+            MaxJuryMembers::get(),
         );
 
         assert_ok!(BriefsMod::commence_work(
@@ -354,6 +417,7 @@ fn cancel_brief_works() {
             get_milestones(10),
             None,
             false,
+            MaxJuryMembers::get(),
         ));
 
         assert_ok!(BriefsMod::contribute_to_brief(
@@ -422,6 +486,7 @@ fn cancel_brief_not_brief_owner() {
             get_milestones(10),
             None,
             false,
+            MaxJuryMembers::get(),
         ));
 
         assert_noop!(
@@ -431,6 +496,120 @@ fn cancel_brief_not_brief_owner() {
     });
 }
 
+#[test]
+fn remaining_bounty_is_budget_minus_contributions() {
+    build_test_externality().execute_with(|| {
+        let brief_id = gen_hash(1);
+        assert_eq!(BriefsMod::get_remaining_bounty(brief_id), 0);
+
+        assert_ok!(BriefsMod::create_brief(
+            RuntimeOrigin::signed(BOB),
+            get_brief_owners(1),
+            ALICE,
+            100000,
+            10000,
+            brief_id,
+            CurrencyId::Native,
+            get_milestones(10),
+            None,
+            false,
+            MaxJuryMembers::get(),
+        ));
+        assert_eq!(BriefsMod::get_remaining_bounty(brief_id), 90000);
+
+        assert_ok!(BriefsMod::contribute_to_brief(
+            RuntimeOrigin::signed(BOB),
+            brief_id,
+            20000,
+        ));
+        assert_eq!(BriefsMod::get_remaining_bounty(brief_id), 70000);
+    });
+}
+
+#[test]
+fn brief_info_decodes_brief() {
+    build_test_externality().execute_with(|| {
+        let brief_id = gen_hash(1);
+        assert!(BriefsMod::brief_info(brief_id).is_none());
+
+        assert_ok!(BriefsMod::create_brief(
+            RuntimeOrigin::signed(BOB),
+            get_brief_owners(1),
+            ALICE,
+            100000,
+            10000,
+            brief_id,
+            CurrencyId::Native,
+            get_milestones(10),
+            None,
+            false,
+            MaxJuryMembers::get(),
+        ));
+
+        let info = BriefsMod::brief_info(brief_id).expect("brief was just created; qed");
+        assert_eq!(info.brief_id, brief_id);
+        assert_eq!(info.applicant, ALICE);
+        assert!(info.brief_owners.contains(&BOB));
+        assert_eq!(info.budget, 100000);
+        assert_eq!(info.remaining_bounty, 90000);
+        assert_eq!(info.milestones.len(), 10);
+        assert!(info
+            .milestones
+            .iter()
+            .all(|ms| ms.percentage_to_unlock == 10));
+
+        let contributions = BriefsMod::brief_contributions_info(brief_id);
+        assert_eq!(contributions.len(), 1);
+        assert_eq!(contributions[0].who, BOB);
+        assert_eq!(contributions[0].value, 10000);
+    });
+}
+
+#[test]
+fn briefs_by_owner_and_applicant() {
+    build_test_externality().execute_with(|| {
+        let brief_1 = gen_hash(1);
+        let brief_2 = gen_hash(2);
+        assert_ok!(BriefsMod::create_brief(
+            RuntimeOrigin::signed(BOB),
+            get_brief_owners(1),
+            ALICE,
+            100000,
+            0,
+            brief_1,
+            CurrencyId::Native,
+            get_milestones(10),
+            None,
+            false,
+            MaxJuryMembers::get(),
+        ));
+        assert_ok!(BriefsMod::create_brief(
+            RuntimeOrigin::signed(CHARLIE),
+            get_brief_owners(1),
+            BOB,
+            100000,
+            0,
+            brief_2,
+            CurrencyId::Native,
+            get_milestones(10),
+            None,
+            false,
+            MaxJuryMembers::get(),
+        ));
+
+        assert_eq!(BriefsMod::briefs_by_owner(&BOB), vec![brief_1]);
+        assert_eq!(BriefsMod::briefs_by_owner(&CHARLIE), vec![brief_2]);
+        assert_eq!(BriefsMod::briefs_by_applicant(&ALICE), vec![brief_1]);
+        assert_eq!(BriefsMod::briefs_by_applicant(&BOB), vec![brief_2]);
+        assert!(BriefsMod::briefs_by_applicant(&CHARLIE).is_empty());
+
+        assert_ok!(BriefsMod::cancel_brief(RuntimeOrigin::signed(BOB), brief_1));
+        assert!(BriefsMod::briefs_by_owner(&BOB).is_empty());
+        assert!(BriefsMod::briefs_by_applicant(&ALICE).is_empty());
+        assert_eq!(BriefsMod::briefs_by_owner(&CHARLIE), vec![brief_2]);
+    });
+}
+
 pub(crate) fn _run_to_block(n: u64) {
     while System::block_number() < n {
         System::set_block_number(System::block_number() + 1);
@@ -461,8 +640,10 @@ pub(crate) fn get_milestones(mut n: u32) -> BoundedProposedMilestones<Test> {
     (0..n)
         .map(|_| ProposedMilestone {
             percentage_to_unlock: Percent::from_percent((100 / n) as u8),
+            content_hash: Default::default(),
+            due_block: None,
         })
-        .collect::<Vec<ProposedMilestone>>()
+        .collect::<Vec<ProposedMilestone<BlockNumber>>>()
         .try_into()
         .expect("qed")
 }
@@ -6,8 +6,10 @@ use crate::Pallet as CrowdFunding;
 use common_types::CurrencyId;
 use frame_benchmarking::v2::*;
 use frame_support::assert_ok;
-use frame_system::{EventRecord, RawOrigin};
+use frame_support::sp_runtime::SaturatedConversion;
+use frame_system::{pallet_prelude::BlockNumberFor, EventRecord, RawOrigin};
 use orml_traits::MultiCurrency;
+use pallet_fellowship::traits::SelectJury;
 use pallet_proposals::ProposedMilestone;
 use sp_arithmetic::per_things::Percent;
 use sp_core::{Get, H256};
@@ -20,19 +22,21 @@ mod benchmarks {
     #[benchmark]
     fn create_crowdfund() {
         let caller: T::AccountId = whitelisted_caller();
+        fund_account::<T>(&caller, 1_000_000_000_000_000_000);
         let milestones = get_max_milestones::<T>();
         let required_funds = u32::MAX;
         let _currency_id = CurrencyId::Native;
         let agg_hash = H256::from([10u8; 32]);
         let crowdfund_key = 0;
-        // (Origin, agg_hash, ProposedMilestones, RequiredFunds, CurrencyId)
+        // (Origin, agg_hash, ProposedMilestones, RequiredFunds, CurrencyId, JurySize)
         #[extrinsic_call]
         create_crowdfund(
-            RawOrigin::Signed(whitelisted_caller()),
+            RawOrigin::Signed(caller.clone()),
             agg_hash,
             milestones,
             required_funds.into(),
             CurrencyId::Native,
+            <T::JurySelector as SelectJury<AccountIdOf<T>>>::JurySize::get(),
         );
         assert_last_event::<T>(
             Event::<T>::CrowdFundCreated(
@@ -130,7 +134,8 @@ mod benchmarks {
     fn approve_crowdfund_for_milestone_submission() {
         let required_funds: u32 = 100_000u32;
         create_crowdfund_common::<T>(required_funds);
-        let alice: T::AccountId = create_funded_user::<T>("candidate", 1, required_funds);
+        let alice: T::AccountId =
+            create_funded_user::<T>("candidate", 1, 1_000_000_000_000_000_000);
         let _ = CrowdFunding::<T>::open_contributions(RawOrigin::Root.into(), 0);
         let _ = CrowdFunding::<T>::contribute(
             RawOrigin::Signed(alice).into(),
@@ -143,26 +148,72 @@ mod benchmarks {
         approve_crowdfund_for_milestone_submission(RawOrigin::Root, 0);
         assert_last_event::<T>(Event::<T>::CrowdFundApproved(0).into());
     }
+    #[benchmark]
+    fn cancel_crowdfund(c: Linear<0, { <T as Config>::MaxContributionsPerCrowdFund::get() }>) {
+        let required_funds: u32 = u32::MAX;
+        let caller = create_crowdfund_common::<T>(required_funds);
+        let _ = CrowdFunding::<T>::open_contributions(RawOrigin::Root.into(), 0);
+        contribute_many::<T>(c);
+
+        //(Origin, CrowdFundKey)
+        #[extrinsic_call]
+        cancel_crowdfund(RawOrigin::Signed(caller), 0);
+        assert_last_event::<T>(Event::<T>::CrowdFundCancelled(0).into());
+    }
+
+    // The last contribution round failing is the worst case, the crowdfund is cancelled as well.
+    #[benchmark]
+    fn close_contribution_round(
+        c: Linear<0, { <T as Config>::MaxContributionsPerCrowdFund::get() }>,
+    ) {
+        let required_funds: u32 = u32::MAX;
+        create_crowdfund_common::<T>(required_funds);
+        let _ = CrowdFunding::<T>::open_contributions(RawOrigin::Root.into(), 0);
+        contribute_many::<T>(c);
+        CrowdFunds::<T>::mutate(0, |maybe_crowdfund| {
+            if let Some(crowdfund) = maybe_crowdfund {
+                crowdfund.contribution_rounds = <T as Config>::MaxContributionRounds::get();
+            }
+        });
+
+        #[block]
+        {
+            CrowdFunding::<T>::close_contribution_round(0);
+        }
+        assert_last_event::<T>(Event::<T>::CrowdFundCancelled(0).into());
+    }
+
     impl_benchmark_test_suite!(CrowdFunding, crate::mock::new_test_ext(), crate::mock::Test);
 }
 
-fn create_funded_user<T: Config>(
-    string: &'static str,
-    n: u32,
-    balance_factor: u32,
-) -> T::AccountId {
+fn create_funded_user<T: Config>(string: &'static str, n: u32, balance: u128) -> T::AccountId {
     let user = account(string, n, 99);
-    let balance: BalanceOf<T> = balance_factor.into();
-    let _ = <T::MultiCurrency as MultiCurrency<BlockNumberFor<T>>>::deposit(
+    fund_account::<T>(&user, balance);
+    user
+}
+
+fn fund_account<T: Config>(who: &T::AccountId, balance: u128) {
+    let _ = <T::MultiCurrency as MultiCurrency<AccountIdOf<T>>>::deposit(
         CurrencyId::Native,
-        &user,
-        balance,
+        who,
+        balance.saturated_into(),
     );
-    user
+}
+
+fn contribute_many<T: Config>(contributions: u32) {
+    for i in 0..contributions {
+        let contributor: T::AccountId =
+            create_funded_user::<T>("contributor", i, 1_000_000_000_000_000_000);
+        assert_ok!(CrowdFunding::<T>::contribute(
+            RawOrigin::Signed(contributor).into(),
+            0u32,
+            1_000u32.into(),
+        ));
+    }
 }
 
 fn create_crowdfund_common<T: Config>(required_funds: u32) -> T::AccountId {
-    let bob: T::AccountId = create_funded_user::<T>("initiator", 1, 100_000_000);
+    let bob: T::AccountId = create_funded_user::<T>("initiator", 1, 1_000_000_000_000_000_000);
     let milestones = get_max_milestones::<T>();
 
     let agg_hash = H256::from([20; 32]);
@@ -173,7 +224,8 @@ fn create_crowdfund_common<T: Config>(required_funds: u32) -> T::AccountId {
         agg_hash,
         milestones,
         required_funds.into(),
-        currency_id
+        currency_id,
+        <T::JurySelector as SelectJury<AccountIdOf<T>>>::JurySize::get(),
     ));
     bob
 }
@@ -187,8 +239,10 @@ fn get_milestones<T: Config>(mut n: u32) -> BoundedProposedMilestones<T> {
     (0..n)
         .map(|_| ProposedMilestone {
             percentage_to_unlock: Percent::from_percent((100 / n) as u8),
+            content_hash: Default::default(),
+            due_block: None,
         })
-        .collect::<Vec<ProposedMilestone>>()
+        .collect::<Vec<ProposedMilestone<BlockNumberFor<T>>>>()
         .try_into()
         .expect("qed")
 }
@@ -1,7 +1,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 pub use pallet::*;
 
-mod weights;
+pub mod weights;
 
 #[cfg(test)]
 mod mock;
@@ -15,17 +15,19 @@ mod benchmarking;
 #[frame_support::pallet]
 pub mod pallet {
     use crate::weights::WeightInfo;
-    use common_types::{CurrencyId};
-    use frame_support::sp_runtime::Saturating;
+    use common_types::CurrencyId;
+    use frame_support::sp_runtime::{traits::Zero, Saturating};
     use frame_support::{pallet_prelude::*, transactional, BoundedBTreeMap};
     use frame_system::pallet_prelude::*;
     use orml_traits::{MultiCurrency, MultiReservableCurrency};
+    use pallet_deposits::traits::DepositHandler;
+    use pallet_fellowship::traits::SelectJury;
     use pallet_identity::Judgement;
     use pallet_proposals::traits::IntoProposal;
-    use pallet_proposals::{Contribution, Milestone, ProposedMilestone};
+    use pallet_proposals::{Contribution, FundingPath, Milestone, ProposedMilestone};
     use sp_arithmetic::per_things::Percent;
     use sp_core::H256;
-    use sp_std::collections::btree_map::BTreeMap;
+    use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
 
     pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
     pub type BalanceOf<T> =
@@ -39,12 +41,22 @@ pub mod pallet {
     >;
     pub type BoundedMilestoneKeys<T> =
         BoundedVec<MilestoneKey, <T as Config>::MaxMilestonesPerCrowdFund>;
-    pub type BoundedMilestones<T> =
-        BoundedBTreeMap<MilestoneKey, Milestone<BlockNumberFor<T>>, <T as Config>::MaxMilestonesPerCrowdFund>;
+    pub type BoundedMilestones<T> = BoundedBTreeMap<
+        MilestoneKey,
+        Milestone<BlockNumberFor<T>>,
+        <T as Config>::MaxMilestonesPerCrowdFund,
+    >;
     pub type BoundedWhitelistSpots<T> =
         BoundedBTreeMap<AccountIdOf<T>, BalanceOf<T>, <T as Config>::MaxWhitelistPerCrowdFund>;
     pub type BoundedProposedMilestones<T> =
-        BoundedVec<ProposedMilestone, <T as Config>::MaxMilestonesPerCrowdFund>;
+        BoundedVec<ProposedMilestone<BlockNumberFor<T>>, <T as Config>::MaxMilestonesPerCrowdFund>;
+
+    type StorageItemOf<T> = <<T as Config>::DepositHandler as DepositHandler<
+        BalanceOf<T>,
+        AccountIdOf<T>,
+    >>::StorageItem;
+    pub type DepositIdOf<T> =
+        <<T as Config>::DepositHandler as DepositHandler<BalanceOf<T>, AccountIdOf<T>>>::DepositId;
 
     pub type CrowdFundKey = u32;
     pub type MilestoneKey = u32;
@@ -62,6 +74,9 @@ pub mod pallet {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
         type MultiCurrency: MultiReservableCurrency<AccountIdOf<Self>, CurrencyId = CurrencyId>;
         type RoundExpiry: Get<BlockNumberFor<Self>>;
+        /// The maximum number of contribution rounds a crowdfund can hold.
+        /// Once the last round fails the crowdfund is cancelled.
+        type MaxContributionRounds: Get<u32>;
         type MaxKeysPerRound: Get<u32>;
         type MaxContributionsPerCrowdFund: Get<u32>;
         type MaxMilestonesPerCrowdFund: Get<u32>;
@@ -69,6 +84,12 @@ pub mod pallet {
         type IsIdentityRequired: Get<bool>;
         type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
         type IntoProposals: IntoProposal<AccountIdOf<Self>, BalanceOf<Self>, BlockNumberFor<Self>>;
+        /// Storage deposits.
+        type CrowdFundStorageItem: Get<StorageItemOf<Self>>;
+        /// Handler for deposits.
+        type DepositHandler: DepositHandler<BalanceOf<Self>, AccountIdOf<Self>>;
+        /// The type that selects a list of jury members.
+        type JurySelector: SelectJury<AccountIdOf<Self>>;
         type WeightInfo: WeightInfo;
     }
 
@@ -130,6 +151,15 @@ pub mod pallet {
         ContributeSucceeded(T::AccountId, CrowdFundKey, BalanceOf<T>),
         /// A crowdfund has been approved.
         CrowdFundApproved(CrowdFundKey),
+        /// A crowdfund has been cancelled.
+        CrowdFundCancelled(CrowdFundKey),
+        /// A contribution round ended with the required funds raised.
+        ContributionRoundSucceeded(CrowdFundKey),
+        /// A contribution round ended without the required funds, the contributions have been refunded.
+        /// Contains the amount that was actually unreserved.
+        ContributionRoundFailed(CrowdFundKey, BalanceOf<T>),
+        /// The storage deposit of a crowdfund cancelled after its last contribution round could not be returned.
+        DepositReturnFailed(CrowdFundKey, DispatchError),
     }
 
     #[pallet::error]
@@ -172,10 +202,19 @@ pub mod pallet {
         CrowdFundCancelled,
         /// The conversion to a Project has failed.
         CrowdFundConversionFailedGeneric,
+        /// The crowdfund has held the maximum number of contribution rounds.
+        MaxContributionRoundsReached,
+        /// There are more milestones than a project can hold.
+        TooManyMilestones,
+        /// The selected jury is larger than a project allows.
+        TooManyJuryMembers,
+        /// The jury size must be at least one and at most the JurySize of the JurySelector.
+        InvalidJurySize,
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
+        /// Create a crowdfund, the jury_size is the number of jurors selected once it is approved.
         #[pallet::call_index(0)]
         #[pallet::weight(<T as Config>::WeightInfo::create_crowdfund())]
         pub fn create_crowdfund(
@@ -184,13 +223,15 @@ pub mod pallet {
             proposed_milestones: BoundedProposedMilestones<T>,
             required_funds: BalanceOf<T>,
             currency_id: common_types::CurrencyId,
+            jury_size: u32,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
-            let total_percentage = proposed_milestones
-                .iter()
-                .fold(Percent::zero(), |acc: Percent, ms: &ProposedMilestone| {
+            let total_percentage = proposed_milestones.iter().fold(
+                Percent::zero(),
+                |acc: Percent, ms: &ProposedMilestone<BlockNumberFor<T>>| {
                     acc.saturating_add(ms.percentage_to_unlock)
-                });
+                },
+            );
             ensure!(
                 total_percentage.is_one(),
                 Error::<T>::MilestonesTotalPercentageMustEqual100
@@ -201,6 +242,7 @@ pub mod pallet {
                 proposed_milestones,
                 required_funds,
                 currency_id,
+                jury_size,
             )?;
             Ok(().into())
         }
@@ -300,15 +342,21 @@ pub mod pallet {
             //length: BlockNumberFor<T>,
         ) -> DispatchResultWithPostInfo {
             <T as Config>::AuthorityOrigin::ensure_origin(origin)?;
-            ensure!(
-                CrowdFunds::<T>::contains_key(crowdfund_key),
-                Error::<T>::CrowdFundDoesNotExist
-            );
+            let crowdfund =
+                CrowdFunds::<T>::get(crowdfund_key).ok_or(Error::<T>::CrowdFundDoesNotExist)?;
             ensure!(
                 !CrowdFundsInRound::<T>::contains_key(crowdfund_key, RoundType::ContributionRound),
                 Error::<T>::AlreadyInContributionRound
             );
-            //todo: ensure it hasnt already had a contribution round?
+            ensure!(
+                !crowdfund.is_converted,
+                Error::<T>::CrowdFundAlreadyConverted
+            );
+            ensure!(!crowdfund.cancelled, Error::<T>::CrowdFundCancelled);
+            ensure!(
+                crowdfund.contribution_rounds < <T as Config>::MaxContributionRounds::get(),
+                Error::<T>::MaxContributionRoundsReached
+            );
             let _ = Self::start_contribution_round(crowdfund_key)?;
             Self::deposit_event(Event::FundingRoundCreated(crowdfund_key));
 
@@ -335,7 +383,11 @@ pub mod pallet {
         /// Approve crowdfund
         /// If the crowdfund is approved, the crowdfund is converted into a type that is able to submit milestones.
         #[pallet::call_index(7)]
-        #[pallet::weight(<T as Config>::WeightInfo::approve_crowdfund_for_milestone_submission())]
+        #[pallet::weight(<T as Config>::WeightInfo::approve_crowdfund_for_milestone_submission().saturating_add(
+            <T::JurySelector as SelectJury<AccountIdOf<T>>>::select_jury_weight(
+                T::MaxContributionsPerCrowdFund::get().saturating_add(1)
+            )
+        ))]
         pub fn approve_crowdfund_for_milestone_submission(
             origin: OriginFor<T>,
             crowdfund_key: CrowdFundKey,
@@ -345,23 +397,38 @@ pub mod pallet {
             Ok(().into())
         }
 
-        // What happens if the threshold is not met and the the contribution round is over?
-        // TODO: New extrinsic to cancel a project and unreserve all assets.
-        // Also allow for a project to undergo another contribution round.
-        // Like in briefs
+        /// Cancel a crowdfund, callable by the initiator or the authority.
+        /// All contributions are unreserved and the storage deposit is returned.
+        #[pallet::call_index(8)]
+        #[pallet::weight(<T as Config>::WeightInfo::cancel_crowdfund(
+            <T as Config>::MaxContributionsPerCrowdFund::get()
+        ))]
+        pub fn cancel_crowdfund(
+            origin: OriginFor<T>,
+            crowdfund_key: CrowdFundKey,
+        ) -> DispatchResultWithPostInfo {
+            if <T as Config>::AuthorityOrigin::ensure_origin(origin.clone()).is_err() {
+                let who = ensure_signed(origin)?;
+                Self::ensure_initiator(who, crowdfund_key)?;
+            }
+            let contributions = Self::do_cancel(crowdfund_key)?;
+            Ok(Some(<T as Config>::WeightInfo::cancel_crowdfund(contributions)).into())
+        }
     }
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
         fn on_initialize(n: BlockNumberFor<T>) -> Weight {
-            let weight: Weight = Default::default();
+            let mut weight: Weight = T::DbWeight::get().reads_writes(1, 1);
             let crowdfund_keys: BoundedKeysPerRound<T> = RoundsExpiring::<T>::take(n);
-            weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
 
-            // SAFETY: BoundedKeysPerRound must be sane as to not have overweight blocks.
+            // SAFETY: BoundedKeysPerRound must be sane as to not have overweight blocks,
+            // each key can refund up to MaxContributionsPerCrowdFund contributions.
             crowdfund_keys.iter().for_each(|key| {
                 CrowdFundsInRound::<T>::remove(key, RoundType::ContributionRound);
-                weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+                weight = weight
+                    .saturating_add(T::DbWeight::get().writes(1))
+                    .saturating_add(Self::close_contribution_round(*key));
             });
 
             weight
@@ -375,15 +442,27 @@ pub mod pallet {
             proposed_milestones: BoundedProposedMilestones<T>,
             required_funds: BalanceOf<T>,
             currency_id: common_types::CurrencyId,
+            jury_size: u32,
         ) -> Result<CrowdFundKey, DispatchError> {
             // Check if identity is required
             if <T as Config>::IsIdentityRequired::get() {
                 Self::ensure_identity_is_decent(&who)?;
             }
+            ensure!(
+                jury_size > 0
+                    && jury_size
+                        <= <T::JurySelector as SelectJury<AccountIdOf<T>>>::JurySize::get(),
+                Error::<T>::InvalidJurySize
+            );
 
             let crowdfund_key = CrowdFundCount::<T>::get();
 
-            // Todo: Take storage deposit>
+            // Take storage deposit
+            let deposit_id = <T as Config>::DepositHandler::take_deposit(
+                who.clone(),
+                <T as Config>::CrowdFundStorageItem::get(),
+                CurrencyId::Native,
+            )?;
 
             // For now we keep them as proposed milestones until the project is able to submit.
             let crowdfund = CrowdFund {
@@ -400,6 +479,9 @@ pub mod pallet {
                 approved_for_funding: false,
                 cancelled: false,
                 is_converted: false,
+                deposit_id,
+                contribution_rounds: 0,
+                jury_size,
             };
 
             // Add crowdfund to list
@@ -428,11 +510,12 @@ pub mod pallet {
             agreement_hash: Option<H256>,
         ) -> DispatchResult {
             if let Some(ms) = proposed_milestones {
-                let total_percentage =
-                    ms.iter()
-                        .fold(Percent::zero(), |acc: Percent, ms: &ProposedMilestone| {
-                            acc.saturating_add(ms.percentage_to_unlock)
-                        });
+                let total_percentage = ms.iter().fold(
+                    Percent::zero(),
+                    |acc: Percent, ms: &ProposedMilestone<BlockNumberFor<T>>| {
+                        acc.saturating_add(ms.percentage_to_unlock)
+                    },
+                );
                 ensure!(
                     total_percentage.is_one(),
                     Error::<T>::MilestonesTotalPercentageMustEqual100
@@ -468,7 +551,8 @@ pub mod pallet {
             );
             CrowdFunds::<T>::try_mutate(crowdfund_key, |crowdfund| -> DispatchResult {
                 if let Some(p) = crowdfund {
-                    p.approved_for_funding = true
+                    p.approved_for_funding = true;
+                    p.contribution_rounds = p.contribution_rounds.saturating_add(1);
                 }
                 Ok(())
             })?;
@@ -539,18 +623,56 @@ pub mod pallet {
         pub fn do_approve(crowdfund_key: CrowdFundKey) -> DispatchResultWithPostInfo {
             let crowdfund =
                 CrowdFunds::<T>::get(crowdfund_key).ok_or(Error::<T>::CrowdFundDoesNotExist)?;
+            ensure!(
+                !crowdfund.is_converted,
+                Error::<T>::CrowdFundAlreadyConverted
+            );
+            ensure!(!crowdfund.cancelled, Error::<T>::CrowdFundCancelled);
             ensure!(
                 crowdfund.raised_funds >= crowdfund.required_funds,
                 Error::<T>::RequiredFundsNotReached
             );
 
+            <T as Config>::DepositHandler::return_deposit(crowdfund.deposit_id)?;
+
+            // The initiator and contributors cannot sit on the jury.
+            let mut excluded = crowdfund
+                .contributions
+                .keys()
+                .cloned()
+                .collect::<Vec<AccountIdOf<T>>>();
+            excluded.push(crowdfund.initiator.clone());
+            let jury = <T::JurySelector as SelectJury<AccountIdOf<T>>>::select_jury_excluding(
+                crowdfund.jury_size,
+                &excluded,
+            );
+
+            let contributions = crowdfund
+                .contributions
+                .into_inner()
+                .try_into()
+                .map_err(|_| Error::<T>::TooManyContributions)?;
+            let refund_locations =
+                <T as Config>::IntoProposals::convert_contributions_to_refund_locations(
+                    &contributions,
+                );
+
             <T as Config>::IntoProposals::convert_to_proposal(
                 crowdfund.currency_id,
-                crowdfund.contributions.into_inner(),
+                contributions,
                 crowdfund.agreement_hash,
                 crowdfund.initiator,
-                crowdfund.milestones.into_inner(),
-                FundingType::Proposal,
+                crowdfund
+                    .milestones
+                    .to_vec()
+                    .try_into()
+                    .map_err(|_| Error::<T>::TooManyMilestones)?,
+                refund_locations,
+                jury.to_vec()
+                    .try_into()
+                    .map_err(|_| Error::<T>::TooManyJuryMembers)?,
+                FundingPath::TakeFromReserved,
+                None,
             )
             .map_err(|_| Error::<T>::CrowdFundConversionFailedGeneric)?;
 
@@ -564,6 +686,95 @@ pub mod pallet {
             Ok(().into())
         }
 
+        /// Called when a contribution round expires.
+        /// If the required funds were not raised the contributions are refunded and,
+        /// when no more rounds are allowed, the crowdfund is cancelled.
+        pub(crate) fn close_contribution_round(crowdfund_key: CrowdFundKey) -> Weight {
+            let weight = T::DbWeight::get().reads(1);
+            let mut crowdfund = match CrowdFunds::<T>::get(crowdfund_key) {
+                Some(cf) => cf,
+                None => return weight,
+            };
+            if crowdfund.is_converted || crowdfund.cancelled {
+                return weight;
+            }
+            if crowdfund.raised_funds >= crowdfund.required_funds {
+                Self::deposit_event(Event::ContributionRoundSucceeded(crowdfund_key));
+                return weight;
+            }
+
+            let contributors = crowdfund.contributions.len() as u32;
+            let refunded = Self::refund_contributions(&mut crowdfund);
+            crowdfund.approved_for_funding = false;
+            Self::deposit_event(Event::ContributionRoundFailed(crowdfund_key, refunded));
+
+            if crowdfund.contribution_rounds >= <T as Config>::MaxContributionRounds::get() {
+                // The hook cannot fail, so a deposit that cannot be returned is reported instead.
+                if let Err(error) =
+                    <T as Config>::DepositHandler::return_deposit(crowdfund.deposit_id)
+                {
+                    Self::deposit_event(Event::DepositReturnFailed(crowdfund_key, error));
+                }
+                crowdfund.cancelled = true;
+                Self::deposit_event(Event::CrowdFundCancelled(crowdfund_key));
+            }
+            CrowdFunds::<T>::insert(crowdfund_key, crowdfund);
+            <T as Config>::WeightInfo::close_contribution_round(contributors)
+        }
+
+        /// Returns the number of contributions that were refunded.
+        pub fn do_cancel(crowdfund_key: CrowdFundKey) -> Result<u32, DispatchError> {
+            let mut crowdfund =
+                CrowdFunds::<T>::get(crowdfund_key).ok_or(Error::<T>::CrowdFundDoesNotExist)?;
+            ensure!(
+                !crowdfund.is_converted,
+                Error::<T>::CrowdFundAlreadyConverted
+            );
+            ensure!(!crowdfund.cancelled, Error::<T>::CrowdFundCancelled);
+
+            if CrowdFundsInRound::<T>::contains_key(crowdfund_key, RoundType::ContributionRound) {
+                let expiry_block =
+                    CrowdFundsInRound::<T>::take(crowdfund_key, RoundType::ContributionRound);
+                RoundsExpiring::<T>::mutate(expiry_block, |keys| {
+                    keys.retain(|key| *key != crowdfund_key)
+                });
+            }
+
+            <T as Config>::DepositHandler::return_deposit(crowdfund.deposit_id)?;
+            let contributions = crowdfund.contributions.len() as u32;
+            let _ = Self::refund_contributions(&mut crowdfund);
+            crowdfund.approved_for_funding = false;
+            crowdfund.cancelled = true;
+            CrowdFunds::<T>::insert(crowdfund_key, crowdfund);
+
+            Self::deposit_event(Event::CrowdFundCancelled(crowdfund_key));
+            Ok(contributions)
+        }
+
+        /// Unreserve every contribution of a crowdfund and reset the raised funds.
+        /// Returns the total amount actually unreserved, which falls short of the contributions
+        /// if a contributor no longer has all of their contribution reserved.
+        fn refund_contributions(crowdfund: &mut CrowdFund<T>) -> BalanceOf<T> {
+            let mut refunded: BalanceOf<T> = Zero::zero();
+            crowdfund
+                .contributions
+                .iter()
+                .for_each(|(who, contribution)| {
+                    let remaining = <T as Config>::MultiCurrency::unreserve(
+                        crowdfund.currency_id,
+                        who,
+                        contribution.value,
+                    );
+                    refunded =
+                        refunded.saturating_add(contribution.value.saturating_sub(remaining));
+                });
+            crowdfund.contributions = BTreeMap::new()
+                .try_into()
+                .expect("empty BTree is smaller than bound; qed");
+            crowdfund.raised_funds = Zero::zero();
+            refunded
+        }
+
         pub fn ensure_initiator(
             who: T::AccountId,
             crowdfund_key: CrowdFundKey,
@@ -605,6 +816,10 @@ pub mod pallet {
         pub initiator: AccountIdOf<T>,
         pub created_on: BlockNumberFor<T>,
         pub is_converted: bool,
+        pub deposit_id: DepositIdOf<T>,
+        pub contribution_rounds: u32,
+        /// The number of jurors selected for the project once the crowdfund is approved.
+        pub jury_size: u32,
     }
 
     // Called to ensure that an account is is a contributor to a crowdfund.
@@ -59,18 +59,21 @@ impl frame_system::Config for Test {
 
 parameter_types! {
     pub RoundExpiry: BlockNumber = 100;
+    pub MaxContributionRounds: u32 = 2;
     pub MaxKeysPerRound: u32 = 50;
-    pub MaxContributionsPerCrowdFund: u32 = 1000;
-    pub MaxMilestonesPerCrowdFund: u32 = 50;
+    pub MaxContributionsPerCrowdFund: u32 = 50;
+    pub MaxMilestonesPerCrowdFund: u32 = 10;
     pub MaxWhitelistPerCrowdFund: u32 = 50;
     pub MinimumRequiredFunds: Balance = 2000;
     pub MinimumContribution: Balance = 5;
+    pub CrowdFundStorageItem: StorageItem = StorageItem::CrowdFund;
 }
 
 impl pallet_crowdfunding::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type MultiCurrency = Tokens;
     type RoundExpiry = RoundExpiry;
+    type MaxContributionRounds = MaxContributionRounds;
     type MaxContributionsPerCrowdFund = MaxContributionsPerCrowdFund;
     type MaxKeysPerRound = MaxKeysPerRound;
     type MaxMilestonesPerCrowdFund = MaxMilestonesPerCrowdFund;
@@ -78,6 +81,9 @@ impl pallet_crowdfunding::Config for Test {
     type IsIdentityRequired = IsIdentityRequired;
     type AuthorityOrigin = EnsureRoot<AccountId>;
     type IntoProposals = pallet_proposals::Pallet<Test>;
+    type CrowdFundStorageItem = CrowdFundStorageItem;
+    type DepositHandler = MockDepositHandler;
+    type JurySelector = MockJurySelector;
     type WeightInfo = ();
 }
 
@@ -166,14 +172,25 @@ parameter_types! {
     pub RefundsPerBlock: u8 = 2;
     pub IsIdentityRequired: bool = false;
     pub MilestoneVotingWindow: BlockNumber  =  100800u64;
+    pub NoConfidenceVotingWindow: BlockNumber = 100800u64;
+    pub PercentRequiredForVoteNoConfidenceToPass: Percent = Percent::from_percent(75u8);
     pub MaxMilestonesPerProject: u32 = 10;
     pub ProjectStorageDeposit: Balance = 100;
     pub ImbueFee: Percent = Percent::from_percent(5u8);
     pub ExpiringProjectRoundsPerBlock: u32 = 10;
     pub ProjectStorageItem: StorageItem = StorageItem::Project;
+    pub DisputeStorageItem: StorageItem = StorageItem::Dispute;
     pub MaxProjectsPerAccount: u16 = 100;
     pub FeeAccount: AccountId = TREASURY;
     pub MaxJuryMembers: u32 = 100;
+    pub MaxDisputesPerJuror: u32 = 50;
+    pub MaxJuriesPerAccount: u32 = 50;
+    pub MinimumJurySize: u32 = 2;
+    pub ArbitrationWindow: BlockNumber = 100;
+    pub DefaultResubmissionPolicy: pallet_proposals::ResubmissionPolicy<BlockNumber> =
+        pallet_proposals::ResubmissionPolicy { cooldown: 0, max_attempts: 3 };
+    pub DefaultVotingExpiryPolicy: pallet_proposals::VotingExpiryPolicy =
+        pallet_proposals::VotingExpiryPolicy::Reject;
 }
 
 impl pallet_proposals::Config for Test {
@@ -184,6 +201,8 @@ impl pallet_proposals::Config for Test {
     type PercentRequiredForVoteToPass = PercentRequiredForVoteToPass;
     type MaximumContributorsPerProject = MaximumContributorsPerProject;
     type MilestoneVotingWindow = MilestoneVotingWindow;
+    type NoConfidenceVotingWindow = NoConfidenceVotingWindow;
+    type PercentRequiredForVoteNoConfidenceToPass = PercentRequiredForVoteNoConfidenceToPass;
     type ExternalRefundHandler = pallet_proposals::traits::MockRefundHandler<Test>;
     type MaxMilestonesPerProject = MaxMilestonesPerProject;
     type ImbueFee = ImbueFee;
@@ -191,9 +210,18 @@ impl pallet_proposals::Config for Test {
     type ExpiringProjectRoundsPerBlock = ExpiringProjectRoundsPerBlock;
     type DepositHandler = MockDepositHandler;
     type ProjectStorageItem = ProjectStorageItem;
+    type DisputeStorageItem = DisputeStorageItem;
     type MaxProjectsPerAccount = MaxProjectsPerAccount;
     type DisputeRaiser = MockDisputeRaiser;
     type JurySelector = MockJurySelector;
+    type MaxJuriesPerAccount = MaxJuriesPerAccount;
+    type AssetSignerOrigin = EnsureRoot<AccountId>;
+    type MinimumJurySize = MinimumJurySize;
+    type ArbitrationOrigin = EnsureRoot<AccountId>;
+    type ArbitrationWindow = ArbitrationWindow;
+    type DefaultResubmissionPolicy = DefaultResubmissionPolicy;
+    type DefaultVotingExpiryPolicy = DefaultVotingExpiryPolicy;
+    type ProjectPolicyOrigin = EnsureRoot<AccountId>;
 }
 
 #[derive(Encode, Decode, PartialEq, Eq, Clone, Debug, MaxEncodedLen, TypeInfo, Copy)]
@@ -202,6 +230,11 @@ pub enum StorageItem {
     Brief,
     Grant,
     Project,
+    Dispute,
+}
+
+parameter_types! {
+    pub static ReturnDepositFails: bool = false;
 }
 
 pub struct MockDepositHandler;
@@ -216,6 +249,9 @@ impl DepositHandler<Balance, AccountId> for MockDepositHandler {
         Ok(0u64)
     }
     fn return_deposit(_deposit_id: Self::DepositId) -> DispatchResult {
+        if ReturnDepositFails::get() {
+            return Err(DispatchError::Other("deposit not returned"));
+        }
         Ok(())
     }
     fn slash_reserve_deposit(_deposit_id: Self::DepositId) -> DispatchResult {
@@ -223,16 +259,12 @@ impl DepositHandler<Balance, AccountId> for MockDepositHandler {
     }
 }
 
-<<<<<<< HEAD
-pub static ALICE: Lazy<Public> = Lazy::new(|| Public::from_raw([125u8; 32]));
-pub static BOB: Lazy<Public> = Lazy::new(|| Public::from_raw([126u8; 32]));
-pub static CHARLIE: Lazy<Public> = Lazy::new(|| Public::from_raw([127u8; 32]));
-pub static TREASURY: Lazy<Public> = Lazy::new(|| Public::from_raw([127u8; 32]));
-=======
 pub static ALICE: AccountId = 125;
 pub static BOB: AccountId = 126;
 pub static CHARLIE: AccountId = 127;
->>>>>>> 691fff9d503ce4e6ba20c8cc81f451649c04c07e
+pub static TREASURY: AccountId = 200;
+pub static JURY_1: AccountId = 1000;
+pub static JURY_2: AccountId = 1001;
 
 pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
     let t = frame_system::GenesisConfig::<Test>::default()
@@ -252,25 +284,42 @@ pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
 
 pub struct MockDisputeRaiser;
 impl pallet_disputes::traits::DisputeRaiser<AccountId> for MockDisputeRaiser {
-type DisputeKey = u32;
-type SpecificId = u32;
-type MaxJurySize = MaxJuryMembers;
-type MaxSpecifics = MaxMilestonesPerProject;
+    type DisputeKey = u32;
+    type SpecificId = u32;
+    type MaxJurySize = MaxJuryMembers;
+    type MaxDisputesPerJuror = MaxDisputesPerJuror;
+    type MaxSpecifics = MaxMilestonesPerProject;
+    type BondId = u64;
     fn raise_dispute(
-        dispute_key: Self::DisputeKey,
-        raised_by: AccountId,
-        jury: BoundedVec<AccountId, Self::MaxJurySize>,
-        specific_ids: BoundedVec<Self::SpecificId, Self::MaxSpecifics>,
+        _dispute_key: Self::DisputeKey,
+        _raised_by: AccountId,
+        _jury: BoundedVec<AccountId, Self::MaxJurySize>,
+        _specific_ids: BoundedVec<Self::SpecificId, Self::MaxSpecifics>,
+        _bond: Option<Self::BondId>,
+    ) -> Result<(), DispatchError> {
+        Ok(())
+    }
+    fn disputes_of_juror(_juror: &AccountId) -> Vec<Self::DisputeKey> {
+        Vec::new()
+    }
+    fn replace_juror(
+        _dispute_key: Self::DisputeKey,
+        _juror: &AccountId,
+        _replacement: Option<AccountId>,
     ) -> Result<(), DispatchError> {
         Ok(())
     }
 }
 
-
 pub struct MockJurySelector;
 impl pallet_fellowship::traits::SelectJury<AccountId> for MockJurySelector {
     type JurySize = MaxJuryMembers;
-    fn select_jury() -> BoundedVec<AccountId, Self::JurySize> {
-        BoundedVec::new()
+    fn select_jury(amount: u32) -> BoundedVec<AccountId, Self::JurySize> {
+        BoundedVec::truncate_from(
+            vec![JURY_1, JURY_2]
+                .into_iter()
+                .take(amount as usize)
+                .collect(),
+        )
     }
 }
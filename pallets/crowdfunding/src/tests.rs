@@ -1,15 +1,15 @@
 use crate::pallet::BoundedProposedMilestones;
 use crate::{
-    mock::*, Config, CrowdFundCount, CrowdFundKey, CrowdFunds, CrowdFundsInRound, Error, RoundType,
-    RoundsExpiring,
+    mock::*, Config, CrowdFundCount, CrowdFundKey, CrowdFunds, CrowdFundsInRound, Error, Event,
+    RoundType, RoundsExpiring,
 };
 use common_types::CurrencyId;
 use frame_support::{assert_noop, assert_ok, traits::Hooks};
 use orml_traits::MultiReservableCurrency;
-use pallet_proposals::ProposedMilestone;
+use pallet_proposals::{Locality, ProjectCount, Projects, ProposedMilestone};
 use sp_arithmetic::per_things::Percent;
 use sp_core::H256;
-use sp_runtime::DispatchError::BadOrigin;
+use sp_runtime::DispatchError::{self, BadOrigin};
 use test_utils::*;
 
 pub(crate) mod test_utils {
@@ -23,8 +23,10 @@ pub(crate) mod test_utils {
         (0..n)
             .map(|_| ProposedMilestone {
                 percentage_to_unlock: Percent::from_percent((100 / n) as u8),
+                content_hash: Default::default(),
+                due_block: None,
             })
-            .collect::<Vec<ProposedMilestone>>()
+            .collect::<Vec<ProposedMilestone<BlockNumber>>>()
             .try_into()
             .expect("bound is ensured; qed")
     }
@@ -40,7 +42,8 @@ pub(crate) mod test_utils {
             get_hash(2u8),
             get_milestones(10),
             amount,
-            CurrencyId::Native
+            CurrencyId::Native,
+            MaxJuryMembers::get(),
         ));
         key
     }
@@ -66,6 +69,13 @@ pub(crate) mod test_utils {
         cf_key
     }
 
+    pub fn reserved_balance(who: AccountId) -> Balance {
+        <<Test as Config>::MultiCurrency as MultiReservableCurrency<AccountId>>::reserved_balance(
+            CurrencyId::Native,
+            &who,
+        )
+    }
+
     pub fn run_to_block(n: BlockNumber) {
         while System::block_number() < n {
             System::set_block_number(System::block_number() + 1);
@@ -93,6 +103,8 @@ fn milestones_must_sum_to_100_on_creation() {
     new_test_ext().execute_with(|| {
         let milestones_under_100: BoundedProposedMilestones<Test> = vec![ProposedMilestone {
             percentage_to_unlock: Percent::from_percent(50u8),
+            content_hash: Default::default(),
+            due_block: None,
         }]
         .try_into()
         .expect("qed");
@@ -102,7 +114,8 @@ fn milestones_must_sum_to_100_on_creation() {
                 get_hash(1u8),
                 milestones_under_100,
                 100_000,
-                CurrencyId::Native
+                CurrencyId::Native,
+                MaxJuryMembers::get(),
             ),
             Error::<Test>::MilestonesTotalPercentageMustEqual100
         );
@@ -115,6 +128,8 @@ fn update_crowdfund_ms_must_sum_to_100() {
         create_cf_default(ALICE, 100_000u64);
         let milestones_under_100: BoundedProposedMilestones<Test> = vec![ProposedMilestone {
             percentage_to_unlock: Percent::from_percent(50u8),
+            content_hash: Default::default(),
+            due_block: None,
         }]
         .try_into()
         .expect("qed");
@@ -175,7 +190,22 @@ fn update_crowdfund_already_approved() {
 #[test]
 fn update_crowdfund_already_cancelled() {
     new_test_ext().execute_with(|| {
-        // todo: cancel extrinsic
+        let key = create_cf_default(ALICE, 100_000);
+        assert_ok!(CrowdFunding::cancel_crowdfund(
+            RuntimeOrigin::signed(ALICE),
+            key
+        ));
+        assert_noop!(
+            CrowdFunding::update_crowdfund(
+                RuntimeOrigin::signed(ALICE),
+                key,
+                None,
+                None,
+                None,
+                None,
+            ),
+            Error::<Test>::CrowdFundCancelled
+        );
     });
 }
 
@@ -223,7 +253,7 @@ fn update_crowdfund_actually_mutates() {
         assert_ok!(CrowdFunding::update_crowdfund(
             RuntimeOrigin::signed(ALICE),
             key,
-            Some(get_milestones(20)),
+            Some(get_milestones(5)),
             Some(50_000),
             Some(CurrencyId::KSM),
             Some(get_hash(69u8)),
@@ -231,7 +261,7 @@ fn update_crowdfund_actually_mutates() {
 
         let cf_after = CrowdFunds::<Test>::get(key).expect("qed");
         assert_ne!(cf_before, cf_after);
-        assert_eq!(cf_after.milestones.len(), 20usize);
+        assert_eq!(cf_after.milestones.len(), 5usize);
         assert_eq!(cf_after.required_funds, 50_000u64);
         assert_eq!(cf_after.currency_id, CurrencyId::KSM);
         assert_eq!(cf_after.agreement_hash, get_hash(69));
@@ -455,3 +485,356 @@ fn on_initialize_removes_contribution_round() {
         assert!(!RoundsExpiring::<Test>::contains_key(expiry_block));
     });
 }
+
+#[test]
+fn create_crowdfund_invalid_jury_size() {
+    new_test_ext().execute_with(|| {
+        for jury_size in [0, MaxJuryMembers::get() + 1] {
+            assert_noop!(
+                CrowdFunding::create_crowdfund(
+                    RuntimeOrigin::signed(ALICE),
+                    get_hash(1u8),
+                    get_milestones(10),
+                    100_000,
+                    CurrencyId::Native,
+                    jury_size,
+                ),
+                Error::<Test>::InvalidJurySize
+            );
+        }
+    });
+}
+
+#[test]
+fn do_approve_selects_the_jury_size_of_the_crowdfund() {
+    new_test_ext().execute_with(|| {
+        let key = create_cf_default_and_contribute(ALICE, vec![BOB, CHARLIE], 100_000);
+        CrowdFunds::<Test>::mutate(key, |maybe_cf| {
+            maybe_cf.as_mut().expect("crowdfund exists; qed").jury_size = 1;
+        });
+        assert_ok!(CrowdFunding::approve_crowdfund_for_milestone_submission(
+            RuntimeOrigin::root(),
+            key
+        ));
+
+        let project =
+            Projects::<Test>::get(ProjectCount::<Test>::get()).expect("project was created; qed");
+        assert_eq!(project.jury.to_vec(), vec![JURY_1]);
+    });
+}
+
+#[test]
+fn do_approve_converts_crowdfund_into_project() {
+    new_test_ext().execute_with(|| {
+        let key = create_cf_default_and_contribute(ALICE, vec![BOB, CHARLIE], 100_000);
+        assert_ok!(CrowdFunding::approve_crowdfund_for_milestone_submission(
+            RuntimeOrigin::root(),
+            key
+        ));
+        System::assert_last_event(RuntimeEvent::CrowdFunding(
+            Event::<Test>::CrowdFundApproved(key),
+        ));
+
+        let cf = CrowdFunds::<Test>::get(key).expect("crowdfund still exists; qed");
+        assert!(cf.is_converted);
+
+        let project_key = ProjectCount::<Test>::get();
+        let project = Projects::<Test>::get(project_key).expect("project was created; qed");
+        assert_eq!(project.initiator, ALICE);
+        assert_eq!(project.raised_funds, 100_000);
+        assert_eq!(project.milestones.len(), cf.milestones.len());
+        assert_eq!(project.jury.to_vec(), vec![JURY_1, JURY_2]);
+        assert_eq!(project.refund_locations.len(), 2);
+        assert!(project
+            .refund_locations
+            .iter()
+            .all(|(loc, percent)| *percent == Percent::from_percent(50u8)
+                && (*loc == Locality::Local(BOB) || *loc == Locality::Local(CHARLIE))));
+    });
+}
+
+#[test]
+fn do_approve_takes_contributions_from_reserved() {
+    new_test_ext().execute_with(|| {
+        let key = create_cf_default_and_contribute(ALICE, vec![BOB, CHARLIE], 100_000);
+        assert_ok!(CrowdFunding::approve_crowdfund_for_milestone_submission(
+            RuntimeOrigin::root(),
+            key
+        ));
+        for who in [BOB, CHARLIE] {
+            let reserved = <<Test as Config>::MultiCurrency as MultiReservableCurrency<
+                AccountId,
+            >>::reserved_balance(CurrencyId::Native, &who);
+            assert_eq!(reserved, 0u64);
+        }
+    });
+}
+
+#[test]
+fn do_approve_already_converted() {
+    new_test_ext().execute_with(|| {
+        let key = create_cf_default_and_contribute(ALICE, vec![BOB, CHARLIE], 100_000);
+        assert_ok!(CrowdFunding::approve_crowdfund_for_milestone_submission(
+            RuntimeOrigin::root(),
+            key
+        ));
+        assert_noop!(
+            CrowdFunding::approve_crowdfund_for_milestone_submission(RuntimeOrigin::root(), key),
+            Error::<Test>::CrowdFundAlreadyConverted
+        );
+    });
+}
+
+#[test]
+fn cancel_crowdfund_by_initiator_refunds_contributions() {
+    new_test_ext().execute_with(|| {
+        let key = create_cf_default_and_contribute(ALICE, vec![BOB, CHARLIE], 100_000);
+        assert_eq!(reserved_balance(BOB), 50_000);
+        assert_ok!(CrowdFunding::cancel_crowdfund(
+            RuntimeOrigin::signed(ALICE),
+            key
+        ));
+        System::assert_last_event(RuntimeEvent::CrowdFunding(
+            Event::<Test>::CrowdFundCancelled(key),
+        ));
+
+        assert_eq!(reserved_balance(BOB), 0);
+        assert_eq!(reserved_balance(CHARLIE), 0);
+        let cf = CrowdFunds::<Test>::get(key).expect("crowdfund still exists; qed");
+        assert!(cf.cancelled);
+        assert!(cf.contributions.is_empty());
+        assert_eq!(cf.raised_funds, 0);
+    });
+}
+
+#[test]
+fn cancel_crowdfund_by_authority() {
+    new_test_ext().execute_with(|| {
+        let key = create_cf_default_and_contribute(ALICE, vec![BOB], 100_000);
+        assert_ok!(CrowdFunding::cancel_crowdfund(RuntimeOrigin::root(), key));
+        assert_eq!(reserved_balance(BOB), 0);
+        assert!(CrowdFunds::<Test>::get(key).unwrap().cancelled);
+    });
+}
+
+#[test]
+fn cancel_crowdfund_not_initiator() {
+    new_test_ext().execute_with(|| {
+        let key = create_cf_default(ALICE, 100_000);
+        assert_noop!(
+            CrowdFunding::cancel_crowdfund(RuntimeOrigin::signed(BOB), key),
+            Error::<Test>::UserIsNotInitiator
+        );
+    });
+}
+
+#[test]
+fn cancel_crowdfund_already_cancelled() {
+    new_test_ext().execute_with(|| {
+        let key = create_cf_default(ALICE, 100_000);
+        assert_ok!(CrowdFunding::cancel_crowdfund(
+            RuntimeOrigin::signed(ALICE),
+            key
+        ));
+        assert_noop!(
+            CrowdFunding::cancel_crowdfund(RuntimeOrigin::signed(ALICE), key),
+            Error::<Test>::CrowdFundCancelled
+        );
+    });
+}
+
+#[test]
+fn cancel_crowdfund_already_converted() {
+    new_test_ext().execute_with(|| {
+        let key = create_cf_default_and_contribute(ALICE, vec![BOB, CHARLIE], 100_000);
+        assert_ok!(CrowdFunding::approve_crowdfund_for_milestone_submission(
+            RuntimeOrigin::root(),
+            key
+        ));
+        assert_noop!(
+            CrowdFunding::cancel_crowdfund(RuntimeOrigin::signed(ALICE), key),
+            Error::<Test>::CrowdFundAlreadyConverted
+        );
+    });
+}
+
+#[test]
+fn cancel_crowdfund_closes_contribution_round() {
+    new_test_ext().execute_with(|| {
+        let key = create_cf_default(ALICE, 100_000);
+        assert_ok!(CrowdFunding::open_contributions(RuntimeOrigin::root(), key));
+        let expiry_block =
+            frame_system::Pallet::<Test>::block_number() + <Test as Config>::RoundExpiry::get();
+        assert_ok!(CrowdFunding::cancel_crowdfund(
+            RuntimeOrigin::signed(ALICE),
+            key
+        ));
+
+        assert!(!CrowdFundsInRound::<Test>::contains_key(
+            key,
+            RoundType::ContributionRound
+        ));
+        assert!(!RoundsExpiring::<Test>::get(expiry_block).contains(&key));
+        assert_noop!(
+            CrowdFunding::contribute(RuntimeOrigin::signed(BOB), key, 100_000),
+            Error::<Test>::ContributionRoundNotStarted
+        );
+        assert_noop!(
+            CrowdFunding::open_contributions(RuntimeOrigin::root(), key),
+            Error::<Test>::CrowdFundCancelled
+        );
+    });
+}
+
+#[test]
+fn failed_contribution_round_refunds_contributions() {
+    new_test_ext().execute_with(|| {
+        let key = create_cf_default(ALICE, 1_000_000u64);
+        assert_ok!(CrowdFunding::open_contributions(RuntimeOrigin::root(), key));
+        assert_ok!(CrowdFunding::contribute(
+            RuntimeOrigin::signed(BOB),
+            key,
+            100_000
+        ));
+        let expiry_block =
+            frame_system::Pallet::<Test>::block_number() + <Test as Config>::RoundExpiry::get();
+        run_to_block(expiry_block);
+
+        System::assert_has_event(RuntimeEvent::CrowdFunding(
+            Event::<Test>::ContributionRoundFailed(key, 100_000),
+        ));
+        assert_eq!(reserved_balance(BOB), 0);
+        let cf = CrowdFunds::<Test>::get(key).expect("crowdfund still exists; qed");
+        assert!(!cf.cancelled);
+        assert!(!cf.approved_for_funding);
+        assert!(cf.contributions.is_empty());
+        assert_eq!(cf.raised_funds, 0);
+    });
+}
+
+#[test]
+fn failed_contribution_round_allows_another_round() {
+    new_test_ext().execute_with(|| {
+        let key = create_cf_default(ALICE, 1_000_000u64);
+        assert_ok!(CrowdFunding::open_contributions(RuntimeOrigin::root(), key));
+        let expiry_block =
+            frame_system::Pallet::<Test>::block_number() + <Test as Config>::RoundExpiry::get();
+        run_to_block(expiry_block);
+
+        // The initiator can amend the crowdfund before the second round.
+        assert_ok!(CrowdFunding::update_crowdfund(
+            RuntimeOrigin::signed(ALICE),
+            key,
+            None,
+            Some(100_000),
+            None,
+            None,
+        ));
+        assert_ok!(CrowdFunding::open_contributions(RuntimeOrigin::root(), key));
+        assert_ok!(CrowdFunding::contribute(
+            RuntimeOrigin::signed(BOB),
+            key,
+            100_000
+        ));
+        assert_ok!(CrowdFunding::approve_crowdfund_for_milestone_submission(
+            RuntimeOrigin::root(),
+            key
+        ));
+    });
+}
+
+#[test]
+fn last_failed_contribution_round_cancels_crowdfund() {
+    new_test_ext().execute_with(|| {
+        let key = create_cf_default(ALICE, 1_000_000u64);
+        for _ in 0..<Test as Config>::MaxContributionRounds::get() {
+            assert_ok!(CrowdFunding::open_contributions(RuntimeOrigin::root(), key));
+            assert_ok!(CrowdFunding::contribute(
+                RuntimeOrigin::signed(BOB),
+                key,
+                100_000
+            ));
+            let expiry_block =
+                frame_system::Pallet::<Test>::block_number() + <Test as Config>::RoundExpiry::get();
+            run_to_block(expiry_block);
+        }
+
+        System::assert_last_event(RuntimeEvent::CrowdFunding(
+            Event::<Test>::CrowdFundCancelled(key),
+        ));
+        assert_eq!(reserved_balance(BOB), 0);
+        assert!(CrowdFunds::<Test>::get(key).unwrap().cancelled);
+        assert_noop!(
+            CrowdFunding::open_contributions(RuntimeOrigin::root(), key),
+            Error::<Test>::CrowdFundCancelled
+        );
+    });
+}
+
+#[test]
+fn failed_contribution_round_reports_the_amount_unreserved() {
+    new_test_ext().execute_with(|| {
+        let key = create_cf_default(ALICE, 1_000_000u64);
+        assert_ok!(CrowdFunding::open_contributions(RuntimeOrigin::root(), key));
+        assert_ok!(CrowdFunding::contribute(
+            RuntimeOrigin::signed(BOB),
+            key,
+            100_000
+        ));
+        // Part of the contribution is no longer reserved when the round closes.
+        <<Test as Config>::MultiCurrency as MultiReservableCurrency<AccountId>>::unreserve(
+            CurrencyId::Native,
+            &BOB,
+            40_000,
+        );
+        let expiry_block =
+            frame_system::Pallet::<Test>::block_number() + <Test as Config>::RoundExpiry::get();
+        run_to_block(expiry_block);
+
+        System::assert_has_event(RuntimeEvent::CrowdFunding(
+            Event::<Test>::ContributionRoundFailed(key, 60_000),
+        ));
+        assert_eq!(reserved_balance(BOB), 0);
+    });
+}
+
+#[test]
+fn last_failed_contribution_round_reports_a_deposit_that_cannot_be_returned() {
+    new_test_ext().execute_with(|| {
+        let key = create_cf_default(ALICE, 1_000_000u64);
+        ReturnDepositFails::set(true);
+        for _ in 0..<Test as Config>::MaxContributionRounds::get() {
+            assert_ok!(CrowdFunding::open_contributions(RuntimeOrigin::root(), key));
+            let expiry_block =
+                frame_system::Pallet::<Test>::block_number() + <Test as Config>::RoundExpiry::get();
+            run_to_block(expiry_block);
+        }
+
+        System::assert_has_event(RuntimeEvent::CrowdFunding(
+            Event::<Test>::DepositReturnFailed(key, DispatchError::Other("deposit not returned")),
+        ));
+        System::assert_last_event(RuntimeEvent::CrowdFunding(
+            Event::<Test>::CrowdFundCancelled(key),
+        ));
+        assert!(CrowdFunds::<Test>::get(key).unwrap().cancelled);
+    });
+}
+
+#[test]
+fn successful_contribution_round_keeps_contributions() {
+    new_test_ext().execute_with(|| {
+        let key = create_cf_default_and_contribute(ALICE, vec![BOB, CHARLIE], 100_000);
+        let expiry_block =
+            frame_system::Pallet::<Test>::block_number() + <Test as Config>::RoundExpiry::get();
+        run_to_block(expiry_block);
+
+        System::assert_has_event(RuntimeEvent::CrowdFunding(
+            Event::<Test>::ContributionRoundSucceeded(key),
+        ));
+        assert_eq!(reserved_balance(BOB), 50_000);
+        assert_ok!(CrowdFunding::approve_crowdfund_for_milestone_submission(
+            RuntimeOrigin::root(),
+            key
+        ));
+    });
+}
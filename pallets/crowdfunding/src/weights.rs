@@ -16,6 +16,8 @@ pub trait WeightInfo {
     fn open_contributions() -> Weight;
     fn contribute() -> Weight;
     fn approve_crowdfund_for_milestone_submission() -> Weight;
+    fn cancel_crowdfund(c: u32) -> Weight;
+    fn close_contribution_round(c: u32) -> Weight;
 }
 
 /// Weights for pallet_proposals using the Substrate node, recommended hardware should be used.
@@ -44,6 +46,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     fn approve_crowdfund_for_milestone_submission() -> Weight {
         <Weight as Default>::default()
     }
+    fn cancel_crowdfund(_c: u32) -> Weight {
+        <Weight as Default>::default()
+    }
+    fn close_contribution_round(_c: u32) -> Weight {
+        <Weight as Default>::default()
+    }
 }
 
 // For backwards compatibility and tests
@@ -69,4 +77,10 @@ impl WeightInfo for () {
     fn approve_crowdfund_for_milestone_submission() -> Weight {
         <Weight as Default>::default()
     }
+    fn cancel_crowdfund(_c: u32) -> Weight {
+        <Weight as Default>::default()
+    }
+    fn close_contribution_round(_c: u32) -> Weight {
+        <Weight as Default>::default()
+    }
 }
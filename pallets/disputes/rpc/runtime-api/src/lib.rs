@@ -0,0 +1,81 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_std::vec::Vec;
+
+/// A dispute as returned by the runtime api, bounded collections are flattened into plain vectors.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct DisputeInfo<AccountId, SpecificId, BlockNumber> {
+    /// Who this was raised by.
+    pub raised_by: AccountId,
+    /// The party responsible for the vote.
+    pub jury: Vec<AccountId>,
+    /// The votes cast so far on each specific, yay is true.
+    pub votes: Vec<(AccountId, Vec<(SpecificId, bool)>)>,
+    /// The specific entities the dispute is raised upon.
+    pub specifiers: Vec<SpecificId>,
+    /// The number of times the time limit of the dispute has been extended.
+    pub extensions: u32,
+    /// The jurors that have voted for the next extension of the dispute.
+    pub extension_votes: Vec<AccountId>,
+    /// The expiration block of the dispute.
+    pub expiration: BlockNumber,
+    /// The block voting opens, evidence can be submitted until then.
+    pub voting_starts: BlockNumber,
+    /// The evidence attached to the dispute in the order it was submitted.
+    pub evidence: Vec<EvidenceInfo<AccountId>>,
+}
+
+/// The role of the account that submitted a piece of evidence.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum EvidenceRole {
+    Raiser,
+    Defendant,
+    Juror,
+}
+
+/// A piece of evidence attached to a dispute.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct EvidenceInfo<AccountId> {
+    /// Who submitted the evidence.
+    pub submitted_by: AccountId,
+    /// The role of the submitter in the dispute.
+    pub role: EvidenceRole,
+    /// The hash of the evidence, usually an IPFS CID.
+    pub hash: Vec<u8>,
+}
+
+/// The result a specific of a dispute would have if it were finalised now.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum DisputeOutcome {
+    Success,
+    Failure,
+}
+
+sp_api::decl_runtime_apis! {
+    pub trait DisputesApi<AccountId, DisputeKey, SpecificId, BlockNumber>
+    where
+        AccountId: codec::Codec,
+        DisputeKey: codec::Codec,
+        SpecificId: codec::Codec,
+        BlockNumber: codec::Codec,
+    {
+        /// Get a dispute by its key.
+        fn get_dispute(dispute_key: DisputeKey) -> Option<DisputeInfo<AccountId, SpecificId, BlockNumber>>;
+        /// Get the dispute keys finalising on each block in the inclusive range `from..=to`.
+        /// Blocks without finalising disputes are omitted.
+        /// Long ranges are truncated, the last block searched is returned so the rest can be queried after it.
+        fn get_disputes_finalising_between(from: BlockNumber, to: BlockNumber) -> (Vec<(BlockNumber, Vec<DisputeKey>)>, BlockNumber);
+        /// Get the keys of the disputes where `juror` is part of the jury and has not yet voted.
+        fn get_disputes_awaiting_vote(juror: AccountId) -> Vec<DisputeKey>;
+        /// Get the result each specific of the dispute would have if it were finalised at the current block.
+        fn get_projected_result(dispute_key: DisputeKey) -> Option<Vec<(SpecificId, DisputeOutcome)>>;
+    }
+}
@@ -0,0 +1,134 @@
+use codec::Codec;
+
+use jsonrpsee::{
+    core::{Error as JsonRpseeError, RpcResult},
+    proc_macros::rpc,
+    types::error::{CallError, ErrorObject},
+};
+pub use pallet_disputes_rpc_runtime_api::{
+    DisputeInfo, DisputeOutcome, DisputesApi as DisputesRuntimeApi, EvidenceInfo, EvidenceRole,
+};
+
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+use sp_std::vec::Vec;
+
+use std::sync::Arc;
+
+#[rpc(client, server)]
+pub trait DisputesApi<BlockHash, AccountId, DisputeKey, SpecificId, BlockNumber> {
+    #[method(name = "disputes_getDispute")]
+    fn dispute(
+        &self,
+        dispute_key: DisputeKey,
+    ) -> RpcResult<Option<DisputeInfo<AccountId, SpecificId, BlockNumber>>>;
+    #[method(name = "disputes_getDisputesFinalisingBetween")]
+    fn disputes_finalising_between(
+        &self,
+        from: BlockNumber,
+        to: BlockNumber,
+    ) -> RpcResult<(Vec<(BlockNumber, Vec<DisputeKey>)>, BlockNumber)>;
+    #[method(name = "disputes_getDisputesAwaitingVote")]
+    fn disputes_awaiting_vote(&self, juror: AccountId) -> RpcResult<Vec<DisputeKey>>;
+    #[method(name = "disputes_getProjectedResult")]
+    fn projected_result(
+        &self,
+        dispute_key: DisputeKey,
+    ) -> RpcResult<Option<Vec<(SpecificId, DisputeOutcome)>>>;
+}
+
+pub struct Disputes<C, B> {
+    /// Shared reference to the client.
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<B>,
+}
+
+impl<C, P> Disputes<C, P> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+    /// The call to runtime failed.
+    RuntimeError,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+        }
+    }
+}
+
+impl<C, B, AccountId, DisputeKey, SpecificId, BlockNumber>
+    DisputesApiServer<<B as BlockT>::Hash, AccountId, DisputeKey, SpecificId, BlockNumber>
+    for Disputes<C, B>
+where
+    C: sp_api::ProvideRuntimeApi<B>,
+    C: HeaderBackend<B>,
+    C: Send + Sync + 'static,
+    C::Api: DisputesRuntimeApi<B, AccountId, DisputeKey, SpecificId, BlockNumber>,
+    B: BlockT,
+    AccountId: Codec + Send + Sync + 'static,
+    DisputeKey: Codec + Send + Sync + 'static,
+    SpecificId: Codec + Send + Sync + 'static,
+    BlockNumber: Codec + Send + Sync + 'static,
+{
+    fn dispute(
+        &self,
+        dispute_key: DisputeKey,
+    ) -> RpcResult<Option<DisputeInfo<AccountId, SpecificId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        api.get_dispute(at, dispute_key)
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn disputes_finalising_between(
+        &self,
+        from: BlockNumber,
+        to: BlockNumber,
+    ) -> RpcResult<(Vec<(BlockNumber, Vec<DisputeKey>)>, BlockNumber)> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        api.get_disputes_finalising_between(at, from, to)
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn disputes_awaiting_vote(&self, juror: AccountId) -> RpcResult<Vec<DisputeKey>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        api.get_disputes_awaiting_vote(at, juror)
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn projected_result(
+        &self,
+        dispute_key: DisputeKey,
+    ) -> RpcResult<Option<Vec<(SpecificId, DisputeOutcome)>>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        api.get_projected_result(at, dispute_key)
+            .map_err(runtime_error_into_rpc_err)
+    }
+}
+
+/// Converts a runtime trap into an RPC error.
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> JsonRpseeError {
+    CallError::Custom(ErrorObject::owned(
+        Error::RuntimeError.into(),
+        "Could not query the disputes runtime api",
+        Some(format!("{err:?}")),
+    ))
+    .into()
+}
@@ -4,14 +4,15 @@ use super::*;
 use crate::traits::DisputeRaiser;
 use crate::Pallet as DisputesPallet;
 use frame_benchmarking::v2::*;
-use frame_support::{assert_ok, traits::Get, BoundedVec};
+use frame_support::{assert_ok, dispatch::RawOrigin, traits::Get, BoundedVec};
 use frame_system::Pallet as System;
+use orml_traits::MultiCurrency;
+use sp_runtime::Saturating;
 use sp_std::{vec, vec::Vec};
 
 #[benchmarks(where Event::<T>: Into<<T as frame_system::Config>::RuntimeEvent>)]
 mod benchmarks {
     use super::*;
-    use frame_support::dispatch::RawOrigin;
 
     #[benchmark]
     fn raise_dispute() {
@@ -26,6 +27,7 @@ mod benchmarks {
                 alice,
                 jury,
                 specifics,
+                None,
             )
             .unwrap();
         }
@@ -43,12 +45,34 @@ mod benchmarks {
             alice.clone(),
             jury,
             specifics,
+            None,
         ));
 
         #[extrinsic_call]
         <Pallet<T>>::extend_dispute(RawOrigin::Signed(bob), 10u32.into());
     }
 
+    #[benchmark]
+    fn cancel_dispute() {
+        let alice: AccountIdOf<T> = account("ALICE", 0, 0);
+        let bob: AccountIdOf<T> = account("BOB", 0, 0);
+        let jury = get_jury::<T>(vec![bob]);
+        let specifics = get_specifics::<T>(vec![0u32.into(), 1u32.into()]);
+
+        assert_ok!(<Pallet<T> as DisputeRaiser<AccountIdOf<T>>>::raise_dispute(
+            10u32.into(),
+            alice.clone(),
+            jury,
+            specifics,
+            None,
+        ));
+
+        #[extrinsic_call]
+        <Pallet<T>>::cancel_dispute(RawOrigin::Signed(alice), 10u32.into());
+
+        assert!(Disputes::<T>::get(10u32.into()).is_none());
+    }
+
     // Worst case atm is causing it to autofinalise.
     #[benchmark]
     fn vote_on_dispute() {
@@ -62,12 +86,44 @@ mod benchmarks {
             alice.clone(),
             jury,
             specifics,
+            None,
         ));
+        open_voting::<T>(10u32.into());
 
         #[extrinsic_call]
         <Pallet<T>>::vote_on_dispute(RawOrigin::Signed(bob), 10u32.into(), true);
     }
 
+    // Worst case atm is voting on every specific and causing it to autofinalise.
+    #[benchmark]
+    fn vote_on_specifics() {
+        let alice: AccountIdOf<T> = account("ALICE", 0, 0);
+        let bob: AccountIdOf<T> = account("BOB", 0, 0);
+        let jury = get_jury::<T>(vec![bob.clone()]);
+        let specific_ids = (0..T::MaxSpecifics::get())
+            .map(|i| i.into())
+            .collect::<Vec<T::SpecificId>>();
+        let votes: BoundedVec<(T::SpecificId, bool), T::MaxSpecifics> = specific_ids
+            .iter()
+            .map(|specific_id| (*specific_id, true))
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("bounded by MaxSpecifics");
+        let specifics = get_specifics::<T>(specific_ids);
+
+        assert_ok!(<Pallet<T> as DisputeRaiser<AccountIdOf<T>>>::raise_dispute(
+            10u32.into(),
+            alice.clone(),
+            jury,
+            specifics,
+            None,
+        ));
+        open_voting::<T>(10u32.into());
+
+        #[extrinsic_call]
+        <Pallet<T>>::vote_on_specifics(RawOrigin::Signed(bob), 10u32.into(), votes);
+    }
+
     #[benchmark]
     fn force_fail_dispute() {
         let alice: AccountIdOf<T> = account("ALICE", 0, 0);
@@ -80,15 +136,16 @@ mod benchmarks {
             alice.clone(),
             jury,
             specifics,
+            None,
         ));
 
         #[extrinsic_call]
         <Pallet<T>>::force_fail_dispute(RawOrigin::Root, dispute_key);
 
         System::<T>::assert_last_event(
-            Event::<T>::DisputeCompleted {
+            Event::<T>::VerdictsFinalised {
                 dispute_key,
-                dispute_result: DisputeResult::Failure,
+                verdicts: get_verdicts::<T>(vec![0u32.into(), 1u32.into()], DisputeResult::Failure),
             }
             .into(),
         );
@@ -106,20 +163,175 @@ mod benchmarks {
             alice.clone(),
             jury,
             specifics,
+            None,
         ));
 
         #[extrinsic_call]
         <Pallet<T>>::force_succeed_dispute(RawOrigin::Root, dispute_key);
 
         System::<T>::assert_last_event(
-            Event::<T>::DisputeCompleted {
+            Event::<T>::VerdictsFinalised {
+                dispute_key,
+                verdicts: get_verdicts::<T>(vec![0u32.into(), 1u32.into()], DisputeResult::Success),
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn appeal_dispute() {
+        let alice: AccountIdOf<T> = account("ALICE", 0, 0);
+        let bob: AccountIdOf<T> = account("BOB", 0, 0);
+        let dispute_key = 10u32.into();
+        complete_with_failure::<T>(dispute_key, alice.clone(), bob);
+
+        #[extrinsic_call]
+        <Pallet<T>>::appeal_dispute(RawOrigin::Signed(alice.clone()), dispute_key);
+
+        System::<T>::assert_last_event(
+            Event::<T>::DisputeAppealed {
+                dispute_key,
+                who: alice,
+            }
+            .into(),
+        );
+    }
+
+    // Worst case is upholding the verdicts as the bond is moved to the JurorRewardPot.
+    #[benchmark]
+    fn decide_appeal() {
+        let alice: AccountIdOf<T> = account("ALICE", 0, 0);
+        let bob: AccountIdOf<T> = account("BOB", 0, 0);
+        let dispute_key = 10u32.into();
+        complete_with_failure::<T>(dispute_key, alice.clone(), bob);
+        assert_ok!(<Pallet<T>>::appeal_dispute(
+            RawOrigin::Signed(alice).into(),
+            dispute_key
+        ));
+        let verdicts = get_verdicts::<T>(vec![0u32.into(), 1u32.into()], DisputeResult::Failure);
+
+        #[extrinsic_call]
+        <Pallet<T>>::decide_appeal(RawOrigin::Root, dispute_key, verdicts.clone());
+
+        System::<T>::assert_last_event(
+            Event::<T>::VerdictsFinalised {
+                dispute_key,
+                verdicts,
+            }
+            .into(),
+        );
+    }
+
+    // Worst case is a juror submitting the last piece of evidence allowed.
+    #[benchmark]
+    fn submit_evidence() {
+        let alice: AccountIdOf<T> = account("ALICE", 0, 0);
+        let bob: AccountIdOf<T> = account("BOB", 0, 0);
+        let dispute_key = 10u32.into();
+        assert_ok!(<Pallet<T> as DisputeRaiser<AccountIdOf<T>>>::raise_dispute(
+            dispute_key,
+            alice.clone(),
+            get_jury::<T>(vec![bob.clone()]),
+            get_specifics::<T>(vec![0u32.into(), 1u32.into()]),
+            None,
+        ));
+        let hash: EvidenceHash = vec![1u8; MAX_EVIDENCE_HASH_LEN as usize]
+            .try_into()
+            .expect("bounded by MAX_EVIDENCE_HASH_LEN");
+        for _ in 1..T::MaxEvidence::get() {
+            assert_ok!(<Pallet<T>>::submit_evidence(
+                RawOrigin::Signed(alice.clone()).into(),
+                dispute_key,
+                hash.clone()
+            ));
+        }
+
+        #[extrinsic_call]
+        <Pallet<T>>::submit_evidence(RawOrigin::Signed(bob.clone()), dispute_key, hash.clone());
+
+        System::<T>::assert_last_event(
+            Event::<T>::EvidenceSubmitted {
+                dispute_key,
+                who: bob,
+                role: EvidenceRole::Juror,
+                hash,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn commit_vote() {
+        let alice: AccountIdOf<T> = account("ALICE", 0, 0);
+        let bob: AccountIdOf<T> = account("BOB", 0, 0);
+        let dispute_key = 10u32.into();
+        assert_ok!(<Pallet<T> as DisputeRaiser<AccountIdOf<T>>>::raise_dispute(
+            dispute_key,
+            alice,
+            get_jury::<T>(vec![bob.clone()]),
+            get_specifics::<T>(vec![0u32.into(), 1u32.into()]),
+            None,
+        ));
+        use_secret_voting::<T>(dispute_key);
+        let commitment = <Pallet<T>>::vote_commitment(
+            &bob,
+            dispute_key,
+            &[(0u32.into(), true), (1u32.into(), true)],
+            &[0u8; 32],
+        );
+
+        #[extrinsic_call]
+        <Pallet<T>>::commit_vote(RawOrigin::Signed(bob.clone()), dispute_key, commitment);
+
+        System::<T>::assert_last_event(
+            Event::<T>::VoteCommitted {
+                who: bob,
                 dispute_key,
-                dispute_result: DisputeResult::Success,
             }
             .into(),
         );
     }
 
+    // Worst case is revealing the last commitment on every specific and causing it to autofinalise.
+    #[benchmark]
+    fn reveal_vote() {
+        let alice: AccountIdOf<T> = account("ALICE", 0, 0);
+        let bob: AccountIdOf<T> = account("BOB", 0, 0);
+        let dispute_key = 10u32.into();
+        let specific_ids = (0..T::MaxSpecifics::get())
+            .map(|i| i.into())
+            .collect::<Vec<T::SpecificId>>();
+        let votes: BoundedVec<(T::SpecificId, bool), T::MaxSpecifics> = specific_ids
+            .iter()
+            .map(|specific_id| (*specific_id, true))
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("bounded by MaxSpecifics");
+        assert_ok!(<Pallet<T> as DisputeRaiser<AccountIdOf<T>>>::raise_dispute(
+            dispute_key,
+            alice,
+            get_jury::<T>(vec![bob.clone()]),
+            get_specifics::<T>(specific_ids),
+            None,
+        ));
+        use_secret_voting::<T>(dispute_key);
+        let salt = [0u8; 32];
+        assert_ok!(<Pallet<T>>::commit_vote(
+            RawOrigin::Signed(bob.clone()).into(),
+            dispute_key,
+            <Pallet<T>>::vote_commitment(&bob, dispute_key, &votes, &salt)
+        ));
+        let reveal_starts = Disputes::<T>::get(dispute_key)
+            .and_then(|dispute| dispute.reveal_starts)
+            .expect("secret voting was just enabled");
+        System::<T>::set_block_number(reveal_starts);
+
+        #[extrinsic_call]
+        <Pallet<T>>::reveal_vote(RawOrigin::Signed(bob), dispute_key, votes, salt);
+
+        assert!(Disputes::<T>::get(dispute_key).is_none());
+    }
+
     // Linear relationship with jury members.
     #[benchmark]
     fn calculate_winner() {
@@ -138,8 +350,10 @@ mod benchmarks {
             10u32.into(),
             alice,
             jury.clone(),
-            specifics
+            specifics,
+            None
         ));
+        open_voting::<T>(dispute_key);
         let mut dispute = Disputes::<T>::get(dispute_key).expect("just inserted, should exist.");
 
         for i in 0..T::MaxJurySize::get() {
@@ -149,7 +363,7 @@ mod benchmarks {
 
         #[block]
         {
-            dispute.calculate_winner();
+            dispute.calculate_winner(&0u32.into());
         }
     }
 
@@ -171,3 +385,73 @@ pub fn get_specifics<T: Config>(
 ) -> BoundedVec<T::SpecificId, T::MaxSpecifics> {
     specifics.try_into().expect("too many specific ids.")
 }
+
+pub fn get_verdicts<T: Config>(
+    specifics: Vec<T::SpecificId>,
+    result: DisputeResult,
+) -> Verdicts<T> {
+    specifics
+        .into_iter()
+        .map(|specific_id| (specific_id, result))
+        .collect::<Vec<_>>()
+        .try_into()
+        .expect("too many specific ids.")
+}
+
+/// Raise a dispute and have the single juror fail it so the raiser can appeal.
+fn complete_with_failure<T: Config>(
+    dispute_key: T::DisputeKey,
+    raiser: AccountIdOf<T>,
+    juror: AccountIdOf<T>,
+) {
+    let _ = <T as Config>::MultiCurrency::deposit(
+        T::JurorRewardCurrencyId::get(),
+        &raiser,
+        T::JurorRewardPerDispute::get().saturating_mul(1_000u32.into()),
+    );
+    assert_ok!(<Pallet<T> as DisputeRaiser<AccountIdOf<T>>>::raise_dispute(
+        dispute_key,
+        raiser,
+        get_jury::<T>(vec![juror.clone()]),
+        get_specifics::<T>(vec![0u32.into(), 1u32.into()]),
+        None,
+    ));
+    open_voting::<T>(dispute_key);
+    assert_ok!(<Pallet<T>>::vote_on_dispute(
+        RawOrigin::Signed(juror).into(),
+        dispute_key,
+        false
+    ));
+}
+
+/// Move to the block where voting on the dispute opens.
+/// Votes are cast in the open, secret votes have their own benchmarks.
+fn open_voting<T: Config>(dispute_key: T::DisputeKey) {
+    let dispute = Disputes::<T>::get(dispute_key).expect("dispute should exist");
+    if dispute.reveal_starts.is_some() {
+        Disputes::<T>::mutate(dispute_key, |maybe_dispute| {
+            if let Some(d) = maybe_dispute {
+                d.reveal_starts = None;
+            }
+        });
+    }
+    if System::<T>::block_number() < dispute.voting_starts {
+        System::<T>::set_block_number(dispute.voting_starts);
+    }
+}
+
+/// Move to the block where voting on the dispute opens and have the jury vote in secret,
+/// whatever the RevealPeriod is configured to.
+fn use_secret_voting<T: Config>(dispute_key: T::DisputeKey) {
+    let dispute = Disputes::<T>::get(dispute_key).expect("dispute should exist");
+    if dispute.reveal_starts.is_none() {
+        Disputes::<T>::mutate(dispute_key, |maybe_dispute| {
+            if let Some(d) = maybe_dispute {
+                d.reveal_starts = Some(d.expiration);
+            }
+        });
+    }
+    if System::<T>::block_number() < dispute.voting_starts {
+        System::<T>::set_block_number(dispute.voting_starts);
+    }
+}
@@ -1,15 +1,27 @@
 use crate::*;
-use frame_support::BoundedVec;
-use sp_runtime::DispatchError;
+use frame_support::{ensure, pallet_prelude::Weight, traits::Get, BoundedVec};
+use frame_system::pallet_prelude::BlockNumberFor;
+use orml_traits::{MultiCurrency, MultiReservableCurrency};
+use sp_runtime::{
+    traits::{Hash, One, Zero},
+    DispatchError, Saturating,
+};
+use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
 
-use crate::pallet::{AccountIdOf, Config, Dispute};
-use crate::traits::{DisputeHooks, DisputeRaiser};
+use crate::pallet::{
+    AccountIdOf, AppealWindowClosesOn, BalanceOf, BoundedVotes, Config, Dispute, DisputeResult,
+    Disputes, DisputesFinaliseOn, JurorDisputeCount, JurorDisputes, MissedJuryVotes,
+    PendingVerdict, PendingVerdicts, Verdicts,
+};
+use crate::traits::{DisputeHooks, DisputeRaiser, JurorPenaltyHandler};
 
-impl<T: Config> DisputeRaiser<AccountIdOf<T>> for Pallet<T> {
+impl<T: Config<I>, I: 'static> DisputeRaiser<AccountIdOf<T>> for Pallet<T, I> {
     type DisputeKey = T::DisputeKey;
     type SpecificId = T::SpecificId;
-    type MaxJurySize = <T as Config>::MaxJurySize;
-    type MaxSpecifics = <T as Config>::MaxSpecifics;
+    type MaxJurySize = <T as Config<I>>::MaxJurySize;
+    type MaxSpecifics = <T as Config<I>>::MaxSpecifics;
+    type MaxDisputesPerJuror = <T as Config<I>>::MaxDisputesPerJuror;
+    type BondId = T::BondId;
 
     /// Public interface for Dispute::new()
     fn raise_dispute(
@@ -17,8 +29,383 @@ impl<T: Config> DisputeRaiser<AccountIdOf<T>> for Pallet<T> {
         raised_by: AccountIdOf<T>,
         jury: BoundedVec<AccountIdOf<T>, Self::MaxJurySize>,
         specifiers: BoundedVec<Self::SpecificId, Self::MaxSpecifics>,
+        bond: Option<Self::BondId>,
     ) -> Result<(), DispatchError> {
-        Dispute::<T>::new(dispute_key, raised_by, jury, specifiers)?;
+        Dispute::<T, I>::new(dispute_key, raised_by, jury, specifiers, bond)?;
         Ok(())
     }
+
+    fn disputes_of_juror(juror: &AccountIdOf<T>) -> Vec<Self::DisputeKey> {
+        JurorDisputes::<T, I>::iter_key_prefix(juror).collect()
+    }
+
+    fn replace_juror(
+        dispute_key: Self::DisputeKey,
+        juror: &AccountIdOf<T>,
+        replacement: Option<AccountIdOf<T>>,
+    ) -> Result<(), DispatchError> {
+        Disputes::<T, I>::try_mutate(dispute_key, |maybe_dispute| {
+            let dispute = maybe_dispute
+                .as_mut()
+                .ok_or(Error::<T, I>::DisputeDoesNotExist)?;
+            let index = dispute
+                .jury
+                .iter()
+                .position(|member| member == juror)
+                .ok_or(Error::<T, I>::NotAJuryAccount)?;
+            match replacement.as_ref() {
+                Some(new_juror) => {
+                    ensure!(
+                        !dispute.jury.contains(new_juror),
+                        Error::<T, I>::AlreadyAJuryAccount
+                    );
+                    Self::add_juror_dispute(new_juror, dispute_key)?;
+                    dispute.jury[index] = new_juror.clone();
+                }
+                None => {
+                    dispute.jury.remove(index);
+                }
+            }
+            dispute.votes.remove(juror);
+            dispute.commitments.remove(juror);
+            dispute.extension_votes.retain(|member| member != juror);
+            Self::remove_juror_dispute(juror, dispute_key);
+            Ok::<(), DispatchError>(())
+        })?;
+
+        Self::deposit_event(Event::<T, I>::JurorReplaced {
+            dispute_key,
+            juror: juror.clone(),
+            replacement,
+        });
+        Ok(())
+    }
+}
+
+/// The maximum number of blocks that can be queried at once by `disputes_finalising_between`.
+pub const MAX_FINALISE_QUERY_RANGE: u32 = 10_000;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+    /// Get the dispute keys finalising on each block in the inclusive range `from..=to`.
+    /// The range is capped at MAX_FINALISE_QUERY_RANGE blocks and empty blocks are skipped.
+    /// The last block searched is returned alongside, if it is before `to` the rest of the
+    /// range can be queried from the block after it.
+    /// Used by the runtime api.
+    pub fn disputes_finalising_between(
+        from: BlockNumberFor<T>,
+        to: BlockNumberFor<T>,
+    ) -> (
+        Vec<(BlockNumberFor<T>, Vec<T::DisputeKey>)>,
+        BlockNumberFor<T>,
+    ) {
+        let to = to.min(from.saturating_add(MAX_FINALISE_QUERY_RANGE.into()));
+        let mut block = from;
+        let mut finalising = Vec::new();
+        while block <= to {
+            let keys = DisputesFinaliseOn::<T, I>::get(block);
+            if !keys.is_empty() {
+                finalising.push((block, keys.into_inner()));
+            }
+            if block == to {
+                break;
+            }
+            block = block.saturating_add(One::one());
+        }
+        (finalising, to)
+    }
+
+    /// The commitment a juror submits to vote in secret, the salt keeps the votes from being guessed.
+    /// The same votes and salt must be given when the vote is revealed.
+    /// The dispute key is included so a commitment cannot be replayed on another dispute.
+    pub fn vote_commitment(
+        who: &AccountIdOf<T>,
+        dispute_key: T::DisputeKey,
+        votes: &[(T::SpecificId, bool)],
+        salt: &[u8; 32],
+    ) -> T::Hash {
+        T::Hashing::hash_of(&(who, dispute_key, votes, salt))
+    }
+
+    /// Get the keys of the disputes where `juror` sits on the jury and has not yet voted.
+    /// Used by the runtime api.
+    pub fn disputes_awaiting_vote(juror: &AccountIdOf<T>) -> Vec<T::DisputeKey> {
+        JurorDisputes::<T, I>::iter_key_prefix(juror)
+            .filter(|dispute_key| {
+                Disputes::<T, I>::get(dispute_key)
+                    .map_or(false, |dispute| !dispute.votes.contains_key(juror))
+            })
+            .collect()
+    }
+
+    /// Record that `juror` sits on an open dispute.
+    /// Fails if they already sit on MaxDisputesPerJuror open disputes.
+    pub(crate) fn add_juror_dispute(
+        juror: &AccountIdOf<T>,
+        dispute_key: T::DisputeKey,
+    ) -> Result<(), DispatchError> {
+        if JurorDisputes::<T, I>::contains_key(juror, dispute_key) {
+            return Ok(());
+        }
+        JurorDisputeCount::<T, I>::try_mutate(juror, |count| {
+            ensure!(
+                *count < T::MaxDisputesPerJuror::get(),
+                Error::<T, I>::JurorHasTooManyDisputes
+            );
+            *count = count.saturating_add(1);
+            Ok::<(), DispatchError>(())
+        })?;
+        JurorDisputes::<T, I>::insert(juror, dispute_key, ());
+        Ok(())
+    }
+
+    /// Remove the record that `juror` sits on a dispute.
+    pub(crate) fn remove_juror_dispute(juror: &AccountIdOf<T>, dispute_key: T::DisputeKey) {
+        if JurorDisputes::<T, I>::take(juror, dispute_key).is_some() {
+            JurorDisputeCount::<T, I>::mutate_exists(juror, |maybe_count| {
+                let count = maybe_count.unwrap_or_default().saturating_sub(1);
+                *maybe_count = (!count.is_zero()).then_some(count);
+            });
+        }
+    }
+
+    /// Remove the records of a jury once their dispute is no longer open.
+    pub(crate) fn remove_jury_disputes(
+        dispute_key: T::DisputeKey,
+        jury: &[AccountIdOf<T>],
+    ) -> Weight {
+        for juror in jury.iter() {
+            Self::remove_juror_dispute(juror, dispute_key);
+        }
+        T::DbWeight::get().reads_writes(2 * jury.len() as u64, 2 * jury.len() as u64)
+    }
+
+    /// The weight of removing a full jury from JurorDisputes, added to the calls that can close a dispute.
+    pub(crate) fn max_jury_disputes_weight() -> Weight {
+        let jury_size = <T as Config<I>>::MaxJurySize::get() as u64;
+        T::DbWeight::get().reads_writes(2 * jury_size, 2 * jury_size)
+    }
+
+    /// The most settling the jury of a dispute can cost.
+    pub(crate) fn max_settle_jury_weight() -> Weight {
+        let jury_size = <T as Config<I>>::MaxJurySize::get() as u64;
+        T::DbWeight::get().reads_writes(3 * jury_size, 3 * jury_size)
+    }
+
+    /// The most recording the missed votes of a dispute can cost, including the JurorPenaltyHandler
+    /// for every juror, such as revoking their fellowship and replacing them on their other juries.
+    pub(crate) fn max_missed_votes_weight() -> Weight {
+        let jury_size = <T as Config<I>>::MaxJurySize::get() as u64;
+        T::DbWeight::get()
+            .reads_writes(1, 1)
+            .saturating_add(T::JurorPenaltyHandler::max_on_missed_vote_weight())
+            .saturating_mul(jury_size)
+    }
+
+    /// Get the result each specific of a dispute would have if it were finalised now.
+    /// Used by the runtime api.
+    pub fn projected_result(
+        dispute_key: T::DisputeKey,
+    ) -> Option<Vec<(T::SpecificId, DisputeResult)>> {
+        Disputes::<T, I>::get(dispute_key).map(|dispute| dispute.calculate_verdicts().into_inner())
+    }
+
+    /// Reward the jurors that voted in line with the final verdicts of a dispute
+    /// and reset the missed votes of every juror that voted.
+    /// The reward is split equally between the specifics, the share of each specific is split
+    /// equally between the jurors that voted with its verdict.
+    /// The reward is skipped for any juror the pot cannot pay.
+    pub(crate) fn settle_jury(
+        dispute_key: T::DisputeKey,
+        votes: &BoundedVotes<T, I>,
+        verdicts: &Verdicts<T, I>,
+    ) -> Weight {
+        let mut weight = Weight::zero();
+        let reward = T::JurorRewardPerDispute::get();
+        let mut rewards: BTreeMap<AccountIdOf<T>, BalanceOf<T, I>> = BTreeMap::new();
+        if !verdicts.is_empty() && !reward.is_zero() {
+            let verdicts_len: BalanceOf<T, I> = (verdicts.len() as u32).into();
+            let reward_per_specific = reward / verdicts_len;
+            for (specific_id, result) in verdicts.iter() {
+                let winning_vote = *result == DisputeResult::Success;
+                let winners = votes
+                    .iter()
+                    .filter(|(_, votes)| votes.get(specific_id) == Some(&winning_vote))
+                    .map(|(juror, _)| juror.clone())
+                    .collect::<Vec<AccountIdOf<T>>>();
+                if winners.is_empty() {
+                    continue;
+                }
+                let winners_len: BalanceOf<T, I> = (winners.len() as u32).into();
+                let reward_per_juror = reward_per_specific / winners_len;
+                for juror in winners.into_iter() {
+                    let juror_reward = rewards.entry(juror).or_default();
+                    *juror_reward = juror_reward.saturating_add(reward_per_juror);
+                }
+            }
+        }
+
+        let pot = T::JurorRewardPot::get();
+        for (juror, amount) in rewards.into_iter() {
+            weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 2));
+            if <T as Config<I>>::MultiCurrency::transfer(
+                T::JurorRewardCurrencyId::get(),
+                &pot,
+                &juror,
+                amount,
+            )
+            .is_ok()
+            {
+                Self::deposit_event(Event::<T, I>::JurorRewarded {
+                    who: juror,
+                    dispute_key,
+                    amount,
+                });
+            }
+        }
+
+        for juror in votes.keys() {
+            weight = weight.saturating_add(T::DbWeight::get().writes(1));
+            MissedJuryVotes::<T, I>::remove(juror);
+        }
+        weight
+    }
+
+    /// Move the expiry of a dispute from block `n` to the next block.
+    /// Returns false if the next block already has MaxDisputesPerBlock disputes expiring.
+    pub(crate) fn defer_dispute_expiry(dispute_key: T::DisputeKey, n: BlockNumberFor<T>) -> bool {
+        let next = n.saturating_add(One::one());
+        let deferred = DisputesFinaliseOn::<T, I>::mutate(next, |finalising| {
+            finalising.try_push(dispute_key).is_ok()
+        });
+        if deferred {
+            Disputes::<T, I>::mutate(dispute_key, |maybe_dispute| {
+                if let Some(dispute) = maybe_dispute {
+                    dispute.expiration = next;
+                }
+            });
+        }
+        deferred
+    }
+
+    /// Record a missed vote against the jurors that did not vote at all.
+    /// Only called on the disputes that expire once their voting has been open for the full period,
+    /// a dispute that is forced, cancelled or decided early gives the jury no chance to miss a vote.
+    pub(crate) fn record_missed_votes(
+        dispute_key: T::DisputeKey,
+        jury: &[AccountIdOf<T>],
+        votes: &BoundedVotes<T, I>,
+    ) -> Weight {
+        let mut weight = Weight::zero();
+        for juror in jury.iter().filter(|juror| !votes.contains_key(juror)) {
+            weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+            let missed_in_a_row = MissedJuryVotes::<T, I>::mutate(juror, |missed| {
+                *missed = missed.saturating_add(1);
+                *missed
+            });
+            weight = weight.saturating_add(T::JurorPenaltyHandler::on_missed_vote(
+                juror,
+                missed_in_a_row,
+            ));
+            Self::deposit_event(Event::<T, I>::JurorMissedVote {
+                who: juror.clone(),
+                dispute_key,
+                missed_in_a_row,
+            });
+        }
+        weight
+    }
+
+    /// Hold back the verdicts of a completed dispute until its appeal window closes.
+    /// The verdicts are finalised straight away if there is no appeal window or
+    /// too many windows already close on the same block.
+    pub(crate) fn open_appeal_window(
+        dispute_key: T::DisputeKey,
+        dispute: Dispute<T, I>,
+        verdicts: Verdicts<T, I>,
+    ) -> Weight {
+        let appeal_window = T::AppealWindow::get();
+        if appeal_window.is_zero() {
+            return Self::finalise_verdicts(
+                dispute_key,
+                &dispute.jury,
+                &dispute.votes,
+                verdicts,
+                dispute.bond,
+            );
+        }
+
+        let appeal_window_closes =
+            frame_system::Pallet::<T>::block_number().saturating_add(appeal_window);
+        let scheduled = AppealWindowClosesOn::<T, I>::mutate(appeal_window_closes, |closing| {
+            closing.try_push(dispute_key).is_ok()
+        });
+        if !scheduled {
+            return Self::finalise_verdicts(
+                dispute_key,
+                &dispute.jury,
+                &dispute.votes,
+                verdicts,
+                dispute.bond,
+            );
+        }
+
+        PendingVerdicts::<T, I>::insert(
+            dispute_key,
+            PendingVerdict {
+                raised_by: dispute.raised_by,
+                verdicts,
+                bond: dispute.bond,
+                appeal_window_closes,
+                appealed_by: None,
+                appeal_bond: None,
+                appeal_lapses: None,
+                jury: dispute.jury,
+                votes: dispute.votes,
+            },
+        );
+        T::DbWeight::get().reads_writes(1, 2)
+    }
+
+    /// Finalise the original verdicts of an appeal the AppealOrigin did not decide in time.
+    /// The appellant is not at fault so the appeal bond is returned.
+    pub(crate) fn lapse_appeal(
+        dispute_key: T::DisputeKey,
+        pending: PendingVerdict<T, I>,
+    ) -> Weight {
+        if let Some(appeal_bond) = pending.appeal_bond {
+            if let Err(error) = <T as Config<I>>::DepositHandler::return_deposit(appeal_bond) {
+                Self::deposit_event(Event::<T, I>::AppealBondFailed { dispute_key, error });
+            }
+        }
+        PendingVerdicts::<T, I>::remove(dispute_key);
+        Self::deposit_event(Event::<T, I>::AppealLapsed { dispute_key });
+        T::DbWeight::get()
+            .reads_writes(3, 4)
+            .saturating_add(Self::finalise_verdicts(
+                dispute_key,
+                &pending.jury,
+                &pending.votes,
+                pending.verdicts,
+                pending.bond,
+            ))
+    }
+
+    /// Settle the jury against the final verdicts of a dispute and hand them to the DisputeHooks.
+    /// Every way a dispute can be decided ends here, so the jury is settled exactly once.
+    pub(crate) fn finalise_verdicts(
+        dispute_key: T::DisputeKey,
+        jury: &[AccountIdOf<T>],
+        votes: &BoundedVotes<T, I>,
+        verdicts: Verdicts<T, I>,
+        bond: Option<T::BondId>,
+    ) -> Weight {
+        let weight = Self::settle_jury(dispute_key, votes, &verdicts).saturating_add(
+            T::DisputeHooks::on_dispute_complete(dispute_key, verdicts.clone().into_inner(), bond),
+        );
+        Self::deposit_event(Event::<T, I>::VerdictsFinalised {
+            dispute_key,
+            verdicts,
+        });
+        weight
+    }
 }
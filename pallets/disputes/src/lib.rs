@@ -1,6 +1,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 pub use pallet::*;
 pub mod impls;
+pub mod migration;
 pub mod traits;
 pub mod weights;
 
@@ -15,24 +16,50 @@ mod tests;
 
 #[frame_support::pallet]
 pub mod pallet {
-    use crate::traits::DisputeHooks;
+    use crate::traits::{DisputeHooks, DisputeOutcomePolicy, DisputeParties, JurorPenaltyHandler};
     use codec::{FullCodec, FullEncode};
+    use common_types::CurrencyId;
     use frame_support::{pallet_prelude::*, weights::Weight, BoundedBTreeMap};
     use frame_system::pallet_prelude::*;
+    use orml_traits::{MultiCurrency, MultiReservableCurrency};
+    use pallet_deposits::traits::DepositHandler;
     use sp_runtime::traits::{AtLeast32BitUnsigned, Saturating, Zero};
-    use sp_std::fmt::Debug;
+    use sp_std::{fmt::Debug, vec::Vec};
 
     pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+    pub type BalanceOf<T, I = ()> =
+        <<T as Config<I>>::MultiCurrency as MultiCurrency<AccountIdOf<T>>>::Balance;
+    pub type StorageItemOf<T, I = ()> = <<T as Config<I>>::DepositHandler as DepositHandler<
+        BalanceOf<T, I>,
+        AccountIdOf<T>,
+    >>::StorageItem;
 
-    pub type BoundedVotes<T> = BoundedBTreeMap<AccountIdOf<T>, bool, <T as Config>::MaxJurySize>;
+    /// The vote of a single juror on each of the specifics in a dispute, yay is true.
+    pub type SpecificVotes<T, I = ()> =
+        BoundedBTreeMap<<T as Config<I>>::SpecificId, bool, <T as Config<I>>::MaxSpecifics>;
+    pub type BoundedVotes<T, I = ()> =
+        BoundedBTreeMap<AccountIdOf<T>, SpecificVotes<T, I>, <T as Config<I>>::MaxJurySize>;
+    /// The result of a dispute for each of its specifics.
+    pub type Verdicts<T, I = ()> =
+        BoundedVec<(<T as Config<I>>::SpecificId, DisputeResult), <T as Config<I>>::MaxSpecifics>;
+
+    /// The maximum length of an evidence hash, long enough for an IPFS CID.
+    pub const MAX_EVIDENCE_HASH_LEN: u32 = 64;
+    pub type EvidenceHash = BoundedVec<u8, ConstU32<MAX_EVIDENCE_HASH_LEN>>;
+
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(8);
 
     #[pallet::pallet]
-    pub struct Pallet<T>(_);
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
 
+    /// The pallet is instantiable, each consumer of disputes can be given its own instance
+    /// with its own DisputeHooks and limits.
     #[pallet::config]
-    pub trait Config: frame_system::Config {
+    pub trait Config<I: 'static = ()>: frame_system::Config {
         /// Overarching event type.
-        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        type RuntimeEvent: From<Event<Self, I>>
+            + IsType<<Self as frame_system::Config>::RuntimeEvent>;
         /// The weights generated by the benchmarks.
         type WeightInfo: WeightInfoT;
         /// The key that links the dispute to the project.
@@ -61,23 +88,67 @@ pub mod pallet {
         type VotingTimeLimit: Get<BlockNumberFor<Self>>;
         /// The origin used to force cancel and pass disputes.
         type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// The id of the bond taken by the consumer when a dispute is raised.
+        type BondId: Member + FullCodec + MaxEncodedLen + TypeInfo + Copy;
         /// External hooks to handle the completion of a dispute.
-        type DisputeHooks: DisputeHooks<Self::DisputeKey, Self::SpecificId>;
+        type DisputeHooks: DisputeHooks<Self::DisputeKey, Self::SpecificId, Self::BondId>;
+        /// The currency the juror rewards are paid in and appeal bonds are reserved in.
+        type MultiCurrency: MultiReservableCurrency<AccountIdOf<Self>, CurrencyId = CurrencyId>;
+        /// The currency id of the juror rewards and the appeal bonds.
+        type JurorRewardCurrencyId: Get<CurrencyId>;
+        /// The account the juror rewards are paid from.
+        type JurorRewardPot: Get<AccountIdOf<Self>>;
+        /// The reward shared between the jurors that voted in line with the result of a dispute.
+        type JurorRewardPerDispute: Get<BalanceOf<Self, I>>;
+        /// Handles the jurors that fail to vote before a dispute expires.
+        type JurorPenaltyHandler: JurorPenaltyHandler<AccountIdOf<Self>>;
+        /// Decides the result of each specific from the votes and when a dispute can finalise early.
+        type DisputeOutcomePolicy: DisputeOutcomePolicy;
+        /// The amount of time after a dispute is raised where evidence can be submitted, voting opens after this.
+        type EvidencePeriod: Get<BlockNumberFor<Self>>;
+        /// The maximum number of pieces of evidence that can be attached to a dispute.
+        type MaxEvidence: Get<u32>;
+        /// The amount of time the losing side has to appeal the verdicts of a completed dispute.
+        type AppealWindow: Get<BlockNumberFor<Self>>;
+        /// Takes the bond of an appeal, it is slashed if the appeal changes nothing.
+        type DepositHandler: DepositHandler<
+            BalanceOf<Self, I>,
+            AccountIdOf<Self>,
+            DepositId = Self::BondId,
+        >;
+        /// The storage item the bond of an appeal is taken for.
+        type AppealStorageItem: Get<StorageItemOf<Self, I>>;
+        /// The origin that decides appeals.
+        type AppealOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// The amount of time the AppealOrigin has to decide an appeal,
+        /// after which the appeal lapses and the original verdicts are finalised.
+        type AppealDecisionPeriod: Get<BlockNumberFor<Self>>;
+        /// Defines who is defending against a dispute.
+        type DisputeParties: DisputeParties<AccountIdOf<Self>, Self::DisputeKey>;
+        /// The amount of time after voting closes where jurors reveal the votes they committed in secret.
+        /// If this is zero votes are cast in the open instead.
+        /// Otherwise every dispute uses secret voting: vote_on_dispute and vote_on_specifics are rejected,
+        /// and a dispute can only finalise before its expiration once every commitment has been revealed.
+        type RevealPeriod: Get<BlockNumberFor<Self>>;
+        /// The number of times a dispute can be extended, each extension needs a majority of the jury.
+        type MaxExtensions: Get<u32>;
+        /// The maximum number of open disputes a juror can sit on at once.
+        type MaxDisputesPerJuror: Get<u32>;
     }
 
     /// Used to store the disputes that is being raised, given the dispute key it returns the Dispute
     /// Key: DisputeKey
-    /// Value: Dispute<T>
+    /// Value: Dispute<T, I>
     #[pallet::storage]
     #[pallet::getter(fn disputes)]
-    pub type Disputes<T: Config> =
-        StorageMap<_, Blake2_128Concat, T::DisputeKey, Dispute<T>, OptionQuery>;
+    pub type Disputes<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::DisputeKey, Dispute<T, I>, OptionQuery>;
 
     /// Stores the dispute keys that will finalise on a given block.
     /// Key: BlockNumber
     /// Value: Vec<DisputeKey>
     #[pallet::storage]
-    pub type DisputesFinaliseOn<T: Config> = StorageMap<
+    pub type DisputesFinaliseOn<T: Config<I>, I: 'static = ()> = StorageMap<
         _,
         Blake2_128Concat,
         BlockNumberFor<T>,
@@ -85,9 +156,71 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// The verdicts of completed disputes that can still be appealed or are being appealed.
+    /// The DisputeHooks are called once the appeal window closes or the appeal is decided.
+    /// Key: DisputeKey
+    /// Value: PendingVerdict<T, I>
+    #[pallet::storage]
+    pub type PendingVerdicts<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::DisputeKey, PendingVerdict<T, I>, OptionQuery>;
+
+    /// Stores the dispute keys whose appeal window closes on a given block.
+    /// Key: BlockNumber
+    /// Value: Vec<DisputeKey>
+    #[pallet::storage]
+    pub type AppealWindowClosesOn<T: Config<I>, I: 'static = ()> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BlockNumberFor<T>,
+        BoundedVec<T::DisputeKey, T::MaxDisputesPerBlock>,
+        ValueQuery,
+    >;
+
+    /// Stores the dispute keys whose undecided appeal lapses on a given block.
+    /// Key: BlockNumber
+    /// Value: Vec<DisputeKey>
+    #[pallet::storage]
+    pub type AppealsLapseOn<T: Config<I>, I: 'static = ()> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BlockNumberFor<T>,
+        BoundedVec<T::DisputeKey, T::MaxDisputesPerBlock>,
+        ValueQuery,
+    >;
+
+    /// The number of consecutive disputes a juror has failed to vote on.
+    /// Reset when the juror votes on a dispute that completes.
+    /// Key: AccountId
+    /// Value: u32
+    #[pallet::storage]
+    pub type MissedJuryVotes<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, AccountIdOf<T>, u32, ValueQuery>;
+
+    /// The open disputes each juror sits on, kept so they can be found without reading every dispute.
+    /// Key 1: AccountId
+    /// Key 2: DisputeKey
+    /// Value: ()
+    #[pallet::storage]
+    pub type JurorDisputes<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AccountIdOf<T>,
+        Blake2_128Concat,
+        T::DisputeKey,
+        (),
+        OptionQuery,
+    >;
+
+    /// The number of open disputes a juror sits on, bound by MaxDisputesPerJuror.
+    /// Key: AccountId
+    /// Value: u32
+    #[pallet::storage]
+    pub type JurorDisputeCount<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, AccountIdOf<T>, u32, ValueQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
-    pub enum Event<T: Config> {
+    pub enum Event<T: Config<I>, I: 'static = ()> {
         /// A dispute has been raised.
         DisputeRaised {
             who: AccountIdOf<T>,
@@ -99,21 +232,87 @@ pub mod pallet {
             dispute_key: T::DisputeKey,
             vote: bool,
         },
-        /// A dispute has been completed.
-        // TODO: Not in use
+        /// A dispute has been voted on specific by specific.
+        DisputeSpecificsVotedOn {
+            who: AccountIdOf<T>,
+            dispute_key: T::DisputeKey,
+            votes: SpecificVotes<T, I>,
+        },
+        /// A dispute has been completed with a result for each specific.
         DisputeCompleted {
             dispute_key: T::DisputeKey,
-            dispute_result: DisputeResult,
+            verdicts: Verdicts<T, I>,
         },
-        /// A dispute has been cancelled.
-        // TODO: Not in use
+        /// A dispute has been cancelled by its raiser.
         DisputeCancelled { dispute_key: T::DisputeKey },
         /// A dispute has been extended.
         DisputeExtended { dispute_key: T::DisputeKey },
+        /// A juror has voted to extend a dispute, it is extended once a majority of the jury has.
+        ExtensionVoted {
+            who: AccountIdOf<T>,
+            dispute_key: T::DisputeKey,
+        },
+        /// A juror has been rewarded for voting in line with the result of a dispute.
+        JurorRewarded {
+            who: AccountIdOf<T>,
+            dispute_key: T::DisputeKey,
+            amount: BalanceOf<T, I>,
+        },
+        /// Evidence has been attached to a dispute.
+        EvidenceSubmitted {
+            dispute_key: T::DisputeKey,
+            who: AccountIdOf<T>,
+            role: EvidenceRole,
+            hash: EvidenceHash,
+        },
+        /// The verdicts of a dispute have been appealed.
+        DisputeAppealed {
+            dispute_key: T::DisputeKey,
+            who: AccountIdOf<T>,
+        },
+        /// An appeal has been decided by the AppealOrigin.
+        AppealDecided {
+            dispute_key: T::DisputeKey,
+            verdicts: Verdicts<T, I>,
+        },
+        /// The verdicts of a dispute can no longer be appealed and have been handed to the DisputeHooks.
+        VerdictsFinalised {
+            dispute_key: T::DisputeKey,
+            verdicts: Verdicts<T, I>,
+        },
+        /// A juror failed to vote on a dispute before it expired.
+        JurorMissedVote {
+            who: AccountIdOf<T>,
+            dispute_key: T::DisputeKey,
+            missed_in_a_row: u32,
+        },
+        /// A juror has committed a secret vote on a dispute.
+        VoteCommitted {
+            who: AccountIdOf<T>,
+            dispute_key: T::DisputeKey,
+        },
+        /// A juror has left the jury of an open dispute, their votes have been discarded.
+        JurorReplaced {
+            dispute_key: T::DisputeKey,
+            juror: AccountIdOf<T>,
+            replacement: Option<AccountIdOf<T>>,
+        },
+        /// The AppealOrigin did not decide an appeal in time, the original verdicts have been finalised.
+        AppealLapsed { dispute_key: T::DisputeKey },
+        /// The bond of an appeal could not be returned or slashed once the appeal was settled.
+        AppealBondFailed {
+            dispute_key: T::DisputeKey,
+            error: DispatchError,
+        },
+        /// A juror already sat on MaxDisputesPerJuror disputes and was left off the jury of a new dispute.
+        JurorSkipped {
+            dispute_key: T::DisputeKey,
+            juror: AccountIdOf<T>,
+        },
     }
 
     #[pallet::error]
-    pub enum Error<T> {
+    pub enum Error<T, I = ()> {
         /// Dispute key does not exist.
         DisputeDoesNotExist,
         /// Dispute key already exists.
@@ -122,38 +321,138 @@ pub mod pallet {
         NotAJuryAccount,
         /// There have been too many disputes on this block. Try next block.
         TooManyDisputesThisBlock,
-        /// The dispute has already been extended. You can only extend a dispute once.
+        /// The dispute has already been extended the maximum number of times.
         DisputeAlreadyExtended,
         /// There have been more than required votes for a given dispute
         TooManyDisputeVotes,
         /// A dispute key is not inserted in DisputesFinaliseOn as expected, this is a bug, contact development.
         AutoFinaliseStateMismatch,
+        /// A vote has been cast on a specific that is not part of the dispute.
+        SpecificNotInDispute,
+        /// A vote must be cast on every specific in the dispute.
+        MissingSpecificVote,
+        /// Voting opens once the evidence period has ended.
+        VotingNotOpen,
+        /// Evidence can only be submitted before voting opens.
+        EvidencePeriodEnded,
+        /// Only the raiser, the defendant and the jury can submit evidence.
+        NotADisputeParty,
+        /// The maximum amount of evidence has been submitted for this dispute.
+        TooMuchEvidence,
+        /// There are no verdicts awaiting appeal for this dispute.
+        NoVerdictsToAppeal,
+        /// The verdicts of this dispute have already been appealed.
+        AlreadyAppealed,
+        /// The appeal window of this dispute has closed.
+        AppealWindowClosed,
+        /// Only the raiser or the defendant can appeal, and only against a verdict they lost.
+        NotALosingParty,
+        /// This dispute has not been appealed.
+        NotAppealed,
+        /// The decided verdicts must cover exactly the specifics of the dispute.
+        InvalidAppealVerdicts,
+        /// The replacement juror already sits on the jury of this dispute.
+        AlreadyAJuryAccount,
+        /// Votes on this dispute are secret, they must be committed and revealed later.
+        VotesMustBeCommitted,
+        /// Votes on this dispute are cast in the open.
+        SecretVotingDisabled,
+        /// Votes can only be committed before the reveal period.
+        CommitPeriodEnded,
+        /// Votes can only be revealed once the commit period has ended.
+        RevealPeriodNotOpen,
+        /// There is no committed vote to reveal.
+        NoVoteCommitted,
+        /// The revealed votes and salt do not match the committed vote.
+        RevealMismatch,
+        /// This juror has already voted for the next extension of the dispute.
+        AlreadyVotedToExtend,
+        /// Only the raiser of a dispute can cancel it.
+        NotTheRaiser,
+        /// A majority of the jury has voted, the dispute can no longer be cancelled.
+        QuorumReached,
+        /// A juror already sits on the maximum number of open disputes.
+        JurorHasTooManyDisputes,
+        /// Too many appeals already lapse on the same block. Try next block.
+        TooManyAppealsThisBlock,
     }
 
     #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-        // TODO: WEIGHT + BENCHMARKS
+    impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+        /// The weight is built up from each dispute, appeal window and appeal handled in the block,
+        /// the hooks report their own weight.
         fn on_initialize(n: BlockNumberFor<T>) -> Weight {
-            let mut weight: Weight = Zero::zero();
-            let expiring_disputes = DisputesFinaliseOn::<T>::take(n);
+            // DisputesFinaliseOn, AppealWindowClosesOn and AppealsLapseOn are taken for the block.
+            let mut weight: Weight = T::DbWeight::get().reads_writes(3, 3);
+            let max_block_weight = <T as frame_system::Config>::BlockWeights::get().max_block;
+            let expiring_disputes = DisputesFinaliseOn::<T, I>::take(n);
             expiring_disputes.iter().for_each(|dispute_id| {
                 weight = weight.saturating_add(T::DbWeight::get().reads(1));
-                if let Some(dispute) = Disputes::<T>::take(dispute_id) {
-                    weight = weight.saturating_add(T::WeightInfo::calculate_winner());
-                    let result = dispute.calculate_winner();
-                    // TODO: actually benchmark.
-                    let hook_weight = <T::DisputeHooks as DisputeHooks<
-                        T::DisputeKey,
-                        T::SpecificId,
-                    >>::on_dispute_complete(
-                        *dispute_id, dispute.specifiers.into_inner(), result
+                // A full jury missing their votes can revoke fellowships and replace jurors across
+                // many projects, the dispute is left to the next block if that could overweigh this one.
+                let used = frame_system::Pallet::<T>::block_weight()
+                    .total()
+                    .saturating_add(weight);
+                if used
+                    .saturating_add(Self::max_missed_votes_weight())
+                    .any_gt(max_block_weight)
+                    && Self::defer_dispute_expiry(*dispute_id, n)
+                {
+                    weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 2));
+                    return;
+                }
+                if let Some(dispute) = Disputes::<T, I>::take(dispute_id) {
+                    weight = weight
+                        .saturating_add(Self::remove_jury_disputes(*dispute_id, &dispute.jury));
+                    weight = weight.saturating_add(
+                        T::WeightInfo::calculate_winner()
+                            .saturating_mul(dispute.specifiers.len() as u64),
                     );
-
-                    weight = weight.saturating_add(hook_weight);
-                    Self::deposit_event(Event::<T>::DisputeCompleted {
+                    let verdicts = dispute.calculate_verdicts();
+                    Self::deposit_event(Event::<T, I>::DisputeCompleted {
                         dispute_key: *dispute_id,
-                        dispute_result: result,
+                        verdicts: verdicts.clone(),
                     });
+                    // At most max_missed_votes_weight, the jury has had the full voting period.
+                    if n >= dispute.voting_starts {
+                        weight = weight.saturating_add(Self::record_missed_votes(
+                            *dispute_id,
+                            &dispute.jury,
+                            &dispute.votes,
+                        ));
+                    }
+                    let hook_weight = Self::open_appeal_window(*dispute_id, dispute, verdicts);
+                    weight = weight.saturating_add(hook_weight);
+                }
+            });
+
+            let closing_windows = AppealWindowClosesOn::<T, I>::take(n);
+            closing_windows.iter().for_each(|dispute_key| {
+                weight = weight.saturating_add(T::DbWeight::get().reads(1));
+                if let Some(pending) = PendingVerdicts::<T, I>::get(dispute_key) {
+                    // Appealed verdicts wait for the AppealOrigin.
+                    // The window must also match in case the key has since been reused.
+                    if pending.appealed_by.is_none() && pending.appeal_window_closes == n {
+                        PendingVerdicts::<T, I>::remove(dispute_key);
+                        weight = weight.saturating_add(Self::finalise_verdicts(
+                            *dispute_key,
+                            &pending.jury,
+                            &pending.votes,
+                            pending.verdicts,
+                            pending.bond,
+                        ));
+                    }
+                }
+            });
+
+            let lapsing_appeals = AppealsLapseOn::<T, I>::take(n);
+            lapsing_appeals.iter().for_each(|dispute_key| {
+                weight = weight.saturating_add(T::DbWeight::get().reads(1));
+                if let Some(pending) = PendingVerdicts::<T, I>::get(dispute_key) {
+                    // The deadline must match in case the key has since been reused.
+                    if pending.appeal_lapses == Some(n) {
+                        weight = weight.saturating_add(Self::lapse_appeal(*dispute_key, pending));
+                    }
                 }
             });
             weight
@@ -161,35 +460,35 @@ pub mod pallet {
     }
 
     #[pallet::call]
-    impl<T: Config> Pallet<T> {
-        /// Vote on a dispute that already exists.
-        /// If all votes are unanimous and everyone has voted, the dispute is autofinalised.
+    impl<T: Config<I>, I: 'static> Pallet<T, I> {
+        /// Vote on a dispute that already exists, the vote is cast on every specific in the dispute.
+        /// Once the DisputeOutcomePolicy has decided every specific, the dispute is autofinalised.
+        /// Not available when votes are committed in secret.
         #[pallet::call_index(0)]
-        #[pallet::weight(<T as Config>::WeightInfo::vote_on_dispute())]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::vote_on_dispute()
+            .saturating_add(Pallet::<T, I>::max_jury_disputes_weight())
+            .saturating_add(Pallet::<T, I>::max_settle_jury_weight()))]
         pub fn vote_on_dispute(
             origin: OriginFor<T>,
             dispute_key: T::DisputeKey,
             is_yay: bool,
         ) -> DispatchResult {
-            let mut total_jury = 0;
             let who = ensure_signed(origin)?;
-            let votes = Disputes::<T>::try_mutate(dispute_key, |dispute| {
+            let dispute = Disputes::<T, I>::try_mutate(dispute_key, |dispute| {
                 if let Some(d) = dispute {
-                    total_jury = d.jury.len();
-                    d.try_add_vote(who.clone(), is_yay, dispute_key)
+                    ensure!(
+                        d.reveal_starts.is_none(),
+                        Error::<T, I>::VotesMustBeCommitted
+                    );
+                    d.try_add_vote(who.clone(), is_yay, dispute_key)?;
+                    Ok::<Dispute<T, I>, DispatchError>(d.clone())
                 } else {
-                    Err(Error::<T>::DisputeDoesNotExist.into())
+                    Err(Error::<T, I>::DisputeDoesNotExist.into())
                 }
             })?;
 
-            if votes.len() == total_jury {
-                if votes.iter().all(|v| *v.1) {
-                    Dispute::<T>::try_finalise_with_result(dispute_key, DisputeResult::Success)?;
-                }
-
-                if votes.iter().all(|v| !*v.1) {
-                    Dispute::<T>::try_finalise_with_result(dispute_key, DisputeResult::Failure)?;
-                }
+            if let Some(verdicts) = dispute.decided_verdicts() {
+                Dispute::<T, I>::try_finalise_with_verdicts(dispute_key, verdicts)?;
             }
             Ok(())
         }
@@ -198,15 +497,17 @@ pub mod pallet {
         /// Must be called by T::ForceOrigin
         /// We have a seperate force_fail and force_succeed extrinsics as the two paths may be of vastly different weight.
         #[pallet::call_index(1)]
-        #[pallet::weight(<T as Config>::WeightInfo::force_fail_dispute())]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::force_fail_dispute()
+            .saturating_add(Pallet::<T, I>::max_jury_disputes_weight())
+            .saturating_add(Pallet::<T, I>::max_settle_jury_weight()))]
         pub fn force_fail_dispute(
             origin: OriginFor<T>,
             dispute_key: T::DisputeKey,
         ) -> DispatchResult {
             T::ForceOrigin::ensure_origin(origin)?;
             let dispute =
-                Disputes::<T>::take(dispute_key).ok_or(Error::<T>::DisputeDoesNotExist)?;
-            DisputesFinaliseOn::<T>::mutate(dispute.expiration, |finalising| {
+                Disputes::<T, I>::take(dispute_key).ok_or(Error::<T, I>::DisputeDoesNotExist)?;
+            DisputesFinaliseOn::<T, I>::mutate(dispute.expiration, |finalising| {
                 if let Some(index) = finalising
                     .iter()
                     .position(|finalising_key| finalising_key == &dispute_key)
@@ -215,30 +516,37 @@ pub mod pallet {
                 }
                 // Dont mind if this fails as the autofinalise will skip.
             });
-            T::DisputeHooks::on_dispute_complete(
-                dispute_key,
-                dispute.specifiers.into_inner(),
-                DisputeResult::Failure,
-            );
-            Self::deposit_event(Event::<T>::DisputeCompleted {
+            let _ = Self::remove_jury_disputes(dispute_key, &dispute.jury);
+            let verdicts = dispute.verdicts_with(DisputeResult::Failure);
+            Self::deposit_event(Event::<T, I>::DisputeCompleted {
                 dispute_key,
-                dispute_result: DisputeResult::Failure,
+                verdicts: verdicts.clone(),
             });
+            // Forced verdicts cannot be appealed.
+            let _ = Self::finalise_verdicts(
+                dispute_key,
+                &dispute.jury,
+                &dispute.votes,
+                verdicts,
+                dispute.bond,
+            );
             Ok(())
         }
 
         /// Force a dispute to pass.
         /// Must be called by T::ForceOrigin.
         #[pallet::call_index(2)]
-        #[pallet::weight(<T as Config>::WeightInfo::force_succeed_dispute())]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::force_succeed_dispute()
+            .saturating_add(Pallet::<T, I>::max_jury_disputes_weight())
+            .saturating_add(Pallet::<T, I>::max_settle_jury_weight()))]
         pub fn force_succeed_dispute(
             origin: OriginFor<T>,
             dispute_key: T::DisputeKey,
         ) -> DispatchResult {
             T::ForceOrigin::ensure_origin(origin)?;
             let dispute =
-                Disputes::<T>::take(dispute_key).ok_or(Error::<T>::DisputeDoesNotExist)?;
-            DisputesFinaliseOn::<T>::mutate(dispute.expiration, |finalising| {
+                Disputes::<T, I>::take(dispute_key).ok_or(Error::<T, I>::DisputeDoesNotExist)?;
+            DisputesFinaliseOn::<T, I>::mutate(dispute.expiration, |finalising| {
                 if let Some(index) = finalising
                     .iter()
                     .position(|finalising_key| finalising_key == &dispute_key)
@@ -247,149 +555,600 @@ pub mod pallet {
                 }
                 // Dont mind if this fails as the autofinalise will skip.
             });
-            T::DisputeHooks::on_dispute_complete(
-                dispute_key,
-                dispute.specifiers.into_inner(),
-                DisputeResult::Success,
-            );
-            Self::deposit_event(Event::<T>::DisputeCompleted {
+            let _ = Self::remove_jury_disputes(dispute_key, &dispute.jury);
+            let verdicts = dispute.verdicts_with(DisputeResult::Success);
+            Self::deposit_event(Event::<T, I>::DisputeCompleted {
                 dispute_key,
-                dispute_result: DisputeResult::Success,
+                verdicts: verdicts.clone(),
             });
+            // Forced verdicts cannot be appealed.
+            let _ = Self::finalise_verdicts(
+                dispute_key,
+                &dispute.jury,
+                &dispute.votes,
+                verdicts,
+                dispute.bond,
+            );
             Ok(())
         }
 
-        /// Extend a given dispute by T::VotingTimeLimit.
-        /// This can only be called once and must be called by a member of the jury.
+        /// Vote to extend a given dispute by T::VotingTimeLimit, must be called by a member of the jury.
+        /// The dispute is extended once a majority of the jury has voted for it,
+        /// a dispute can be extended up to T::MaxExtensions times.
         #[pallet::call_index(3)]
-        #[pallet::weight(<T as Config>::WeightInfo::extend_dispute())]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::extend_dispute())]
         pub fn extend_dispute(origin: OriginFor<T>, dispute_key: T::DisputeKey) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            let dispute = Disputes::<T>::get(dispute_key).ok_or(Error::<T>::DisputeDoesNotExist)?;
-            ensure!(!dispute.is_extended, Error::<T>::DisputeAlreadyExtended);
+            let mut dispute =
+                Disputes::<T, I>::get(dispute_key).ok_or(Error::<T, I>::DisputeDoesNotExist)?;
+            ensure!(
+                dispute.extensions < T::MaxExtensions::get(),
+                Error::<T, I>::DisputeAlreadyExtended
+            );
             ensure!(
                 dispute.jury.iter().any(|e| e == &who),
-                Error::<T>::NotAJuryAccount
+                Error::<T, I>::NotAJuryAccount
             );
-            // Ensure that the dispute does not end on the old date.
-            DisputesFinaliseOn::<T>::try_mutate(dispute.expiration, |finalising| {
-                if let Some(index) = finalising
+            ensure!(
+                !dispute.extension_votes.contains(&who),
+                Error::<T, I>::AlreadyVotedToExtend
+            );
+            dispute
+                .extension_votes
+                .try_push(who.clone())
+                .map_err(|_| Error::<T, I>::TooManyDisputeVotes)?;
+            Self::deposit_event(Event::<T, I>::ExtensionVoted { who, dispute_key });
+
+            if dispute.is_majority(dispute.extension_votes.len()) {
+                // Ensure that the dispute does not end on the old date.
+                DisputesFinaliseOn::<T, I>::try_mutate(dispute.expiration, |finalising| {
+                    if let Some(index) = finalising
+                        .iter()
+                        .position(|finalising_key| finalising_key == &dispute_key)
+                    {
+                        finalising.remove(index);
+                    } else {
+                        // This error is unreachable unless the VotingTimeLimit config item is reduced.
+                        // Thereby allowing the extension of a dispute ontop of possible more than MaxDisputes PerBlock.
+                        // If the VotingTimeLimit is the same then this would have autofinalised before this can be called.
+                        return Err(Error::<T, I>::AutoFinaliseStateMismatch.into());
+                    }
+
+                    Ok::<(), DispatchError>(())
+                })?;
+
+                // Insert the new date.
+                let new_expiry = dispute.expiration.saturating_add(T::VotingTimeLimit::get());
+                DisputesFinaliseOn::<T, I>::try_mutate(new_expiry, |finalising| {
+                    finalising
+                        .try_push(dispute_key)
+                        .map_err(|_| Error::<T, I>::TooManyDisputesThisBlock)?;
+                    Ok::<(), DispatchError>(())
+                })?;
+
+                dispute.expiration = new_expiry;
+                dispute.extensions = dispute.extensions.saturating_add(1);
+                // The next extension needs a fresh majority.
+                dispute.extension_votes = Default::default();
+                // The extra time is given to the commit period.
+                dispute.reveal_starts = dispute
+                    .reveal_starts
+                    .map(|reveal_starts| reveal_starts.saturating_add(T::VotingTimeLimit::get()));
+                Self::deposit_event(Event::<T, I>::DisputeExtended { dispute_key });
+            }
+
+            Disputes::<T, I>::insert(dispute_key, dispute);
+            Ok(())
+        }
+
+        /// Appeal the verdicts of a completed dispute while the appeal window is open.
+        /// Only the raiser or the defendant can appeal, and only if a verdict went against them.
+        /// A bond is taken for the AppealStorageItem until the AppealOrigin decides the appeal.
+        /// If the appeal is not decided within the AppealDecisionPeriod it lapses,
+        /// the original verdicts are finalised and the bond is returned.
+        #[pallet::call_index(5)]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::appeal_dispute())]
+        pub fn appeal_dispute(origin: OriginFor<T>, dispute_key: T::DisputeKey) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let mut pending = PendingVerdicts::<T, I>::get(dispute_key)
+                .ok_or(Error::<T, I>::NoVerdictsToAppeal)?;
+            ensure!(
+                pending.appealed_by.is_none(),
+                Error::<T, I>::AlreadyAppealed
+            );
+            ensure!(
+                frame_system::Pallet::<T>::block_number() < pending.appeal_window_closes,
+                Error::<T, I>::AppealWindowClosed
+            );
+
+            let raiser_lost = pending
+                .verdicts
+                .iter()
+                .any(|(_, result)| *result == DisputeResult::Failure);
+            let defendant_lost = pending
+                .verdicts
+                .iter()
+                .any(|(_, result)| *result == DisputeResult::Success);
+            let is_defendant = T::DisputeParties::defendant(dispute_key).as_ref() == Some(&who);
+            ensure!(
+                (who == pending.raised_by && raiser_lost) || (is_defendant && defendant_lost),
+                Error::<T, I>::NotALosingParty
+            );
+
+            let appeal_lapses = frame_system::Pallet::<T>::block_number()
+                .saturating_add(T::AppealDecisionPeriod::get());
+            AppealsLapseOn::<T, I>::try_mutate(appeal_lapses, |lapsing| {
+                lapsing
+                    .try_push(dispute_key)
+                    .map_err(|_| Error::<T, I>::TooManyAppealsThisBlock)
+            })?;
+            let appeal_bond = <T as Config<I>>::DepositHandler::take_deposit(
+                who.clone(),
+                T::AppealStorageItem::get(),
+                T::JurorRewardCurrencyId::get(),
+            )?;
+            pending.appealed_by = Some(who.clone());
+            pending.appeal_bond = Some(appeal_bond);
+            pending.appeal_lapses = Some(appeal_lapses);
+            PendingVerdicts::<T, I>::insert(dispute_key, pending);
+
+            Self::deposit_event(Event::<T, I>::DisputeAppealed { dispute_key, who });
+            Ok(())
+        }
+
+        /// Decide an appeal, the decided verdicts are handed to the DisputeHooks.
+        /// The appeal bond is returned if the decision changes any verdict, otherwise it is slashed.
+        /// Must be called by T::AppealOrigin.
+        #[pallet::call_index(6)]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::decide_appeal().saturating_add(
+            Pallet::<T, I>::max_settle_jury_weight()
+        ))]
+        pub fn decide_appeal(
+            origin: OriginFor<T>,
+            dispute_key: T::DisputeKey,
+            verdicts: Verdicts<T, I>,
+        ) -> DispatchResult {
+            T::AppealOrigin::ensure_origin(origin)?;
+            let pending = PendingVerdicts::<T, I>::get(dispute_key)
+                .ok_or(Error::<T, I>::NoVerdictsToAppeal)?;
+            ensure!(pending.appealed_by.is_some(), Error::<T, I>::NotAppealed);
+            ensure!(
+                verdicts.len() == pending.verdicts.len()
+                    && pending.verdicts.iter().all(|(specific_id, _)| {
+                        verdicts
+                            .iter()
+                            .any(|(decided_id, _)| decided_id == specific_id)
+                    }),
+                Error::<T, I>::InvalidAppealVerdicts
+            );
+
+            if let Some(appeal_bond) = pending.appeal_bond {
+                let upheld = pending
+                    .verdicts
                     .iter()
-                    .position(|finalising_key| finalising_key == &dispute_key)
-                {
-                    finalising.remove(index);
+                    .all(|verdict| verdicts.contains(verdict));
+                let settled = if upheld {
+                    <T as Config<I>>::DepositHandler::slash_reserve_deposit(appeal_bond)
                 } else {
-                    // This error is unreachable unless the VotingTimeLimit config item is reduced.
-                    // Thereby allowing the extension of a dispute ontop of possible more than MaxDisputes PerBlock.
-                    // If the VotingTimeLimit is the same then this would have autofinalised before this can be called.
-                    return Err(Error::<T>::AutoFinaliseStateMismatch.into());
+                    <T as Config<I>>::DepositHandler::return_deposit(appeal_bond)
+                };
+                // The verdicts are still final, a bond that cannot be settled is reported instead.
+                if let Err(error) = settled {
+                    Self::deposit_event(Event::<T, I>::AppealBondFailed { dispute_key, error });
                 }
+            }
 
-                Ok::<(), DispatchError>(())
+            PendingVerdicts::<T, I>::remove(dispute_key);
+            if let Some(appeal_lapses) = pending.appeal_lapses {
+                AppealsLapseOn::<T, I>::mutate(appeal_lapses, |lapsing| {
+                    lapsing.retain(|lapsing_key| *lapsing_key != dispute_key)
+                });
+            }
+            Self::deposit_event(Event::<T, I>::AppealDecided {
+                dispute_key,
+                verdicts: verdicts.clone(),
+            });
+            let _ = Self::finalise_verdicts(
+                dispute_key,
+                &pending.jury,
+                &pending.votes,
+                verdicts,
+                pending.bond,
+            );
+            Ok(())
+        }
+
+        /// Attach the hash of a piece of evidence, such as an IPFS CID, to a dispute.
+        /// Can be called by the raiser, the defendant or a juror until voting opens.
+        /// The role of the caller is recorded alongside the hash.
+        #[pallet::call_index(7)]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::submit_evidence())]
+        pub fn submit_evidence(
+            origin: OriginFor<T>,
+            dispute_key: T::DisputeKey,
+            hash: EvidenceHash,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let role = Disputes::<T, I>::try_mutate(dispute_key, |maybe_dispute| {
+                let dispute = maybe_dispute
+                    .as_mut()
+                    .ok_or(Error::<T, I>::DisputeDoesNotExist)?;
+                ensure!(
+                    frame_system::Pallet::<T>::block_number() < dispute.voting_starts,
+                    Error::<T, I>::EvidencePeriodEnded
+                );
+                let role = if who == dispute.raised_by {
+                    EvidenceRole::Raiser
+                } else if T::DisputeParties::defendant(dispute_key).as_ref() == Some(&who) {
+                    EvidenceRole::Defendant
+                } else if dispute.jury.contains(&who) {
+                    EvidenceRole::Juror
+                } else {
+                    return Err(Error::<T, I>::NotADisputeParty);
+                };
+                dispute
+                    .evidence
+                    .try_push(Evidence {
+                        submitted_by: who.clone(),
+                        role,
+                        hash: hash.clone(),
+                    })
+                    .map_err(|_| Error::<T, I>::TooMuchEvidence)?;
+                Ok::<EvidenceRole, Error<T, I>>(role)
             })?;
 
-            // Insert the new date.
-            let new_expiry = dispute.expiration.saturating_add(T::VotingTimeLimit::get());
-            DisputesFinaliseOn::<T>::try_mutate(new_expiry, |finalising| {
-                finalising
-                    .try_push(dispute_key)
-                    .map_err(|_| Error::<T>::TooManyDisputesThisBlock)?;
+            Self::deposit_event(Event::<T, I>::EvidenceSubmitted {
+                dispute_key,
+                who,
+                role,
+                hash,
+            });
+            Ok(())
+        }
+
+        /// Vote on each specific of a dispute that already exists, a vote must be cast on every specific.
+        /// Once the DisputeOutcomePolicy has decided every specific, the dispute is autofinalised.
+        /// Not available when votes are committed in secret.
+        #[pallet::call_index(4)]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::vote_on_specifics()
+            .saturating_add(Pallet::<T, I>::max_jury_disputes_weight())
+            .saturating_add(Pallet::<T, I>::max_settle_jury_weight()))]
+        pub fn vote_on_specifics(
+            origin: OriginFor<T>,
+            dispute_key: T::DisputeKey,
+            votes: BoundedVec<(T::SpecificId, bool), T::MaxSpecifics>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let dispute = Disputes::<T, I>::try_mutate(dispute_key, |dispute| {
+                if let Some(d) = dispute {
+                    ensure!(
+                        d.reveal_starts.is_none(),
+                        Error::<T, I>::VotesMustBeCommitted
+                    );
+                    d.try_add_specific_votes(who.clone(), votes, dispute_key)?;
+                    Ok::<Dispute<T, I>, DispatchError>(d.clone())
+                } else {
+                    Err(Error::<T, I>::DisputeDoesNotExist.into())
+                }
+            })?;
+
+            if let Some(verdicts) = dispute.decided_verdicts() {
+                Dispute::<T, I>::try_finalise_with_verdicts(dispute_key, verdicts)?;
+            }
+            Ok(())
+        }
+
+        /// Commit a secret vote on a dispute, the commitment is the hash given by `vote_commitment`.
+        /// Can be called by a juror while voting is open and before the reveal period,
+        /// a later commitment replaces an earlier one.
+        #[pallet::call_index(8)]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::commit_vote())]
+        pub fn commit_vote(
+            origin: OriginFor<T>,
+            dispute_key: T::DisputeKey,
+            commitment: T::Hash,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Disputes::<T, I>::try_mutate(dispute_key, |maybe_dispute| {
+                let dispute = maybe_dispute
+                    .as_mut()
+                    .ok_or(Error::<T, I>::DisputeDoesNotExist)?;
+                let reveal_starts = dispute
+                    .reveal_starts
+                    .ok_or(Error::<T, I>::SecretVotingDisabled)?;
+                ensure!(dispute.jury.contains(&who), Error::<T, I>::NotAJuryAccount);
+                let now = frame_system::Pallet::<T>::block_number();
+                ensure!(now >= dispute.voting_starts, Error::<T, I>::VotingNotOpen);
+                ensure!(now < reveal_starts, Error::<T, I>::CommitPeriodEnded);
+                dispute
+                    .commitments
+                    .try_insert(who.clone(), commitment)
+                    .map_err(|_| Error::<T, I>::TooManyDisputeVotes)?;
                 Ok::<(), DispatchError>(())
             })?;
 
-            Disputes::<T>::mutate(dispute_key, |maybe_dispute| {
-                if let Some(d) = maybe_dispute {
-                    d.expiration = new_expiry;
-                    d.is_extended = true
+            Self::deposit_event(Event::<T, I>::VoteCommitted { who, dispute_key });
+            Ok(())
+        }
+
+        /// Reveal the votes committed on a dispute, a vote must be cast on every specific.
+        /// Can be called during the reveal period, commitments that are never revealed count as abstentions.
+        /// Once every commitment has been revealed the dispute is autofinalised.
+        #[pallet::call_index(9)]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::reveal_vote()
+            .saturating_add(Pallet::<T, I>::max_jury_disputes_weight())
+            .saturating_add(Pallet::<T, I>::max_settle_jury_weight()))]
+        pub fn reveal_vote(
+            origin: OriginFor<T>,
+            dispute_key: T::DisputeKey,
+            votes: BoundedVec<(T::SpecificId, bool), T::MaxSpecifics>,
+            salt: [u8; 32],
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let dispute = Disputes::<T, I>::try_mutate(dispute_key, |maybe_dispute| {
+                let dispute = maybe_dispute
+                    .as_mut()
+                    .ok_or(Error::<T, I>::DisputeDoesNotExist)?;
+                let reveal_starts = dispute
+                    .reveal_starts
+                    .ok_or(Error::<T, I>::SecretVotingDisabled)?;
+                ensure!(
+                    frame_system::Pallet::<T>::block_number() >= reveal_starts,
+                    Error::<T, I>::RevealPeriodNotOpen
+                );
+                let commitment = dispute
+                    .commitments
+                    .remove(&who)
+                    .ok_or(Error::<T, I>::NoVoteCommitted)?;
+                ensure!(
+                    commitment == Self::vote_commitment(&who, dispute_key, &votes, &salt),
+                    Error::<T, I>::RevealMismatch
+                );
+                dispute.try_add_specific_votes(who.clone(), votes, dispute_key)?;
+                Ok::<Dispute<T, I>, DispatchError>(dispute.clone())
+            })?;
+
+            // No more votes can be committed, so once every commitment is revealed the result is final.
+            if dispute.commitments.is_empty() {
+                Dispute::<T, I>::try_finalise_with_verdicts(
+                    dispute_key,
+                    dispute.calculate_verdicts(),
+                )?;
+            }
+            Ok(())
+        }
+
+        /// Withdraw a dispute before a majority of the jury has voted on it, must be called by the raiser.
+        /// The dispute is removed without any verdicts and the DisputeHooks are told it was cancelled.
+        #[pallet::call_index(10)]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::cancel_dispute()
+            .saturating_add(Pallet::<T, I>::max_jury_disputes_weight())
+            .saturating_add(T::DisputeHooks::on_dispute_cancel_weight(T::MaxSpecifics::get()))
+        )]
+        pub fn cancel_dispute(origin: OriginFor<T>, dispute_key: T::DisputeKey) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let dispute =
+                Disputes::<T, I>::get(dispute_key).ok_or(Error::<T, I>::DisputeDoesNotExist)?;
+            ensure!(dispute.raised_by == who, Error::<T, I>::NotTheRaiser);
+            // Secret votes count towards the quorum once they are committed.
+            ensure!(
+                !dispute.is_majority(
+                    dispute
+                        .votes
+                        .len()
+                        .saturating_add(dispute.commitments.len())
+                ),
+                Error::<T, I>::QuorumReached
+            );
+
+            Disputes::<T, I>::remove(dispute_key);
+            let _ = Self::remove_jury_disputes(dispute_key, &dispute.jury);
+            DisputesFinaliseOn::<T, I>::mutate(dispute.expiration, |finalising| {
+                if let Some(index) = finalising
+                    .iter()
+                    .position(|finalising_key| finalising_key == &dispute_key)
+                {
+                    finalising.remove(index);
                 }
+                // Dont mind if this fails as the autofinalise will skip.
             });
 
-            Self::deposit_event(Event::<T>::DisputeExtended { dispute_key });
+            Self::deposit_event(Event::<T, I>::DisputeCancelled { dispute_key });
+            T::DisputeHooks::on_dispute_cancel(
+                dispute_key,
+                dispute.specifiers.into_inner(),
+                dispute.bond,
+            )?;
             Ok(())
         }
     }
 
-    #[derive(Encode, Decode, PartialEq, Eq, Clone, Debug, TypeInfo, MaxEncodedLen)]
-    #[scale_info(skip_type_params(T))]
-    pub struct Dispute<T: Config> {
+    #[derive(
+        Encode,
+        Decode,
+        PartialEqNoBound,
+        EqNoBound,
+        CloneNoBound,
+        RuntimeDebugNoBound,
+        TypeInfo,
+        MaxEncodedLen,
+    )]
+    #[scale_info(skip_type_params(T, I))]
+    pub struct Dispute<T: Config<I>, I: 'static = ()> {
         /// Who this was raised by.
         pub raised_by: AccountIdOf<T>,
         /// The votes of each jury.
-        pub votes: BoundedVotes<T>,
+        pub votes: BoundedVotes<T, I>,
         /// The party responsible for the vote.
-        pub jury: BoundedVec<AccountIdOf<T>, <T as Config>::MaxJurySize>,
+        pub jury: BoundedVec<AccountIdOf<T>, <T as Config<I>>::MaxJurySize>,
         /// The specific entities the dispute is raised upon.
         pub specifiers: BoundedVec<T::SpecificId, T::MaxSpecifics>,
-        /// Marks wether a dispute has already had its time limit extended.
-        pub is_extended: bool,
+        /// The number of times the time limit of the dispute has been extended.
+        pub extensions: u32,
+        /// The jurors that have voted for the next extension of the dispute.
+        pub extension_votes: BoundedVec<AccountIdOf<T>, <T as Config<I>>::MaxJurySize>,
         /// The expiration block of the dispute.
         pub expiration: BlockNumberFor<T>,
+        /// The bond taken from the raiser, settled by the DisputeHooks on completion.
+        pub bond: Option<T::BondId>,
+        /// The block voting opens, evidence can be submitted until then.
+        pub voting_starts: BlockNumberFor<T>,
+        /// The evidence attached to the dispute.
+        pub evidence: BoundedVec<Evidence<T>, T::MaxEvidence>,
+        /// The block the reveal period starts if votes are committed in secret.
+        pub reveal_starts: Option<BlockNumberFor<T>>,
+        /// The secret votes committed by the jury that have not yet been revealed.
+        pub commitments: BoundedBTreeMap<AccountIdOf<T>, T::Hash, <T as Config<I>>::MaxJurySize>,
     }
 
-    impl<T: Config> Dispute<T> {
+    impl<T: Config<I>, I: 'static> Dispute<T, I> {
         // Create a new dispute and setup state so that pallet will operate as intended.
         pub(crate) fn new(
             dispute_key: T::DisputeKey,
             raised_by: AccountIdOf<T>,
             jury: BoundedVec<AccountIdOf<T>, T::MaxJurySize>,
             specifiers: BoundedVec<T::SpecificId, T::MaxSpecifics>,
+            bond: Option<T::BondId>,
         ) -> Result<(), DispatchError> {
             ensure!(
-                !Disputes::<T>::contains_key(dispute_key),
-                Error::<T>::DisputeAlreadyExists
+                !Disputes::<T, I>::contains_key(dispute_key)
+                    && !PendingVerdicts::<T, I>::contains_key(dispute_key),
+                Error::<T, I>::DisputeAlreadyExists
             );
 
-            let expiration_block =
-                frame_system::Pallet::<T>::block_number().saturating_add(T::VotingTimeLimit::get());
+            // The voting time limit starts once the evidence period has ended.
+            let voting_starts =
+                frame_system::Pallet::<T>::block_number().saturating_add(T::EvidencePeriod::get());
+            let voting_ends = voting_starts.saturating_add(T::VotingTimeLimit::get());
+            // Secret votes are revealed after voting has ended.
+            let reveal_period = T::RevealPeriod::get();
+            let reveal_starts = (!reveal_period.is_zero()).then_some(voting_ends);
+            let expiration_block = voting_ends.saturating_add(reveal_period);
+            // Jurors that already sit on MaxDisputesPerJuror disputes are left off the jury.
+            let mut jury = jury;
+            let mut skipped = Vec::new();
+            jury.retain(|juror| {
+                let has_room =
+                    JurorDisputeCount::<T, I>::get(juror) < T::MaxDisputesPerJuror::get();
+                if !has_room {
+                    skipped.push(juror.clone());
+                }
+                has_room
+            });
             let dispute = Self {
                 raised_by: raised_by.clone(),
                 jury,
                 votes: Default::default(),
                 specifiers,
-                is_extended: false,
+                extensions: 0,
+                extension_votes: Default::default(),
                 expiration: expiration_block,
+                bond,
+                voting_starts,
+                evidence: Default::default(),
+                reveal_starts,
+                commitments: Default::default(),
             };
 
-            Disputes::<T>::insert(dispute_key, dispute);
-            DisputesFinaliseOn::<T>::try_mutate(expiration_block, |b_vec| {
+            for juror in dispute.jury.iter() {
+                crate::Pallet::<T, I>::add_juror_dispute(juror, dispute_key)?;
+            }
+            Disputes::<T, I>::insert(dispute_key, dispute);
+            DisputesFinaliseOn::<T, I>::try_mutate(expiration_block, |b_vec| {
                 b_vec
                     .try_push(dispute_key)
-                    .map_err(|_| Error::<T>::TooManyDisputesThisBlock)?;
+                    .map_err(|_| Error::<T, I>::TooManyDisputesThisBlock)?;
 
                 Ok::<(), DispatchError>(())
             })?;
 
-            crate::Pallet::<T>::deposit_event(Event::<T>::DisputeRaised {
+            crate::Pallet::<T, I>::deposit_event(Event::<T, I>::DisputeRaised {
                 who: raised_by,
                 dispute_key,
             });
+            for juror in skipped {
+                crate::Pallet::<T, I>::deposit_event(Event::<T, I>::JurorSkipped {
+                    dispute_key,
+                    juror,
+                });
+            }
             Ok(())
         }
 
-        /// Calculate the winner in a dispute at the current moment.
+        /// Whether `count` jurors make up a majority of the jury.
+        pub(crate) fn is_majority(&self, count: usize) -> bool {
+            count.saturating_mul(2) > self.jury.len()
+        }
+
+        /// Count the yay and nay votes cast on a specific.
+        fn tally(&self, specific_id: &T::SpecificId) -> (u32, u32) {
+            self.votes
+                .values()
+                .filter_map(|specific_votes| specific_votes.get(specific_id))
+                .fold((0u32, 0u32), |(yay, nay), &vote| {
+                    if vote {
+                        (yay.saturating_add(1), nay)
+                    } else {
+                        (yay, nay.saturating_add(1))
+                    }
+                })
+        }
+
+        /// Calculate the winner of a specific in a dispute at the current moment.
         /// This ofcourse is subject to change if more votes are had.
-        pub(crate) fn calculate_winner(&self) -> DisputeResult {
-            if self.votes.values().len() == 0 {
-                return DisputeResult::Failure;
-            }
+        pub fn calculate_winner(&self, specific_id: &T::SpecificId) -> DisputeResult {
+            let (yay, nay) = self.tally(specific_id);
+            T::DisputeOutcomePolicy::result(yay, nay, self.jury.len() as u32)
+        }
 
-            if self.votes.values().filter(|&&x| x).count()
-                >= self.votes.values().filter(|&&x| !x).count()
-            {
-                DisputeResult::Success
-            } else {
-                DisputeResult::Failure
-            }
+        /// Calculate the winner of every specific in a dispute at the current moment.
+        pub fn calculate_verdicts(&self) -> Verdicts<T, I> {
+            let verdicts = self
+                .specifiers
+                .iter()
+                .map(|specific_id| (*specific_id, self.calculate_winner(specific_id)))
+                .collect::<Vec<_>>();
+            // The verdicts are bound by the same bound as the specifiers.
+            verdicts.try_into().unwrap_or_default()
+        }
+
+        /// Apply the same result to every specific in the dispute.
+        pub fn verdicts_with(&self, result: DisputeResult) -> Verdicts<T, I> {
+            let verdicts = self
+                .specifiers
+                .iter()
+                .map(|specific_id| (*specific_id, result))
+                .collect::<Vec<_>>();
+            verdicts.try_into().unwrap_or_default()
+        }
+
+        /// Get the verdicts if the result of every specific is decided by the DisputeOutcomePolicy,
+        /// however the outstanding jurors vote.
+        pub(crate) fn decided_verdicts(&self) -> Option<Verdicts<T, I>> {
+            let jury_size = self.jury.len() as u32;
+            let verdicts = self
+                .specifiers
+                .iter()
+                .map(|specific_id| {
+                    let (yay, nay) = self.tally(specific_id);
+                    T::DisputeOutcomePolicy::decided_result(yay, nay, jury_size)
+                        .map(|result| (*specific_id, result))
+                })
+                .collect::<Option<Vec<_>>>()?;
+            // The verdicts are bound by the same bound as the specifiers.
+            verdicts.try_into().ok()
         }
 
         /// Falliably finalise a dispute.
         /// This method will clean up storage associated with a dispute and the dispute itself.
-        pub(crate) fn try_finalise_with_result(
+        /// The verdicts are handed to the DisputeHooks once the appeal window closes.
+        pub(crate) fn try_finalise_with_verdicts(
             dispute_key: T::DisputeKey,
-            result: DisputeResult,
+            verdicts: Verdicts<T, I>,
         ) -> Result<(), DispatchError> {
-            let dispute = Disputes::<T>::get(dispute_key).ok_or(Error::<T>::DisputeDoesNotExist)?;
-            DisputesFinaliseOn::<T>::try_mutate(dispute.expiration, |finalising| {
+            let dispute =
+                Disputes::<T, I>::get(dispute_key).ok_or(Error::<T, I>::DisputeDoesNotExist)?;
+            DisputesFinaliseOn::<T, I>::try_mutate(dispute.expiration, |finalising| {
                 if let Some(index) = finalising
                     .iter()
                     .position(|finalising_key| finalising_key == &dispute_key)
@@ -398,54 +1157,166 @@ pub mod pallet {
                 } else {
                     // If the index for the dispute is not found in DisputeFinalisingOn then,
                     // we have a state mismatch and autofinalisation may not work so throw an error.
-                    return Err(Error::<T>::AutoFinaliseStateMismatch.into());
+                    return Err(Error::<T, I>::AutoFinaliseStateMismatch.into());
                 }
 
                 Ok::<(), DispatchError>(())
             })?;
 
-            // Remove the dispute once the hooks gets successfully completed.
-            Disputes::<T>::remove(dispute_key);
-            crate::Pallet::<T>::deposit_event(Event::<T>::DisputeCompleted {
+            Disputes::<T, I>::remove(dispute_key);
+            let _ = crate::Pallet::<T, I>::remove_jury_disputes(dispute_key, &dispute.jury);
+            crate::Pallet::<T, I>::deposit_event(Event::<T, I>::DisputeCompleted {
                 dispute_key,
-                dispute_result: result,
+                verdicts: verdicts.clone(),
             });
 
             // Dont need to return the weight here.
-            let _ = T::DisputeHooks::on_dispute_complete(
-                dispute_key,
-                dispute.specifiers.into_inner(),
-                result,
-            );
+            let _ = crate::Pallet::<T, I>::open_appeal_window(dispute_key, dispute, verdicts);
             Ok(())
         }
 
-        /// Try and add a vote to self.
+        /// Try and add a vote on every specific to self.
         /// Fails if who is not part of the jury or DisputesPerBlock bound is violated.
         pub(crate) fn try_add_vote(
             &mut self,
             who: AccountIdOf<T>,
             is_yay: bool,
             dispute_key: T::DisputeKey,
-        ) -> Result<BoundedVotes<T>, DispatchError> {
-            ensure!(
-                self.jury.iter().any(|e| e == &who),
-                Error::<T>::NotAJuryAccount
-            );
-
-            self.votes
-                .try_insert(who.clone(), is_yay)
-                .map_err(|_| Error::<T>::TooManyDisputeVotes)?;
+        ) -> Result<BoundedVotes<T, I>, DispatchError> {
+            let mut specific_votes: SpecificVotes<T, I> = BoundedBTreeMap::new();
+            for specific_id in self.specifiers.iter() {
+                specific_votes
+                    .try_insert(*specific_id, is_yay)
+                    .map_err(|_| Error::<T, I>::TooManyDisputeVotes)?;
+            }
+            self.try_insert_votes(who.clone(), specific_votes)?;
 
-            crate::Pallet::<T>::deposit_event(Event::<T>::DisputeVotedOn {
+            crate::Pallet::<T, I>::deposit_event(Event::<T, I>::DisputeVotedOn {
                 who,
                 dispute_key,
                 vote: is_yay,
             });
 
             //TODO: This is kinda messy, ideally we dont want to clone such a big data set.
-            Ok::<BoundedVotes<T>, DispatchError>(self.votes.clone())
+            Ok::<BoundedVotes<T, I>, DispatchError>(self.votes.clone())
         }
+
+        /// Try and add a vote on each specific to self.
+        /// Fails if who is not part of the jury or the votes do not cover exactly the specifics of the dispute.
+        pub(crate) fn try_add_specific_votes(
+            &mut self,
+            who: AccountIdOf<T>,
+            votes: BoundedVec<(T::SpecificId, bool), T::MaxSpecifics>,
+            dispute_key: T::DisputeKey,
+        ) -> Result<BoundedVotes<T, I>, DispatchError> {
+            let mut specific_votes: SpecificVotes<T, I> = BoundedBTreeMap::new();
+            for (specific_id, is_yay) in votes.into_iter() {
+                ensure!(
+                    self.specifiers.contains(&specific_id),
+                    Error::<T, I>::SpecificNotInDispute
+                );
+                specific_votes
+                    .try_insert(specific_id, is_yay)
+                    .map_err(|_| Error::<T, I>::TooManyDisputeVotes)?;
+            }
+            ensure!(
+                self.specifiers
+                    .iter()
+                    .all(|specific_id| specific_votes.contains_key(specific_id)),
+                Error::<T, I>::MissingSpecificVote
+            );
+            self.try_insert_votes(who.clone(), specific_votes.clone())?;
+
+            crate::Pallet::<T, I>::deposit_event(Event::<T, I>::DisputeSpecificsVotedOn {
+                who,
+                dispute_key,
+                votes: specific_votes,
+            });
+
+            Ok::<BoundedVotes<T, I>, DispatchError>(self.votes.clone())
+        }
+
+        fn try_insert_votes(
+            &mut self,
+            who: AccountIdOf<T>,
+            specific_votes: SpecificVotes<T, I>,
+        ) -> Result<(), DispatchError> {
+            ensure!(
+                self.jury.iter().any(|e| e == &who),
+                Error::<T, I>::NotAJuryAccount
+            );
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= self.voting_starts,
+                Error::<T, I>::VotingNotOpen
+            );
+
+            self.votes
+                .try_insert(who, specific_votes)
+                .map_err(|_| Error::<T, I>::TooManyDisputeVotes)?;
+            Ok(())
+        }
+    }
+
+    /// The role of the account that submitted a piece of evidence.
+    #[derive(Clone, Copy, PartialEq, Eq, Debug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    pub enum EvidenceRole {
+        Raiser,
+        Defendant,
+        Juror,
+    }
+
+    /// A piece of evidence attached to a dispute.
+    #[derive(
+        Encode,
+        Decode,
+        PartialEqNoBound,
+        EqNoBound,
+        CloneNoBound,
+        RuntimeDebugNoBound,
+        TypeInfo,
+        MaxEncodedLen,
+    )]
+    #[scale_info(skip_type_params(T))]
+    pub struct Evidence<T: frame_system::Config> {
+        /// Who submitted the evidence.
+        pub submitted_by: AccountIdOf<T>,
+        /// The role of the submitter in the dispute.
+        pub role: EvidenceRole,
+        /// The hash of the evidence, usually an IPFS CID.
+        pub hash: EvidenceHash,
+    }
+
+    /// The verdicts of a completed dispute waiting for the appeal window to close or an appeal to be decided.
+    #[derive(
+        Encode,
+        Decode,
+        PartialEqNoBound,
+        EqNoBound,
+        CloneNoBound,
+        RuntimeDebugNoBound,
+        TypeInfo,
+        MaxEncodedLen,
+    )]
+    #[scale_info(skip_type_params(T, I))]
+    pub struct PendingVerdict<T: Config<I>, I: 'static = ()> {
+        /// Who raised the dispute.
+        pub raised_by: AccountIdOf<T>,
+        /// The verdicts reached by the jury.
+        pub verdicts: Verdicts<T, I>,
+        /// The bond taken from the raiser, settled by the DisputeHooks on completion.
+        pub bond: Option<T::BondId>,
+        /// The block the verdicts can no longer be appealed.
+        pub appeal_window_closes: BlockNumberFor<T>,
+        /// Who appealed the verdicts, if anyone.
+        pub appealed_by: Option<AccountIdOf<T>>,
+        /// The bond taken from the appellant, settled once the appeal is decided or lapses.
+        pub appeal_bond: Option<T::BondId>,
+        /// The block an undecided appeal lapses, if the verdicts have been appealed.
+        pub appeal_lapses: Option<BlockNumberFor<T>>,
+        /// The jury of the dispute, settled once the verdicts are final.
+        pub jury: BoundedVec<AccountIdOf<T>, <T as Config<I>>::MaxJurySize>,
+        /// The votes cast by the jury.
+        pub votes: BoundedVotes<T, I>,
     }
 
     #[derive(Clone, Copy, PartialEq, Debug, Encode, Decode, TypeInfo)]
@@ -453,7 +1324,7 @@ pub mod pallet {
         pub votes: T,
     }
 
-    #[derive(Clone, Copy, PartialEq, Debug, Encode, Decode, TypeInfo)]
+    #[derive(Clone, Copy, PartialEq, Eq, Debug, Encode, Decode, TypeInfo, MaxEncodedLen)]
     pub enum DisputeResult {
         Success = 0,
         Failure = 1,
@@ -461,10 +1332,17 @@ pub mod pallet {
 
     pub trait WeightInfoT {
         fn vote_on_dispute() -> Weight;
+        fn vote_on_specifics() -> Weight;
         fn extend_dispute() -> Weight;
         fn raise_dispute() -> Weight;
         fn force_succeed_dispute() -> Weight;
         fn force_fail_dispute() -> Weight;
         fn calculate_winner() -> Weight;
+        fn appeal_dispute() -> Weight;
+        fn decide_appeal() -> Weight;
+        fn submit_evidence() -> Weight;
+        fn commit_vote() -> Weight;
+        fn reveal_vote() -> Weight;
+        fn cancel_dispute() -> Weight;
     }
 }
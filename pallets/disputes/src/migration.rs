@@ -0,0 +1,1136 @@
+use crate::*;
+use frame_support::traits::OnRuntimeUpgrade;
+use frame_support::{pallet_prelude::*, *};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::traits::Zero;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// Each juror voted once for every specific in the dispute.
+pub type BoolVotes<T, I = ()> =
+    BoundedBTreeMap<AccountIdOf<T>, bool, <T as Config<I>>::MaxJurySize>;
+
+mod v0 {
+    use super::*;
+
+    #[derive(Encode, Decode, PartialEq, Eq, Clone, Debug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T, I))]
+    pub struct DisputeV0<T: Config<I>, I: 'static = ()> {
+        pub raised_by: AccountIdOf<T>,
+        pub votes: BoolVotes<T, I>,
+        pub jury: BoundedVec<AccountIdOf<T>, <T as Config<I>>::MaxJurySize>,
+        pub specifiers: BoundedVec<T::SpecificId, T::MaxSpecifics>,
+        pub is_extended: bool,
+        pub expiration: BlockNumberFor<T>,
+    }
+}
+
+pub mod v1 {
+    use super::*;
+
+    #[derive(Encode, Decode, PartialEq, Eq, Clone, Debug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T, I))]
+    pub struct DisputeV1<T: Config<I>, I: 'static = ()> {
+        pub raised_by: AccountIdOf<T>,
+        pub votes: BoolVotes<T, I>,
+        pub jury: BoundedVec<AccountIdOf<T>, <T as Config<I>>::MaxJurySize>,
+        pub specifiers: BoundedVec<T::SpecificId, T::MaxSpecifics>,
+        pub is_extended: bool,
+        pub expiration: BlockNumberFor<T>,
+        pub bond: Option<T::BondId>,
+    }
+
+    #[storage_alias]
+    pub type Disputes<T: Config<I>, I: 'static> = StorageMap<
+        Pallet<T, I>,
+        Blake2_128Concat,
+        <T as Config<I>>::DisputeKey,
+        DisputeV1<T, I>,
+        OptionQuery,
+    >;
+
+    pub struct MigrateToV1<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV1<T, I> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            log::warn!( target: "pallet-disputes", "Running pre_upgrade()");
+            let onchain = <Pallet<T, I> as GetStorageVersion>::on_chain_storage_version();
+
+            ensure!(onchain == 0, "Onchain must be set to v0");
+            Ok((v1::Disputes::<T, I>::iter_keys().count() as u32).encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let mut weight = T::DbWeight::get().reads_writes(1, 1);
+            log::warn!("****** STARTING MIGRATION *****");
+
+            let onchain = <Pallet<T, I> as GetStorageVersion>::on_chain_storage_version();
+            if onchain == 0 {
+                migrate_dispute_bonds::<T, I>(&mut weight);
+                StorageVersion::new(1).put::<Pallet<T, I>>();
+                log::warn!("v1 has been successfully applied");
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 1));
+            } else {
+                log::warn!("Skipping v1 due to mismatched version, this be removed from Executive");
+                weight = weight.saturating_add(T::DbWeight::get().reads(1));
+            }
+
+            log::warn!("****** ENDING MIGRATION *****");
+            weight
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            log::warn!( target:  "pallet-disputes", "Running post_upgrade()");
+            let dispute_count: u32 = Decode::decode(&mut state.as_slice())
+                .map_err(|_| "Failed to decode the pre upgrade state")?;
+            ensure!(
+                v1::Disputes::<T, I>::iter_values().count() as u32 == dispute_count,
+                "All disputes must be migrated"
+            );
+            ensure!(
+                Pallet::<T, I>::on_chain_storage_version() == 1,
+                "Storage version should be v1 after the migration"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Disputes raised before bonds existed were raised for free, so they have no bond to settle.
+    pub(super) fn migrate_dispute_bonds<T: Config<I>, I: 'static>(weight: &mut Weight) {
+        v1::Disputes::<T, I>::translate(|_dispute_key, dispute: v0::DisputeV0<T, I>| {
+            *weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+            Some(DisputeV1 {
+                raised_by: dispute.raised_by,
+                votes: dispute.votes,
+                jury: dispute.jury,
+                specifiers: dispute.specifiers,
+                is_extended: dispute.is_extended,
+                expiration: dispute.expiration,
+                bond: None,
+            })
+        });
+    }
+}
+
+pub mod v2 {
+    use super::*;
+
+    #[derive(Encode, Decode, PartialEq, Eq, Clone, Debug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T, I))]
+    pub struct DisputeV2<T: Config<I>, I: 'static = ()> {
+        pub raised_by: AccountIdOf<T>,
+        pub votes: BoundedVotes<T, I>,
+        pub jury: BoundedVec<AccountIdOf<T>, <T as Config<I>>::MaxJurySize>,
+        pub specifiers: BoundedVec<T::SpecificId, T::MaxSpecifics>,
+        pub is_extended: bool,
+        pub expiration: BlockNumberFor<T>,
+        pub bond: Option<T::BondId>,
+    }
+
+    #[storage_alias]
+    pub type Disputes<T: Config<I>, I: 'static> = StorageMap<
+        Pallet<T, I>,
+        Blake2_128Concat,
+        <T as Config<I>>::DisputeKey,
+        DisputeV2<T, I>,
+        OptionQuery,
+    >;
+
+    pub struct MigrateToV2<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV2<T, I> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            log::warn!( target: "pallet-disputes", "Running pre_upgrade()");
+            let current = <Pallet<T, I> as GetStorageVersion>::current_storage_version();
+            let onchain = <Pallet<T, I> as GetStorageVersion>::on_chain_storage_version();
+
+            ensure!(onchain == 1, "Onchain must be set to v1");
+            Ok((v1::Disputes::<T, I>::iter_keys().count() as u32).encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let mut weight = T::DbWeight::get().reads_writes(1, 1);
+            log::warn!("****** STARTING MIGRATION *****");
+
+            let onchain = <Pallet<T, I> as GetStorageVersion>::on_chain_storage_version();
+            if onchain == 1 {
+                migrate_specific_votes::<T, I>(&mut weight);
+                StorageVersion::new(2).put::<Pallet<T, I>>();
+                log::warn!("v2 has been successfully applied");
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 1));
+            } else {
+                log::warn!("Skipping v2 due to mismatched version, this be removed from Executive");
+                weight = weight.saturating_add(T::DbWeight::get().reads(1));
+            }
+
+            log::warn!("****** ENDING MIGRATION *****");
+            weight
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            log::warn!( target:  "pallet-disputes", "Running post_upgrade()");
+            let dispute_count: u32 = Decode::decode(&mut state.as_slice())
+                .map_err(|_| "Failed to decode the pre upgrade state")?;
+            ensure!(
+                v2::Disputes::<T, I>::iter_values().count() as u32 == dispute_count,
+                "All disputes must be migrated"
+            );
+            ensure!(
+                Pallet::<T, I>::on_chain_storage_version() == 2,
+                "Storage version should be v2 after the migration"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// A vote cast before per specific voting applies to every specific in the dispute.
+    pub(super) fn migrate_specific_votes<T: Config<I>, I: 'static>(weight: &mut Weight) {
+        v2::Disputes::<T, I>::translate(|_dispute_key, dispute: v1::DisputeV1<T, I>| {
+            *weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+            let mut votes: BoundedVotes<T, I> = BoundedBTreeMap::new();
+            dispute.votes.into_iter().for_each(|(juror, is_yay)| {
+                let mut specific_votes: SpecificVotes<T, I> = BoundedBTreeMap::new();
+                dispute.specifiers.iter().for_each(|specific_id| {
+                    // The bound is the same as the specifiers so this cannot fail.
+                    let _ = specific_votes.try_insert(*specific_id, is_yay);
+                });
+                // The bound has not changed so this cannot fail.
+                let _ = votes.try_insert(juror, specific_votes);
+            });
+
+            Some(DisputeV2 {
+                raised_by: dispute.raised_by,
+                votes,
+                jury: dispute.jury,
+                specifiers: dispute.specifiers,
+                is_extended: dispute.is_extended,
+                expiration: dispute.expiration,
+                bond: dispute.bond,
+            })
+        });
+    }
+}
+
+pub mod v3 {
+    use super::*;
+
+    #[derive(Encode, Decode, PartialEq, Eq, Clone, Debug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T, I))]
+    pub struct DisputeV3<T: Config<I>, I: 'static = ()> {
+        pub raised_by: AccountIdOf<T>,
+        pub votes: BoundedVotes<T, I>,
+        pub jury: BoundedVec<AccountIdOf<T>, <T as Config<I>>::MaxJurySize>,
+        pub specifiers: BoundedVec<T::SpecificId, T::MaxSpecifics>,
+        pub is_extended: bool,
+        pub expiration: BlockNumberFor<T>,
+        pub bond: Option<T::BondId>,
+        pub voting_starts: BlockNumberFor<T>,
+        pub evidence: BoundedVec<Evidence<T>, T::MaxEvidence>,
+    }
+
+    #[storage_alias]
+    pub type Disputes<T: Config<I>, I: 'static> = StorageMap<
+        Pallet<T, I>,
+        Blake2_128Concat,
+        <T as Config<I>>::DisputeKey,
+        DisputeV3<T, I>,
+        OptionQuery,
+    >;
+
+    pub struct MigrateToV3<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV3<T, I> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            log::warn!( target: "pallet-disputes", "Running pre_upgrade()");
+            let current = <Pallet<T, I> as GetStorageVersion>::current_storage_version();
+            let onchain = <Pallet<T, I> as GetStorageVersion>::on_chain_storage_version();
+
+            ensure!(onchain == 2, "Onchain must be set to v2");
+            Ok((v2::Disputes::<T, I>::iter_keys().count() as u32).encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let mut weight = T::DbWeight::get().reads_writes(1, 1);
+            log::warn!("****** STARTING MIGRATION *****");
+
+            let onchain = <Pallet<T, I> as GetStorageVersion>::on_chain_storage_version();
+            if onchain == 2 {
+                migrate_dispute_evidence::<T, I>(&mut weight);
+                StorageVersion::new(3).put::<Pallet<T, I>>();
+                log::warn!("v3 has been successfully applied");
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 1));
+            } else {
+                log::warn!("Skipping v3 due to mismatched version, this be removed from Executive");
+                weight = weight.saturating_add(T::DbWeight::get().reads(1));
+            }
+
+            log::warn!("****** ENDING MIGRATION *****");
+            weight
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            log::warn!( target:  "pallet-disputes", "Running post_upgrade()");
+            let dispute_count: u32 = Decode::decode(&mut state.as_slice())
+                .map_err(|_| "Failed to decode the pre upgrade state")?;
+            ensure!(
+                v3::Disputes::<T, I>::iter_values().count() as u32 == dispute_count,
+                "All disputes must be migrated"
+            );
+            ensure!(
+                Pallet::<T, I>::on_chain_storage_version() == 3,
+                "Storage version should be v3 after the migration"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Disputes raised before the evidence period existed are already open for voting and have no evidence.
+    pub(super) fn migrate_dispute_evidence<T: Config<I>, I: 'static>(weight: &mut Weight) {
+        v3::Disputes::<T, I>::translate(|_dispute_key, dispute: v2::DisputeV2<T, I>| {
+            *weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+            Some(DisputeV3 {
+                raised_by: dispute.raised_by,
+                votes: dispute.votes,
+                jury: dispute.jury,
+                specifiers: dispute.specifiers,
+                is_extended: dispute.is_extended,
+                expiration: dispute.expiration,
+                bond: dispute.bond,
+                voting_starts: Zero::zero(),
+                evidence: Default::default(),
+            })
+        });
+    }
+}
+
+pub mod v4 {
+    use super::*;
+
+    #[derive(Encode, Decode, PartialEq, Eq, Clone, Debug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T, I))]
+    pub struct DisputeV4<T: Config<I>, I: 'static = ()> {
+        pub raised_by: AccountIdOf<T>,
+        pub votes: BoundedVotes<T, I>,
+        pub jury: BoundedVec<AccountIdOf<T>, <T as Config<I>>::MaxJurySize>,
+        pub specifiers: BoundedVec<T::SpecificId, T::MaxSpecifics>,
+        pub is_extended: bool,
+        pub expiration: BlockNumberFor<T>,
+        pub bond: Option<T::BondId>,
+        pub voting_starts: BlockNumberFor<T>,
+        pub evidence: BoundedVec<Evidence<T>, T::MaxEvidence>,
+        pub reveal_starts: Option<BlockNumberFor<T>>,
+        pub commitments: BoundedBTreeMap<AccountIdOf<T>, T::Hash, <T as Config<I>>::MaxJurySize>,
+    }
+
+    #[storage_alias]
+    pub type Disputes<T: Config<I>, I: 'static> = StorageMap<
+        Pallet<T, I>,
+        Blake2_128Concat,
+        <T as Config<I>>::DisputeKey,
+        DisputeV4<T, I>,
+        OptionQuery,
+    >;
+
+    pub struct MigrateToV4<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV4<T, I> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            log::warn!( target: "pallet-disputes", "Running pre_upgrade()");
+            let current = <Pallet<T, I> as GetStorageVersion>::current_storage_version();
+            let onchain = <Pallet<T, I> as GetStorageVersion>::on_chain_storage_version();
+
+            ensure!(onchain == 3, "Onchain must be set to v3");
+            Ok((v3::Disputes::<T, I>::iter_keys().count() as u32).encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let mut weight = T::DbWeight::get().reads_writes(1, 1);
+            log::warn!("****** STARTING MIGRATION *****");
+
+            let onchain = <Pallet<T, I> as GetStorageVersion>::on_chain_storage_version();
+            if onchain == 3 {
+                migrate_secret_votes::<T, I>(&mut weight);
+                StorageVersion::new(4).put::<Pallet<T, I>>();
+                log::warn!("v4 has been successfully applied");
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 1));
+            } else {
+                log::warn!("Skipping v4 due to mismatched version, this be removed from Executive");
+                weight = weight.saturating_add(T::DbWeight::get().reads(1));
+            }
+
+            log::warn!("****** ENDING MIGRATION *****");
+            weight
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            log::warn!( target:  "pallet-disputes", "Running post_upgrade()");
+            let dispute_count: u32 = Decode::decode(&mut state.as_slice())
+                .map_err(|_| "Failed to decode the pre upgrade state")?;
+            ensure!(
+                v4::Disputes::<T, I>::iter_values().count() as u32 == dispute_count,
+                "All disputes must be migrated"
+            );
+            ensure!(
+                Pallet::<T, I>::on_chain_storage_version() == 4,
+                "Storage version should be v4 after the migration"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Disputes raised before secret voting existed keep voting in the open.
+    pub(super) fn migrate_secret_votes<T: Config<I>, I: 'static>(weight: &mut Weight) {
+        v4::Disputes::<T, I>::translate(|_dispute_key, dispute: v3::DisputeV3<T, I>| {
+            *weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+            Some(DisputeV4 {
+                raised_by: dispute.raised_by,
+                votes: dispute.votes,
+                jury: dispute.jury,
+                specifiers: dispute.specifiers,
+                is_extended: dispute.is_extended,
+                expiration: dispute.expiration,
+                bond: dispute.bond,
+                voting_starts: dispute.voting_starts,
+                evidence: dispute.evidence,
+                reveal_starts: None,
+                commitments: Default::default(),
+            })
+        });
+    }
+}
+
+pub mod v5 {
+    use super::*;
+
+    pub struct MigrateToV5<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV5<T, I> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            log::warn!( target: "pallet-disputes", "Running pre_upgrade()");
+            let current = <Pallet<T, I> as GetStorageVersion>::current_storage_version();
+            let onchain = <Pallet<T, I> as GetStorageVersion>::on_chain_storage_version();
+
+            ensure!(
+                current == 5 && onchain == 4,
+                "Current version must be set to v5 and onchain to v4"
+            );
+            Ok((v4::Disputes::<T, I>::iter_keys().count() as u32).encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let mut weight = T::DbWeight::get().reads_writes(1, 1);
+            log::warn!("****** STARTING MIGRATION *****");
+
+            let current = <Pallet<T, I> as GetStorageVersion>::current_storage_version();
+            let onchain = <Pallet<T, I> as GetStorageVersion>::on_chain_storage_version();
+            if current == 5 && onchain == 4 {
+                migrate_dispute_extensions::<T, I>(&mut weight);
+                current.put::<Pallet<T, I>>();
+                log::warn!("v5 has been successfully applied");
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 1));
+            } else {
+                log::warn!("Skipping v5 due to mismatched version, this be removed from Executive");
+                weight = weight.saturating_add(T::DbWeight::get().reads(1));
+            }
+
+            log::warn!("****** ENDING MIGRATION *****");
+            weight
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            log::warn!( target:  "pallet-disputes", "Running post_upgrade()");
+            let dispute_count: u32 = Decode::decode(&mut state.as_slice())
+                .map_err(|_| "Failed to decode the pre upgrade state")?;
+            ensure!(
+                crate::Disputes::<T, I>::iter_values().count() as u32 == dispute_count,
+                "All disputes must be migrated"
+            );
+            ensure!(
+                Pallet::<T, I>::on_chain_storage_version() == 5,
+                "Storage version should be v5 after the migration"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// A dispute that was already extended has used up one extension and no juror has voted for the next.
+    pub(super) fn migrate_dispute_extensions<T: Config<I>, I: 'static>(weight: &mut Weight) {
+        crate::Disputes::<T, I>::translate(|_dispute_key, dispute: v4::DisputeV4<T, I>| {
+            *weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+            Some(crate::Dispute {
+                raised_by: dispute.raised_by,
+                votes: dispute.votes,
+                jury: dispute.jury,
+                specifiers: dispute.specifiers,
+                extensions: dispute.is_extended.into(),
+                extension_votes: Default::default(),
+                expiration: dispute.expiration,
+                bond: dispute.bond,
+                voting_starts: dispute.voting_starts,
+                evidence: dispute.evidence,
+                reveal_starts: dispute.reveal_starts,
+                commitments: dispute.commitments,
+            })
+        });
+    }
+}
+
+pub mod v6 {
+    use super::*;
+
+    #[derive(Encode, Decode, PartialEq, Eq, Clone, Debug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T, I))]
+    pub struct PendingVerdictV6<T: Config<I>, I: 'static = ()> {
+        pub raised_by: AccountIdOf<T>,
+        pub verdicts: Verdicts<T, I>,
+        pub bond: Option<T::BondId>,
+        pub appeal_window_closes: BlockNumberFor<T>,
+        pub appealed_by: Option<AccountIdOf<T>>,
+    }
+
+    #[storage_alias]
+    pub type PendingVerdicts<T: Config<I>, I: 'static> = StorageMap<
+        Pallet<T, I>,
+        Blake2_128Concat,
+        <T as Config<I>>::DisputeKey,
+        PendingVerdictV6<T, I>,
+        OptionQuery,
+    >;
+
+    pub struct MigrateToV6<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV6<T, I> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            log::warn!( target: "pallet-disputes", "Running pre_upgrade()");
+            let current = <Pallet<T, I> as GetStorageVersion>::current_storage_version();
+            let onchain = <Pallet<T, I> as GetStorageVersion>::on_chain_storage_version();
+
+            ensure!(
+                current == 6 && onchain == 5,
+                "Current version must be set to v6 and onchain to v5"
+            );
+            let seats = crate::Disputes::<T, I>::iter_values()
+                .map(|dispute| dispute.jury.len() as u32)
+                .sum::<u32>();
+            Ok(seats.encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let mut weight = T::DbWeight::get().reads_writes(1, 1);
+            log::warn!("****** STARTING MIGRATION *****");
+
+            let current = <Pallet<T, I> as GetStorageVersion>::current_storage_version();
+            let onchain = <Pallet<T, I> as GetStorageVersion>::on_chain_storage_version();
+            if current == 6 && onchain == 5 {
+                migrate_juror_disputes::<T, I>(&mut weight);
+                current.put::<Pallet<T, I>>();
+                log::warn!("v6 has been successfully applied");
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 1));
+            } else {
+                log::warn!("Skipping v6 due to mismatched version, this be removed from Executive");
+                weight = weight.saturating_add(T::DbWeight::get().reads(1));
+            }
+
+            log::warn!("****** ENDING MIGRATION *****");
+            weight
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            log::warn!( target:  "pallet-disputes", "Running post_upgrade()");
+            let seats: u32 = Decode::decode(&mut state.as_slice())
+                .map_err(|_| "Failed to decode the pre upgrade state")?;
+            ensure!(
+                crate::JurorDisputes::<T, I>::iter_keys().count() as u32 == seats,
+                "Every juror of an open dispute must be indexed"
+            );
+            ensure!(
+                Pallet::<T, I>::on_chain_storage_version() == 6,
+                "Storage version should be v6 after the migration"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Index the jurors of every open dispute.
+    /// Existing disputes are indexed even if a juror ends up above MaxDisputesPerJuror,
+    /// the bound only applies to new disputes.
+    pub(super) fn migrate_juror_disputes<T: Config<I>, I: 'static>(weight: &mut Weight) {
+        crate::Disputes::<T, I>::iter().for_each(|(dispute_key, dispute)| {
+            *weight = weight.saturating_add(T::DbWeight::get().reads(1));
+            for juror in dispute.jury.iter() {
+                *weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 2));
+                crate::JurorDisputes::<T, I>::insert(juror, dispute_key, ());
+                crate::JurorDisputeCount::<T, I>::mutate(juror, |count| {
+                    *count = count.saturating_add(1)
+                });
+            }
+        });
+    }
+}
+
+pub mod v7 {
+    use super::*;
+
+    pub struct MigrateToV7<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV7<T, I> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            log::warn!( target: "pallet-disputes", "Running pre_upgrade()");
+            let current = <Pallet<T, I> as GetStorageVersion>::current_storage_version();
+            let onchain = <Pallet<T, I> as GetStorageVersion>::on_chain_storage_version();
+
+            ensure!(
+                current == 7 && onchain == 6,
+                "Current version must be set to v7 and onchain to v6"
+            );
+            Ok((crate::PendingVerdicts::<T, I>::iter_keys().count() as u32).encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let mut weight = T::DbWeight::get().reads_writes(1, 1);
+            log::warn!("****** STARTING MIGRATION *****");
+
+            let current = <Pallet<T, I> as GetStorageVersion>::current_storage_version();
+            let onchain = <Pallet<T, I> as GetStorageVersion>::on_chain_storage_version();
+            if current == 7 && onchain == 6 {
+                migrate_pending_verdicts::<T, I>(&mut weight);
+                current.put::<Pallet<T, I>>();
+                log::warn!("v7 has been successfully applied");
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 1));
+            } else {
+                log::warn!("Skipping v7 due to mismatched version, this be removed from Executive");
+                weight = weight.saturating_add(T::DbWeight::get().reads(1));
+            }
+
+            log::warn!("****** ENDING MIGRATION *****");
+            weight
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            log::warn!( target:  "pallet-disputes", "Running post_upgrade()");
+            let pending_count: u32 = Decode::decode(&mut state.as_slice())
+                .map_err(|_| "Failed to decode the pre upgrade state")?;
+            ensure!(
+                v8::PendingVerdicts::<T, I>::iter_values().count() as u32 == pending_count,
+                "All pending verdicts must be migrated"
+            );
+            ensure!(
+                Pallet::<T, I>::on_chain_storage_version() == 7,
+                "Storage version should be v7 after the migration"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// The jury of a verdict pending before v7 was settled when its dispute completed,
+    /// so it is given an empty jury and no votes to avoid settling it again.
+    /// Appeals already awaiting a decision are given the full AppealDecisionPeriod from now,
+    /// unless too many appeals already lapse on that block in which case they still wait for the AppealOrigin.
+    pub(super) fn migrate_pending_verdicts<T: Config<I>, I: 'static>(weight: &mut Weight) {
+        let appeal_lapses = frame_system::Pallet::<T>::block_number()
+            .saturating_add(<T as Config<I>>::AppealDecisionPeriod::get());
+        v8::PendingVerdicts::<T, I>::translate(
+            |dispute_key, pending: v6::PendingVerdictV6<T, I>| {
+                *weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+                let appeal_lapses = if pending.appealed_by.is_some() {
+                    *weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+                    let scheduled =
+                        crate::AppealsLapseOn::<T, I>::mutate(appeal_lapses, |lapsing| {
+                            lapsing.try_push(dispute_key).is_ok()
+                        });
+                    scheduled.then_some(appeal_lapses)
+                } else {
+                    None
+                };
+                Some(v8::PendingVerdictV7 {
+                    raised_by: pending.raised_by,
+                    verdicts: pending.verdicts,
+                    bond: pending.bond,
+                    appeal_window_closes: pending.appeal_window_closes,
+                    appealed_by: pending.appealed_by,
+                    appeal_lapses,
+                    jury: Default::default(),
+                    votes: Default::default(),
+                })
+            },
+        );
+    }
+}
+
+pub mod v8 {
+    use super::*;
+    use pallet_deposits::traits::DepositHandler;
+
+    #[derive(
+        Encode,
+        Decode,
+        PartialEqNoBound,
+        EqNoBound,
+        CloneNoBound,
+        RuntimeDebugNoBound,
+        TypeInfo,
+        MaxEncodedLen,
+    )]
+    #[scale_info(skip_type_params(T, I))]
+    pub struct PendingVerdictV7<T: Config<I>, I: 'static = ()> {
+        pub raised_by: AccountIdOf<T>,
+        pub verdicts: Verdicts<T, I>,
+        pub bond: Option<T::BondId>,
+        pub appeal_window_closes: BlockNumberFor<T>,
+        pub appealed_by: Option<AccountIdOf<T>>,
+        pub appeal_lapses: Option<BlockNumberFor<T>>,
+        pub jury: BoundedVec<AccountIdOf<T>, <T as Config<I>>::MaxJurySize>,
+        pub votes: BoundedVotes<T, I>,
+    }
+
+    #[storage_alias]
+    pub type PendingVerdicts<T: Config<I>, I: 'static> = StorageMap<
+        Pallet<T, I>,
+        Blake2_128Concat,
+        <T as Config<I>>::DisputeKey,
+        PendingVerdictV7<T, I>,
+        OptionQuery,
+    >;
+
+    /// `OldAppealBond` is the amount reserved from each appellant before appeal bonds were taken as deposits.
+    pub struct MigrateToV8<T, I, OldAppealBond>(PhantomData<(T, I, OldAppealBond)>);
+
+    impl<T: Config<I>, I: 'static, OldAppealBond: Get<BalanceOf<T, I>>> OnRuntimeUpgrade
+        for MigrateToV8<T, I, OldAppealBond>
+    {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            log::warn!( target: "pallet-disputes", "Running pre_upgrade()");
+            let current = <Pallet<T, I> as GetStorageVersion>::current_storage_version();
+            let onchain = <Pallet<T, I> as GetStorageVersion>::on_chain_storage_version();
+
+            ensure!(
+                current == 8 && onchain == 7,
+                "Current version must be set to v8 and onchain to v7"
+            );
+            Ok((PendingVerdicts::<T, I>::iter_keys().count() as u32).encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let mut weight = T::DbWeight::get().reads_writes(1, 1);
+            log::warn!("****** STARTING MIGRATION *****");
+
+            let current = <Pallet<T, I> as GetStorageVersion>::current_storage_version();
+            let onchain = <Pallet<T, I> as GetStorageVersion>::on_chain_storage_version();
+            if current == 8 && onchain == 7 {
+                migrate_appeal_bonds::<T, I>(OldAppealBond::get(), &mut weight);
+                current.put::<Pallet<T, I>>();
+                log::warn!("v8 has been successfully applied");
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 1));
+            } else {
+                log::warn!("Skipping v8 due to mismatched version, this be removed from Executive");
+                weight = weight.saturating_add(T::DbWeight::get().reads(1));
+            }
+
+            log::warn!("****** ENDING MIGRATION *****");
+            weight
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            log::warn!( target:  "pallet-disputes", "Running post_upgrade()");
+            let pending_count: u32 = Decode::decode(&mut state.as_slice())
+                .map_err(|_| "Failed to decode the pre upgrade state")?;
+            ensure!(
+                crate::PendingVerdicts::<T, I>::iter_values().count() as u32 == pending_count,
+                "All pending verdicts must be migrated"
+            );
+            ensure!(
+                Pallet::<T, I>::on_chain_storage_version() == 8,
+                "Storage version should be v8 after the migration"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Swap the bond reserved from each appellant for a deposit taken through the DepositHandler.
+    /// If the deposit cannot be taken the appeal carries on without a bond.
+    pub(super) fn migrate_appeal_bonds<T: Config<I>, I: 'static>(
+        old_appeal_bond: BalanceOf<T, I>,
+        weight: &mut Weight,
+    ) {
+        let currency_id = T::JurorRewardCurrencyId::get();
+        crate::PendingVerdicts::<T, I>::translate(
+            |dispute_key, pending: PendingVerdictV7<T, I>| {
+                *weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+                let mut appeal_bond = None;
+                if let Some(appellant) = pending.appealed_by.as_ref() {
+                    *weight = weight.saturating_add(T::DbWeight::get().reads_writes(3, 4));
+                    let _ = <T as Config<I>>::MultiCurrency::unreserve(
+                        currency_id,
+                        appellant,
+                        old_appeal_bond,
+                    );
+                    appeal_bond = <T as Config<I>>::DepositHandler::take_deposit(
+                        appellant.clone(),
+                        T::AppealStorageItem::get(),
+                        currency_id,
+                    )
+                    .map_err(|_| {
+                        log::warn!(
+                            target: "pallet-disputes",
+                            "The appeal on dispute {:?} is left without a bond",
+                            dispute_key
+                        )
+                    })
+                    .ok();
+                }
+                Some(crate::PendingVerdict {
+                    raised_by: pending.raised_by,
+                    verdicts: pending.verdicts,
+                    bond: pending.bond,
+                    appeal_window_closes: pending.appeal_window_closes,
+                    appealed_by: pending.appealed_by,
+                    appeal_bond,
+                    appeal_lapses: pending.appeal_lapses,
+                    jury: pending.jury,
+                    votes: pending.votes,
+                })
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::mock::*;
+    use crate::tests::test_utils::{get_jury, get_specifics};
+    use common_types::CurrencyId;
+    use orml_traits::MultiReservableCurrency;
+
+    #[storage_alias]
+    type Disputes<T: Config<I>, I: 'static> = StorageMap<
+        Pallet<T, I>,
+        Blake2_128Concat,
+        <T as Config<I>>::DisputeKey,
+        v0::DisputeV0<T, I>,
+        OptionQuery,
+    >;
+
+    #[test]
+    fn migrate_v0_to_v1() {
+        new_test_ext().execute_with(|| {
+            let dispute_key = 10;
+            let old_dispute = v0::DisputeV0::<Test> {
+                raised_by: ALICE,
+                votes: Default::default(),
+                jury: get_jury::<Test>(vec![BOB, CHARLIE]),
+                specifiers: get_specifics::<Test>(vec![0, 1]),
+                is_extended: false,
+                expiration: 100,
+            };
+            Disputes::<Test, ()>::insert(dispute_key, &old_dispute);
+
+            let mut weight = <Weight as Default>::default();
+            v1::migrate_dispute_bonds::<Test, ()>(&mut weight);
+
+            let migrated = v1::Disputes::<Test, ()>::get(dispute_key).unwrap();
+            assert_eq!(migrated.raised_by, old_dispute.raised_by);
+            assert_eq!(migrated.jury, old_dispute.jury);
+            assert_eq!(migrated.specifiers, old_dispute.specifiers);
+            assert_eq!(migrated.expiration, old_dispute.expiration);
+            assert_eq!(migrated.bond, None);
+        })
+    }
+
+    #[test]
+    fn migrate_v1_to_v2() {
+        new_test_ext().execute_with(|| {
+            let dispute_key = 10;
+            let mut old_votes: BoolVotes<Test> = BoundedBTreeMap::new();
+            old_votes.try_insert(BOB, true).unwrap();
+            old_votes.try_insert(CHARLIE, false).unwrap();
+            let old_dispute = v1::DisputeV1::<Test> {
+                raised_by: ALICE,
+                votes: old_votes,
+                jury: get_jury::<Test>(vec![BOB, CHARLIE]),
+                specifiers: get_specifics::<Test>(vec![0, 1]),
+                is_extended: true,
+                expiration: 100,
+                bond: Some(3),
+            };
+            v1::Disputes::<Test, ()>::insert(dispute_key, &old_dispute);
+
+            let mut weight = <Weight as Default>::default();
+            v2::migrate_specific_votes::<Test, ()>(&mut weight);
+
+            let migrated = v2::Disputes::<Test, ()>::get(dispute_key).unwrap();
+            let bob_votes = migrated.votes.get(&BOB).unwrap();
+            let charlie_votes = migrated.votes.get(&CHARLIE).unwrap();
+            assert_eq!(bob_votes.len(), 2);
+            assert!(bob_votes.values().all(|vote| *vote));
+            assert_eq!(charlie_votes.len(), 2);
+            assert!(charlie_votes.values().all(|vote| !*vote));
+            assert_eq!(migrated.specifiers, old_dispute.specifiers);
+            assert!(migrated.is_extended);
+            assert_eq!(migrated.bond, Some(3));
+        })
+    }
+
+    #[test]
+    fn migrate_v2_to_v3() {
+        new_test_ext().execute_with(|| {
+            let dispute_key = 10;
+            let old_dispute = v2::DisputeV2::<Test> {
+                raised_by: ALICE,
+                votes: Default::default(),
+                jury: get_jury::<Test>(vec![BOB, CHARLIE]),
+                specifiers: get_specifics::<Test>(vec![0, 1]),
+                is_extended: false,
+                expiration: 100,
+                bond: Some(3),
+            };
+            v2::Disputes::<Test, ()>::insert(dispute_key, &old_dispute);
+
+            let mut weight = <Weight as Default>::default();
+            v3::migrate_dispute_evidence::<Test, ()>(&mut weight);
+
+            let migrated = v3::Disputes::<Test, ()>::get(dispute_key).unwrap();
+            assert_eq!(migrated.raised_by, old_dispute.raised_by);
+            assert_eq!(migrated.jury, old_dispute.jury);
+            assert_eq!(migrated.expiration, old_dispute.expiration);
+            assert_eq!(migrated.bond, Some(3));
+            assert_eq!(migrated.voting_starts, 0);
+            assert!(migrated.evidence.is_empty());
+        })
+    }
+
+    #[test]
+    fn migrate_v3_to_v4() {
+        new_test_ext().execute_with(|| {
+            let dispute_key = 10;
+            let old_dispute = v3::DisputeV3::<Test> {
+                raised_by: ALICE,
+                votes: Default::default(),
+                jury: get_jury::<Test>(vec![BOB, CHARLIE]),
+                specifiers: get_specifics::<Test>(vec![0, 1]),
+                is_extended: false,
+                expiration: 100,
+                bond: Some(3),
+                voting_starts: 20,
+                evidence: Default::default(),
+            };
+            v3::Disputes::<Test, ()>::insert(dispute_key, &old_dispute);
+
+            let mut weight = <Weight as Default>::default();
+            v4::migrate_secret_votes::<Test, ()>(&mut weight);
+
+            let migrated = v4::Disputes::<Test, ()>::get(dispute_key).unwrap();
+            assert_eq!(migrated.raised_by, old_dispute.raised_by);
+            assert_eq!(migrated.jury, old_dispute.jury);
+            assert_eq!(migrated.expiration, old_dispute.expiration);
+            assert_eq!(migrated.voting_starts, 20);
+            assert_eq!(migrated.bond, Some(3));
+            assert_eq!(migrated.reveal_starts, None);
+            assert!(migrated.commitments.is_empty());
+        })
+    }
+
+    #[test]
+    fn migrate_v4_to_v5() {
+        new_test_ext().execute_with(|| {
+            let old_dispute = |is_extended| v4::DisputeV4::<Test> {
+                raised_by: ALICE,
+                votes: Default::default(),
+                jury: get_jury::<Test>(vec![BOB, CHARLIE]),
+                specifiers: get_specifics::<Test>(vec![0, 1]),
+                is_extended,
+                expiration: 100,
+                bond: Some(3),
+                voting_starts: 20,
+                evidence: Default::default(),
+                reveal_starts: Some(80),
+                commitments: Default::default(),
+            };
+            v4::Disputes::<Test, ()>::insert(10, old_dispute(false));
+            v4::Disputes::<Test, ()>::insert(11, old_dispute(true));
+
+            let mut weight = <Weight as Default>::default();
+            v5::migrate_dispute_extensions::<Test, ()>(&mut weight);
+
+            let migrated = crate::Disputes::<Test>::get(10).unwrap();
+            assert_eq!(migrated.extensions, 0);
+            assert!(migrated.extension_votes.is_empty());
+            assert_eq!(migrated.expiration, 100);
+            assert_eq!(migrated.reveal_starts, Some(80));
+            assert_eq!(migrated.bond, Some(3));
+            let migrated = crate::Disputes::<Test>::get(11).unwrap();
+            assert_eq!(migrated.extensions, 1);
+            assert!(migrated.extension_votes.is_empty());
+        })
+    }
+
+    #[test]
+    fn migrate_v5_to_v6() {
+        new_test_ext().execute_with(|| {
+            let dispute = |jury| crate::Dispute::<Test> {
+                raised_by: ALICE,
+                votes: Default::default(),
+                jury: get_jury::<Test>(jury),
+                specifiers: get_specifics::<Test>(vec![0, 1]),
+                extensions: 0,
+                extension_votes: Default::default(),
+                expiration: 100,
+                bond: None,
+                voting_starts: 20,
+                evidence: Default::default(),
+                reveal_starts: None,
+                commitments: Default::default(),
+            };
+            crate::Disputes::<Test>::insert(10, dispute(vec![BOB, CHARLIE]));
+            crate::Disputes::<Test>::insert(11, dispute(vec![BOB]));
+
+            let mut weight = <Weight as Default>::default();
+            v6::migrate_juror_disputes::<Test, ()>(&mut weight);
+
+            let mut bob_disputes =
+                crate::JurorDisputes::<Test>::iter_key_prefix(BOB).collect::<Vec<u32>>();
+            bob_disputes.sort();
+            assert_eq!(bob_disputes, vec![10, 11]);
+            assert_eq!(crate::JurorDisputeCount::<Test>::get(BOB), 2);
+            assert!(crate::JurorDisputes::<Test>::contains_key(CHARLIE, 10));
+            assert_eq!(crate::JurorDisputeCount::<Test>::get(CHARLIE), 1);
+        })
+    }
+
+    #[test]
+    fn migrate_v5_to_v6_only_touches_its_own_instance() {
+        new_test_ext().execute_with(|| {
+            let dispute = crate::Dispute::<Test, crate::Instance1> {
+                raised_by: ALICE,
+                votes: Default::default(),
+                jury: get_jury::<Test>(vec![BOB]),
+                specifiers: vec![0].try_into().unwrap(),
+                extensions: 0,
+                extension_votes: Default::default(),
+                expiration: 100,
+                bond: None,
+                voting_starts: 20,
+                evidence: Default::default(),
+                reveal_starts: None,
+                commitments: Default::default(),
+            };
+            crate::Disputes::<Test, crate::Instance1>::insert(10, dispute);
+
+            let mut weight = <Weight as Default>::default();
+            v6::migrate_juror_disputes::<Test, crate::Instance1>(&mut weight);
+
+            assert!(crate::JurorDisputes::<Test, crate::Instance1>::contains_key(BOB, 10));
+            assert_eq!(
+                crate::JurorDisputeCount::<Test, crate::Instance1>::get(BOB),
+                1
+            );
+            assert!(!crate::JurorDisputes::<Test>::contains_key(BOB, 10));
+        })
+    }
+
+    #[test]
+    fn migrate_v6_to_v7() {
+        new_test_ext().execute_with(|| {
+            let old_pending = v6::PendingVerdictV6::<Test> {
+                raised_by: ALICE,
+                verdicts: vec![(0, DisputeResult::Success)].try_into().unwrap(),
+                bond: Some(3),
+                appeal_window_closes: 100,
+                appealed_by: Some(BOB),
+            };
+            v6::PendingVerdicts::<Test, ()>::insert(10, &old_pending);
+            v6::PendingVerdicts::<Test, ()>::insert(
+                11,
+                v6::PendingVerdictV6 {
+                    appealed_by: None,
+                    ..old_pending.clone()
+                },
+            );
+
+            let mut weight = <Weight as Default>::default();
+            v7::migrate_pending_verdicts::<Test, ()>(&mut weight);
+
+            let migrated = v8::PendingVerdicts::<Test, ()>::get(10).unwrap();
+            assert_eq!(migrated.raised_by, old_pending.raised_by);
+            assert_eq!(migrated.verdicts, old_pending.verdicts);
+            assert_eq!(migrated.bond, old_pending.bond);
+            assert_eq!(migrated.appeal_window_closes, 100);
+            assert_eq!(migrated.appealed_by, Some(BOB));
+            let appeal_lapses = System::block_number() + AppealDecisionPeriod::get();
+            assert_eq!(migrated.appeal_lapses, Some(appeal_lapses));
+            assert_eq!(crate::AppealsLapseOn::<Test>::get(appeal_lapses), vec![10]);
+            assert!(migrated.jury.is_empty());
+            assert!(migrated.votes.is_empty());
+            let migrated = v8::PendingVerdicts::<Test, ()>::get(11).unwrap();
+            assert_eq!(migrated.appeal_lapses, None);
+        })
+    }
+
+    #[test]
+    fn migrate_v7_to_v8() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Tokens::reserve(CurrencyId::Native, &BOB, AppealBond::get()));
+            let old_pending = v8::PendingVerdictV7::<Test> {
+                raised_by: ALICE,
+                verdicts: vec![(0, DisputeResult::Success)].try_into().unwrap(),
+                bond: Some(3),
+                appeal_window_closes: 100,
+                appealed_by: Some(BOB),
+                appeal_lapses: Some(120),
+                jury: get_jury::<Test>(vec![CHARLIE]),
+                votes: Default::default(),
+            };
+            v8::PendingVerdicts::<Test, ()>::insert(10, &old_pending);
+            v8::PendingVerdicts::<Test, ()>::insert(
+                11,
+                v8::PendingVerdictV7 {
+                    appealed_by: None,
+                    appeal_lapses: None,
+                    ..old_pending.clone()
+                },
+            );
+
+            let mut weight = <Weight as Default>::default();
+            v8::migrate_appeal_bonds::<Test, ()>(AppealBond::get(), &mut weight);
+
+            let migrated = crate::PendingVerdicts::<Test>::get(10).unwrap();
+            assert_eq!(migrated.appealed_by, Some(BOB));
+            assert_eq!(migrated.appeal_bond, Some(0));
+            assert_eq!(migrated.appeal_lapses, Some(120));
+            assert_eq!(migrated.jury, old_pending.jury);
+            assert_eq!(AppealDeposits::get(), vec![(BOB, CurrencyId::Native)]);
+            // The reserved amount is unchanged, it is now held by the deposit.
+            assert_eq!(
+                Tokens::reserved_balance(CurrencyId::Native, &BOB),
+                AppealBond::get()
+            );
+            let migrated = crate::PendingVerdicts::<Test>::get(11).unwrap();
+            assert_eq!(migrated.appeal_bond, None);
+        })
+    }
+}
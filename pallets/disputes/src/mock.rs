@@ -3,11 +3,11 @@ use common_types::CurrencyId;
 use frame_support::traits::{ConstU16, Nothing};
 use frame_support::{pallet_prelude::*, parameter_types};
 use frame_system::EnsureRoot;
-use orml_traits::MultiCurrency;
+use orml_traits::{BalanceStatus, MultiCurrency, MultiReservableCurrency};
 use sp_core::H256;
 use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
-    BuildStorage,
+    BuildStorage, Percent,
 };
 use sp_std::convert::{TryFrom, TryInto};
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -21,6 +21,7 @@ frame_support::construct_runtime!(
     {
         System: frame_system,
         PalletDisputes: pallet_disputes,
+        FellowshipDisputes: pallet_disputes::<Instance1>,
         Tokens: orml_tokens,
     }
 );
@@ -56,6 +57,24 @@ parameter_types! {
     pub MaxSpecifics: u32 = 10;
     pub VotingTimeLimit: BlockNumber = 10;
     pub MaxDisputesPerBlock: u32 = 1000;
+    pub JurorRewardCurrencyId: CurrencyId = CurrencyId::Native;
+    pub JurorRewardPot: AccountId = TREASURY;
+    pub JurorRewardPerDispute: Balance = 1_000_000;
+    pub AppealWindow: BlockNumber = 10;
+    pub MaxEvidence: u32 = 3;
+    pub AppealBond: Balance = 1_000;
+    pub AppealDecisionPeriod: BlockNumber = 20;
+}
+
+parameter_types! {
+    pub static EvidencePeriod: BlockNumber = 0;
+    pub static RevealPeriod: BlockNumber = 0;
+    pub static MaxExtensions: u32 = 1;
+    pub static MaxDisputesPerJuror: u32 = 10;
+    pub static UseSupermajority: bool = false;
+    pub static MinTurnout: Percent = Percent::from_percent(50);
+    pub static Supermajority: Percent = Percent::from_percent(66);
+    pub static TieBreak: crate::pallet::DisputeResult = crate::pallet::DisputeResult::Failure;
 }
 
 impl pallet_disputes::Config for Test {
@@ -68,7 +87,62 @@ impl pallet_disputes::Config for Test {
     type MaxDisputesPerBlock = MaxDisputesPerBlock;
     type VotingTimeLimit = VotingTimeLimit;
     type ForceOrigin = EnsureRoot<AccountId>;
+    type BondId = u64;
     type DisputeHooks = Test;
+    type MultiCurrency = Tokens;
+    type JurorRewardCurrencyId = JurorRewardCurrencyId;
+    type JurorRewardPot = JurorRewardPot;
+    type JurorRewardPerDispute = JurorRewardPerDispute;
+    type JurorPenaltyHandler = Test;
+    type DisputeOutcomePolicy = Test;
+    type EvidencePeriod = EvidencePeriod;
+    type MaxEvidence = MaxEvidence;
+    type AppealWindow = AppealWindow;
+    type DepositHandler = MockDepositHandler;
+    type AppealStorageItem = ();
+    type AppealOrigin = EnsureRoot<AccountId>;
+    type AppealDecisionPeriod = AppealDecisionPeriod;
+    type DisputeParties = Test;
+    type RevealPeriod = RevealPeriod;
+    type MaxExtensions = MaxExtensions;
+    type MaxDisputesPerJuror = MaxDisputesPerJuror;
+}
+
+parameter_types! {
+    pub FellowshipMaxSpecifics: u32 = 1;
+    pub FellowshipVotingTimeLimit: BlockNumber = 20;
+}
+
+/// A second consumer of the disputes pallet with its own hooks and limits.
+impl pallet_disputes::Config<pallet_disputes::Instance1> for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type DisputeKey = u32;
+    type SpecificId = u32;
+    type MaxJurySize = MaxJurySize;
+    type MaxSpecifics = FellowshipMaxSpecifics;
+    type MaxDisputesPerBlock = MaxDisputesPerBlock;
+    type VotingTimeLimit = FellowshipVotingTimeLimit;
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type BondId = u64;
+    type DisputeHooks = FellowshipDisputeHooks;
+    type MultiCurrency = Tokens;
+    type JurorRewardCurrencyId = JurorRewardCurrencyId;
+    type JurorRewardPot = JurorRewardPot;
+    type JurorRewardPerDispute = JurorRewardPerDispute;
+    type JurorPenaltyHandler = Test;
+    type DisputeOutcomePolicy = ();
+    type EvidencePeriod = EvidencePeriod;
+    type MaxEvidence = MaxEvidence;
+    type AppealWindow = AppealWindow;
+    type DepositHandler = MockDepositHandler;
+    type AppealStorageItem = ();
+    type AppealOrigin = EnsureRoot<AccountId>;
+    type AppealDecisionPeriod = AppealDecisionPeriod;
+    type DisputeParties = ();
+    type RevealPeriod = RevealPeriod;
+    type MaxExtensions = MaxExtensions;
+    type MaxDisputesPerJuror = MaxDisputesPerJuror;
 }
 
 orml_traits::parameter_type_with_key! {
@@ -100,6 +174,8 @@ pub static ALICE: AccountId = 125;
 pub static BOB: AccountId = 126;
 pub static CHARLIE: AccountId = 127;
 pub static FERDIE: AccountId = 128;
+pub static DEFENDANT: AccountId = 129;
+pub static TREASURY: AccountId = 200;
 
 pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
     let t = frame_system::GenesisConfig::<Test>::default()
@@ -114,16 +190,159 @@ pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
         let _ = Tokens::deposit(CurrencyId::Native, &BOB, initial_balance);
         let _ = Tokens::deposit(CurrencyId::Native, &CHARLIE, initial_balance);
         let _ = Tokens::deposit(CurrencyId::Native, &FERDIE, initial_balance);
+        let _ = Tokens::deposit(CurrencyId::Native, &DEFENDANT, initial_balance);
+        let _ = Tokens::deposit(CurrencyId::Native, &TREASURY, initial_balance);
     });
     ext
 }
 
-impl crate::traits::DisputeHooks<u32, u32> for Test {
+parameter_types! {
+    pub static CompletedDisputes: Vec<(u32, Vec<(u32, crate::pallet::DisputeResult)>, Option<u64>)> = vec![];
+    pub static CancelledDisputes: Vec<(u32, Vec<u32>, Option<u64>)> = vec![];
+    pub static CancelHookFails: bool = false;
+}
+
+impl crate::traits::DisputeHooks<u32, u32, u64> for Test {
+    fn on_dispute_complete(
+        dispute_key: u32,
+        verdicts: Vec<(u32, crate::pallet::DisputeResult)>,
+        bond: Option<u64>,
+    ) -> Weight {
+        CompletedDisputes::mutate(|completed| completed.push((dispute_key, verdicts, bond)));
+        <Weight as Default>::default()
+    }
+
+    fn on_dispute_cancel(
+        dispute_key: u32,
+        specifics: Vec<u32>,
+        bond: Option<u64>,
+    ) -> Result<Weight, DispatchError> {
+        if CancelHookFails::get() {
+            return Err(DispatchError::Other("cancel hook failed"));
+        }
+        CancelledDisputes::mutate(|cancelled| cancelled.push((dispute_key, specifics, bond)));
+        Ok(<Weight as Default>::default())
+    }
+
+    fn on_dispute_cancel_weight(_specifics: u32) -> Weight {
+        <Weight as Default>::default()
+    }
+}
+
+parameter_types! {
+    pub static CompletedFellowshipDisputes: Vec<(u32, Vec<(u32, crate::pallet::DisputeResult)>, Option<u64>)> = vec![];
+}
+
+pub struct FellowshipDisputeHooks;
+impl crate::traits::DisputeHooks<u32, u32, u64> for FellowshipDisputeHooks {
     fn on_dispute_complete(
+        dispute_key: u32,
+        verdicts: Vec<(u32, crate::pallet::DisputeResult)>,
+        bond: Option<u64>,
+    ) -> Weight {
+        CompletedFellowshipDisputes::mutate(|completed| {
+            completed.push((dispute_key, verdicts, bond))
+        });
+        <Weight as Default>::default()
+    }
+
+    fn on_dispute_cancel(
         _dispute_key: u32,
         _specifics: Vec<u32>,
-        _dispute_result: crate::pallet::DisputeResult,
-    ) -> Weight {
+        _bond: Option<u64>,
+    ) -> Result<Weight, DispatchError> {
+        Ok(<Weight as Default>::default())
+    }
+
+    fn on_dispute_cancel_weight(_specifics: u32) -> Weight {
+        <Weight as Default>::default()
+    }
+}
+
+parameter_types! {
+    pub static AppealDeposits: Vec<(AccountId, CurrencyId)> = vec![];
+}
+
+/// Reserves the AppealBond for each deposit, slashed deposits are sent to the JurorRewardPot.
+pub struct MockDepositHandler;
+impl pallet_deposits::traits::DepositHandler<Balance, AccountId> for MockDepositHandler {
+    type DepositId = u64;
+    type StorageItem = ();
+
+    fn take_deposit(
+        who: AccountId,
+        _storage_item: Self::StorageItem,
+        currency_id: CurrencyId,
+    ) -> Result<Self::DepositId, DispatchError> {
+        Tokens::reserve(currency_id, &who, AppealBond::get())?;
+        AppealDeposits::mutate(|deposits| deposits.push((who, currency_id)));
+        Ok(AppealDeposits::get().len() as u64 - 1)
+    }
+
+    fn return_deposit(deposit_id: Self::DepositId) -> DispatchResult {
+        let (who, currency_id) = AppealDeposits::get()
+            .get(deposit_id as usize)
+            .copied()
+            .ok_or(DispatchError::Other("deposit not found"))?;
+        let _ = Tokens::unreserve(currency_id, &who, AppealBond::get());
+        Ok(())
+    }
+
+    fn slash_reserve_deposit(deposit_id: Self::DepositId) -> DispatchResult {
+        let (who, currency_id) = AppealDeposits::get()
+            .get(deposit_id as usize)
+            .copied()
+            .ok_or(DispatchError::Other("deposit not found"))?;
+        Tokens::repatriate_reserved(
+            currency_id,
+            &who,
+            &JurorRewardPot::get(),
+            AppealBond::get(),
+            BalanceStatus::Free,
+        )?;
+        Ok(())
+    }
+}
+
+impl crate::traits::DisputeParties<AccountId, u32> for Test {
+    fn defendant(_dispute_key: u32) -> Option<AccountId> {
+        Some(DEFENDANT)
+    }
+}
+
+/// Uses the default rules unless a test switches to the SupermajorityPolicy.
+impl crate::traits::DisputeOutcomePolicy for Test {
+    fn result(yay: u32, nay: u32, jury_size: u32) -> crate::pallet::DisputeResult {
+        if UseSupermajority::get() {
+            MockSupermajorityPolicy::result(yay, nay, jury_size)
+        } else {
+            <() as crate::traits::DisputeOutcomePolicy>::result(yay, nay, jury_size)
+        }
+    }
+
+    fn decided_result(yay: u32, nay: u32, jury_size: u32) -> Option<crate::pallet::DisputeResult> {
+        if UseSupermajority::get() {
+            MockSupermajorityPolicy::decided_result(yay, nay, jury_size)
+        } else {
+            <() as crate::traits::DisputeOutcomePolicy>::decided_result(yay, nay, jury_size)
+        }
+    }
+}
+
+pub type MockSupermajorityPolicy =
+    crate::traits::SupermajorityPolicy<MinTurnout, Supermajority, TieBreak>;
+
+parameter_types! {
+    pub static PenalisedJurors: Vec<(AccountId, u32)> = vec![];
+}
+
+impl crate::traits::JurorPenaltyHandler<AccountId> for Test {
+    fn on_missed_vote(juror: &AccountId, missed_in_a_row: u32) -> Weight {
+        PenalisedJurors::mutate(|penalised| penalised.push((*juror, missed_in_a_row)));
+        <Weight as Default>::default()
+    }
+
+    fn max_on_missed_vote_weight() -> Weight {
         <Weight as Default>::default()
     }
 }
@@ -132,6 +351,9 @@ impl crate::WeightInfoT for () {
     fn vote_on_dispute() -> Weight {
         <Weight as Default>::default()
     }
+    fn vote_on_specifics() -> Weight {
+        <Weight as Default>::default()
+    }
     fn extend_dispute() -> Weight {
         <Weight as Default>::default()
     }
@@ -147,4 +369,22 @@ impl crate::WeightInfoT for () {
     fn calculate_winner() -> Weight {
         <Weight as Default>::default()
     }
+    fn appeal_dispute() -> Weight {
+        <Weight as Default>::default()
+    }
+    fn decide_appeal() -> Weight {
+        <Weight as Default>::default()
+    }
+    fn submit_evidence() -> Weight {
+        <Weight as Default>::default()
+    }
+    fn commit_vote() -> Weight {
+        <Weight as Default>::default()
+    }
+    fn reveal_vote() -> Weight {
+        <Weight as Default>::default()
+    }
+    fn cancel_dispute() -> Weight {
+        <Weight as Default>::default()
+    }
 }
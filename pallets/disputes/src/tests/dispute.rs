@@ -6,10 +6,16 @@ fn test_calculate_winner_success_on_half() {
         let dispute_key = 0;
         let jury = get_jury::<Test>(vec![ALICE, BOB]);
         let specifics = get_specifics::<Test>(vec![0, 1, 2]);
-        assert_ok!(Dispute::<Test>::new(dispute_key, CHARLIE, jury, specifics));
+        assert_ok!(Dispute::<Test>::new(
+            dispute_key,
+            CHARLIE,
+            jury,
+            specifics,
+            None
+        ));
         let mut dispute = Disputes::<Test>::get(dispute_key).expect("just inserted, should exist.");
         assert_ok!(dispute.try_add_vote(ALICE, true, dispute_key));
-        assert_eq!(dispute.calculate_winner(), DisputeResult::Success);
+        assert_eq!(dispute.calculate_winner(&0), DisputeResult::Success);
     })
 }
 
@@ -19,11 +25,17 @@ fn test_calculate_winner_success() {
         let dispute_key = 0;
         let jury = get_jury::<Test>(vec![ALICE, BOB]);
         let specifics = get_specifics::<Test>(vec![0, 1, 2]);
-        assert_ok!(Dispute::<Test>::new(dispute_key, CHARLIE, jury, specifics));
+        assert_ok!(Dispute::<Test>::new(
+            dispute_key,
+            CHARLIE,
+            jury,
+            specifics,
+            None
+        ));
         let mut dispute = Disputes::<Test>::get(dispute_key).expect("just inserted, should exist.");
         assert_ok!(dispute.try_add_vote(ALICE, true, dispute_key));
         assert_ok!(dispute.try_add_vote(BOB, true, dispute_key));
-        assert_eq!(dispute.calculate_winner(), DisputeResult::Success);
+        assert_eq!(dispute.calculate_winner(&0), DisputeResult::Success);
     })
 }
 
@@ -33,11 +45,17 @@ fn test_calculate_winner_failure() {
         let dispute_key = 0;
         let jury = get_jury::<Test>(vec![ALICE, BOB]);
         let specifics = get_specifics::<Test>(vec![0, 1, 2]);
-        assert_ok!(Dispute::<Test>::new(dispute_key, CHARLIE, jury, specifics));
+        assert_ok!(Dispute::<Test>::new(
+            dispute_key,
+            CHARLIE,
+            jury,
+            specifics,
+            None
+        ));
         let mut dispute = Disputes::<Test>::get(dispute_key).expect("just inserted, should exist.");
         assert_ok!(dispute.try_add_vote(ALICE, false, dispute_key));
         assert_ok!(dispute.try_add_vote(BOB, false, dispute_key));
-        assert_eq!(dispute.calculate_winner(), DisputeResult::Failure);
+        assert_eq!(dispute.calculate_winner(&0), DisputeResult::Failure);
     })
 }
 
@@ -47,8 +65,110 @@ fn test_calculate_winner_noone_votes_failure() {
         let dispute_key = 0;
         let jury = get_jury::<Test>(vec![ALICE, BOB]);
         let specifics = get_specifics::<Test>(vec![0, 1, 2]);
-        assert_ok!(Dispute::<Test>::new(dispute_key, CHARLIE, jury, specifics));
+        assert_ok!(Dispute::<Test>::new(
+            dispute_key,
+            CHARLIE,
+            jury,
+            specifics,
+            None
+        ));
         let dispute = Disputes::<Test>::get(dispute_key).expect("just inserted, should exist.");
-        assert_eq!(dispute.calculate_winner(), DisputeResult::Failure);
+        assert_eq!(dispute.calculate_winner(&0), DisputeResult::Failure);
+    })
+}
+
+#[test]
+fn test_calculate_verdicts_per_specific() {
+    new_test_ext().execute_with(|| {
+        let dispute_key = 0;
+        let jury = get_jury::<Test>(vec![ALICE, BOB, FERDIE]);
+        let specifics = get_specifics::<Test>(vec![0, 1]);
+        assert_ok!(Dispute::<Test>::new(
+            dispute_key,
+            CHARLIE,
+            jury,
+            specifics,
+            None
+        ));
+        let mut dispute = Disputes::<Test>::get(dispute_key).expect("just inserted, should exist.");
+        let votes: BoundedVec<(u32, bool), MaxSpecifics> =
+            vec![(0, true), (1, false)].try_into().unwrap();
+        assert_ok!(dispute.try_add_specific_votes(ALICE, votes.clone(), dispute_key));
+        assert_ok!(dispute.try_add_specific_votes(BOB, votes, dispute_key));
+        assert_ok!(dispute.try_add_vote(FERDIE, true, dispute_key));
+        assert_eq!(
+            dispute.calculate_verdicts().into_inner(),
+            vec![(0, DisputeResult::Success), (1, DisputeResult::Failure)]
+        );
+    })
+}
+
+#[test]
+fn supermajority_policy_requires_turnout() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            MockSupermajorityPolicy::result(0, 0, 3),
+            DisputeResult::Failure
+        );
+        assert_eq!(
+            MockSupermajorityPolicy::result(1, 0, 4),
+            DisputeResult::Failure
+        );
+        assert_eq!(
+            MockSupermajorityPolicy::result(2, 0, 4),
+            DisputeResult::Success
+        );
+    })
+}
+
+#[test]
+fn supermajority_policy_requires_the_threshold_and_breaks_ties() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            MockSupermajorityPolicy::result(2, 1, 3),
+            DisputeResult::Success
+        );
+        assert_eq!(
+            MockSupermajorityPolicy::result(1, 1, 2),
+            DisputeResult::Failure
+        );
+        Supermajority::set(sp_runtime::Percent::from_percent(50));
+        assert_eq!(
+            MockSupermajorityPolicy::result(1, 1, 2),
+            DisputeResult::Failure
+        );
+        TieBreak::set(DisputeResult::Success);
+        assert_eq!(
+            MockSupermajorityPolicy::result(1, 1, 2),
+            DisputeResult::Success
+        );
+    })
+}
+
+#[test]
+fn supermajority_policy_decides_once_outstanding_votes_cannot_change_the_result() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            MockSupermajorityPolicy::decided_result(2, 0, 3),
+            Some(DisputeResult::Success)
+        );
+        assert_eq!(
+            MockSupermajorityPolicy::decided_result(0, 2, 3),
+            Some(DisputeResult::Failure)
+        );
+        assert_eq!(MockSupermajorityPolicy::decided_result(1, 0, 3), None);
+        assert_eq!(MockSupermajorityPolicy::decided_result(1, 1, 3), None);
+    })
+}
+
+#[test]
+fn default_policy_only_decides_unanimous_juries() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            <() as DisputeOutcomePolicy>::decided_result(2, 0, 2),
+            Some(DisputeResult::Success)
+        );
+        assert_eq!(<() as DisputeOutcomePolicy>::decided_result(2, 0, 3), None);
+        assert_eq!(<() as DisputeOutcomePolicy>::decided_result(1, 1, 2), None);
     })
 }
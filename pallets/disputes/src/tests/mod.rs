@@ -1,3 +1,3 @@
 mod dispute;
 mod pallet_tests;
-mod test_utils;
+pub(crate) mod test_utils;
@@ -13,6 +13,7 @@ fn raise_dispute_assert_state() {
             ALICE,
             jury,
             specifics,
+            None,
         ));
         assert!(PalletDisputes::disputes(dispute_key).is_some());
         assert_eq!(1, PalletDisputes::disputes(dispute_key).iter().count());
@@ -31,6 +32,7 @@ fn raise_dispute_assert_event() {
             ALICE,
             jury,
             specifics,
+            None,
         ));
         System::assert_last_event(RuntimeEvent::PalletDisputes(Event::<Test>::DisputeRaised {
             who: ALICE,
@@ -49,7 +51,7 @@ fn raise_dispute_assert_event_too_many_disputes() {
             let specifics = get_specifics::<Test>(vec![0, 1]);
             if i != disputes_limit {
                 assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
-                    i, ALICE, jury, specifics,
+                    i, ALICE, jury, specifics, None,
                 ));
                 System::assert_last_event(RuntimeEvent::PalletDisputes(
                     Event::<Test>::DisputeRaised {
@@ -59,7 +61,7 @@ fn raise_dispute_assert_event_too_many_disputes() {
                 ));
             } else {
                 let actual_result = <PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
-                    i, ALICE, jury, specifics,
+                    i, ALICE, jury, specifics, None,
                 );
                 assert_noop!(actual_result, Error::<Test>::TooManyDisputesThisBlock);
             }
@@ -78,13 +80,15 @@ fn raise_dispute_already_exists() {
             ALICE,
             jury.clone(),
             specifics.clone(),
+            None,
         ));
         assert_noop!(
             <PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
                 dispute_key,
                 ALICE,
                 jury,
-                specifics
+                specifics,
+                None
             ),
             Error::<Test>::DisputeAlreadyExists
         );
@@ -102,6 +106,7 @@ fn vote_on_dispute_assert_state() {
             ALICE,
             jury,
             specifics,
+            None,
         ));
         let dispute_before_vote = Disputes::<Test>::get(dispute_key).expect("dispute should exist");
 
@@ -112,7 +117,12 @@ fn vote_on_dispute_assert_state() {
             true
         ));
         let dispute_after_vote = Disputes::<Test>::get(dispute_key).expect("dispute should exist");
-        assert_eq!(dispute_after_vote.votes.get(&BOB).unwrap(), &true);
+        assert!(dispute_after_vote
+            .votes
+            .get(&BOB)
+            .unwrap()
+            .values()
+            .all(|vote| *vote == true));
         assert_eq!(1, dispute_after_vote.votes.len());
 
         assert_ok!(PalletDisputes::vote_on_dispute(
@@ -122,8 +132,18 @@ fn vote_on_dispute_assert_state() {
         ));
 
         let dispute_after_vote = Disputes::<Test>::get(dispute_key).expect("dispute should exist");
-        assert_eq!(dispute_after_vote.votes.get(&CHARLIE).unwrap(), &false);
-        assert_eq!(dispute_after_vote.votes.get(&BOB).unwrap(), &true);
+        assert!(dispute_after_vote
+            .votes
+            .get(&CHARLIE)
+            .unwrap()
+            .values()
+            .all(|vote| *vote == false));
+        assert!(dispute_after_vote
+            .votes
+            .get(&BOB)
+            .unwrap()
+            .values()
+            .all(|vote| *vote == true));
         assert_eq!(2, dispute_after_vote.votes.len());
     });
 }
@@ -139,6 +159,7 @@ fn vote_on_dispute_assert_last_event() {
             ALICE,
             jury,
             specifics,
+            None,
         ));
 
         assert_ok!(PalletDisputes::vote_on_dispute(
@@ -167,6 +188,7 @@ fn on_initialize_tries_to_finalise_assert_event() {
             ALICE,
             jury,
             specifics,
+            None,
         ));
         assert_ok!(PalletDisputes::vote_on_dispute(
             RuntimeOrigin::signed(BOB),
@@ -179,7 +201,7 @@ fn on_initialize_tries_to_finalise_assert_event() {
         System::assert_last_event(RuntimeEvent::PalletDisputes(
             Event::<Test>::DisputeCompleted {
                 dispute_key,
-                dispute_result: DisputeResult::Success,
+                verdicts: get_verdicts::<Test>(vec![0, 1], DisputeResult::Success),
             },
         ));
     });
@@ -196,6 +218,7 @@ fn vote_on_dispute_autofinalises_on_unanimous_yes() {
             ALICE,
             jury,
             specifics,
+            None,
         ));
         assert_ok!(PalletDisputes::vote_on_dispute(
             RuntimeOrigin::signed(BOB),
@@ -210,7 +233,7 @@ fn vote_on_dispute_autofinalises_on_unanimous_yes() {
         System::assert_last_event(RuntimeEvent::PalletDisputes(
             Event::<Test>::DisputeCompleted {
                 dispute_key,
-                dispute_result: DisputeResult::Success,
+                verdicts: get_verdicts::<Test>(vec![0, 1], DisputeResult::Success),
             },
         ));
         //verify that the dispute has been removed once auto_finalization is done in case of unanimous yes
@@ -229,6 +252,7 @@ fn vote_on_dispute_autofinalises_on_unanimous_no() {
             ALICE,
             jury,
             specifics,
+            None,
         ));
         assert_ok!(PalletDisputes::vote_on_dispute(
             RuntimeOrigin::signed(BOB),
@@ -243,7 +267,7 @@ fn vote_on_dispute_autofinalises_on_unanimous_no() {
         System::assert_last_event(RuntimeEvent::PalletDisputes(
             Event::<Test>::DisputeCompleted {
                 dispute_key,
-                dispute_result: DisputeResult::Failure,
+                verdicts: get_verdicts::<Test>(vec![0, 1], DisputeResult::Failure),
             },
         ));
         //verify that the dispute has been removed once auto_finalization is done in case of unanimous no
@@ -268,12 +292,14 @@ fn try_auto_finalise_removes_autofinalise_storage() {
                 ALICE,
                 jury.clone(),
                 specifics.clone(),
+                None,
             ));
             assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
                 dispute_key_2,
                 ALICE,
                 jury,
                 specifics,
+                None,
             ));
             assert_ok!(PalletDisputes::vote_on_dispute(
                 RuntimeOrigin::signed(BOB),
@@ -285,9 +311,9 @@ fn try_auto_finalise_removes_autofinalise_storage() {
                 dispute_key_2,
                 true
             ));
-            assert_ok!(Dispute::<Test>::try_finalise_with_result(
+            assert_ok!(Dispute::<Test>::try_finalise_with_verdicts(
                 dispute_key_1,
-                DisputeResult::Success
+                get_verdicts::<Test>(vec![0, 1], DisputeResult::Success)
             ));
             let finalising_disputes = DisputesFinaliseOn::<Test>::get(expiry_block);
 
@@ -310,7 +336,8 @@ fn vote_on_dispute_not_jury_account() {
             dispute_key,
             ALICE,
             jury,
-            specific_ids
+            specific_ids,
+            None
         ));
         assert_noop!(
             PalletDisputes::vote_on_dispute(RuntimeOrigin::signed(CHARLIE), dispute_key, true),
@@ -330,7 +357,8 @@ fn vote_on_dispute_dispute_doesnt_exist() {
             dispute_key,
             ALICE,
             jury,
-            specific_ids
+            specific_ids,
+            None
         ));
 
         assert_noop!(
@@ -351,7 +379,8 @@ fn extend_dispute_dispute_doesnt_exist() {
             dispute_key,
             ALICE,
             jury,
-            specific_ids
+            specific_ids,
+            None
         ));
         assert_noop!(
             PalletDisputes::extend_dispute(RuntimeOrigin::signed(BOB), 1),
@@ -371,7 +400,8 @@ fn extend_dispute_not_a_jury_account() {
             dispute_key,
             ALICE,
             jury,
-            specific_ids
+            specific_ids,
+            None
         ));
         assert_noop!(
             PalletDisputes::extend_dispute(RuntimeOrigin::signed(CHARLIE), dispute_key),
@@ -380,7 +410,7 @@ fn extend_dispute_not_a_jury_account() {
     });
 }
 
-/// testing trying to extend the voting on a dispute which has already been extended and should throw Dispute Already Extended error
+/// testing trying to extend the voting on a dispute which has already been extended MaxExtensions times and should throw Dispute Already Extended error
 #[test]
 fn extend_dispute_already_extended() {
     new_test_ext().execute_with(|| {
@@ -391,7 +421,8 @@ fn extend_dispute_already_extended() {
             dispute_key,
             ALICE,
             jury,
-            specific_ids
+            specific_ids,
+            None
         ));
         assert_ok!(PalletDisputes::extend_dispute(
             RuntimeOrigin::signed(BOB),
@@ -404,7 +435,7 @@ fn extend_dispute_already_extended() {
     });
 }
 
-/// testing trying to extend the voting time and it successfully extend by setting the flag to true
+/// testing trying to extend the voting time and it successfully extend as the only juror is a majority
 #[test]
 fn extend_dispute_works_assert_last_event() {
     new_test_ext().execute_with(|| {
@@ -415,10 +446,11 @@ fn extend_dispute_works_assert_last_event() {
             dispute_key,
             ALICE,
             jury,
-            specific_ids
+            specific_ids,
+            None
         ));
         let d = Disputes::<Test>::get(dispute_key).expect("dispute should exist");
-        assert!(!d.is_extended);
+        assert_eq!(d.extensions, 0);
         assert_ok!(PalletDisputes::extend_dispute(
             RuntimeOrigin::signed(BOB),
             dispute_key
@@ -441,11 +473,12 @@ fn extend_dispute_works_assert_state() {
             dispute_key,
             ALICE,
             jury,
-            specific_ids
+            specific_ids,
+            None
         ));
         // Assert state before extension.
         let d = Disputes::<Test>::get(dispute_key).expect("dispute should exist");
-        assert!(!d.is_extended);
+        assert_eq!(d.extensions, 0);
         assert_eq!(initial_expiry, d.expiration);
 
         // Assert it will autofinalise on the old expiry block
@@ -460,7 +493,7 @@ fn extend_dispute_works_assert_state() {
 
         // Assert state after extension.
         let d = Disputes::<Test>::get(dispute_key).expect("dispute should exist");
-        assert!(d.is_extended);
+        assert_eq!(d.extensions, 1);
         assert_eq!(
             initial_expiry + <Test as Config>::VotingTimeLimit::get(),
             d.expiration
@@ -476,6 +509,120 @@ fn extend_dispute_works_assert_state() {
     });
 }
 
+#[test]
+fn extend_dispute_needs_a_majority_of_the_jury() {
+    new_test_ext().execute_with(|| {
+        let dispute_key = 10;
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            dispute_key,
+            ALICE,
+            get_jury::<Test>(vec![BOB, CHARLIE, FERDIE]),
+            get_specifics::<Test>(vec![0]),
+            None,
+        ));
+        let initial_expiry = Disputes::<Test>::get(dispute_key).unwrap().expiration;
+
+        assert_ok!(PalletDisputes::extend_dispute(
+            RuntimeOrigin::signed(BOB),
+            dispute_key
+        ));
+        System::assert_last_event(RuntimeEvent::PalletDisputes(
+            Event::<Test>::ExtensionVoted {
+                who: BOB,
+                dispute_key,
+            },
+        ));
+        assert_noop!(
+            PalletDisputes::extend_dispute(RuntimeOrigin::signed(BOB), dispute_key),
+            Error::<Test>::AlreadyVotedToExtend
+        );
+        let d = Disputes::<Test>::get(dispute_key).unwrap();
+        assert_eq!(d.extensions, 0);
+        assert_eq!(d.expiration, initial_expiry);
+        assert_eq!(d.extension_votes.into_inner(), vec![BOB]);
+
+        assert_ok!(PalletDisputes::extend_dispute(
+            RuntimeOrigin::signed(FERDIE),
+            dispute_key
+        ));
+        System::assert_last_event(RuntimeEvent::PalletDisputes(
+            Event::<Test>::DisputeExtended { dispute_key },
+        ));
+        let d = Disputes::<Test>::get(dispute_key).unwrap();
+        assert_eq!(d.extensions, 1);
+        assert!(d.extension_votes.is_empty());
+        assert_eq!(
+            d.expiration,
+            initial_expiry + <Test as Config>::VotingTimeLimit::get()
+        );
+        assert!(DisputesFinaliseOn::<Test>::get(initial_expiry).is_empty());
+        assert!(DisputesFinaliseOn::<Test>::get(d.expiration).contains(&dispute_key));
+    });
+}
+
+#[test]
+fn extend_dispute_up_to_max_extensions() {
+    new_test_ext().execute_with(|| {
+        MaxExtensions::set(2);
+        let dispute_key = 10;
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            dispute_key,
+            ALICE,
+            get_jury::<Test>(vec![BOB, CHARLIE]),
+            get_specifics::<Test>(vec![0]),
+            None,
+        ));
+        let initial_expiry = Disputes::<Test>::get(dispute_key).unwrap().expiration;
+        for _ in 0..2 {
+            assert_ok!(PalletDisputes::extend_dispute(
+                RuntimeOrigin::signed(BOB),
+                dispute_key
+            ));
+            assert_ok!(PalletDisputes::extend_dispute(
+                RuntimeOrigin::signed(CHARLIE),
+                dispute_key
+            ));
+        }
+        let d = Disputes::<Test>::get(dispute_key).unwrap();
+        assert_eq!(d.extensions, 2);
+        assert_eq!(
+            d.expiration,
+            initial_expiry + <Test as Config>::VotingTimeLimit::get() * 2
+        );
+        assert_noop!(
+            PalletDisputes::extend_dispute(RuntimeOrigin::signed(BOB), dispute_key),
+            Error::<Test>::DisputeAlreadyExtended
+        );
+    });
+}
+
+#[test]
+fn replaced_jurors_lose_their_extension_vote() {
+    new_test_ext().execute_with(|| {
+        let dispute_key = 10;
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            dispute_key,
+            ALICE,
+            get_jury::<Test>(vec![BOB, CHARLIE]),
+            get_specifics::<Test>(vec![0]),
+            None,
+        ));
+        assert_ok!(PalletDisputes::extend_dispute(
+            RuntimeOrigin::signed(BOB),
+            dispute_key
+        ));
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::replace_juror(
+            dispute_key,
+            &BOB,
+            Some(FERDIE)
+        ));
+        assert!(Disputes::<Test>::get(dispute_key)
+            .unwrap()
+            .extension_votes
+            .is_empty());
+    });
+}
+
 // Where a dispute has been raised in the past and then extended on top of MaxDisputesPerBlock.
 #[test]
 fn extend_dispute_too_many_disputes() {
@@ -489,6 +636,7 @@ fn extend_dispute_too_many_disputes() {
             ALICE,
             jury.clone(),
             specifics.clone(),
+            None,
         ));
 
         run_to_block::<Test>(
@@ -500,6 +648,7 @@ fn extend_dispute_too_many_disputes() {
                 ALICE,
                 jury.clone(),
                 specifics.clone(),
+                None,
             ));
         });
         // It will never exists here unless the VotingTimeLimit is changed to a value smaller than before.
@@ -523,6 +672,7 @@ fn try_auto_finalise_without_votes_fails() {
             ALICE,
             jury,
             specifics,
+            None,
         ));
         // Noone votes, go directly to expiry block
         run_to_block::<Test>(expiry_block);
@@ -530,7 +680,7 @@ fn try_auto_finalise_without_votes_fails() {
         System::assert_last_event(RuntimeEvent::PalletDisputes(
             Event::<Test>::DisputeCompleted {
                 dispute_key,
-                dispute_result: DisputeResult::Failure,
+                verdicts: get_verdicts::<Test>(vec![0, 1], DisputeResult::Failure),
             },
         ));
     });
@@ -547,6 +697,7 @@ fn force_succeed_dispute_not_force_origin() {
             ALICE,
             jury,
             specifics,
+            None,
         ));
         assert_noop!(
             PalletDisputes::force_succeed_dispute(RuntimeOrigin::signed(BOB), dispute_key),
@@ -570,6 +721,7 @@ fn force_succeed_dispute_works_assert_event() {
             ALICE,
             jury,
             specifics,
+            None,
         ));
         assert_ok!(PalletDisputes::force_succeed_dispute(
             RuntimeOrigin::root(),
@@ -578,7 +730,7 @@ fn force_succeed_dispute_works_assert_event() {
         System::assert_last_event(RuntimeEvent::PalletDisputes(
             Event::<Test>::DisputeCompleted {
                 dispute_key,
-                dispute_result: DisputeResult::Success,
+                verdicts: get_verdicts::<Test>(vec![0, 1], DisputeResult::Success),
             },
         ));
     });
@@ -597,6 +749,7 @@ fn force_succeed_dispute_works_assert_state() {
             ALICE,
             jury,
             specifics,
+            None,
         ));
         assert_ok!(PalletDisputes::force_succeed_dispute(
             RuntimeOrigin::root(),
@@ -620,6 +773,7 @@ fn force_fail_dispute_works_assert_state() {
             ALICE,
             jury,
             specifics,
+            None,
         ));
         assert_ok!(PalletDisputes::force_fail_dispute(
             RuntimeOrigin::root(),
@@ -641,6 +795,7 @@ fn force_fail_dispute_works_assert_event() {
             ALICE,
             jury,
             specifics,
+            None,
         ));
         assert_ok!(PalletDisputes::force_fail_dispute(
             RuntimeOrigin::root(),
@@ -649,7 +804,7 @@ fn force_fail_dispute_works_assert_event() {
         System::assert_last_event(RuntimeEvent::PalletDisputes(
             Event::<Test>::DisputeCompleted {
                 dispute_key,
-                dispute_result: DisputeResult::Failure,
+                verdicts: get_verdicts::<Test>(vec![0, 1], DisputeResult::Failure),
             },
         ))
     });
@@ -666,6 +821,7 @@ fn force_fail_dispute_not_force_origin() {
             ALICE,
             jury,
             specifics,
+            None,
         ));
 
         assert_noop!(
@@ -691,6 +847,7 @@ fn e2e() {
             ALICE,
             jury,
             specifics,
+            None,
         ));
         assert_ok!(PalletDisputes::vote_on_dispute(
             RuntimeOrigin::signed(BOB),
@@ -706,3 +863,1813 @@ fn e2e() {
         assert_eq!(0, PalletDisputes::disputes(dispute_key).iter().count());
     });
 }
+
+#[test]
+fn disputes_finalising_between_returns_keys_per_block() {
+    new_test_ext().execute_with(|| {
+        let jury = get_jury::<Test>(vec![CHARLIE, BOB]);
+        let specifics = get_specifics::<Test>(vec![0, 1]);
+        let first_expiry =
+            <Test as Config>::VotingTimeLimit::get() + frame_system::Pallet::<Test>::block_number();
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            0,
+            ALICE,
+            jury.clone(),
+            specifics.clone(),
+            None,
+        ));
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            1,
+            ALICE,
+            jury.clone(),
+            specifics.clone(),
+            None,
+        ));
+        run_to_block::<Test>(frame_system::Pallet::<Test>::block_number() + 2);
+        let second_expiry =
+            <Test as Config>::VotingTimeLimit::get() + frame_system::Pallet::<Test>::block_number();
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            2, ALICE, jury, specifics, None,
+        ));
+
+        assert_eq!(
+            PalletDisputes::disputes_finalising_between(0, second_expiry + 10),
+            (
+                vec![(first_expiry, vec![0, 1]), (second_expiry, vec![2])],
+                second_expiry + 10
+            )
+        );
+        assert_eq!(
+            PalletDisputes::disputes_finalising_between(first_expiry + 1, second_expiry),
+            (vec![(second_expiry, vec![2])], second_expiry)
+        );
+        assert!(
+            PalletDisputes::disputes_finalising_between(second_expiry, first_expiry)
+                .0
+                .is_empty()
+        );
+    });
+}
+
+#[test]
+fn disputes_finalising_between_returns_the_end_of_a_truncated_range() {
+    new_test_ext().execute_with(|| {
+        let max_range: BlockNumber = crate::impls::MAX_FINALISE_QUERY_RANGE.into();
+        System::set_block_number(2 * max_range);
+        let expiry =
+            <Test as Config>::VotingTimeLimit::get() + frame_system::Pallet::<Test>::block_number();
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            0,
+            ALICE,
+            get_jury::<Test>(vec![CHARLIE, BOB]),
+            get_specifics::<Test>(vec![0, 1]),
+            None,
+        ));
+
+        // The dispute is past the end of the first page.
+        let from = expiry - max_range - 1;
+        let (finalising, searched_to) =
+            PalletDisputes::disputes_finalising_between(from, expiry + 10);
+        assert!(finalising.is_empty());
+        assert_eq!(searched_to, from + max_range);
+        assert_eq!(
+            PalletDisputes::disputes_finalising_between(searched_to + 1, expiry + 10),
+            (vec![(expiry, vec![0])], expiry + 10)
+        );
+    });
+}
+
+#[test]
+fn disputes_awaiting_vote_excludes_voted_and_non_jury() {
+    new_test_ext().execute_with(|| {
+        let specifics = get_specifics::<Test>(vec![0, 1]);
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            0,
+            ALICE,
+            get_jury::<Test>(vec![CHARLIE, BOB]),
+            specifics.clone(),
+            None,
+        ));
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            1,
+            ALICE,
+            get_jury::<Test>(vec![BOB]),
+            specifics,
+            None,
+        ));
+        assert_ok!(PalletDisputes::vote_on_dispute(
+            RuntimeOrigin::signed(CHARLIE),
+            0,
+            true
+        ));
+
+        let mut bob_pending = PalletDisputes::disputes_awaiting_vote(&BOB);
+        bob_pending.sort();
+        assert_eq!(bob_pending, vec![0, 1]);
+        assert!(PalletDisputes::disputes_awaiting_vote(&CHARLIE).is_empty());
+        assert!(PalletDisputes::disputes_awaiting_vote(&FERDIE).is_empty());
+    });
+}
+
+#[test]
+fn projected_result_follows_votes() {
+    new_test_ext().execute_with(|| {
+        let dispute_key = 0;
+        assert_eq!(PalletDisputes::projected_result(dispute_key), None);
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            dispute_key,
+            ALICE,
+            get_jury::<Test>(vec![CHARLIE, BOB, FERDIE]),
+            get_specifics::<Test>(vec![0, 1]),
+            None,
+        ));
+        assert_eq!(
+            PalletDisputes::projected_result(dispute_key),
+            Some(vec![
+                (0, DisputeResult::Failure),
+                (1, DisputeResult::Failure)
+            ])
+        );
+        assert_ok!(PalletDisputes::vote_on_dispute(
+            RuntimeOrigin::signed(CHARLIE),
+            dispute_key,
+            true
+        ));
+        assert_eq!(
+            PalletDisputes::projected_result(dispute_key),
+            Some(vec![
+                (0, DisputeResult::Success),
+                (1, DisputeResult::Success)
+            ])
+        );
+    });
+}
+
+#[test]
+fn disputes_of_juror_only_returns_open_disputes() {
+    new_test_ext().execute_with(|| {
+        let specifics = get_specifics::<Test>(vec![0]);
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            0,
+            ALICE,
+            get_jury::<Test>(vec![CHARLIE, BOB]),
+            specifics.clone(),
+            None,
+        ));
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            1,
+            ALICE,
+            get_jury::<Test>(vec![BOB]),
+            specifics,
+            None,
+        ));
+        let mut bob_disputes =
+            <PalletDisputes as DisputeRaiser<AccountId>>::disputes_of_juror(&BOB);
+        bob_disputes.sort();
+        assert_eq!(bob_disputes, vec![0, 1]);
+        assert_eq!(
+            <PalletDisputes as DisputeRaiser<AccountId>>::disputes_of_juror(&CHARLIE),
+            vec![0]
+        );
+
+        // A completed dispute is no longer open.
+        assert_ok!(PalletDisputes::vote_on_dispute(
+            RuntimeOrigin::signed(BOB),
+            1,
+            true
+        ));
+        assert_eq!(
+            <PalletDisputes as DisputeRaiser<AccountId>>::disputes_of_juror(&BOB),
+            vec![0]
+        );
+    });
+}
+
+#[test]
+fn disputes_of_juror_follows_replacements_and_cancellations() {
+    new_test_ext().execute_with(|| {
+        let dispute_key = 0;
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            dispute_key,
+            ALICE,
+            get_jury::<Test>(vec![CHARLIE, BOB]),
+            get_specifics::<Test>(vec![0]),
+            None,
+        ));
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::replace_juror(
+            dispute_key,
+            &CHARLIE,
+            Some(FERDIE)
+        ));
+        assert!(
+            <PalletDisputes as DisputeRaiser<AccountId>>::disputes_of_juror(&CHARLIE).is_empty()
+        );
+        assert_eq!(JurorDisputeCount::<Test>::get(CHARLIE), 0);
+        assert_eq!(
+            <PalletDisputes as DisputeRaiser<AccountId>>::disputes_of_juror(&FERDIE),
+            vec![dispute_key]
+        );
+
+        assert_ok!(PalletDisputes::cancel_dispute(
+            RuntimeOrigin::signed(ALICE),
+            dispute_key
+        ));
+        for juror in [BOB, FERDIE] {
+            assert!(
+                <PalletDisputes as DisputeRaiser<AccountId>>::disputes_of_juror(&juror).is_empty()
+            );
+            assert_eq!(JurorDisputeCount::<Test>::get(juror), 0);
+        }
+    });
+}
+
+#[test]
+fn jurors_sit_on_at_most_max_disputes_per_juror() {
+    new_test_ext().execute_with(|| {
+        MaxDisputesPerJuror::set(1);
+        let specifics = get_specifics::<Test>(vec![0]);
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            0,
+            ALICE,
+            get_jury::<Test>(vec![BOB]),
+            specifics.clone(),
+            None,
+        ));
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            1,
+            ALICE,
+            get_jury::<Test>(vec![CHARLIE]),
+            specifics.clone(),
+            None,
+        ));
+        assert_noop!(
+            <PalletDisputes as DisputeRaiser<AccountId>>::replace_juror(1, &CHARLIE, Some(BOB)),
+            Error::<Test>::JurorHasTooManyDisputes
+        );
+
+        // A juror with no room left is skipped rather than failing the dispute.
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            2,
+            ALICE,
+            get_jury::<Test>(vec![BOB, FERDIE]),
+            specifics.clone(),
+            None,
+        ));
+        assert_eq!(
+            Disputes::<Test>::get(2).unwrap().jury.to_vec(),
+            vec![FERDIE]
+        );
+        assert_eq!(JurorDisputeCount::<Test>::get(BOB), 1);
+        System::assert_has_event(RuntimeEvent::PalletDisputes(Event::<Test>::JurorSkipped {
+            dispute_key: 2,
+            juror: BOB,
+        }));
+
+        // A completed dispute frees up the juror.
+        assert_ok!(PalletDisputes::force_fail_dispute(RuntimeOrigin::root(), 0));
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            3,
+            ALICE,
+            get_jury::<Test>(vec![BOB]),
+            specifics,
+            None,
+        ));
+        assert_eq!(Disputes::<Test>::get(3).unwrap().jury.to_vec(), vec![BOB]);
+    });
+}
+
+#[test]
+fn replace_juror_discards_their_votes() {
+    new_test_ext().execute_with(|| {
+        let dispute_key = 0;
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            dispute_key,
+            ALICE,
+            get_jury::<Test>(vec![CHARLIE, BOB]),
+            get_specifics::<Test>(vec![0]),
+            None,
+        ));
+        assert_ok!(PalletDisputes::vote_on_dispute(
+            RuntimeOrigin::signed(CHARLIE),
+            dispute_key,
+            true
+        ));
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::replace_juror(
+            dispute_key,
+            &CHARLIE,
+            Some(FERDIE)
+        ));
+
+        let dispute = Disputes::<Test>::get(dispute_key).unwrap();
+        assert_eq!(dispute.jury.to_vec(), vec![FERDIE, BOB]);
+        assert!(!dispute.votes.contains_key(&CHARLIE));
+        System::assert_last_event(RuntimeEvent::PalletDisputes(Event::<Test>::JurorReplaced {
+            dispute_key,
+            juror: CHARLIE,
+            replacement: Some(FERDIE),
+        }));
+        assert_noop!(
+            PalletDisputes::vote_on_dispute(RuntimeOrigin::signed(CHARLIE), dispute_key, true),
+            Error::<Test>::NotAJuryAccount
+        );
+        assert_ok!(PalletDisputes::vote_on_dispute(
+            RuntimeOrigin::signed(FERDIE),
+            dispute_key,
+            true
+        ));
+    });
+}
+
+#[test]
+fn replace_juror_without_replacement_shrinks_the_jury() {
+    new_test_ext().execute_with(|| {
+        let dispute_key = 0;
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            dispute_key,
+            ALICE,
+            get_jury::<Test>(vec![CHARLIE, BOB]),
+            get_specifics::<Test>(vec![0]),
+            None,
+        ));
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::replace_juror(
+            dispute_key,
+            &CHARLIE,
+            None
+        ));
+        assert_eq!(
+            Disputes::<Test>::get(dispute_key).unwrap().jury.to_vec(),
+            vec![BOB]
+        );
+    });
+}
+
+#[test]
+fn replace_juror_fails_for_invalid_jurors() {
+    new_test_ext().execute_with(|| {
+        let dispute_key = 0;
+        assert_noop!(
+            <PalletDisputes as DisputeRaiser<AccountId>>::replace_juror(
+                dispute_key,
+                &CHARLIE,
+                Some(FERDIE)
+            ),
+            Error::<Test>::DisputeDoesNotExist
+        );
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            dispute_key,
+            ALICE,
+            get_jury::<Test>(vec![CHARLIE, BOB]),
+            get_specifics::<Test>(vec![0]),
+            None,
+        ));
+        assert_noop!(
+            <PalletDisputes as DisputeRaiser<AccountId>>::replace_juror(
+                dispute_key,
+                &FERDIE,
+                Some(ALICE)
+            ),
+            Error::<Test>::NotAJuryAccount
+        );
+        assert_noop!(
+            <PalletDisputes as DisputeRaiser<AccountId>>::replace_juror(
+                dispute_key,
+                &CHARLIE,
+                Some(BOB)
+            ),
+            Error::<Test>::AlreadyAJuryAccount
+        );
+    });
+}
+
+#[test]
+fn jurors_voting_with_the_result_are_rewarded_on_expiry() {
+    new_test_ext().execute_with(|| {
+        use common_types::CurrencyId;
+        use orml_traits::MultiCurrency;
+        let dispute_key = 10;
+        let jury = get_jury::<Test>(vec![CHARLIE, BOB, FERDIE]);
+        let specifics = get_specifics::<Test>(vec![0, 1]);
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            dispute_key,
+            ALICE,
+            jury,
+            specifics,
+            None,
+        ));
+        assert_ok!(PalletDisputes::vote_on_dispute(
+            RuntimeOrigin::signed(BOB),
+            dispute_key,
+            true
+        ));
+        assert_ok!(PalletDisputes::vote_on_dispute(
+            RuntimeOrigin::signed(CHARLIE),
+            dispute_key,
+            false
+        ));
+        let bob_before = Tokens::free_balance(CurrencyId::Native, &BOB);
+        let charlie_before = Tokens::free_balance(CurrencyId::Native, &CHARLIE);
+        let treasury_before = Tokens::free_balance(CurrencyId::Native, &TREASURY);
+
+        let current_block = frame_system::Pallet::<Test>::current_block_number();
+        run_to_block::<Test>(current_block + <Test as Config>::VotingTimeLimit::get());
+        // The jury is settled once the verdicts can no longer be appealed.
+        assert_eq!(Tokens::free_balance(CurrencyId::Native, &BOB), bob_before);
+        run_to_block::<Test>(System::block_number() + <Test as Config>::AppealWindow::get());
+
+        // A tie succeeds so only BOB voted in line with the result.
+        let reward = <Test as Config>::JurorRewardPerDispute::get();
+        assert_eq!(
+            Tokens::free_balance(CurrencyId::Native, &BOB),
+            bob_before + reward
+        );
+        assert_eq!(
+            Tokens::free_balance(CurrencyId::Native, &CHARLIE),
+            charlie_before
+        );
+        assert_eq!(
+            Tokens::free_balance(CurrencyId::Native, &TREASURY),
+            treasury_before - reward
+        );
+        System::assert_has_event(RuntimeEvent::PalletDisputes(Event::<Test>::JurorRewarded {
+            who: BOB,
+            dispute_key,
+            amount: reward,
+        }));
+    });
+}
+
+#[test]
+fn jurors_share_the_reward_on_unanimous_finalise() {
+    new_test_ext().execute_with(|| {
+        use common_types::CurrencyId;
+        use orml_traits::MultiCurrency;
+        let dispute_key = 10;
+        let jury = get_jury::<Test>(vec![CHARLIE, BOB]);
+        let specifics = get_specifics::<Test>(vec![0, 1]);
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            dispute_key,
+            ALICE,
+            jury,
+            specifics,
+            None,
+        ));
+        let bob_before = Tokens::free_balance(CurrencyId::Native, &BOB);
+        let charlie_before = Tokens::free_balance(CurrencyId::Native, &CHARLIE);
+        assert_ok!(PalletDisputes::vote_on_dispute(
+            RuntimeOrigin::signed(BOB),
+            dispute_key,
+            false
+        ));
+        assert_ok!(PalletDisputes::vote_on_dispute(
+            RuntimeOrigin::signed(CHARLIE),
+            dispute_key,
+            false
+        ));
+        run_to_block::<Test>(System::block_number() + <Test as Config>::AppealWindow::get());
+
+        let reward_per_juror = <Test as Config>::JurorRewardPerDispute::get() / 2;
+        assert_eq!(
+            Tokens::free_balance(CurrencyId::Native, &BOB),
+            bob_before + reward_per_juror
+        );
+        assert_eq!(
+            Tokens::free_balance(CurrencyId::Native, &CHARLIE),
+            charlie_before + reward_per_juror
+        );
+    });
+}
+
+#[test]
+fn missed_votes_are_counted_and_reset() {
+    new_test_ext().execute_with(|| {
+        let jury = get_jury::<Test>(vec![CHARLIE, BOB]);
+        let specifics = get_specifics::<Test>(vec![0]);
+        (0u32..2).for_each(|dispute_key| {
+            assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+                dispute_key,
+                ALICE,
+                jury.clone(),
+                specifics.clone(),
+                None,
+            ));
+            assert_ok!(PalletDisputes::vote_on_dispute(
+                RuntimeOrigin::signed(BOB),
+                dispute_key,
+                true
+            ));
+            let current_block = frame_system::Pallet::<Test>::current_block_number();
+            run_to_block::<Test>(
+                current_block
+                    + <Test as Config>::VotingTimeLimit::get()
+                    + <Test as Config>::AppealWindow::get(),
+            );
+        });
+
+        assert_eq!(MissedJuryVotes::<Test>::get(CHARLIE), 2);
+        assert_eq!(MissedJuryVotes::<Test>::get(BOB), 0);
+        assert_eq!(PenalisedJurors::get(), vec![(CHARLIE, 1), (CHARLIE, 2)]);
+        System::assert_has_event(RuntimeEvent::PalletDisputes(
+            Event::<Test>::JurorMissedVote {
+                who: CHARLIE,
+                dispute_key: 1,
+                missed_in_a_row: 2,
+            },
+        ));
+
+        let dispute_key = 2;
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            dispute_key,
+            ALICE,
+            jury,
+            specifics,
+            None,
+        ));
+        assert_ok!(PalletDisputes::vote_on_dispute(
+            RuntimeOrigin::signed(CHARLIE),
+            dispute_key,
+            true
+        ));
+        let current_block = frame_system::Pallet::<Test>::current_block_number();
+        run_to_block::<Test>(
+            current_block
+                + <Test as Config>::VotingTimeLimit::get()
+                + <Test as Config>::AppealWindow::get(),
+        );
+        assert_eq!(MissedJuryVotes::<Test>::get(CHARLIE), 0);
+        assert_eq!(MissedJuryVotes::<Test>::get(BOB), 1);
+    });
+}
+
+#[test]
+fn dispute_bond_is_handed_to_hooks_on_completion() {
+    new_test_ext().execute_with(|| {
+        let dispute_key = 10;
+        let jury = get_jury::<Test>(vec![CHARLIE, BOB]);
+        let specifics = get_specifics::<Test>(vec![0, 1]);
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            dispute_key,
+            ALICE,
+            jury,
+            specifics,
+            Some(5),
+        ));
+        assert_eq!(Disputes::<Test>::get(dispute_key).unwrap().bond, Some(5));
+        assert_ok!(PalletDisputes::force_fail_dispute(
+            RuntimeOrigin::root(),
+            dispute_key
+        ));
+        assert_eq!(
+            CompletedDisputes::get(),
+            vec![(
+                dispute_key,
+                vec![(0, DisputeResult::Failure), (1, DisputeResult::Failure)],
+                Some(5)
+            )]
+        );
+    });
+}
+
+#[test]
+fn vote_on_specifics_must_cover_every_specific() {
+    new_test_ext().execute_with(|| {
+        let dispute_key = 10;
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            dispute_key,
+            ALICE,
+            get_jury::<Test>(vec![CHARLIE, BOB]),
+            get_specifics::<Test>(vec![0, 1]),
+            None,
+        ));
+        assert_noop!(
+            PalletDisputes::vote_on_specifics(
+                RuntimeOrigin::signed(BOB),
+                dispute_key,
+                vec![(0, true)].try_into().unwrap()
+            ),
+            Error::<Test>::MissingSpecificVote
+        );
+        assert_noop!(
+            PalletDisputes::vote_on_specifics(
+                RuntimeOrigin::signed(BOB),
+                dispute_key,
+                vec![(0, true), (2, true)].try_into().unwrap()
+            ),
+            Error::<Test>::SpecificNotInDispute
+        );
+        assert_noop!(
+            PalletDisputes::vote_on_specifics(
+                RuntimeOrigin::signed(ALICE),
+                dispute_key,
+                vec![(0, true), (1, true)].try_into().unwrap()
+            ),
+            Error::<Test>::NotAJuryAccount
+        );
+    });
+}
+
+#[test]
+fn vote_on_specifics_autofinalises_with_a_verdict_per_specific() {
+    new_test_ext().execute_with(|| {
+        let dispute_key = 10;
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            dispute_key,
+            ALICE,
+            get_jury::<Test>(vec![CHARLIE, BOB]),
+            get_specifics::<Test>(vec![0, 1]),
+            None,
+        ));
+        assert_ok!(PalletDisputes::vote_on_specifics(
+            RuntimeOrigin::signed(BOB),
+            dispute_key,
+            vec![(0, true), (1, false)].try_into().unwrap()
+        ));
+        System::assert_last_event(RuntimeEvent::PalletDisputes(
+            Event::<Test>::DisputeSpecificsVotedOn {
+                who: BOB,
+                dispute_key,
+                votes: frame_support::BoundedBTreeMap::try_from(
+                    [(0, true), (1, false)]
+                        .into_iter()
+                        .collect::<sp_std::collections::btree_map::BTreeMap<_, _>>(),
+                )
+                .unwrap(),
+            },
+        ));
+        assert_ok!(PalletDisputes::vote_on_specifics(
+            RuntimeOrigin::signed(CHARLIE),
+            dispute_key,
+            vec![(1, false), (0, true)].try_into().unwrap()
+        ));
+        let verdicts = vec![(0, DisputeResult::Success), (1, DisputeResult::Failure)];
+        System::assert_last_event(RuntimeEvent::PalletDisputes(
+            Event::<Test>::DisputeCompleted {
+                dispute_key,
+                verdicts: verdicts.clone().try_into().unwrap(),
+            },
+        ));
+        assert!(Disputes::<Test>::get(dispute_key).is_none());
+        assert!(CompletedDisputes::get().is_empty());
+        run_to_block::<Test>(System::block_number() + <Test as Config>::AppealWindow::get());
+        assert_eq!(
+            CompletedDisputes::get(),
+            vec![(dispute_key, verdicts, None)]
+        );
+    });
+}
+
+#[test]
+fn split_votes_on_a_specific_wait_for_expiry() {
+    new_test_ext().execute_with(|| {
+        let dispute_key = 10;
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            dispute_key,
+            ALICE,
+            get_jury::<Test>(vec![CHARLIE, BOB]),
+            get_specifics::<Test>(vec![0, 1]),
+            None,
+        ));
+        assert_ok!(PalletDisputes::vote_on_specifics(
+            RuntimeOrigin::signed(BOB),
+            dispute_key,
+            vec![(0, true), (1, false)].try_into().unwrap()
+        ));
+        assert_ok!(PalletDisputes::vote_on_specifics(
+            RuntimeOrigin::signed(CHARLIE),
+            dispute_key,
+            vec![(0, true), (1, true)].try_into().unwrap()
+        ));
+        assert!(Disputes::<Test>::get(dispute_key).is_some());
+    });
+}
+
+fn complete_with_verdicts(dispute_key: u32, votes: Vec<(u32, bool)>) {
+    assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+        dispute_key,
+        ALICE,
+        get_jury::<Test>(vec![CHARLIE, BOB]),
+        get_specifics::<Test>(votes.iter().map(|(specific_id, _)| *specific_id).collect()),
+        Some(5),
+    ));
+    for juror in [CHARLIE, BOB] {
+        assert_ok!(PalletDisputes::vote_on_specifics(
+            RuntimeOrigin::signed(juror),
+            dispute_key,
+            votes.clone().try_into().unwrap()
+        ));
+    }
+}
+
+#[test]
+fn verdicts_are_finalised_once_the_appeal_window_closes() {
+    new_test_ext().execute_with(|| {
+        let dispute_key = 10;
+        complete_with_verdicts(dispute_key, vec![(0, true), (1, false)]);
+        let pending =
+            PendingVerdicts::<Test>::get(dispute_key).expect("verdicts should be pending");
+        assert_eq!(
+            pending.appeal_window_closes,
+            System::block_number() + <Test as Config>::AppealWindow::get()
+        );
+        assert_noop!(
+            <PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+                dispute_key,
+                ALICE,
+                get_jury::<Test>(vec![CHARLIE, BOB]),
+                get_specifics::<Test>(vec![0]),
+                None,
+            ),
+            Error::<Test>::DisputeAlreadyExists
+        );
+
+        run_to_block::<Test>(pending.appeal_window_closes);
+        let verdicts = vec![(0, DisputeResult::Success), (1, DisputeResult::Failure)];
+        assert_eq!(
+            CompletedDisputes::get(),
+            vec![(dispute_key, verdicts.clone(), Some(5))]
+        );
+        assert!(PendingVerdicts::<Test>::get(dispute_key).is_none());
+        System::assert_last_event(RuntimeEvent::PalletDisputes(
+            Event::<Test>::VerdictsFinalised {
+                dispute_key,
+                verdicts: verdicts.try_into().unwrap(),
+            },
+        ));
+        assert_noop!(
+            PalletDisputes::appeal_dispute(RuntimeOrigin::signed(ALICE), dispute_key),
+            Error::<Test>::NoVerdictsToAppeal
+        );
+    });
+}
+
+#[test]
+fn only_a_losing_party_can_appeal() {
+    new_test_ext().execute_with(|| {
+        use common_types::CurrencyId;
+        use orml_traits::{MultiCurrency, MultiReservableCurrency};
+        let dispute_key = 10;
+        complete_with_verdicts(dispute_key, vec![(0, true), (1, true)]);
+        assert_noop!(
+            PalletDisputes::appeal_dispute(RuntimeOrigin::signed(ALICE), dispute_key),
+            Error::<Test>::NotALosingParty
+        );
+        assert_noop!(
+            PalletDisputes::appeal_dispute(RuntimeOrigin::signed(CHARLIE), dispute_key),
+            Error::<Test>::NotALosingParty
+        );
+        assert_ok!(PalletDisputes::appeal_dispute(
+            RuntimeOrigin::signed(DEFENDANT),
+            dispute_key
+        ));
+        assert_eq!(
+            Tokens::reserved_balance(CurrencyId::Native, &DEFENDANT),
+            AppealBond::get()
+        );
+        assert_noop!(
+            PalletDisputes::appeal_dispute(RuntimeOrigin::signed(DEFENDANT), dispute_key),
+            Error::<Test>::AlreadyAppealed
+        );
+    });
+}
+
+#[test]
+fn appeal_fails_after_the_appeal_window_closes() {
+    new_test_ext().execute_with(|| {
+        let dispute_key = 10;
+        complete_with_verdicts(dispute_key, vec![(0, false)]);
+        let closes = PendingVerdicts::<Test>::get(dispute_key)
+            .unwrap()
+            .appeal_window_closes;
+        PendingVerdicts::<Test>::mutate(dispute_key, |pending| {
+            pending.as_mut().unwrap().appeal_window_closes = System::block_number();
+        });
+        assert_noop!(
+            PalletDisputes::appeal_dispute(RuntimeOrigin::signed(ALICE), dispute_key),
+            Error::<Test>::AppealWindowClosed
+        );
+        // The stale window no longer matches so the verdicts are not finalised.
+        run_to_block::<Test>(closes);
+        assert!(CompletedDisputes::get().is_empty());
+    });
+}
+
+#[test]
+fn appealed_verdicts_wait_for_the_appeal_origin() {
+    new_test_ext().execute_with(|| {
+        use common_types::CurrencyId;
+        use orml_traits::{MultiCurrency, MultiReservableCurrency};
+        let dispute_key = 10;
+        complete_with_verdicts(dispute_key, vec![(0, false), (1, false)]);
+        assert_noop!(
+            PalletDisputes::decide_appeal(
+                RuntimeOrigin::root(),
+                dispute_key,
+                get_verdicts::<Test>(vec![0, 1], DisputeResult::Success)
+            ),
+            Error::<Test>::NotAppealed
+        );
+        assert_ok!(PalletDisputes::appeal_dispute(
+            RuntimeOrigin::signed(ALICE),
+            dispute_key
+        ));
+        System::assert_last_event(RuntimeEvent::PalletDisputes(
+            Event::<Test>::DisputeAppealed {
+                dispute_key,
+                who: ALICE,
+            },
+        ));
+        run_to_block::<Test>(System::block_number() + <Test as Config>::AppealWindow::get());
+        assert!(CompletedDisputes::get().is_empty());
+
+        assert_noop!(
+            PalletDisputes::decide_appeal(
+                RuntimeOrigin::signed(ALICE),
+                dispute_key,
+                get_verdicts::<Test>(vec![0, 1], DisputeResult::Success)
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            PalletDisputes::decide_appeal(
+                RuntimeOrigin::root(),
+                dispute_key,
+                get_verdicts::<Test>(vec![0, 2], DisputeResult::Success)
+            ),
+            Error::<Test>::InvalidAppealVerdicts
+        );
+        let alice_free = Tokens::free_balance(CurrencyId::Native, &ALICE);
+        let decided = vec![(0, DisputeResult::Success), (1, DisputeResult::Failure)];
+        assert_ok!(PalletDisputes::decide_appeal(
+            RuntimeOrigin::root(),
+            dispute_key,
+            decided.clone().try_into().unwrap()
+        ));
+        assert_eq!(
+            Tokens::free_balance(CurrencyId::Native, &ALICE),
+            alice_free + AppealBond::get()
+        );
+        assert_eq!(Tokens::reserved_balance(CurrencyId::Native, &ALICE), 0);
+        assert_eq!(
+            CompletedDisputes::get(),
+            vec![(dispute_key, decided, Some(5))]
+        );
+        assert!(PendingVerdicts::<Test>::get(dispute_key).is_none());
+    });
+}
+
+#[test]
+fn upheld_appeal_slashes_the_appeal_bond() {
+    new_test_ext().execute_with(|| {
+        use common_types::CurrencyId;
+        use orml_traits::{MultiCurrency, MultiReservableCurrency};
+        let dispute_key = 10;
+        complete_with_verdicts(dispute_key, vec![(0, false)]);
+        assert_ok!(PalletDisputes::appeal_dispute(
+            RuntimeOrigin::signed(ALICE),
+            dispute_key
+        ));
+        let alice_free = Tokens::free_balance(CurrencyId::Native, &ALICE);
+        let pot_before = Tokens::free_balance(CurrencyId::Native, &TREASURY);
+        assert_ok!(PalletDisputes::decide_appeal(
+            RuntimeOrigin::root(),
+            dispute_key,
+            get_verdicts::<Test>(vec![0], DisputeResult::Failure)
+        ));
+        assert_eq!(Tokens::free_balance(CurrencyId::Native, &ALICE), alice_free);
+        assert_eq!(Tokens::reserved_balance(CurrencyId::Native, &ALICE), 0);
+        // The pot takes the appeal bond and pays the jurors that voted with the upheld verdict.
+        assert_eq!(
+            Tokens::free_balance(CurrencyId::Native, &TREASURY),
+            pot_before + AppealBond::get() - <Test as Config>::JurorRewardPerDispute::get()
+        );
+        assert_eq!(
+            CompletedDisputes::get(),
+            vec![(dispute_key, vec![(0, DisputeResult::Failure)], Some(5))]
+        );
+    });
+}
+
+#[test]
+fn appeal_bond_that_cannot_be_settled_is_reported() {
+    new_test_ext().execute_with(|| {
+        let dispute_key = 10;
+        complete_with_verdicts(dispute_key, vec![(0, false)]);
+        assert_ok!(PalletDisputes::appeal_dispute(
+            RuntimeOrigin::signed(ALICE),
+            dispute_key
+        ));
+        let appeal_bond = PendingVerdicts::<Test>::get(dispute_key)
+            .unwrap()
+            .appeal_bond;
+        assert_eq!(appeal_bond, Some(0));
+        // The deposit has gone missing by the time the appeal is decided.
+        AppealDeposits::set(vec![]);
+        assert_ok!(PalletDisputes::decide_appeal(
+            RuntimeOrigin::root(),
+            dispute_key,
+            get_verdicts::<Test>(vec![0], DisputeResult::Failure)
+        ));
+        System::assert_has_event(RuntimeEvent::PalletDisputes(
+            Event::<Test>::AppealBondFailed {
+                dispute_key,
+                error: sp_runtime::DispatchError::Other("deposit not found"),
+            },
+        ));
+        // The verdicts are final regardless.
+        assert_eq!(
+            CompletedDisputes::get(),
+            vec![(dispute_key, vec![(0, DisputeResult::Failure)], Some(5))]
+        );
+    });
+}
+
+#[test]
+fn undecided_appeals_lapse_to_the_original_verdicts() {
+    new_test_ext().execute_with(|| {
+        use common_types::CurrencyId;
+        use orml_traits::{MultiCurrency, MultiReservableCurrency};
+        let dispute_key = 10;
+        complete_with_verdicts(dispute_key, vec![(0, false)]);
+        let alice_free = Tokens::free_balance(CurrencyId::Native, &ALICE);
+        assert_ok!(PalletDisputes::appeal_dispute(
+            RuntimeOrigin::signed(ALICE),
+            dispute_key
+        ));
+        let appeal_lapses = System::block_number() + AppealDecisionPeriod::get();
+        assert_eq!(
+            PendingVerdicts::<Test>::get(dispute_key)
+                .unwrap()
+                .appeal_lapses,
+            Some(appeal_lapses)
+        );
+
+        run_to_block::<Test>(appeal_lapses - 1);
+        assert!(CompletedDisputes::get().is_empty());
+        run_to_block::<Test>(appeal_lapses);
+        assert_eq!(
+            CompletedDisputes::get(),
+            vec![(dispute_key, vec![(0, DisputeResult::Failure)], Some(5))]
+        );
+        assert!(PendingVerdicts::<Test>::get(dispute_key).is_none());
+        System::assert_has_event(RuntimeEvent::PalletDisputes(Event::<Test>::AppealLapsed {
+            dispute_key,
+        }));
+        // The appellant is not at fault for the missing decision.
+        assert_eq!(Tokens::free_balance(CurrencyId::Native, &ALICE), alice_free);
+        assert_eq!(Tokens::reserved_balance(CurrencyId::Native, &ALICE), 0);
+        assert_noop!(
+            PalletDisputes::decide_appeal(
+                RuntimeOrigin::root(),
+                dispute_key,
+                get_verdicts::<Test>(vec![0], DisputeResult::Success)
+            ),
+            Error::<Test>::NoVerdictsToAppeal
+        );
+    });
+}
+
+#[test]
+fn decided_appeals_do_not_lapse() {
+    new_test_ext().execute_with(|| {
+        let dispute_key = 10;
+        complete_with_verdicts(dispute_key, vec![(0, false)]);
+        assert_ok!(PalletDisputes::appeal_dispute(
+            RuntimeOrigin::signed(ALICE),
+            dispute_key
+        ));
+        let appeal_lapses = System::block_number() + AppealDecisionPeriod::get();
+        assert_ok!(PalletDisputes::decide_appeal(
+            RuntimeOrigin::root(),
+            dispute_key,
+            get_verdicts::<Test>(vec![0], DisputeResult::Success)
+        ));
+        assert!(AppealsLapseOn::<Test>::get(appeal_lapses).is_empty());
+
+        run_to_block::<Test>(appeal_lapses);
+        assert_eq!(
+            CompletedDisputes::get(),
+            vec![(dispute_key, vec![(0, DisputeResult::Success)], Some(5))]
+        );
+        assert!(!System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::PalletDisputes(Event::<Test>::AppealLapsed { .. })
+        )));
+    });
+}
+
+#[test]
+fn overturned_verdicts_settle_the_jury_against_the_decision() {
+    new_test_ext().execute_with(|| {
+        use common_types::CurrencyId;
+        use orml_traits::MultiCurrency;
+        let dispute_key = 10;
+        let bob_before = Tokens::free_balance(CurrencyId::Native, &BOB);
+        complete_with_verdicts(dispute_key, vec![(0, false)]);
+        assert_ok!(PalletDisputes::appeal_dispute(
+            RuntimeOrigin::signed(ALICE),
+            dispute_key
+        ));
+        run_to_block::<Test>(System::block_number() + <Test as Config>::AppealWindow::get());
+        assert_eq!(Tokens::free_balance(CurrencyId::Native, &BOB), bob_before);
+
+        assert_ok!(PalletDisputes::decide_appeal(
+            RuntimeOrigin::root(),
+            dispute_key,
+            get_verdicts::<Test>(vec![0], DisputeResult::Success)
+        ));
+        // No juror voted with the decided verdict so no one is rewarded.
+        assert_eq!(Tokens::free_balance(CurrencyId::Native, &BOB), bob_before);
+        assert_eq!(MissedJuryVotes::<Test>::get(BOB), 0);
+        assert!(!System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::PalletDisputes(Event::<Test>::JurorRewarded { .. })
+        )));
+    });
+}
+
+#[test]
+fn forced_verdicts_settle_the_jury() {
+    new_test_ext().execute_with(|| {
+        use common_types::CurrencyId;
+        use orml_traits::MultiCurrency;
+        let dispute_key = 10;
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            dispute_key,
+            ALICE,
+            get_jury::<Test>(vec![CHARLIE, BOB]),
+            get_specifics::<Test>(vec![0]),
+            None,
+        ));
+        assert_ok!(PalletDisputes::vote_on_dispute(
+            RuntimeOrigin::signed(BOB),
+            dispute_key,
+            false
+        ));
+        let bob_before = Tokens::free_balance(CurrencyId::Native, &BOB);
+        assert_ok!(PalletDisputes::force_fail_dispute(
+            RuntimeOrigin::root(),
+            dispute_key
+        ));
+        assert_eq!(
+            Tokens::free_balance(CurrencyId::Native, &BOB),
+            bob_before + <Test as Config>::JurorRewardPerDispute::get()
+        );
+        // The dispute was forced before the voting period ended so no vote was missed.
+        assert_eq!(MissedJuryVotes::<Test>::get(CHARLIE), 0);
+
+        // Settling happens once, nothing is left pending to settle again.
+        run_to_block::<Test>(System::block_number() + <Test as Config>::AppealWindow::get());
+        assert_eq!(
+            Tokens::free_balance(CurrencyId::Native, &BOB),
+            bob_before + <Test as Config>::JurorRewardPerDispute::get()
+        );
+        assert_eq!(MissedJuryVotes::<Test>::get(CHARLIE), 0);
+        assert!(PenalisedJurors::get().is_empty());
+    });
+}
+
+fn evidence_hash(byte: u8) -> EvidenceHash {
+    vec![byte; 46].try_into().unwrap()
+}
+
+#[test]
+fn voting_opens_after_the_evidence_period() {
+    new_test_ext().execute_with(|| {
+        EvidencePeriod::set(5);
+        let dispute_key = 10;
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            dispute_key,
+            ALICE,
+            get_jury::<Test>(vec![CHARLIE, BOB]),
+            get_specifics::<Test>(vec![0, 1]),
+            None,
+        ));
+        let dispute = Disputes::<Test>::get(dispute_key).unwrap();
+        assert_eq!(dispute.voting_starts, System::block_number() + 5);
+        assert_eq!(
+            dispute.expiration,
+            dispute.voting_starts + <Test as Config>::VotingTimeLimit::get()
+        );
+        assert_noop!(
+            PalletDisputes::vote_on_dispute(RuntimeOrigin::signed(BOB), dispute_key, true),
+            Error::<Test>::VotingNotOpen
+        );
+        run_to_block::<Test>(dispute.voting_starts);
+        assert_ok!(PalletDisputes::vote_on_dispute(
+            RuntimeOrigin::signed(BOB),
+            dispute_key,
+            true
+        ));
+    });
+}
+
+#[test]
+fn submit_evidence_records_the_role_of_each_party() {
+    new_test_ext().execute_with(|| {
+        EvidencePeriod::set(5);
+        let dispute_key = 10;
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            dispute_key,
+            ALICE,
+            get_jury::<Test>(vec![CHARLIE, BOB]),
+            get_specifics::<Test>(vec![0, 1]),
+            None,
+        ));
+        assert_ok!(PalletDisputes::submit_evidence(
+            RuntimeOrigin::signed(ALICE),
+            dispute_key,
+            evidence_hash(1)
+        ));
+        assert_ok!(PalletDisputes::submit_evidence(
+            RuntimeOrigin::signed(DEFENDANT),
+            dispute_key,
+            evidence_hash(2)
+        ));
+        assert_ok!(PalletDisputes::submit_evidence(
+            RuntimeOrigin::signed(BOB),
+            dispute_key,
+            evidence_hash(3)
+        ));
+        System::assert_last_event(RuntimeEvent::PalletDisputes(
+            Event::<Test>::EvidenceSubmitted {
+                dispute_key,
+                who: BOB,
+                role: EvidenceRole::Juror,
+                hash: evidence_hash(3),
+            },
+        ));
+        let evidence = Disputes::<Test>::get(dispute_key).unwrap().evidence;
+        assert_eq!(
+            evidence
+                .iter()
+                .map(|evidence| (evidence.submitted_by, evidence.role))
+                .collect::<Vec<_>>(),
+            vec![
+                (ALICE, EvidenceRole::Raiser),
+                (DEFENDANT, EvidenceRole::Defendant),
+                (BOB, EvidenceRole::Juror)
+            ]
+        );
+        assert_noop!(
+            PalletDisputes::submit_evidence(
+                RuntimeOrigin::signed(CHARLIE),
+                dispute_key,
+                evidence_hash(4)
+            ),
+            Error::<Test>::TooMuchEvidence
+        );
+    });
+}
+
+#[test]
+fn submit_evidence_fails_for_outsiders_and_after_the_deadline() {
+    new_test_ext().execute_with(|| {
+        EvidencePeriod::set(5);
+        let dispute_key = 10;
+        assert_noop!(
+            PalletDisputes::submit_evidence(
+                RuntimeOrigin::signed(ALICE),
+                dispute_key,
+                evidence_hash(1)
+            ),
+            Error::<Test>::DisputeDoesNotExist
+        );
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            dispute_key,
+            ALICE,
+            get_jury::<Test>(vec![CHARLIE, BOB]),
+            get_specifics::<Test>(vec![0, 1]),
+            None,
+        ));
+        assert_noop!(
+            PalletDisputes::submit_evidence(
+                RuntimeOrigin::signed(FERDIE),
+                dispute_key,
+                evidence_hash(1)
+            ),
+            Error::<Test>::NotADisputeParty
+        );
+        run_to_block::<Test>(System::block_number() + 5);
+        assert_noop!(
+            PalletDisputes::submit_evidence(
+                RuntimeOrigin::signed(ALICE),
+                dispute_key,
+                evidence_hash(1)
+            ),
+            Error::<Test>::EvidencePeriodEnded
+        );
+    });
+}
+
+#[test]
+fn dispute_finalises_early_once_the_policy_has_decided() {
+    new_test_ext().execute_with(|| {
+        UseSupermajority::set(true);
+        let dispute_key = 10;
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            dispute_key,
+            ALICE,
+            get_jury::<Test>(vec![CHARLIE, BOB, FERDIE]),
+            get_specifics::<Test>(vec![0, 1]),
+            None,
+        ));
+        assert_ok!(PalletDisputes::vote_on_dispute(
+            RuntimeOrigin::signed(BOB),
+            dispute_key,
+            true
+        ));
+        assert!(Disputes::<Test>::get(dispute_key).is_some());
+        assert_ok!(PalletDisputes::vote_on_dispute(
+            RuntimeOrigin::signed(CHARLIE),
+            dispute_key,
+            true
+        ));
+        assert!(Disputes::<Test>::get(dispute_key).is_none());
+        System::assert_last_event(RuntimeEvent::PalletDisputes(
+            Event::<Test>::DisputeCompleted {
+                dispute_key,
+                verdicts: get_verdicts::<Test>(vec![0, 1], DisputeResult::Success),
+            },
+        ));
+        run_to_block::<Test>(System::block_number() + <Test as Config>::AppealWindow::get());
+        // FERDIE could still have voted when the dispute finalised early.
+        assert_eq!(MissedJuryVotes::<Test>::get(FERDIE), 0);
+        assert!(PenalisedJurors::get().is_empty());
+    });
+}
+
+#[test]
+fn dispute_without_turnout_fails_on_expiry() {
+    new_test_ext().execute_with(|| {
+        UseSupermajority::set(true);
+        let dispute_key = 10;
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            dispute_key,
+            ALICE,
+            get_jury::<Test>(vec![CHARLIE, BOB, FERDIE]),
+            get_specifics::<Test>(vec![0]),
+            None,
+        ));
+        assert_ok!(PalletDisputes::vote_on_dispute(
+            RuntimeOrigin::signed(BOB),
+            dispute_key,
+            true
+        ));
+        run_to_block::<Test>(System::block_number() + <Test as Config>::VotingTimeLimit::get());
+        System::assert_last_event(RuntimeEvent::PalletDisputes(
+            Event::<Test>::DisputeCompleted {
+                dispute_key,
+                verdicts: get_verdicts::<Test>(vec![0], DisputeResult::Failure),
+            },
+        ));
+    });
+}
+
+#[test]
+fn instances_keep_disputes_with_the_same_key_apart() {
+    new_test_ext().execute_with(|| {
+        let dispute_key = 10;
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            dispute_key,
+            ALICE,
+            get_jury::<Test>(vec![CHARLIE, BOB]),
+            get_specifics::<Test>(vec![0, 1]),
+            None,
+        ));
+        assert_ok!(
+            <FellowshipDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+                dispute_key,
+                ALICE,
+                vec![CHARLIE, BOB].try_into().unwrap(),
+                vec![0].try_into().unwrap(),
+                None,
+            )
+        );
+        System::assert_last_event(RuntimeEvent::FellowshipDisputes(
+            Event::<Test, Instance1>::DisputeRaised {
+                who: ALICE,
+                dispute_key,
+            },
+        ));
+
+        assert_ok!(PalletDisputes::force_fail_dispute(
+            RuntimeOrigin::root(),
+            dispute_key
+        ));
+        assert!(Disputes::<Test>::get(dispute_key).is_none());
+        assert!(Disputes::<Test, Instance1>::get(dispute_key).is_some());
+    });
+}
+
+#[test]
+fn instances_use_their_own_voting_time_limit_and_hooks() {
+    new_test_ext().execute_with(|| {
+        let dispute_key = 10;
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            dispute_key,
+            ALICE,
+            get_jury::<Test>(vec![CHARLIE, BOB]),
+            get_specifics::<Test>(vec![0]),
+            None,
+        ));
+        assert_ok!(
+            <FellowshipDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+                dispute_key,
+                ALICE,
+                vec![CHARLIE, BOB].try_into().unwrap(),
+                vec![0].try_into().unwrap(),
+                Some(1),
+            )
+        );
+
+        let fellowship_expiry = 1 + <Test as Config<Instance1>>::VotingTimeLimit::get();
+        run_to_block::<Test>(1 + <Test as Config>::VotingTimeLimit::get());
+        assert!(Disputes::<Test>::get(dispute_key).is_none());
+        assert!(Disputes::<Test, Instance1>::get(dispute_key).is_some());
+
+        run_to_block::<Test>(fellowship_expiry);
+        assert!(Disputes::<Test, Instance1>::get(dispute_key).is_none());
+
+        run_to_block::<Test>(fellowship_expiry + <Test as Config<Instance1>>::AppealWindow::get());
+        assert_eq!(
+            CompletedDisputes::get(),
+            vec![(dispute_key, vec![(0, DisputeResult::Failure)], None)]
+        );
+        assert_eq!(
+            CompletedFellowshipDisputes::get(),
+            vec![(dispute_key, vec![(0, DisputeResult::Failure)], Some(1))]
+        );
+    });
+}
+
+fn commit_votes(who: AccountId, dispute_key: u32, votes: Vec<(u32, bool)>, salt: [u8; 32]) {
+    let commitment = PalletDisputes::vote_commitment(&who, dispute_key, &votes, &salt);
+    assert_ok!(PalletDisputes::commit_vote(
+        RuntimeOrigin::signed(who),
+        dispute_key,
+        commitment
+    ));
+}
+
+#[test]
+fn secret_votes_are_committed_then_revealed() {
+    new_test_ext().execute_with(|| {
+        RevealPeriod::set(5);
+        let dispute_key = 10;
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            dispute_key,
+            ALICE,
+            get_jury::<Test>(vec![CHARLIE, BOB]),
+            get_specifics::<Test>(vec![0, 1]),
+            None,
+        ));
+        let dispute = Disputes::<Test>::get(dispute_key).unwrap();
+        let reveal_starts = dispute.voting_starts + <Test as Config>::VotingTimeLimit::get();
+        assert_eq!(dispute.reveal_starts, Some(reveal_starts));
+        assert_eq!(dispute.expiration, reveal_starts + 5);
+        assert_noop!(
+            PalletDisputes::vote_on_dispute(RuntimeOrigin::signed(BOB), dispute_key, true),
+            Error::<Test>::VotesMustBeCommitted
+        );
+
+        commit_votes(BOB, dispute_key, vec![(0, true), (1, false)], [1u8; 32]);
+        System::assert_last_event(RuntimeEvent::PalletDisputes(Event::<Test>::VoteCommitted {
+            who: BOB,
+            dispute_key,
+        }));
+        commit_votes(CHARLIE, dispute_key, vec![(0, true), (1, false)], [2u8; 32]);
+        let dispute = Disputes::<Test>::get(dispute_key).unwrap();
+        assert!(dispute.votes.is_empty());
+        assert_eq!(dispute.commitments.len(), 2);
+        assert_noop!(
+            PalletDisputes::reveal_vote(
+                RuntimeOrigin::signed(BOB),
+                dispute_key,
+                vec![(0, true), (1, false)].try_into().unwrap(),
+                [1u8; 32]
+            ),
+            Error::<Test>::RevealPeriodNotOpen
+        );
+
+        run_to_block::<Test>(reveal_starts);
+        assert_ok!(PalletDisputes::reveal_vote(
+            RuntimeOrigin::signed(BOB),
+            dispute_key,
+            vec![(0, true), (1, false)].try_into().unwrap(),
+            [1u8; 32]
+        ));
+        assert!(Disputes::<Test>::get(dispute_key).is_some());
+        assert_ok!(PalletDisputes::reveal_vote(
+            RuntimeOrigin::signed(CHARLIE),
+            dispute_key,
+            vec![(0, true), (1, false)].try_into().unwrap(),
+            [2u8; 32]
+        ));
+        System::assert_last_event(RuntimeEvent::PalletDisputes(
+            Event::<Test>::DisputeCompleted {
+                dispute_key,
+                verdicts: vec![(0, DisputeResult::Success), (1, DisputeResult::Failure)]
+                    .try_into()
+                    .unwrap(),
+            },
+        ));
+        assert!(Disputes::<Test>::get(dispute_key).is_none());
+    });
+}
+
+#[test]
+fn secret_votes_fail_outside_their_periods() {
+    new_test_ext().execute_with(|| {
+        RevealPeriod::set(5);
+        let dispute_key = 10;
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            dispute_key,
+            ALICE,
+            get_jury::<Test>(vec![CHARLIE, BOB]),
+            get_specifics::<Test>(vec![0]),
+            None,
+        ));
+        let commitment =
+            PalletDisputes::vote_commitment(&ALICE, dispute_key, &[(0, true)], &[0u8; 32]);
+        assert_noop!(
+            PalletDisputes::commit_vote(RuntimeOrigin::signed(ALICE), dispute_key, commitment),
+            Error::<Test>::NotAJuryAccount
+        );
+        commit_votes(BOB, dispute_key, vec![(0, true)], [1u8; 32]);
+
+        let reveal_starts = Disputes::<Test>::get(dispute_key)
+            .unwrap()
+            .reveal_starts
+            .unwrap();
+        run_to_block::<Test>(reveal_starts);
+        let commitment =
+            PalletDisputes::vote_commitment(&CHARLIE, dispute_key, &[(0, true)], &[2u8; 32]);
+        assert_noop!(
+            PalletDisputes::commit_vote(RuntimeOrigin::signed(CHARLIE), dispute_key, commitment),
+            Error::<Test>::CommitPeriodEnded
+        );
+        assert_noop!(
+            PalletDisputes::reveal_vote(
+                RuntimeOrigin::signed(CHARLIE),
+                dispute_key,
+                vec![(0, true)].try_into().unwrap(),
+                [2u8; 32]
+            ),
+            Error::<Test>::NoVoteCommitted
+        );
+        assert_noop!(
+            PalletDisputes::reveal_vote(
+                RuntimeOrigin::signed(BOB),
+                dispute_key,
+                vec![(0, false)].try_into().unwrap(),
+                [1u8; 32]
+            ),
+            Error::<Test>::RevealMismatch
+        );
+        assert_noop!(
+            PalletDisputes::reveal_vote(
+                RuntimeOrigin::signed(BOB),
+                dispute_key,
+                vec![(0, true)].try_into().unwrap(),
+                [2u8; 32]
+            ),
+            Error::<Test>::RevealMismatch
+        );
+    });
+}
+
+#[test]
+fn commitments_cannot_be_replayed_on_another_dispute() {
+    new_test_ext().execute_with(|| {
+        RevealPeriod::set(5);
+        for dispute_key in [10, 11] {
+            assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+                dispute_key,
+                ALICE,
+                get_jury::<Test>(vec![CHARLIE, BOB]),
+                get_specifics::<Test>(vec![0]),
+                None,
+            ));
+        }
+        let commitment = PalletDisputes::vote_commitment(&BOB, 10, &[(0, true)], &[1u8; 32]);
+        assert_ok!(PalletDisputes::commit_vote(
+            RuntimeOrigin::signed(BOB),
+            10,
+            commitment
+        ));
+        assert_ok!(PalletDisputes::commit_vote(
+            RuntimeOrigin::signed(BOB),
+            11,
+            commitment
+        ));
+
+        let reveal_starts = Disputes::<Test>::get(11).unwrap().reveal_starts.unwrap();
+        run_to_block::<Test>(reveal_starts);
+        assert_noop!(
+            PalletDisputes::reveal_vote(
+                RuntimeOrigin::signed(BOB),
+                11,
+                vec![(0, true)].try_into().unwrap(),
+                [1u8; 32]
+            ),
+            Error::<Test>::RevealMismatch
+        );
+    });
+}
+
+#[test]
+fn committed_disputes_only_finalise_early_once_every_commitment_is_revealed() {
+    new_test_ext().execute_with(|| {
+        RevealPeriod::set(5);
+        let dispute_key = 10;
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            dispute_key,
+            ALICE,
+            get_jury::<Test>(vec![CHARLIE, BOB]),
+            get_specifics::<Test>(vec![0]),
+            None,
+        ));
+        // Open votes cannot be used to decide a dispute early.
+        assert_noop!(
+            PalletDisputes::vote_on_specifics(
+                RuntimeOrigin::signed(BOB),
+                dispute_key,
+                vec![(0, true)].try_into().unwrap()
+            ),
+            Error::<Test>::VotesMustBeCommitted
+        );
+
+        // A unanimous jury still waits for the reveal period.
+        commit_votes(BOB, dispute_key, vec![(0, true)], [1u8; 32]);
+        commit_votes(CHARLIE, dispute_key, vec![(0, true)], [2u8; 32]);
+        let dispute = Disputes::<Test>::get(dispute_key).unwrap();
+        let (reveal_starts, expiration) = (dispute.reveal_starts.unwrap(), dispute.expiration);
+        run_to_block::<Test>(reveal_starts - 1);
+        assert!(Disputes::<Test>::get(dispute_key).is_some());
+
+        // Revealing the first commitment is not enough, the other may still be revealed.
+        run_to_block::<Test>(reveal_starts);
+        assert_ok!(PalletDisputes::reveal_vote(
+            RuntimeOrigin::signed(BOB),
+            dispute_key,
+            vec![(0, true)].try_into().unwrap(),
+            [1u8; 32]
+        ));
+        assert!(Disputes::<Test>::get(dispute_key).is_some());
+
+        // The last reveal finalises the dispute before its expiration.
+        assert_ok!(PalletDisputes::reveal_vote(
+            RuntimeOrigin::signed(CHARLIE),
+            dispute_key,
+            vec![(0, true)].try_into().unwrap(),
+            [2u8; 32]
+        ));
+        assert!(Disputes::<Test>::get(dispute_key).is_none());
+        assert!(System::block_number() < expiration);
+        assert!(!DisputesFinaliseOn::<Test>::get(expiration).contains(&dispute_key));
+    });
+}
+
+#[test]
+fn secret_voting_is_disabled_without_a_reveal_period() {
+    new_test_ext().execute_with(|| {
+        let dispute_key = 10;
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            dispute_key,
+            ALICE,
+            get_jury::<Test>(vec![CHARLIE, BOB]),
+            get_specifics::<Test>(vec![0]),
+            None,
+        ));
+        assert_eq!(
+            Disputes::<Test>::get(dispute_key).unwrap().reveal_starts,
+            None
+        );
+        let commitment =
+            PalletDisputes::vote_commitment(&BOB, dispute_key, &[(0, true)], &[1u8; 32]);
+        assert_noop!(
+            PalletDisputes::commit_vote(RuntimeOrigin::signed(BOB), dispute_key, commitment),
+            Error::<Test>::SecretVotingDisabled
+        );
+        assert_noop!(
+            PalletDisputes::reveal_vote(
+                RuntimeOrigin::signed(BOB),
+                dispute_key,
+                vec![(0, true)].try_into().unwrap(),
+                [1u8; 32]
+            ),
+            Error::<Test>::SecretVotingDisabled
+        );
+    });
+}
+
+#[test]
+fn unrevealed_votes_count_as_abstentions() {
+    new_test_ext().execute_with(|| {
+        RevealPeriod::set(5);
+        let dispute_key = 10;
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            dispute_key,
+            ALICE,
+            get_jury::<Test>(vec![CHARLIE, BOB, FERDIE]),
+            get_specifics::<Test>(vec![0]),
+            None,
+        ));
+        commit_votes(BOB, dispute_key, vec![(0, true)], [1u8; 32]);
+        commit_votes(CHARLIE, dispute_key, vec![(0, false)], [2u8; 32]);
+
+        let dispute = Disputes::<Test>::get(dispute_key).unwrap();
+        run_to_block::<Test>(dispute.reveal_starts.unwrap());
+        assert_ok!(PalletDisputes::reveal_vote(
+            RuntimeOrigin::signed(BOB),
+            dispute_key,
+            vec![(0, true)].try_into().unwrap(),
+            [1u8; 32]
+        ));
+        assert!(Disputes::<Test>::get(dispute_key).is_some());
+
+        run_to_block::<Test>(dispute.expiration);
+        System::assert_last_event(RuntimeEvent::PalletDisputes(
+            Event::<Test>::DisputeCompleted {
+                dispute_key,
+                verdicts: get_verdicts::<Test>(vec![0], DisputeResult::Success),
+            },
+        ));
+        run_to_block::<Test>(dispute.expiration + <Test as Config>::AppealWindow::get());
+        assert_eq!(MissedJuryVotes::<Test>::get(BOB), 0);
+        assert_eq!(MissedJuryVotes::<Test>::get(CHARLIE), 1);
+        assert_eq!(MissedJuryVotes::<Test>::get(FERDIE), 1);
+    });
+}
+
+#[test]
+fn extend_dispute_extends_the_commit_period() {
+    new_test_ext().execute_with(|| {
+        RevealPeriod::set(5);
+        let dispute_key = 10;
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            dispute_key,
+            ALICE,
+            get_jury::<Test>(vec![CHARLIE, BOB]),
+            get_specifics::<Test>(vec![0]),
+            None,
+        ));
+        let dispute = Disputes::<Test>::get(dispute_key).unwrap();
+        assert_ok!(PalletDisputes::extend_dispute(
+            RuntimeOrigin::signed(BOB),
+            dispute_key
+        ));
+        assert_ok!(PalletDisputes::extend_dispute(
+            RuntimeOrigin::signed(CHARLIE),
+            dispute_key
+        ));
+        let extended = Disputes::<Test>::get(dispute_key).unwrap();
+        assert_eq!(
+            extended.reveal_starts,
+            dispute
+                .reveal_starts
+                .map(|reveal_starts| reveal_starts + <Test as Config>::VotingTimeLimit::get())
+        );
+        assert_eq!(
+            extended.expiration - extended.reveal_starts.unwrap(),
+            RevealPeriod::get()
+        );
+    });
+}
+
+#[test]
+fn cancel_dispute_works() {
+    new_test_ext().execute_with(|| {
+        let dispute_key = 10;
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            dispute_key,
+            ALICE,
+            get_jury::<Test>(vec![BOB, CHARLIE, FERDIE]),
+            get_specifics::<Test>(vec![0, 1]),
+            Some(3),
+        ));
+        assert_ok!(PalletDisputes::vote_on_dispute(
+            RuntimeOrigin::signed(BOB),
+            dispute_key,
+            true
+        ));
+        let expiration = Disputes::<Test>::get(dispute_key).unwrap().expiration;
+
+        assert_ok!(PalletDisputes::cancel_dispute(
+            RuntimeOrigin::signed(ALICE),
+            dispute_key
+        ));
+        System::assert_last_event(RuntimeEvent::PalletDisputes(
+            Event::<Test>::DisputeCancelled { dispute_key },
+        ));
+        assert!(Disputes::<Test>::get(dispute_key).is_none());
+        assert!(DisputesFinaliseOn::<Test>::get(expiration).is_empty());
+        assert_eq!(
+            CancelledDisputes::get(),
+            vec![(dispute_key, vec![0, 1], Some(3))]
+        );
+        assert!(CompletedDisputes::get().is_empty());
+
+        // The jury is not settled so no one has missed a vote.
+        run_to_block::<Test>(expiration + 1);
+        assert_eq!(MissedJuryVotes::<Test>::get(CHARLIE), 0);
+        assert!(CompletedDisputes::get().is_empty());
+    });
+}
+
+#[test]
+fn cancel_dispute_fails_if_the_hook_fails() {
+    new_test_ext().execute_with(|| {
+        let dispute_key = 10;
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            dispute_key,
+            ALICE,
+            get_jury::<Test>(vec![BOB, CHARLIE, FERDIE]),
+            get_specifics::<Test>(vec![0, 1]),
+            Some(3),
+        ));
+        let expiration = Disputes::<Test>::get(dispute_key).unwrap().expiration;
+        CancelHookFails::set(true);
+
+        assert_noop!(
+            PalletDisputes::cancel_dispute(RuntimeOrigin::signed(ALICE), dispute_key),
+            sp_runtime::DispatchError::Other("cancel hook failed")
+        );
+        assert!(Disputes::<Test>::get(dispute_key).is_some());
+        assert_eq!(
+            DisputesFinaliseOn::<Test>::get(expiration).into_inner(),
+            vec![dispute_key]
+        );
+        assert!(CancelledDisputes::get().is_empty());
+    });
+}
+
+#[test]
+fn cancel_dispute_not_the_raiser() {
+    new_test_ext().execute_with(|| {
+        let dispute_key = 10;
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            dispute_key,
+            ALICE,
+            get_jury::<Test>(vec![BOB, CHARLIE]),
+            get_specifics::<Test>(vec![0]),
+            None,
+        ));
+        assert_noop!(
+            PalletDisputes::cancel_dispute(RuntimeOrigin::signed(BOB), dispute_key),
+            Error::<Test>::NotTheRaiser
+        );
+        assert_noop!(
+            PalletDisputes::cancel_dispute(RuntimeOrigin::signed(ALICE), dispute_key + 1),
+            Error::<Test>::DisputeDoesNotExist
+        );
+    });
+}
+
+#[test]
+fn cancel_dispute_fails_once_a_quorum_has_voted() {
+    new_test_ext().execute_with(|| {
+        let dispute_key = 10;
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            dispute_key,
+            ALICE,
+            get_jury::<Test>(vec![BOB, CHARLIE, FERDIE]),
+            get_specifics::<Test>(vec![0]),
+            None,
+        ));
+        assert_ok!(PalletDisputes::vote_on_dispute(
+            RuntimeOrigin::signed(BOB),
+            dispute_key,
+            true
+        ));
+        assert_ok!(PalletDisputes::vote_on_dispute(
+            RuntimeOrigin::signed(CHARLIE),
+            dispute_key,
+            true
+        ));
+        assert_noop!(
+            PalletDisputes::cancel_dispute(RuntimeOrigin::signed(ALICE), dispute_key),
+            Error::<Test>::QuorumReached
+        );
+    });
+}
+
+#[test]
+fn cancel_dispute_counts_committed_votes_towards_the_quorum() {
+    new_test_ext().execute_with(|| {
+        RevealPeriod::set(5);
+        let dispute_key = 10;
+        assert_ok!(<PalletDisputes as DisputeRaiser<AccountId>>::raise_dispute(
+            dispute_key,
+            ALICE,
+            get_jury::<Test>(vec![BOB, CHARLIE, FERDIE]),
+            get_specifics::<Test>(vec![0]),
+            None,
+        ));
+        commit_votes(BOB, dispute_key, vec![(0, true)], [1u8; 32]);
+        commit_votes(CHARLIE, dispute_key, vec![(0, false)], [2u8; 32]);
+        assert_noop!(
+            PalletDisputes::cancel_dispute(RuntimeOrigin::signed(ALICE), dispute_key),
+            Error::<Test>::QuorumReached
+        );
+    });
+}
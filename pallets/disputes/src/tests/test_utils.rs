@@ -9,10 +9,12 @@ pub fn run_to_block<T: Config>(n: BlockNumber) {
     while System::block_number() < n {
         Tokens::on_finalize(System::block_number());
         PalletDisputes::on_finalize(System::block_number());
+        FellowshipDisputes::on_finalize(System::block_number());
         System::on_finalize(System::block_number());
         System::set_block_number(System::block_number() + 1);
         System::on_initialize(System::block_number());
         PalletDisputes::on_initialize(System::block_number());
+        FellowshipDisputes::on_initialize(System::block_number());
         Tokens::on_initialize(System::block_number());
     }
 }
@@ -28,3 +30,15 @@ pub fn get_specifics<T: Config>(
 ) -> BoundedVec<T::SpecificId, T::MaxSpecifics> {
     specifics.try_into().expect("too many specific ids.")
 }
+
+pub fn get_verdicts<T: Config>(
+    specifics: Vec<T::SpecificId>,
+    result: DisputeResult,
+) -> Verdicts<T> {
+    specifics
+        .into_iter()
+        .map(|specific_id| (specific_id, result))
+        .collect::<Vec<_>>()
+        .try_into()
+        .expect("too many specific ids.")
+}
@@ -1,29 +1,167 @@
 use codec::{FullCodec, FullEncode, MaxEncodedLen};
 use frame_support::pallet_prelude::*;
 use scale_info::TypeInfo;
-use sp_runtime::{traits::AtLeast32BitUnsigned, BoundedVec, DispatchError};
-use sp_std::vec::Vec;
+use sp_runtime::{
+    traits::{AtLeast32BitUnsigned, Zero},
+    BoundedVec, DispatchError, Percent,
+};
+use sp_std::{cmp::Ordering, marker::PhantomData, vec::Vec};
+
+use crate::pallet::DisputeResult;
 
 pub trait DisputeRaiser<AccountId> {
     type DisputeKey: AtLeast32BitUnsigned + FullEncode + FullCodec + MaxEncodedLen + TypeInfo;
     type SpecificId: AtLeast32BitUnsigned + FullEncode + FullCodec + MaxEncodedLen + TypeInfo;
     type MaxJurySize: Get<u32>;
     type MaxSpecifics: Get<u32>;
+    /// The maximum number of open disputes a juror can sit on, bounding `disputes_of_juror`.
+    type MaxDisputesPerJuror: Get<u32>;
+    type BondId;
 
+    /// Raise a dispute, the bond is stored on the dispute and handed back on completion.
     fn raise_dispute(
         dispute_key: Self::DisputeKey,
         raised_by: AccountId,
         jury: BoundedVec<AccountId, Self::MaxJurySize>,
         specific_ids: BoundedVec<Self::SpecificId, Self::MaxSpecifics>,
+        bond: Option<Self::BondId>,
+    ) -> Result<(), DispatchError>;
+
+    /// The keys of the open disputes where `juror` sits on the jury, at most MaxDisputesPerJuror.
+    fn disputes_of_juror(juror: &AccountId) -> Vec<Self::DisputeKey>;
+
+    /// Replace a juror on an open dispute, any votes they have cast are discarded.
+    /// Without a replacement the juror is removed and the jury shrinks.
+    /// Fails if the replacement already sits on MaxDisputesPerJuror open disputes.
+    fn replace_juror(
+        dispute_key: Self::DisputeKey,
+        juror: &AccountId,
+        replacement: Option<AccountId>,
     ) -> Result<(), DispatchError>;
 }
 
-pub trait DisputeHooks<DisputeKey, SpecificId> {
+pub trait DisputeHooks<DisputeKey, SpecificId, BondId> {
     /// On the completion of a dispute, this hooks is called.
-    /// Returning only the key that has been handled and the result of the dispute.
+    /// Returning the key that has been handled, the result of the dispute for each specific
+    /// and the bond taken when it was raised so it can be settled.
     fn on_dispute_complete(
         dispute_key: DisputeKey,
-        specifics: Vec<SpecificId>,
-        dispute_result: crate::pallet::DisputeResult,
+        verdicts: Vec<(SpecificId, DisputeResult)>,
+        bond: Option<BondId>,
     ) -> Weight;
+
+    /// On the cancellation of a dispute by its raiser, this hook is called.
+    /// Returning the key that has been cancelled, the specifics it was raised on
+    /// and the bond taken when it was raised so it can be settled.
+    /// If the hook fails the cancellation fails with it and the dispute stays open.
+    fn on_dispute_cancel(
+        dispute_key: DisputeKey,
+        specifics: Vec<SpecificId>,
+        bond: Option<BondId>,
+    ) -> Result<Weight, DispatchError>;
+
+    /// The weight of `on_dispute_cancel` for a dispute raised on `specifics` specifics.
+    fn on_dispute_cancel_weight(specifics: u32) -> Weight;
+}
+
+/// Defines the parties of a dispute.
+pub trait DisputeParties<AccountId, DisputeKey> {
+    /// The account defending against the dispute, who can appeal the verdicts that go against them.
+    fn defendant(dispute_key: DisputeKey) -> Option<AccountId>;
+}
+
+impl<AccountId, DisputeKey> DisputeParties<AccountId, DisputeKey> for () {
+    fn defendant(_dispute_key: DisputeKey) -> Option<AccountId> {
+        None
+    }
+}
+
+/// Handles the jurors that fail to vote on the disputes they sit on.
+pub trait JurorPenaltyHandler<AccountId> {
+    /// Called when a juror fails to vote before a dispute expires.
+    /// `missed_in_a_row` is the number of consecutive disputes the juror has not voted on.
+    fn on_missed_vote(juror: &AccountId, missed_in_a_row: u32) -> Weight;
+
+    /// The most a single call to `on_missed_vote` can cost.
+    fn max_on_missed_vote_weight() -> Weight;
+}
+
+impl<AccountId> JurorPenaltyHandler<AccountId> for () {
+    fn on_missed_vote(_juror: &AccountId, _missed_in_a_row: u32) -> Weight {
+        Weight::zero()
+    }
+
+    fn max_on_missed_vote_weight() -> Weight {
+        Weight::zero()
+    }
+}
+
+/// Decides the result of a specific in a dispute from the votes cast on it.
+pub trait DisputeOutcomePolicy {
+    /// The result of a specific given the yay and nay votes cast by a jury of `jury_size`.
+    fn result(yay: u32, nay: u32, jury_size: u32) -> DisputeResult;
+
+    /// The result of a specific if it can no longer change, however the outstanding jurors vote.
+    /// Once every specific is decided the dispute is finalised early.
+    fn decided_result(yay: u32, nay: u32, jury_size: u32) -> Option<DisputeResult>;
+}
+
+/// A simple majority where ties succeed and a specific without votes fails.
+/// A dispute is only finalised early once every juror has voted unanimously on each specific.
+impl DisputeOutcomePolicy for () {
+    fn result(yay: u32, nay: u32, _jury_size: u32) -> DisputeResult {
+        if yay == 0 && nay == 0 {
+            return DisputeResult::Failure;
+        }
+        if yay >= nay {
+            DisputeResult::Success
+        } else {
+            DisputeResult::Failure
+        }
+    }
+
+    fn decided_result(yay: u32, nay: u32, jury_size: u32) -> Option<DisputeResult> {
+        let unanimous = yay.saturating_add(nay) == jury_size && (yay == 0 || nay == 0);
+        unanimous.then(|| Self::result(yay, nay, jury_size))
+    }
+}
+
+/// A specific succeeds once at least `MinTurnout` of the jury has voted on it and
+/// the share of yay votes is above `Supermajority`, the result of an exact tie is `TieBreak`.
+/// A specific without enough votes fails.
+/// The outcome is decided as soon as neither all outstanding jurors voting yay,
+/// all of them voting nay, or none of them voting at all would change it.
+pub struct SupermajorityPolicy<MinTurnout, Supermajority, TieBreak>(
+    PhantomData<(MinTurnout, Supermajority, TieBreak)>,
+);
+
+impl<MinTurnout, Supermajority, TieBreak> DisputeOutcomePolicy
+    for SupermajorityPolicy<MinTurnout, Supermajority, TieBreak>
+where
+    MinTurnout: Get<Percent>,
+    Supermajority: Get<Percent>,
+    TieBreak: Get<DisputeResult>,
+{
+    fn result(yay: u32, nay: u32, jury_size: u32) -> DisputeResult {
+        let votes = yay.saturating_add(nay);
+        if votes.is_zero() || votes < MinTurnout::get().mul_ceil(jury_size) {
+            return DisputeResult::Failure;
+        }
+        let yay_share = (yay as u64).saturating_mul(100);
+        let threshold = (Supermajority::get().deconstruct() as u64).saturating_mul(votes as u64);
+        match yay_share.cmp(&threshold) {
+            Ordering::Greater => DisputeResult::Success,
+            Ordering::Equal => TieBreak::get(),
+            Ordering::Less => DisputeResult::Failure,
+        }
+    }
+
+    fn decided_result(yay: u32, nay: u32, jury_size: u32) -> Option<DisputeResult> {
+        let outstanding = jury_size.saturating_sub(yay.saturating_add(nay));
+        let current = Self::result(yay, nay, jury_size);
+        // More yays and more votes can only help a specific succeed, so these bound every outcome.
+        let all_yay = Self::result(yay.saturating_add(outstanding), nay, jury_size);
+        let all_nay = Self::result(yay, nay.saturating_add(outstanding), jury_size);
+        (current == all_yay && current == all_nay).then_some(current)
+    }
 }
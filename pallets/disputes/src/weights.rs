@@ -89,6 +89,22 @@ impl<T: frame_system::Config> crate::WeightInfoT for WeightInfo<T> {
 	/// Proof: `ImbueDisputes::Disputes` (`max_values`: None, `max_size`: Some(6602), added: 9077, mode: `MaxEncodedLen`)
 	/// Storage: `ImbueDisputes::DisputesFinaliseOn` (r:1 w:1)
 	/// Proof: `ImbueDisputes::DisputesFinaliseOn` (`max_values`: None, `max_size`: Some(221), added: 2696, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueDisputes::AppealWindowClosesOn` (r:1 w:1)
+	/// Proof: `ImbueDisputes::AppealWindowClosesOn` (`max_values`: None, `max_size`: Some(221), added: 2696, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueDisputes::PendingVerdicts` (r:0 w:1)
+	/// Proof: `ImbueDisputes::PendingVerdicts` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	fn vote_on_specifics() -> Weight {
+		// Placeholder, not benchmarked: estimated from the storage accesses listed above.
+		// Replace with the output of `benchmark pallet` for this extrinsic.
+		Weight::from_parts(344_127_000, 0)
+			.saturating_add(Weight::from_parts(0, 14469))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `ImbueDisputes::Disputes` (r:1 w:1)
+	/// Proof: `ImbueDisputes::Disputes` (`max_values`: None, `max_size`: Some(6602), added: 9077, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueDisputes::DisputesFinaliseOn` (r:1 w:1)
+	/// Proof: `ImbueDisputes::DisputesFinaliseOn` (`max_values`: None, `max_size`: Some(221), added: 2696, mode: `MaxEncodedLen`)
 	/// Storage: `ImbueProposals::Projects` (r:1 w:1)
 	/// Proof: `ImbueProposals::Projects` (`max_values`: None, `max_size`: Some(36350), added: 38825, mode: `MaxEncodedLen`)
 	/// Storage: `ImbueProposals::ProjectsInDispute` (r:0 w:1)
@@ -129,4 +145,98 @@ impl<T: frame_system::Config> crate::WeightInfoT for WeightInfo<T> {
 		Weight::from_parts(13_334_000, 0)
 			.saturating_add(Weight::from_parts(0, 0))
 	}
+	/// Storage: `ImbueDisputes::PendingVerdicts` (r:1 w:1)
+	/// Proof: `ImbueDisputes::PendingVerdicts` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueProposals::Projects` (r:1 w:0)
+	/// Proof: `ImbueProposals::Projects` (`max_values`: None, `max_size`: Some(36350), added: 38825, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:1 w:1)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueDisputes::AppealsLapseOn` (r:1 w:1)
+	/// Proof: `ImbueDisputes::AppealsLapseOn` (`max_values`: None, `max_size`: Some(221), added: 2696, mode: `MaxEncodedLen`)
+	/// Storage: `Deposits::TicketId` (r:1 w:1)
+	/// Proof: `Deposits::TicketId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Deposits::CurrentDeposits` (r:0 w:1)
+	/// Proof: `Deposits::CurrentDeposits` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	fn appeal_dispute() -> Weight {
+		// Placeholder, not benchmarked: estimated from the storage accesses listed above.
+		// Replace with the output of `benchmark pallet` for this extrinsic.
+		Weight::from_parts(101_283_000, 0)
+			.saturating_add(Weight::from_parts(0, 39815))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `ImbueDisputes::PendingVerdicts` (r:1 w:1)
+	/// Proof: `ImbueDisputes::PendingVerdicts` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:2 w:2)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueProposals::Projects` (r:1 w:1)
+	/// Proof: `ImbueProposals::Projects` (`max_values`: None, `max_size`: Some(36350), added: 38825, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueProposals::ProjectsInDispute` (r:0 w:1)
+	/// Proof: `ImbueProposals::ProjectsInDispute` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueDisputes::AppealsLapseOn` (r:1 w:1)
+	/// Proof: `ImbueDisputes::AppealsLapseOn` (`max_values`: None, `max_size`: Some(221), added: 2696, mode: `MaxEncodedLen`)
+	/// Storage: `Deposits::CurrentDeposits` (r:1 w:1)
+	/// Proof: `Deposits::CurrentDeposits` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	fn decide_appeal() -> Weight {
+		// Placeholder, not benchmarked: estimated from the storage accesses listed above.
+		// Replace with the output of `benchmark pallet` for this extrinsic.
+		Weight::from_parts(284_912_000, 0)
+			.saturating_add(Weight::from_parts(0, 39815))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	/// Storage: `ImbueDisputes::Disputes` (r:1 w:1)
+	/// Proof: `ImbueDisputes::Disputes` (`max_values`: None, `max_size`: Some(11053), added: 13528, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueProposals::Projects` (r:1 w:0)
+	/// Proof: `ImbueProposals::Projects` (`max_values`: None, `max_size`: Some(36350), added: 38825, mode: `MaxEncodedLen`)
+	fn submit_evidence() -> Weight {
+		// Placeholder, not benchmarked: estimated from the storage accesses listed above.
+		// Replace with the output of `benchmark pallet` for this extrinsic.
+		Weight::from_parts(63_420_000, 0)
+			.saturating_add(Weight::from_parts(0, 39815))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `ImbueDisputes::Disputes` (r:1 w:1)
+	/// Proof: `ImbueDisputes::Disputes` (`max_values`: None, `max_size`: Some(14358), added: 16833, mode: `MaxEncodedLen`)
+	fn commit_vote() -> Weight {
+		// Placeholder, not benchmarked: estimated from the storage accesses listed above.
+		// Replace with the output of `benchmark pallet` for this extrinsic.
+		Weight::from_parts(60_102_000, 0)
+			.saturating_add(Weight::from_parts(0, 17823))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `ImbueDisputes::Disputes` (r:1 w:1)
+	/// Proof: `ImbueDisputes::Disputes` (`max_values`: None, `max_size`: Some(14358), added: 16833, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueDisputes::DisputesFinaliseOn` (r:1 w:1)
+	/// Proof: `ImbueDisputes::DisputesFinaliseOn` (`max_values`: None, `max_size`: Some(221), added: 2696, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueProposals::Projects` (r:1 w:1)
+	/// Proof: `ImbueProposals::Projects` (`max_values`: None, `max_size`: Some(36350), added: 38825, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueProposals::ProjectsInDispute` (r:0 w:1)
+	/// Proof: `ImbueProposals::ProjectsInDispute` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	fn reveal_vote() -> Weight {
+		// Placeholder, not benchmarked: estimated from the storage accesses listed above.
+		// Replace with the output of `benchmark pallet` for this extrinsic.
+		Weight::from_parts(361_455_000, 0)
+			.saturating_add(Weight::from_parts(0, 39815))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `ImbueDisputes::Disputes` (r:1 w:1)
+	/// Proof: `ImbueDisputes::Disputes` (`max_values`: None, `max_size`: Some(14358), added: 16833, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueDisputes::DisputesFinaliseOn` (r:1 w:1)
+	/// Proof: `ImbueDisputes::DisputesFinaliseOn` (`max_values`: None, `max_size`: Some(221), added: 2696, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueProposals::DisputeProjects` (r:1 w:1)
+	/// Proof: `ImbueProposals::DisputeProjects` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueProposals::MilestoneDisputes` (r:0 w:2)
+	/// Proof: `ImbueProposals::MilestoneDisputes` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	fn cancel_dispute() -> Weight {
+		// Placeholder, not benchmarked: estimated from the storage accesses listed above.
+		// Replace with the output of `benchmark pallet` for this extrinsic.
+		Weight::from_parts(144_262_000, 0)
+			.saturating_add(Weight::from_parts(0, 17823))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
 }
@@ -1,10 +1,20 @@
-use crate::traits::EnsureRole;
+use crate::traits::{EnsureRole, SelectJury};
 use crate::*;
+use codec::{Decode, Encode};
 use common_traits::MaybeConvert;
-use frame_support::{ensure, traits::Get};
+use frame_support::{
+    ensure,
+    traits::{Get, Randomness},
+    weights::Weight,
+    BoundedVec,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
 use orml_traits::MultiReservableCurrency;
-use sp_runtime::{traits::BadOrigin, DispatchError};
-use sp_std::{vec, vec::Vec};
+use sp_runtime::{
+    traits::{BadOrigin, Hash},
+    DispatchError,
+};
+use sp_std::{collections::btree_map::BTreeMap, marker::PhantomData, vec, vec::Vec};
 /// Ensure that a account is of a given role.
 /// Used in other pallets like an ensure origin.
 pub struct EnsureFellowshipRole<T>(T);
@@ -60,6 +70,45 @@ impl<T: Config> Pallet<T> {
         }
         false
     }
+
+    /// Give an account a role, counting it as a fellow if it did not have one already.
+    /// The account is added to or removed from the JuryPool to match its new role and rank.
+    pub(crate) fn insert_role(who: &AccountIdOf<T>, role: Role, rank: Rank) {
+        if !Roles::<T>::contains_key(who) {
+            FellowCount::<T>::mutate(|count| *count = count.saturating_add(1));
+        }
+        Roles::<T>::insert(who, (role, rank));
+        Self::update_jury_pool(who, Self::is_jury_eligible(role, rank));
+    }
+
+    pub(crate) fn is_jury_eligible(role: Role, rank: Rank) -> bool {
+        <T as Config>::JuryRoles::get().contains(&role) && rank >= <T as Config>::MinJuryRank::get()
+    }
+
+    /// Add or remove an account from the JuryPool.
+    /// Removals move the last fellow in the pool into the freed position to keep it dense.
+    pub(crate) fn update_jury_pool(who: &AccountIdOf<T>, eligible: bool) {
+        match (JuryPoolIndex::<T>::get(who), eligible) {
+            (None, true) => {
+                let size = JuryPoolSize::<T>::get();
+                JuryPool::<T>::insert(size, who);
+                JuryPoolIndex::<T>::insert(who, size);
+                JuryPoolSize::<T>::put(size.saturating_add(1));
+            }
+            (Some(index), false) => {
+                let last = JuryPoolSize::<T>::get().saturating_sub(1);
+                if let Some(moved) = JuryPool::<T>::take(last) {
+                    if index != last {
+                        JuryPool::<T>::insert(index, &moved);
+                        JuryPoolIndex::<T>::insert(&moved, index);
+                    }
+                }
+                JuryPoolIndex::<T>::remove(who);
+                JuryPoolSize::<T>::put(last);
+            }
+            _ => (),
+        }
+    }
 }
 
 pub struct VetterAndFreelancerAllPermissions;
@@ -78,3 +127,79 @@ impl crate::traits::FellowshipPermissions<crate::Role, crate::Permission>
         }
     }
 }
+
+/// Select a pseudo-random jury from the JuryPool of the fellowship.
+/// Only fellows with a role in `JuryRoles` and a rank of at least `MinJuryRank` are in the pool.
+/// The seed is taken from `R` and mixed with the `JuryPointer` nonce so that juries
+/// selected within the same block differ.
+/// The jury is only as unpredictable as `R`, if it can be known before the block is built,
+/// as with a parent hash, the block author can influence who is selected.
+/// Only the positions drawn from the pool are read, see `select_jury_weight`.
+pub struct RandomJurySelector<T, R, JurySize>(PhantomData<(T, R, JurySize)>);
+
+impl<T, R, JurySize> SelectJury<AccountIdOf<T>> for RandomJurySelector<T, R, JurySize>
+where
+    T: Config,
+    R: Randomness<T::Hash, BlockNumberFor<T>>,
+    JurySize: Get<u32>,
+{
+    type JurySize = JurySize;
+
+    fn select_jury(amount: u32) -> BoundedVec<AccountIdOf<T>, Self::JurySize> {
+        Self::select_jury_excluding(amount, &[])
+    }
+
+    /// Fellows that have vetted any of the `excluded` accounts are excluded as well.
+    fn select_jury_excluding(
+        amount: u32,
+        excluded: &[AccountIdOf<T>],
+    ) -> BoundedVec<AccountIdOf<T>, Self::JurySize> {
+        let vetters = excluded
+            .iter()
+            .filter_map(FellowToVetter::<T>::get)
+            .collect::<Vec<VetterIdOf<T>>>();
+
+        let nonce = JuryPointer::<T>::get();
+        JuryPointer::<T>::put(nonce.saturating_add(1));
+        let (seed, _) = R::random(&(b"imbue/jury", nonce).encode());
+
+        let amount = amount.min(JurySize::get()) as usize;
+        let pool_size = JuryPoolSize::<T>::get();
+        // A partial Fisher-Yates shuffle of the pool positions, only the swaps are kept in memory.
+        // Each position is drawn at most once so at most amount + 2 * excluded.len() are read.
+        let mut swaps: BTreeMap<u32, u32> = BTreeMap::new();
+        let mut out: BoundedVec<AccountIdOf<T>, Self::JurySize> = BoundedVec::new();
+        for i in 0..pool_size {
+            if out.len() >= amount {
+                break;
+            }
+            let entropy = T::Hashing::hash_of(&(seed, i));
+            let j = i.saturating_add(
+                u32::decode(&mut entropy.as_ref()).unwrap_or_default() % (pool_size - i),
+            );
+            let position = swaps.get(&j).copied().unwrap_or(j);
+            swaps.insert(j, swaps.get(&i).copied().unwrap_or(i));
+
+            if let Some(acc) = JuryPool::<T>::get(position) {
+                if !excluded.contains(&acc) && !vetters.contains(&acc) {
+                    // Bounded by amount which is capped at JurySize.
+                    let _ = out.try_push(acc);
+                }
+            }
+        }
+        out
+    }
+
+    /// Reads the vetter of each excluded account, the nonce, the randomness, the pool size
+    /// and a pool position for each juror and each excluded account or vetter that is drawn.
+    fn select_jury_weight(max_excluded: u32) -> Weight {
+        let max_drawn =
+            (JurySize::get() as u64).saturating_add((max_excluded as u64).saturating_mul(2));
+        T::DbWeight::get().reads_writes(
+            max_drawn
+                .saturating_add(max_excluded as u64)
+                .saturating_add(3),
+            1,
+        )
+    }
+}
@@ -31,10 +31,10 @@ pub mod pallet {
     use frame_system::pallet_prelude::*;
     use orml_traits::{MultiCurrency, MultiReservableCurrency};
     use sp_runtime::traits::Zero;
-    use sp_std::{convert::TryInto, vec};
+    use sp_std::{convert::TryInto, vec, vec::Vec};
 
     use crate::traits::WeightInfoT;
-    use crate::traits::{FellowshipHandle, FellowshipPermissions};
+    use crate::traits::{FellowshipHandle, FellowshipHooks, FellowshipPermissions};
 
     pub(crate) type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
     pub(crate) type VetterIdOf<T> = AccountIdOf<T>;
@@ -49,7 +49,7 @@ pub mod pallet {
         <T as Config>::MaxCandidatesPerShortlist,
     >;
 
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -75,6 +75,12 @@ pub mod pallet {
         type SlashAccount: Get<AccountIdOf<Self>>;
         /// The permissions of a given role.
         type Permissions: FellowshipPermissions<Role, Permission>;
+        /// Called when a member leaves or is removed from the fellowship.
+        type FellowshipHooks: FellowshipHooks<AccountIdOf<Self>>;
+        /// The roles that can be selected for a jury.
+        type JuryRoles: Get<Vec<Role>>;
+        /// The minimum rank a fellow must hold to be selected for a jury.
+        type MinJuryRank: Get<Rank>;
         /// The weights generated by the benchmarks.
         type WeightInfo: WeightInfoT;
     }
@@ -110,7 +116,26 @@ pub mod pallet {
     pub type FellowToVetter<T> =
         StorageMap<_, Blake2_128Concat, AccountIdOf<T>, VetterIdOf<T>, OptionQuery>;
 
+    /// The number of accounts that hold a role in the fellowship.
+    #[pallet::storage]
+    pub type FellowCount<T> = StorageValue<_, u32, ValueQuery>;
+
+    /// The fellows that can be selected for a jury, by their position in the pool.
+    /// Kept dense so the RandomJurySelector can pick positions without reading every fellow.
+    /// Must be rebuilt with a migration if the JuryRoles or MinJuryRank change.
+    #[pallet::storage]
+    pub type JuryPool<T> = StorageMap<_, Twox64Concat, u32, AccountIdOf<T>, OptionQuery>;
+
+    /// The position of each fellow in the JuryPool.
+    #[pallet::storage]
+    pub type JuryPoolIndex<T> = StorageMap<_, Blake2_128Concat, AccountIdOf<T>, u32, OptionQuery>;
+
+    /// The number of fellows in the JuryPool.
+    #[pallet::storage]
+    pub type JuryPoolSize<T> = StorageValue<_, u32, ValueQuery>;
+
     /// Jury pointer for setting the mark at which fellows have been selected for jury.
+    /// Used as a nonce by the RandomJurySelector.
     #[pallet::storage]
     pub type JuryPointer<T> = StorageValue<_, u128, ValueQuery>;
 
@@ -201,27 +226,41 @@ pub mod pallet {
 
         /// Remove the account from the fellowship,
         /// Called by the fellow and returns the deposit to them.
+        /// The FellowshipHooks can prevent this, i.e while the fellow sits on an open dispute.
         #[pallet::call_index(1)]
-        #[pallet::weight(<T as Config>::WeightInfo::leave_fellowship())]
-        pub fn leave_fellowship(origin: OriginFor<T>) -> DispatchResult {
+        #[pallet::weight(<T as Config>::WeightInfo::leave_fellowship().saturating_add(
+            <T as Config>::FellowshipHooks::max_hooks_weight()
+        ))]
+        pub fn leave_fellowship(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
-            // TODO??: ensure that the fellow is not currently in a dispute.
-            <Self as FellowshipHandle<AccountIdOf<T>>>::revoke_fellowship(&who, false)?;
+            <T as Config>::FellowshipHooks::can_leave_fellowship(&who)?;
+            let hooks_weight =
+                <Self as FellowshipHandle<AccountIdOf<T>>>::revoke_fellowship(&who, false)?;
             Self::deposit_event(Event::<T>::FellowshipRemoved { who });
-            Ok(())
+            Ok(
+                Some(<T as Config>::WeightInfo::leave_fellowship().saturating_add(hooks_weight))
+                    .into(),
+            )
         }
 
         /// Force remove a fellow and slashed their deposit as defined in the Config.
         #[pallet::call_index(2)]
-        #[pallet::weight(<T as Config>::WeightInfo::force_remove_and_slash_fellowship())]
+        #[pallet::weight(<T as Config>::WeightInfo::force_remove_and_slash_fellowship().saturating_add(
+            <T as Config>::FellowshipHooks::max_hooks_weight()
+        ))]
         pub fn force_remove_and_slash_fellowship(
             origin: OriginFor<T>,
             who: AccountIdOf<T>,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             <T as Config>::ForceAuthority::ensure_origin(origin)?;
-            <Self as FellowshipHandle<AccountIdOf<T>>>::revoke_fellowship(&who, true)?;
+            let hooks_weight =
+                <Self as FellowshipHandle<AccountIdOf<T>>>::revoke_fellowship(&who, true)?;
             Self::deposit_event(Event::<T>::FellowshipSlashed { who });
-            Ok(())
+            Ok(Some(
+                <T as Config>::WeightInfo::force_remove_and_slash_fellowship()
+                    .saturating_add(hooks_weight),
+            )
+            .into())
         }
 
         /// Add a candidate to a shortlist.
@@ -308,7 +347,7 @@ pub mod pallet {
             )?;
             FellowshipReserves::<T>::insert(&who, membership_deposit);
             PendingFellows::<T>::remove(&who);
-            Roles::<T>::insert(&who, (role, rank));
+            Self::insert_role(&who, role, rank);
 
             Self::deposit_event(Event::<T>::FellowshipAdded { who, role });
             Ok(())
@@ -337,7 +376,7 @@ pub mod pallet {
             if !Roles::<T>::contains_key(who) {
                 if take_membership_deposit {
                     if Self::try_take_deposit(who) {
-                        Roles::<T>::insert(who, (role, rank));
+                        Self::insert_role(who, role, rank);
                     } else {
                         PendingFellows::<T>::insert(who, (role, rank));
                         Self::deposit_event(Event::<T>::MemberAddedToPendingFellows {
@@ -345,14 +384,14 @@ pub mod pallet {
                         });
                     }
                 } else {
-                    Roles::<T>::insert(who, (role, rank));
+                    Self::insert_role(who, role, rank);
                 }
 
                 if let Some(v) = vetter {
                     FellowToVetter::<T>::insert(who, v);
                 }
             } else {
-                Roles::<T>::insert(who, (role, rank));
+                Self::insert_role(who, role, rank);
             }
         }
 
@@ -360,17 +399,22 @@ pub mod pallet {
         /// Revoke the fellowship from an account.
         /// If they have not paid the deposit but are eligable then they can still be revoked
         /// using this method.
+        /// The FellowshipHooks are called once the fellowship has been revoked, their weight is returned.
         fn revoke_fellowship(
             who: &AccountIdOf<T>,
             slash_deposit: bool,
-        ) -> Result<(), DispatchError> {
+        ) -> Result<Weight, DispatchError> {
             let has_role = Roles::<T>::contains_key(who);
             ensure!(
                 PendingFellows::<T>::contains_key(who) || has_role,
                 Error::<T>::NotAFellow
             );
             PendingFellows::<T>::remove(who);
-            Roles::<T>::remove(who);
+            if has_role {
+                Roles::<T>::remove(who);
+                FellowCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+                Self::update_jury_pool(who, false);
+            }
             FellowToVetter::<T>::remove(who);
 
             // Deposits are only taken when a role is assigned
@@ -391,7 +435,8 @@ pub mod pallet {
                     }
                 }
             }
-            Ok(())
+
+            Ok(<T as Config>::FellowshipHooks::on_fellowship_revoked(who))
         }
     }
 
@@ -94,11 +94,12 @@ pub mod v0 {
             let current = <Pallet<T> as GetStorageVersion>::current_storage_version();
             let onchain = <Pallet<T> as GetStorageVersion>::on_chain_storage_version();
 
-            if current == 1 && onchain == 0 {
+            // Later versions build on v1 so it is put explicitly rather than the current version.
+            if current >= 1 && onchain == 0 {
                 let initial_fellows = Self::get_initial_fellows();
                 Self::insert_initial_fellows(&mut weight, initial_fellows);
 
-                current.put::<Pallet<T>>();
+                StorageVersion::new(1).put::<Pallet<T>>();
                 log::warn!("v1 has been successfully applied");
                 weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 1));
             } else {
@@ -142,7 +143,7 @@ pub mod v0 {
             });
 
             ensure!(
-                Pallet::<T>::current_storage_version() == 1,
+                Pallet::<T>::on_chain_storage_version() == 1,
                 "Storage version should be v1 after the migration"
             );
 
@@ -150,3 +151,187 @@ pub mod v0 {
         }
     }
 }
+
+pub mod v2 {
+    use super::*;
+
+    pub struct MigrateToV2<T: Config>(T);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            log::warn!( target: "pallet-fellowship", "Running pre_upgrade()");
+            let current = <Pallet<T> as GetStorageVersion>::current_storage_version();
+            let onchain = <Pallet<T> as GetStorageVersion>::on_chain_storage_version();
+
+            ensure!(
+                current == 2 && onchain == 1,
+                "Current version must be set to v2 and onchain to v1"
+            );
+            Ok((Roles::<T>::iter_keys().count() as u32).encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let mut weight = T::DbWeight::get().reads_writes(1, 1);
+            log::warn!("****** STARTING MIGRATION *****");
+
+            let current = <Pallet<T> as GetStorageVersion>::current_storage_version();
+            let onchain = <Pallet<T> as GetStorageVersion>::on_chain_storage_version();
+            if current == 2 && onchain == 1 {
+                migrate_fellow_count::<T>(&mut weight);
+                current.put::<Pallet<T>>();
+                log::warn!("v2 has been successfully applied");
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 1));
+            } else {
+                log::warn!("Skipping v2 due to mismatched version, this be removed from Executive");
+                weight = weight.saturating_add(T::DbWeight::get().reads(1));
+            }
+
+            log::warn!("****** ENDING MIGRATION *****");
+            weight
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            log::warn!( target:  "pallet-fellowship", "Running post_upgrade()");
+            let fellow_count: u32 = Decode::decode(&mut state.as_slice())
+                .map_err(|_| "Failed to decode the pre upgrade state")?;
+            ensure!(
+                FellowCount::<T>::get() == fellow_count,
+                "Every fellow must be counted"
+            );
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 2,
+                "Storage version should be v2 after the migration"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Count the accounts that already hold a role.
+    pub(super) fn migrate_fellow_count<T: Config>(weight: &mut Weight) {
+        let fellow_count = Roles::<T>::iter_keys().fold(0u32, |count, _| {
+            *weight = weight.saturating_add(T::DbWeight::get().reads(1));
+            count.saturating_add(1)
+        });
+        FellowCount::<T>::put(fellow_count);
+        *weight = weight.saturating_add(T::DbWeight::get().writes(1));
+    }
+}
+
+pub mod v3 {
+    use super::*;
+
+    pub struct MigrateToV3<T: Config>(T);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            log::warn!( target: "pallet-fellowship", "Running pre_upgrade()");
+            let current = <Pallet<T> as GetStorageVersion>::current_storage_version();
+            let onchain = <Pallet<T> as GetStorageVersion>::on_chain_storage_version();
+
+            ensure!(
+                current == 3 && onchain == 2,
+                "Current version must be set to v3 and onchain to v2"
+            );
+            let eligible = Roles::<T>::iter_values()
+                .filter(|(role, rank)| Pallet::<T>::is_jury_eligible(*role, *rank))
+                .count() as u32;
+            Ok(eligible.encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let mut weight = T::DbWeight::get().reads_writes(1, 1);
+            log::warn!("****** STARTING MIGRATION *****");
+
+            let current = <Pallet<T> as GetStorageVersion>::current_storage_version();
+            let onchain = <Pallet<T> as GetStorageVersion>::on_chain_storage_version();
+            if current == 3 && onchain == 2 {
+                migrate_jury_pool::<T>(&mut weight);
+                current.put::<Pallet<T>>();
+                log::warn!("v3 has been successfully applied");
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 1));
+            } else {
+                log::warn!("Skipping v3 due to mismatched version, this be removed from Executive");
+                weight = weight.saturating_add(T::DbWeight::get().reads(1));
+            }
+
+            log::warn!("****** ENDING MIGRATION *****");
+            weight
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            log::warn!( target:  "pallet-fellowship", "Running post_upgrade()");
+            let eligible: u32 = Decode::decode(&mut state.as_slice())
+                .map_err(|_| "Failed to decode the pre upgrade state")?;
+            ensure!(
+                JuryPoolSize::<T>::get() == eligible,
+                "Every eligible fellow must be in the jury pool"
+            );
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 3,
+                "Storage version should be v3 after the migration"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Rebuild the JuryPool from the fellows that hold an eligible role and rank.
+    /// The pool is cleared first so that this can also be used when the JuryRoles or MinJuryRank change.
+    pub(super) fn migrate_jury_pool<T: Config>(weight: &mut Weight) {
+        let _ = JuryPool::<T>::clear(u32::MAX, None);
+        let _ = JuryPoolIndex::<T>::clear(u32::MAX, None);
+        JuryPoolSize::<T>::kill();
+        Roles::<T>::iter().for_each(|(acc, (role, rank))| {
+            *weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 3));
+            if Pallet::<T>::is_jury_eligible(role, rank) {
+                Pallet::<T>::update_jury_pool(&acc, true);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::mock::*;
+
+    #[test]
+    fn migrate_v1_to_v2() {
+        new_test_ext().execute_with(|| {
+            Roles::<Test>::insert(ALICE, (Role::Vetter, 10));
+            Roles::<Test>::insert(BOB, (Role::Freelancer, 10));
+            PendingFellows::<Test>::insert(CHARLIE, (Role::Freelancer, 10));
+
+            let mut weight = <Weight as Default>::default();
+            v2::migrate_fellow_count::<Test>(&mut weight);
+
+            assert_eq!(FellowCount::<Test>::get(), 2);
+        })
+    }
+
+    #[test]
+    fn migrate_v2_to_v3() {
+        new_test_ext().execute_with(|| {
+            Roles::<Test>::insert(ALICE, (Role::Vetter, 10));
+            Roles::<Test>::insert(BOB, (Role::Freelancer, 10));
+            // Below the MinJuryRank.
+            Roles::<Test>::insert(CHARLIE, (Role::Freelancer, 1));
+
+            let mut weight = <Weight as Default>::default();
+            v3::migrate_jury_pool::<Test>(&mut weight);
+
+            assert_eq!(JuryPoolSize::<Test>::get(), 2);
+            assert!(JuryPoolIndex::<Test>::contains_key(ALICE));
+            assert!(JuryPoolIndex::<Test>::contains_key(BOB));
+            assert!(!JuryPoolIndex::<Test>::contains_key(CHARLIE));
+            let mut pool = JuryPool::<Test>::iter_values().collect::<Vec<_>>();
+            pool.sort();
+            assert_eq!(pool, vec![ALICE, BOB]);
+        })
+    }
+}
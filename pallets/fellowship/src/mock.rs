@@ -1,13 +1,16 @@
 use crate as pallet_fellowship;
 
 use common_types::CurrencyId;
-use frame_support::traits::{ConstU16, Nothing};
+use frame_support::{
+    traits::{ConstU16, Nothing},
+    weights::Weight,
+};
 use frame_system::EnsureRoot;
 use orml_traits::MultiCurrency;
 use sp_core::H256;
 use sp_runtime::{
     traits::{parameter_types, BlakeTwo256, IdentityLookup},
-    BuildStorage,
+    BuildStorage, DispatchError,
 };
 use sp_std::convert::{TryFrom, TryInto};
 
@@ -71,9 +74,37 @@ impl pallet_fellowship::Config for Test {
     type DepositCurrencyId = DepositCurrencyId;
     type SlashAccount = SlashAccount;
     type Permissions = crate::impls::VetterAndFreelancerAllPermissions;
+    type FellowshipHooks = MockFellowshipHooks;
+    type JuryRoles = JuryRoles;
+    type MinJuryRank = MinJuryRank;
     type WeightInfo = ();
 }
 
+parameter_types! {
+    pub static LeavingBlocked: bool = false;
+    pub static RevokedFellows: Vec<AccountId> = vec![];
+    pub RevokedHookWeight: Weight = Weight::from_parts(1_000, 0);
+}
+
+pub struct MockFellowshipHooks;
+impl crate::traits::FellowshipHooks<AccountId> for MockFellowshipHooks {
+    fn can_leave_fellowship(_who: &AccountId) -> Result<(), DispatchError> {
+        if LeavingBlocked::get() {
+            return Err(DispatchError::Other("leaving blocked"));
+        }
+        Ok(())
+    }
+
+    fn on_fellowship_revoked(who: &AccountId) -> Weight {
+        RevokedFellows::mutate(|revoked| revoked.push(*who));
+        RevokedHookWeight::get()
+    }
+
+    fn max_hooks_weight() -> Weight {
+        RevokedHookWeight::get()
+    }
+}
+
 orml_traits::parameter_type_with_key! {
     pub ExistentialDeposits: |_currency_id: CurrencyId| -> Balance {
         100
@@ -99,6 +130,22 @@ impl orml_tokens::Config for Test {
     type ReserveIdentifier = [u8; 8];
 }
 
+pub struct MockRandomness;
+impl frame_support::traits::Randomness<H256, BlockNumber> for MockRandomness {
+    fn random(subject: &[u8]) -> (H256, BlockNumber) {
+        use sp_runtime::traits::Hash;
+        (BlakeTwo256::hash(subject), System::block_number())
+    }
+}
+
+parameter_types! {
+    pub JurySize: u32 = 3;
+    pub JuryRoles: Vec<crate::Role> = vec![crate::Role::Vetter, crate::Role::Freelancer];
+    pub MinJuryRank: u16 = 5;
+}
+
+pub type MockJurySelector = crate::impls::RandomJurySelector<Test, MockRandomness, JurySize>;
+
 pub static ALICE: AccountId = 125;
 pub static BOB: AccountId = 126;
 pub static CHARLIE: AccountId = 127;
@@ -0,0 +1,118 @@
+use super::*;
+
+#[test]
+fn select_jury_only_picks_eligible_fellows() {
+    new_test_ext().execute_with(|| {
+        Fellowship::insert_role(&ALICE, Role::Vetter, 10);
+        Fellowship::insert_role(&BOB, Role::Freelancer, 5);
+        // Rank too low.
+        Fellowship::insert_role(&CHARLIE, Role::Freelancer, 4);
+
+        let jury = MockJurySelector::select_jury(JurySize::get());
+        assert_eq!(jury.len(), 2);
+        assert!(jury.contains(&ALICE));
+        assert!(jury.contains(&BOB));
+    });
+}
+
+#[test]
+fn select_jury_is_capped_at_jury_size() {
+    new_test_ext().execute_with(|| {
+        (1000u128..1010).for_each(|acc| Fellowship::insert_role(&acc, Role::Vetter, 10));
+
+        let jury = MockJurySelector::select_jury(JurySize::get());
+        assert_eq!(jury.len(), JurySize::get() as usize);
+        let jury = MockJurySelector::select_jury_excluding(u32::MAX, &[]);
+        assert_eq!(jury.len(), JurySize::get() as usize);
+        let jury = MockJurySelector::select_jury_excluding(2, &[]);
+        assert_eq!(jury.len(), 2);
+    });
+}
+
+#[test]
+fn select_jury_has_no_duplicates() {
+    new_test_ext().execute_with(|| {
+        (1000u128..1004).for_each(|acc| Fellowship::insert_role(&acc, Role::Vetter, 10));
+
+        (0..20).for_each(|_| {
+            let mut jury = MockJurySelector::select_jury(JurySize::get()).into_inner();
+            jury.sort();
+            jury.dedup();
+            assert_eq!(jury.len(), JurySize::get() as usize);
+        });
+    });
+}
+
+#[test]
+fn select_jury_excluding_skips_excluded_and_their_vetters() {
+    new_test_ext().execute_with(|| {
+        Fellowship::insert_role(&ALICE, Role::Vetter, 10);
+        Fellowship::insert_role(&BOB, Role::Freelancer, 10);
+        Fellowship::insert_role(&CHARLIE, Role::Freelancer, 10);
+        Fellowship::insert_role(&EMPTY, Role::Vetter, 10);
+        FellowToVetter::<Test>::insert(BOB, ALICE);
+
+        let jury = MockJurySelector::select_jury_excluding(3, &[BOB]);
+        assert_eq!(jury.len(), 2);
+        assert!(!jury.contains(&BOB));
+        assert!(!jury.contains(&ALICE));
+        assert!(jury.contains(&CHARLIE));
+        assert!(jury.contains(&EMPTY));
+    });
+}
+
+#[test]
+fn select_jury_varies_between_selections() {
+    new_test_ext().execute_with(|| {
+        (1000u128..1020).for_each(|acc| Fellowship::insert_role(&acc, Role::Vetter, 10));
+
+        let first = MockJurySelector::select_jury(JurySize::get());
+        let differs = (0..10).any(|_| MockJurySelector::select_jury(JurySize::get()) != first);
+        assert!(
+            differs,
+            "the nonce should change the seed between selections"
+        );
+    });
+}
+
+#[test]
+fn select_jury_picks_the_requested_amount() {
+    new_test_ext().execute_with(|| {
+        (1000u128..1010).for_each(|acc| Fellowship::insert_role(&acc, Role::Vetter, 10));
+
+        assert_eq!(MockJurySelector::select_jury(1).len(), 1);
+        assert_eq!(MockJurySelector::select_jury(2).len(), 2);
+        assert_eq!(
+            MockJurySelector::select_jury(u32::MAX).len(),
+            JurySize::get() as usize
+        );
+    });
+}
+
+#[test]
+fn jury_pool_follows_role_changes_and_removals() {
+    new_test_ext().execute_with(|| {
+        Fellowship::insert_role(&ALICE, Role::Vetter, 10);
+        Fellowship::insert_role(&BOB, Role::Freelancer, 10);
+        Fellowship::insert_role(&CHARLIE, Role::Freelancer, 10);
+        assert_eq!(JuryPoolSize::<Test>::get(), 3);
+
+        // Dropping below the MinJuryRank removes them from the pool.
+        Fellowship::insert_role(&ALICE, Role::Vetter, 1);
+        assert_eq!(JuryPoolSize::<Test>::get(), 2);
+        assert!(!JuryPoolIndex::<Test>::contains_key(ALICE));
+
+        assert_ok!(Fellowship::force_remove_and_slash_fellowship(
+            RuntimeOrigin::root(),
+            BOB
+        ));
+        assert_eq!(JuryPoolSize::<Test>::get(), 1);
+        // The last fellow is moved into the freed position.
+        assert_eq!(JuryPool::<Test>::get(0), Some(CHARLIE));
+        assert_eq!(JuryPoolIndex::<Test>::get(CHARLIE), Some(0));
+        assert!(JuryPool::<Test>::get(1).is_none());
+
+        let jury = MockJurySelector::select_jury(JurySize::get());
+        assert_eq!(jury.into_inner(), vec![CHARLIE]);
+    });
+}
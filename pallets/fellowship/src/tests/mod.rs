@@ -4,7 +4,7 @@ use crate::*;
 use crate::{mock::*, Error, Event, FellowToVetter, Role, Roles};
 use common_traits::MaybeConvert;
 use common_types::CurrencyId;
-use frame_support::{assert_noop, assert_ok, traits::Hooks, BoundedBTreeMap};
+use frame_support::{assert_noop, assert_ok, traits::Hooks, weights::Weight, BoundedBTreeMap};
 use orml_tokens::Error as TokensError;
 use orml_traits::{MultiCurrency, MultiReservableCurrency};
 use sp_runtime::{traits::BadOrigin, DispatchError};
@@ -12,6 +12,7 @@ use sp_std::vec;
 
 mod ensure_role;
 mod fellowship_permissions;
+mod jury_selection;
 mod pallet_tests;
 mod test_utils;
 pub(crate) use test_utils::*;
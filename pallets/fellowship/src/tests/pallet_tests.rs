@@ -87,6 +87,80 @@ fn leave_fellowship_assert_event() {
     });
 }
 
+#[test]
+fn leave_fellowship_can_be_blocked_by_hooks() {
+    new_test_ext().execute_with(|| {
+        Fellowship::force_add_fellowship(RuntimeOrigin::root(), ALICE, Role::Freelancer, 10)
+            .expect("qed");
+        LeavingBlocked::set(true);
+        assert_noop!(
+            Fellowship::leave_fellowship(RuntimeOrigin::signed(ALICE)),
+            DispatchError::Other("leaving blocked")
+        );
+        assert!(Roles::<Test>::contains_key(ALICE));
+        assert!(RevokedFellows::get().is_empty());
+    });
+}
+
+#[test]
+fn revoking_fellowship_calls_hooks() {
+    new_test_ext().execute_with(|| {
+        Fellowship::force_add_fellowship(RuntimeOrigin::root(), ALICE, Role::Freelancer, 10)
+            .expect("qed");
+        Fellowship::force_add_fellowship(RuntimeOrigin::root(), BOB, Role::Freelancer, 10)
+            .expect("qed");
+        assert_ok!(Fellowship::leave_fellowship(RuntimeOrigin::signed(ALICE)));
+        assert_ok!(Fellowship::force_remove_and_slash_fellowship(
+            RuntimeOrigin::root(),
+            BOB
+        ));
+        assert_eq!(RevokedFellows::get(), vec![ALICE, BOB]);
+    });
+}
+
+#[test]
+fn removing_a_fellow_charges_the_weight_of_the_hooks() {
+    new_test_ext().execute_with(|| {
+        Fellowship::force_add_fellowship(RuntimeOrigin::root(), ALICE, Role::Freelancer, 10)
+            .expect("qed");
+        Fellowship::force_add_fellowship(RuntimeOrigin::root(), BOB, Role::Freelancer, 10)
+            .expect("qed");
+        let post_info = Fellowship::leave_fellowship(RuntimeOrigin::signed(ALICE)).unwrap();
+        assert_eq!(
+            post_info.actual_weight,
+            Some(
+                <Test as Config>::WeightInfo::leave_fellowship()
+                    .saturating_add(RevokedHookWeight::get())
+            )
+        );
+        let post_info =
+            Fellowship::force_remove_and_slash_fellowship(RuntimeOrigin::root(), BOB).unwrap();
+        assert_eq!(
+            post_info.actual_weight,
+            Some(
+                <Test as Config>::WeightInfo::force_remove_and_slash_fellowship()
+                    .saturating_add(RevokedHookWeight::get())
+            )
+        );
+    });
+}
+
+#[test]
+fn fellow_count_follows_the_roles() {
+    new_test_ext().execute_with(|| {
+        Fellowship::force_add_fellowship(RuntimeOrigin::root(), ALICE, Role::Freelancer, 10)
+            .expect("qed");
+        Fellowship::force_add_fellowship(RuntimeOrigin::root(), BOB, Role::Freelancer, 10)
+            .expect("qed");
+        // Changing a role does not add a fellow.
+        Fellowship::force_add_fellowship(RuntimeOrigin::root(), ALICE, Role::Vetter, 10)
+            .expect("qed");
+        assert_eq!(FellowCount::<Test>::get(), 2);
+        assert_ok!(Fellowship::leave_fellowship(RuntimeOrigin::signed(ALICE)));
+        assert_eq!(FellowCount::<Test>::get(), 1);
+    });
+}
+
 #[test]
 fn add_to_fellowship_takes_deposit_if_avaliable() {
     new_test_ext().execute_with(|| {
@@ -16,7 +16,7 @@ pub(crate) fn add_to_fellowship_take_deposit(
 pub(crate) fn revoke_fellowship(
     who: &AccountIdOf<Test>,
     slash_deposit: bool,
-) -> Result<(), DispatchError> {
+) -> Result<Weight, DispatchError> {
     <Fellowship as FellowshipHandle<AccountIdOf<Test>>>::revoke_fellowship(who, slash_deposit)
 }
 
@@ -16,7 +16,33 @@ pub trait FellowshipHandle<AccountId> {
         vetter: Option<&AccountId>,
         take_membership_deposit: bool,
     );
-    fn revoke_fellowship(who: &AccountId, slash_deposit: bool) -> Result<(), DispatchError>;
+    /// Returns the weight used by the FellowshipHooks.
+    fn revoke_fellowship(who: &AccountId, slash_deposit: bool) -> Result<Weight, DispatchError>;
+}
+
+/// Lets external pallets react to members leaving or being removed from the fellowship.
+pub trait FellowshipHooks<AccountId> {
+    /// Called before a fellow leaves the fellowship of their own accord.
+    /// Returning an error prevents them from leaving, i.e while they sit on an open dispute.
+    fn can_leave_fellowship(who: &AccountId) -> Result<(), DispatchError>;
+    /// Called once an account has been removed from the fellowship.
+    fn on_fellowship_revoked(who: &AccountId) -> Weight;
+    /// The most weight the hooks can use when a fellow is removed, charged up front by the calls that remove them.
+    fn max_hooks_weight() -> Weight;
+}
+
+impl<AccountId> FellowshipHooks<AccountId> for () {
+    fn can_leave_fellowship(_who: &AccountId) -> Result<(), DispatchError> {
+        Ok(())
+    }
+
+    fn on_fellowship_revoked(_who: &AccountId) -> Weight {
+        Weight::zero()
+    }
+
+    fn max_hooks_weight() -> Weight {
+        Weight::zero()
+    }
 }
 
 pub trait EnsureRole<AccountId> {
@@ -36,7 +62,33 @@ pub trait EnsureRole<AccountId> {
 /// Select a pseudo-random jury of a specified amount.
 pub trait SelectJury<AccountId> {
     type JurySize: Get<u32>;
-    fn select_jury() -> BoundedVec<AccountId, Self::JurySize>;
+
+    /// Select a jury of up to `amount` members.
+    /// The amount is capped at `JurySize`.
+    fn select_jury(amount: u32) -> BoundedVec<AccountId, Self::JurySize>;
+
+    /// Select a jury of up to `amount` members, none of which are in `excluded`.
+    /// The amount is capped at `JurySize`.
+    fn select_jury_excluding(
+        amount: u32,
+        excluded: &[AccountId],
+    ) -> BoundedVec<AccountId, Self::JurySize>
+    where
+        AccountId: PartialEq,
+    {
+        let jury = Self::select_jury(Self::JurySize::get())
+            .into_iter()
+            .filter(|juror| !excluded.contains(juror))
+            .take(amount as usize)
+            .collect::<Vec<AccountId>>();
+        // Can only shrink the jury so this cannot fail.
+        BoundedVec::truncate_from(jury)
+    }
+
+    /// The weight of selecting a jury while excluding up to `max_excluded` accounts.
+    fn select_jury_weight(_max_excluded: u32) -> Weight {
+        Weight::zero()
+    }
 }
 
 /// Custom definition for permissions for each role.
@@ -47,27 +47,47 @@ impl<T: frame_system::Config> crate::traits::WeightInfoT for WeightInfo<T> {
 	/// Proof: `ImbueFellowship::FellowToVetter` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	/// Storage: `ImbueFellowship::FellowshipReserves` (r:0 w:1)
 	/// Proof: `ImbueFellowship::FellowshipReserves` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueFellowship::FellowCount` (r:1 w:1)
+	/// Proof: `ImbueFellowship::FellowCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueFellowship::JuryPoolIndex` (r:1 w:1)
+	/// Proof: `ImbueFellowship::JuryPoolIndex` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueFellowship::JuryPoolSize` (r:1 w:1)
+	/// Proof: `ImbueFellowship::JuryPoolSize` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueFellowship::JuryPool` (r:0 w:1)
+	/// Proof: `ImbueFellowship::JuryPool` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
 	fn add_to_fellowship() -> Weight {
+		// The storage accesses were adjusted by hand since the last run of `benchmark pallet`,
+		// the measurements below are out of date until it is run again.
 		// Proof Size summary in bytes:
 		//  Measured:  `145`
 		//  Estimated: `3593`
 		// Minimum execution time: 297_927_000 picoseconds.
 		Weight::from_parts(299_772_000, 0)
 			.saturating_add(Weight::from_parts(0, 3593))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(8))
 	}
 	/// Storage: `ImbueFellowship::Roles` (r:1 w:1)
 	/// Proof: `ImbueFellowship::Roles` (`max_values`: None, `max_size`: Some(51), added: 2526, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueFellowship::FellowCount` (r:1 w:1)
+	/// Proof: `ImbueFellowship::FellowCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueFellowship::JuryPoolIndex` (r:1 w:1)
+	/// Proof: `ImbueFellowship::JuryPoolIndex` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueFellowship::JuryPoolSize` (r:1 w:1)
+	/// Proof: `ImbueFellowship::JuryPoolSize` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueFellowship::JuryPool` (r:0 w:1)
+	/// Proof: `ImbueFellowship::JuryPool` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
 	fn force_add_fellowship() -> Weight {
+		// The storage accesses were adjusted by hand since the last run of `benchmark pallet`,
+		// the measurements below are out of date until it is run again.
 		// Proof Size summary in bytes:
 		//  Measured:  `42`
 		//  Estimated: `3516`
 		// Minimum execution time: 153_043_000 picoseconds.
 		Weight::from_parts(154_780_000, 0)
 			.saturating_add(Weight::from_parts(0, 3516))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `ImbueFellowship::Roles` (r:1 w:1)
 	/// Proof: `ImbueFellowship::Roles` (`max_values`: None, `max_size`: Some(51), added: 2526, mode: `MaxEncodedLen`)
@@ -79,15 +99,25 @@ impl<T: frame_system::Config> crate::traits::WeightInfoT for WeightInfo<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `ImbueFellowship::FellowToVetter` (r:0 w:1)
 	/// Proof: `ImbueFellowship::FellowToVetter` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueFellowship::FellowCount` (r:1 w:1)
+	/// Proof: `ImbueFellowship::FellowCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueFellowship::JuryPoolIndex` (r:1 w:2)
+	/// Proof: `ImbueFellowship::JuryPoolIndex` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueFellowship::JuryPoolSize` (r:1 w:1)
+	/// Proof: `ImbueFellowship::JuryPoolSize` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueFellowship::JuryPool` (r:1 w:2)
+	/// Proof: `ImbueFellowship::JuryPool` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
 	fn leave_fellowship() -> Weight {
+		// The storage accesses were adjusted by hand since the last run of `benchmark pallet`,
+		// the measurements below are out of date until it is run again.
 		// Proof Size summary in bytes:
 		//  Measured:  `410`
 		//  Estimated: `3593`
 		// Minimum execution time: 463_315_000 picoseconds.
 		Weight::from_parts(479_038_000, 0)
 			.saturating_add(Weight::from_parts(0, 3593))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(10))
 	}
 	/// Storage: `ImbueFellowship::Roles` (r:1 w:1)
 	/// Proof: `ImbueFellowship::Roles` (`max_values`: None, `max_size`: Some(51), added: 2526, mode: `MaxEncodedLen`)
@@ -99,15 +129,25 @@ impl<T: frame_system::Config> crate::traits::WeightInfoT for WeightInfo<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `ImbueFellowship::FellowToVetter` (r:0 w:1)
 	/// Proof: `ImbueFellowship::FellowToVetter` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueFellowship::FellowCount` (r:1 w:1)
+	/// Proof: `ImbueFellowship::FellowCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueFellowship::JuryPoolIndex` (r:1 w:2)
+	/// Proof: `ImbueFellowship::JuryPoolIndex` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueFellowship::JuryPoolSize` (r:1 w:1)
+	/// Proof: `ImbueFellowship::JuryPoolSize` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueFellowship::JuryPool` (r:1 w:2)
+	/// Proof: `ImbueFellowship::JuryPool` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
 	fn force_remove_and_slash_fellowship() -> Weight {
+		// The storage accesses were adjusted by hand since the last run of `benchmark pallet`,
+		// the measurements below are out of date until it is run again.
 		// Proof Size summary in bytes:
 		//  Measured:  `513`
 		//  Estimated: `6196`
 		// Minimum execution time: 894_392_000 picoseconds.
 		Weight::from_parts(915_373_000, 0)
 			.saturating_add(Weight::from_parts(0, 6196))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(11))
 	}
 	/// Storage: `ImbueFellowship::Roles` (r:2 w:0)
 	/// Proof: `ImbueFellowship::Roles` (`max_values`: None, `max_size`: Some(51), added: 2526, mode: `MaxEncodedLen`)
@@ -151,14 +191,24 @@ impl<T: frame_system::Config> crate::traits::WeightInfoT for WeightInfo<T> {
 	/// Proof: `ImbueFellowship::Roles` (`max_values`: None, `max_size`: Some(51), added: 2526, mode: `MaxEncodedLen`)
 	/// Storage: `ImbueFellowship::FellowshipReserves` (r:0 w:1)
 	/// Proof: `ImbueFellowship::FellowshipReserves` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueFellowship::FellowCount` (r:1 w:1)
+	/// Proof: `ImbueFellowship::FellowCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueFellowship::JuryPoolIndex` (r:1 w:1)
+	/// Proof: `ImbueFellowship::JuryPoolIndex` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueFellowship::JuryPoolSize` (r:1 w:1)
+	/// Proof: `ImbueFellowship::JuryPoolSize` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueFellowship::JuryPool` (r:0 w:1)
+	/// Proof: `ImbueFellowship::JuryPool` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
 	fn pay_deposit_to_remove_pending_status() -> Weight {
+		// The storage accesses were adjusted by hand since the last run of `benchmark pallet`,
+		// the measurements below are out of date until it is run again.
 		// Proof Size summary in bytes:
 		//  Measured:  `259`
 		//  Estimated: `3593`
 		// Minimum execution time: 392_591_000 picoseconds.
 		Weight::from_parts(401_207_000, 0)
 			.saturating_add(Weight::from_parts(0, 3593))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(8))
 	}
 }
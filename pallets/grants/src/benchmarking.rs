@@ -5,7 +5,7 @@ use super::*;
 use crate::test_utils::gen_grant_id;
 use crate::Pallet as Grants;
 use crate::{BoundedApprovers, BoundedPMilestones, Config};
-use common_types::{CurrencyId, TreasuryOrigin, ForeignOwnedAccount};
+use common_types::{CurrencyId, ForeignOwnedAccount, TreasuryOrigin};
 use frame_benchmarking::v2::*;
 use frame_support::{assert_ok, traits::Get};
 use frame_system::pallet_prelude::BlockNumberFor;
@@ -59,8 +59,10 @@ fn get_milestones<T: Config>(n: u32) -> BoundedPMilestones<T> {
     (0..n)
         .map(|_| ProposedMilestone {
             percentage_to_unlock: Percent::from_percent((100 / n) as u8),
+            content_hash: Default::default(),
+            due_block: None,
         })
-        .collect::<Vec<ProposedMilestone>>()
+        .collect::<Vec<ProposedMilestone<BlockNumberFor<T>>>>()
         .try_into()
         .expect("qed")
 }
@@ -40,7 +40,7 @@ pub mod pallet {
         <<T as Config>::RMultiCurrency as MultiCurrency<AccountIdOf<T>>>::Balance;
 
     pub(crate) type BoundedPMilestones<T> =
-        BoundedVec<ProposedMilestone, <T as Config>::MaxMilestonesPerGrant>;
+        BoundedVec<ProposedMilestone<BlockNumberFor<T>>, <T as Config>::MaxMilestonesPerGrant>;
     pub(crate) type BoundedApprovers<T> = BoundedVec<AccountIdOf<T>, <T as Config>::MaxApprovers>;
     pub type GrantId = H256;
 
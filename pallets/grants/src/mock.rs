@@ -117,6 +117,7 @@ pub enum StorageItem {
     Brief,
     Grant,
     Project,
+    Dispute,
 }
 
 pub struct MockDepositHandler;
@@ -164,14 +165,25 @@ parameter_types! {
     pub RefundsPerBlock: u8 = 2;
     pub IsIdentityRequired: bool = false;
     pub MilestoneVotingWindow: BlockNumber  =  100800u64;
+    pub NoConfidenceVotingWindow: BlockNumber = 100800u64;
+    pub PercentRequiredForVoteNoConfidenceToPass: Percent = Percent::from_percent(75u8);
     pub MaxMilestonesPerProject: u32 = 100;
     pub ProjectStorageDeposit: Balance = 100;
     pub ImbueFee: Percent = Percent::from_percent(5u8);
     pub ExpiringProjectRoundsPerBlock: u32 = 10;
     pub ProjectStorageItem: StorageItem = StorageItem::Project;
+    pub DisputeStorageItem: StorageItem = StorageItem::Dispute;
     pub MaxProjectsPerAccount: u16 = 100;
     pub MaxJuryMembers: u32 = 100;
+    pub MaxDisputesPerJuror: u32 = 50;
+    pub MaxJuriesPerAccount: u32 = 50;
     pub FeeAccount: AccountId = TREASURY;
+    pub MinimumJurySize: u32 = 2;
+    pub ArbitrationWindow: BlockNumber = 100;
+    pub DefaultResubmissionPolicy: pallet_proposals::ResubmissionPolicy<BlockNumber> =
+        pallet_proposals::ResubmissionPolicy { cooldown: 0, max_attempts: 3 };
+    pub DefaultVotingExpiryPolicy: pallet_proposals::VotingExpiryPolicy =
+        pallet_proposals::VotingExpiryPolicy::Reject;
 }
 
 impl pallet_proposals::Config for Test {
@@ -182,6 +194,8 @@ impl pallet_proposals::Config for Test {
     type PercentRequiredForVoteToPass = PercentRequiredForVoteToPass;
     type MaximumContributorsPerProject = MaximumContributorsPerProject;
     type MilestoneVotingWindow = MilestoneVotingWindow;
+    type NoConfidenceVotingWindow = NoConfidenceVotingWindow;
+    type PercentRequiredForVoteNoConfidenceToPass = PercentRequiredForVoteNoConfidenceToPass;
     type ExternalRefundHandler = pallet_proposals::traits::MockRefundHandler<Test>;
     type MaxMilestonesPerProject = MaxMilestonesPerProject;
     type ImbueFee = ImbueFee;
@@ -189,10 +203,18 @@ impl pallet_proposals::Config for Test {
     type ExpiringProjectRoundsPerBlock = ExpiringProjectRoundsPerBlock;
     type DepositHandler = MockDepositHandler;
     type ProjectStorageItem = ProjectStorageItem;
+    type DisputeStorageItem = DisputeStorageItem;
     type MaxProjectsPerAccount = MaxProjectsPerAccount;
     type DisputeRaiser = MockDisputeRaiser;
     type JurySelector = MockJurySelector;
+    type MaxJuriesPerAccount = MaxJuriesPerAccount;
     type AssetSignerOrigin = EnsureRoot<AccountId>;
+    type MinimumJurySize = MinimumJurySize;
+    type ArbitrationOrigin = EnsureRoot<AccountId>;
+    type ArbitrationWindow = ArbitrationWindow;
+    type DefaultResubmissionPolicy = DefaultResubmissionPolicy;
+    type DefaultVotingExpiryPolicy = DefaultVotingExpiryPolicy;
+    type ProjectPolicyOrigin = EnsureRoot<AccountId>;
 }
 
 parameter_types! {
@@ -242,15 +264,28 @@ pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
 
 pub struct MockDisputeRaiser;
 impl pallet_disputes::traits::DisputeRaiser<AccountId> for MockDisputeRaiser {
-    type DisputeKey = pallet_proposals::ProjectKey;
+    type DisputeKey = pallet_proposals::DisputeId;
     type SpecificId = pallet_proposals::MilestoneKey;
     type MaxJurySize = MaxJuryMembers;
+    type MaxDisputesPerJuror = MaxDisputesPerJuror;
     type MaxSpecifics = MaxMilestonesPerProject;
+    type BondId = u64;
     fn raise_dispute(
         _dispute_key: Self::DisputeKey,
         _raised_by: AccountId,
         _jury: BoundedVec<AccountId, Self::MaxJurySize>,
         _specific_ids: BoundedVec<Self::SpecificId, Self::MaxSpecifics>,
+        _bond: Option<Self::BondId>,
+    ) -> Result<(), DispatchError> {
+        Ok(())
+    }
+    fn disputes_of_juror(_juror: &AccountId) -> Vec<Self::DisputeKey> {
+        Vec::new()
+    }
+    fn replace_juror(
+        _dispute_key: Self::DisputeKey,
+        _juror: &AccountId,
+        _replacement: Option<AccountId>,
     ) -> Result<(), DispatchError> {
         Ok(())
     }
@@ -259,7 +294,7 @@ impl pallet_disputes::traits::DisputeRaiser<AccountId> for MockDisputeRaiser {
 pub struct MockJurySelector;
 impl pallet_fellowship::traits::SelectJury<AccountId> for MockJurySelector {
     type JurySize = MaxJuryMembers;
-    fn select_jury() -> BoundedVec<AccountId, Self::JurySize> {
+    fn select_jury(_amount: u32) -> BoundedVec<AccountId, Self::JurySize> {
         BoundedVec::new()
     }
 }
@@ -3,6 +3,7 @@ use crate::mock::*;
 use crate::pallet::{BoundedApprovers, BoundedPMilestones, Config, Error};
 use common_types::{CurrencyId, TreasuryOrigin};
 use frame_support::{assert_noop, pallet_prelude::*};
+use frame_system::pallet_prelude::BlockNumberFor;
 use pallet_proposals::ProposedMilestone;
 use sp_arithmetic::per_things::Percent;
 use sp_core::H256;
@@ -12,6 +13,8 @@ fn ensure_milestone_percent_equal_100() {
     new_test_ext().execute_with(|| {
         let milestones: BoundedPMilestones<Test> = vec![ProposedMilestone {
             percentage_to_unlock: Percent::from_percent(50u8),
+            content_hash: Default::default(),
+            due_block: None,
         }]
         .try_into()
         .expect("qed");
@@ -76,8 +79,10 @@ pub(crate) fn get_milestones(mut n: u32) -> BoundedPMilestones<Test> {
     (0..n)
         .map(|_m| ProposedMilestone {
             percentage_to_unlock: percent,
+            content_hash: Default::default(),
+            due_block: None,
         })
-        .collect::<Vec<ProposedMilestone>>()
+        .collect::<Vec<ProposedMilestone<BlockNumberFor<Test>>>>()
         .try_into()
         .expect("qed")
 }
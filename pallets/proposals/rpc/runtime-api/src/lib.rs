@@ -1,12 +1,113 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+use codec::{Decode, Encode};
+use common_types::CurrencyId;
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_core::H256;
 use sp_std::vec::Vec;
 
+/// A decoded project as returned by the runtime api.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ProjectInfo<AccountId, Balance, BlockNumber> {
+    pub project_key: u32,
+    pub initiator: AccountId,
+    pub agreement_hash: H256,
+    pub currency_id: CurrencyId,
+    pub raised_funds: Balance,
+    pub withdrawn_funds: Balance,
+    pub refunded_funds: Balance,
+    /// The amount the initiator can currently withdraw, before fees.
+    pub withdrawable_funds: Balance,
+    /// The amount that can currently be refunded to the contributors, before fees.
+    pub refundable_funds: Balance,
+    pub funding_state: FundingStatus<Balance>,
+    pub created_on: BlockNumber,
+    pub cancelled: bool,
+    pub milestones: Vec<MilestoneInfo<AccountId, Balance, BlockNumber>>,
+    pub contributions: Vec<ContributionInfo<AccountId, Balance, BlockNumber>>,
+    pub jury: Vec<AccountId>,
+}
+
+/// A milestone of a project along with its current status and votes.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct MilestoneInfo<AccountId, Balance, BlockNumber> {
+    pub milestone_key: u32,
+    /// The percentage of the raised funds unlocked by this milestone.
+    pub percentage_to_unlock: u8,
+    pub status: MilestoneStatus<BlockNumber>,
+    pub content_hash: Vec<u8>,
+    pub due_block: Option<BlockNumber>,
+    pub evidence_hash: Option<Vec<u8>>,
+    /// The weighted total of the votes in the latest voting round.
+    pub yay: Balance,
+    pub nay: Balance,
+    /// The individual votes in the latest voting round, yay is true.
+    pub votes: Vec<(AccountId, bool)>,
+    /// The votes in the latest voting round cast by a delegate, as (delegator, delegate).
+    pub delegated_votes: Vec<(AccountId, AccountId)>,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum MilestoneStatus<BlockNumber> {
+    /// The milestone has not been approved yet.
+    Pending,
+    /// The milestone has been submitted and is being voted on.
+    InVoting,
+    /// The milestone is being disputed.
+    InDispute,
+    /// The milestone has been approved and can be withdrawn.
+    Approved,
+    /// A dispute on the milestone succeeded and its funds can be refunded.
+    Refundable,
+    Withdrawn {
+        on: BlockNumber,
+    },
+    Refunded {
+        on: BlockNumber,
+    },
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ContributionInfo<AccountId, Balance, BlockNumber> {
+    pub who: AccountId,
+    pub value: Balance,
+    pub timestamp: BlockNumber,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum FundingStatus<Balance> {
+    AwaitingFunding,
+    PartiallyFunded { received: Balance },
+    Funded,
+}
+
 sp_api::decl_runtime_apis! {
+    #[api_version(2)]
     pub trait ProposalsApi<AccountId, Balance>
     where AccountId: codec::Codec + Ord,
+    Balance: codec::Codec,
     {
         fn get_project_account_by_id(project_id: u32) -> AccountId;
+        /// Only the earliest open dispute of the project is returned, use get_project_disputes for all of them.
         fn get_all_project_data(project_id: u32) -> (Option<Vec<u8>>, Option<Vec<u8>>, Option<Vec<u8>>, Vec<u32>);
+        /// Get every open dispute of a project along with its encoded votes, ordered by dispute key.
+        #[api_version(2)]
+        fn get_project_disputes(project_id: u32) -> Vec<(u32, Vec<u8>)>;
+        /// Get a decoded project by its key, block numbers are u32 like the project and milestone keys.
+        #[api_version(2)]
+        fn get_project_info(project_id: u32) -> Option<ProjectInfo<AccountId, Balance, u32>>;
+        /// Get a page of the projects created by an initiator, ordered by project key.
+        #[api_version(2)]
+        fn get_projects_by_initiator(initiator: AccountId, offset: u32, limit: u32) -> Vec<ProjectInfo<AccountId, Balance, u32>>;
+        /// Get a page of the projects with milestones in a voting round, ordered by project key.
+        #[api_version(2)]
+        fn get_projects_in_voting(offset: u32, limit: u32) -> Vec<(u32, Vec<u32>)>;
     }
 }
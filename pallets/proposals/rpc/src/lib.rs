@@ -5,7 +5,10 @@ use jsonrpsee::{
     proc_macros::rpc,
     types::error::{CallError, ErrorObject},
 };
-pub use pallet_proposals_rpc_runtime_api::ProposalsApi as ProposalsRuntimeApi;
+pub use pallet_proposals_rpc_runtime_api::{
+    ContributionInfo, FundingStatus, MilestoneInfo, MilestoneStatus, ProjectInfo,
+    ProposalsApi as ProposalsRuntimeApi,
+};
 
 use sp_blockchain::HeaderBackend;
 use sp_runtime::traits::Block as BlockT;
@@ -28,6 +31,22 @@ where
         &self,
         project_id: u32,
     ) -> RpcResult<(Option<Vec<u8>>, Option<Vec<u8>>, Option<Vec<u8>>, Vec<u32>)>;
+    #[method(name = "proposals_getProjectDisputes")]
+    fn project_disputes(&self, project_id: u32) -> RpcResult<Vec<(u32, Vec<u8>)>>;
+    #[method(name = "proposals_getProjectInfo")]
+    fn project_info(
+        &self,
+        project_id: u32,
+    ) -> RpcResult<Option<ProjectInfo<AccountId, Balance, u32>>>;
+    #[method(name = "proposals_getProjectsByInitiator")]
+    fn projects_by_initiator(
+        &self,
+        initiator: AccountId,
+        offset: u32,
+        limit: u32,
+    ) -> RpcResult<Vec<ProjectInfo<AccountId, Balance, u32>>>;
+    #[method(name = "proposals_getProjectsInVoting")]
+    fn projects_in_voting(&self, offset: u32, limit: u32) -> RpcResult<Vec<(u32, Vec<u32>)>>;
 }
 
 pub struct Proposals<C, B> {
@@ -71,6 +90,7 @@ where
     C::Api: ProposalsRuntimeApi<B, AccountId, Balance>,
     B: BlockT,
     AccountId: Clone + Display + Codec + Send + 'static + Ord,
+    Balance: Codec + Send + Sync + 'static,
 {
     fn project_account_id(&self, project_id: u32) -> RpcResult<AccountId> {
         let api = self.client.runtime_api();
@@ -89,6 +109,42 @@ where
         api.get_all_project_data(at, project_id)
             .map_err(runtime_error_into_rpc_err)
     }
+    fn project_disputes(&self, project_id: u32) -> RpcResult<Vec<(u32, Vec<u8>)>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        api.get_project_disputes(at, project_id)
+            .map_err(runtime_error_into_rpc_err)
+    }
+    fn project_info(
+        &self,
+        project_id: u32,
+    ) -> RpcResult<Option<ProjectInfo<AccountId, Balance, u32>>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        api.get_project_info(at, project_id)
+            .map_err(runtime_error_into_rpc_err)
+    }
+    fn projects_by_initiator(
+        &self,
+        initiator: AccountId,
+        offset: u32,
+        limit: u32,
+    ) -> RpcResult<Vec<ProjectInfo<AccountId, Balance, u32>>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        api.get_projects_by_initiator(at, initiator, offset, limit)
+            .map_err(runtime_error_into_rpc_err)
+    }
+    fn projects_in_voting(&self, offset: u32, limit: u32) -> RpcResult<Vec<(u32, Vec<u32>)>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        api.get_projects_in_voting(at, offset, limit)
+            .map_err(runtime_error_into_rpc_err)
+    }
 }
 
 /// Converts a runtime trap into an RPC error.
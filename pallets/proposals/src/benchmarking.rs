@@ -15,8 +15,8 @@ use pallet_disputes::traits::DisputeHooks;
 use pallet_disputes::DisputeResult;
 
 use test_utils::{
-    assert_last_event, create_and_fund_project, create_funded_user, get_contributions,
-    get_max_milestones, get_milestones,
+    assert_last_event, create_and_fund_project, create_funded_user,
+    create_project_awaiting_funding, get_contributions, get_max_milestones, get_milestones,
 };
 
 #[benchmarks]
@@ -43,7 +43,7 @@ mod benchmarks {
         .unwrap();
 
         #[extrinsic_call]
-        submit_milestone(RawOrigin::Signed(bob), project_key, 0);
+        submit_milestone(RawOrigin::Signed(bob), project_key, 0, None);
         assert_last_event::<T>(Event::<T>::VotingRoundCreated(project_key).into());
     }
 
@@ -70,7 +70,8 @@ mod benchmarks {
         assert_ok!(Proposals::<T>::submit_milestone(
             RawOrigin::Signed(alice).into(),
             project_key,
-            0
+            0,
+            None
         ));
 
         #[extrinsic_call]
@@ -107,7 +108,8 @@ mod benchmarks {
             assert_ok!(Proposals::<T>::submit_milestone(
                 RawOrigin::Signed(alice.clone()).into(),
                 project_key,
-                milestone_key
+                milestone_key,
+                None
             ));
 
             // Contributors vote on the milestone
@@ -130,11 +132,33 @@ mod benchmarks {
     // Benchmark for a single loop of on_initialise as a voting round (most expensive).
     #[benchmark]
     fn on_initialize() {
+        let alice: T::AccountId =
+            create_funded_user::<T>("initiator", 1, 1_000_000_000_000_000_000u128);
+        let bob: T::AccountId =
+            create_funded_user::<T>("contributor", 1, 1_000_000_000_000_000_000u128);
+        let jury = get_funded_jury::<T>(10);
+        let contributions = get_contributions::<T>(vec![bob], 100_000_000_000_000_000u128);
+        let project_key = create_and_fund_project::<T>(
+            alice.clone(),
+            contributions,
+            get_max_milestones::<T>(),
+            CurrencyId::Native,
+            jury,
+        )
+        .unwrap();
+        // The expired vote is decided by the voting expiry policy of the project.
+        assert_ok!(Proposals::<T>::submit_milestone(
+            RawOrigin::Signed(alice).into(),
+            project_key,
+            0,
+            None
+        ));
+
         let block_number = 100u32.into();
         let keys: BoundedVec<
             (ProjectKey, RoundType, MilestoneKey),
             <T as Config>::ExpiringProjectRoundsPerBlock,
-        > = vec![(0, RoundType::VotingRound, 0)]
+        > = vec![(project_key, RoundType::VotingRound, 0)]
             .try_into()
             .expect("bound will be larger than 1;");
 
@@ -220,10 +244,19 @@ mod benchmarks {
             project_key,
             milestone_keys.clone()
         ));
-        let _ = <crate::Pallet<T> as DisputeHooks<ProjectKey, MilestoneKey>>::on_dispute_complete(
-            project_key,
-            milestone_keys.into_inner(),
-            DisputeResult::Success,
+        let dispute_id = MilestoneDisputes::<T>::get(project_key, milestone_keys[0])
+            .expect("dispute was just raised");
+        let _ = <crate::Pallet<T> as DisputeHooks<
+            DisputeId,
+            MilestoneKey,
+            DepositIdOf<T>,
+        >>::on_dispute_complete(
+            dispute_id,
+            milestone_keys
+                .iter()
+                .map(|milestone_key| (*milestone_key, DisputeResult::Success))
+                .collect(),
+            None,
         );
 
         #[extrinsic_call]
@@ -237,6 +270,322 @@ mod benchmarks {
         );
     }
 
+    #[benchmark]
+    fn check_project_funding() {
+        let contribution_amount = 1_000_000_000_000u128;
+        let alice: T::AccountId =
+            create_funded_user::<T>("initiator", 1, 1_000_000_000_000_000_000u128);
+        let bob: T::AccountId =
+            create_funded_user::<T>("contributor", 1, 1_000_000_000_000_000_000u128);
+        let jury = get_funded_jury::<T>(10);
+
+        let contributions = get_contributions::<T>(vec![bob.clone()], contribution_amount);
+        let prop_milestones = get_max_milestones::<T>();
+        let project_key = create_project_awaiting_funding::<T>(
+            alice,
+            contributions,
+            prop_milestones,
+            CurrencyId::Native,
+            MultiLocation::here(),
+            jury,
+        )
+        .unwrap();
+
+        let project_account = Proposals::<T>::project_account_id(project_key);
+        assert_ok!(<T as Config>::MultiCurrency::deposit(
+            CurrencyId::Native,
+            &project_account,
+            contribution_amount.saturated_into()
+        ));
+        // The deposit hook may have already picked up the funds, reset so we hit the worst case.
+        Projects::<T>::mutate(project_key, |maybe_project| {
+            if let Some(project) = maybe_project {
+                project.funding_state = FundingState::AwaitingFunding;
+            }
+        });
+        ProjectsAwaitingFunding::<T>::insert(&project_account, project_key);
+
+        #[extrinsic_call]
+        check_project_funding(RawOrigin::Signed(bob), project_key);
+        assert_last_event::<T>(
+            Event::<T>::ProjectFunded {
+                project_key,
+                amount: contribution_amount.saturated_into(),
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn raise_vote_of_no_confidence() {
+        let alice: T::AccountId =
+            create_funded_user::<T>("initiator", 1, 1_000_000_000_000_000_000u128);
+        let bob: T::AccountId =
+            create_funded_user::<T>("contributor", 1, 1_000_000_000_000_000_000u128);
+        let charlie: T::AccountId =
+            create_funded_user::<T>("contributor", 2, 1_000_000_000_000_000_000u128);
+        let jury = get_funded_jury::<T>(10);
+
+        let mut contributions = get_contributions::<T>(vec![bob.clone()], 1_000_000_000_000u128);
+        assert_ok!(contributions
+            .try_insert(
+                charlie,
+                Contribution {
+                    value: 100_000_000_000_000u128.saturated_into(),
+                    timestamp: frame_system::Pallet::<T>::block_number(),
+                },
+            )
+            .map(|_| ()));
+        let prop_milestones = get_max_milestones::<T>();
+        let project_key = create_and_fund_project::<T>(
+            alice,
+            contributions,
+            prop_milestones,
+            CurrencyId::Native,
+            jury,
+        )
+        .unwrap();
+
+        #[extrinsic_call]
+        raise_vote_of_no_confidence(RawOrigin::Signed(bob.clone()), project_key);
+        assert_last_event::<T>(
+            Event::<T>::NoConfidenceRoundCreated {
+                who: bob,
+                project_key,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn vote_on_no_confidence_round() {
+        let alice: T::AccountId =
+            create_funded_user::<T>("initiator", 1, 1_000_000_000_000_000_000u128);
+        let bob: T::AccountId =
+            create_funded_user::<T>("contributor", 1, 1_000_000_000_000_000_000u128);
+        let charlie: T::AccountId =
+            create_funded_user::<T>("contributor", 2, 1_000_000_000_000_000_000u128);
+        let jury = get_funded_jury::<T>(10);
+
+        let mut contributions = get_contributions::<T>(vec![bob.clone()], 1_000_000_000_000u128);
+        assert_ok!(contributions
+            .try_insert(
+                charlie.clone(),
+                Contribution {
+                    value: 100_000_000_000_000u128.saturated_into(),
+                    timestamp: frame_system::Pallet::<T>::block_number(),
+                },
+            )
+            .map(|_| ()));
+        let prop_milestones = get_max_milestones::<T>();
+        let project_key = create_and_fund_project::<T>(
+            alice,
+            contributions,
+            prop_milestones,
+            CurrencyId::Native,
+            jury,
+        )
+        .unwrap();
+        assert_ok!(Proposals::<T>::raise_vote_of_no_confidence(
+            RawOrigin::Signed(bob).into(),
+            project_key
+        ));
+
+        // The worst case is when the vote finalises the round.
+        #[extrinsic_call]
+        vote_on_no_confidence_round(RawOrigin::Signed(charlie), project_key, false);
+        assert_last_event::<T>(Event::<T>::NoConfidenceRoundFinalised { project_key }.into());
+    }
+
+    #[benchmark]
+    fn arbitrate_dispute() {
+        let alice: T::AccountId =
+            create_funded_user::<T>("initiator", 1, 1_000_000_000_000_000_000u128);
+        let bob: T::AccountId =
+            create_funded_user::<T>("contributor", 0, 1_000_000_000_000_000_000u128);
+        // A single jury member sends the dispute to arbitration.
+        let jury = get_funded_jury::<T>(1);
+
+        let contributors: Vec<T::AccountId> = (0
+            ..<T as Config>::MaximumContributorsPerProject::get())
+            .map(|i| create_funded_user::<T>("contributor", i, 1_000_000_000_000_000_000u128))
+            .collect();
+
+        let contributions = get_contributions::<T>(contributors, 1_000_000_000_000u128);
+        let milestone_count = <T as Config>::MaxMilestonesPerProject::get();
+        let prop_milestones = get_milestones(milestone_count as u8);
+        let milestone_keys: BoundedVec<u32, <T as Config>::MaxMilestonesPerProject> = (0u32
+            ..prop_milestones.len() as u32)
+            .collect::<Vec<u32>>()
+            .try_into()
+            .unwrap();
+
+        let project_key = create_and_fund_project::<T>(
+            alice,
+            contributions,
+            prop_milestones,
+            CurrencyId::Native,
+            jury,
+        )
+        .unwrap();
+
+        assert_ok!(Proposals::<T>::raise_dispute(
+            RawOrigin::Signed(bob).into(),
+            project_key,
+            milestone_keys.clone()
+        ));
+        let dispute_id = MilestoneDisputes::<T>::get(project_key, milestone_keys[0])
+            .expect("dispute was just raised");
+        let verdicts: BoundedVec<(MilestoneKey, DisputeResult), T::MaxMilestonesPerProject> =
+            milestone_keys
+                .iter()
+                .map(|milestone_key| (*milestone_key, DisputeResult::Success))
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap();
+
+        #[extrinsic_call]
+        arbitrate_dispute(RawOrigin::Root, dispute_id, verdicts);
+        assert!(!Arbitrations::<T>::contains_key(dispute_id));
+    }
+
+    // Worst case is every other juror having proposed a refund share.
+    #[benchmark]
+    fn propose_refund_share() {
+        let alice: T::AccountId =
+            create_funded_user::<T>("initiator", 1, 1_000_000_000_000_000_000u128);
+        let bob: T::AccountId =
+            create_funded_user::<T>("contributor", 0, 1_000_000_000_000_000_000u128);
+        let jury = get_funded_jury::<T>(MaxJuryOf::<T>::get());
+        let contributions = get_contributions::<T>(vec![bob.clone()], 1_000_000_000_000u128);
+        let project_key = create_and_fund_project::<T>(
+            alice,
+            contributions,
+            get_max_milestones::<T>(),
+            CurrencyId::Native,
+            jury.clone(),
+        )
+        .unwrap();
+        assert_ok!(Proposals::<T>::raise_dispute(
+            RawOrigin::Signed(bob).into(),
+            project_key,
+            vec![0u32].try_into().unwrap()
+        ));
+        let (juror, other_jurors) = jury.split_last().expect("jury is not empty");
+        for other_juror in other_jurors {
+            assert_ok!(Proposals::<T>::propose_refund_share(
+                RawOrigin::Signed(other_juror.clone()).into(),
+                project_key,
+                0,
+                Percent::from_percent(50)
+            ));
+        }
+
+        #[extrinsic_call]
+        propose_refund_share(
+            RawOrigin::Signed(juror.clone()),
+            project_key,
+            0,
+            Percent::from_percent(50),
+        );
+        assert_last_event::<T>(
+            Event::<T>::RefundShareProposed {
+                who: juror.clone(),
+                project_key,
+                milestone_key: 0,
+                refund_share: Percent::from_percent(50),
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn delegate_vote() {
+        let alice: T::AccountId =
+            create_funded_user::<T>("initiator", 1, 1_000_000_000_000_000_000u128);
+        let bob: T::AccountId =
+            create_funded_user::<T>("contributor", 0, 1_000_000_000_000_000_000u128);
+        let charlie: T::AccountId =
+            create_funded_user::<T>("delegate", 0, 1_000_000_000_000_000_000u128);
+        let jury = get_funded_jury::<T>(10);
+        let contributions = get_contributions::<T>(vec![bob.clone()], 1_000_000_000_000u128);
+        let project_key = create_and_fund_project::<T>(
+            alice,
+            contributions,
+            get_max_milestones::<T>(),
+            CurrencyId::Native,
+            jury,
+        )
+        .unwrap();
+        let scope = DelegationScope::Project(project_key);
+
+        #[extrinsic_call]
+        delegate_vote(RawOrigin::Signed(bob.clone()), scope, charlie.clone());
+        assert_last_event::<T>(
+            Event::<T>::VoteDelegated {
+                who: bob,
+                delegate: charlie,
+                scope,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn revoke_delegation() {
+        let bob: T::AccountId =
+            create_funded_user::<T>("contributor", 0, 1_000_000_000_000_000_000u128);
+        let charlie: T::AccountId =
+            create_funded_user::<T>("delegate", 0, 1_000_000_000_000_000_000u128);
+        let scope = DelegationScope::AllProjects;
+        assert_ok!(Proposals::<T>::delegate_vote(
+            RawOrigin::Signed(bob.clone()).into(),
+            scope,
+            charlie
+        ));
+
+        #[extrinsic_call]
+        revoke_delegation(RawOrigin::Signed(bob.clone()), scope);
+        assert_last_event::<T>(Event::<T>::DelegationRevoked { who: bob, scope }.into());
+    }
+
+    #[benchmark]
+    fn set_project_policies() {
+        let alice: T::AccountId =
+            create_funded_user::<T>("initiator", 1, 1_000_000_000_000_000_000u128);
+        let bob: T::AccountId =
+            create_funded_user::<T>("contributor", 0, 1_000_000_000_000_000_000u128);
+        let contributions = get_contributions::<T>(vec![bob], 1_000_000_000_000u128);
+        let project_key = create_and_fund_project::<T>(
+            alice,
+            contributions,
+            get_max_milestones::<T>(),
+            CurrencyId::Native,
+            get_funded_jury::<T>(1),
+        )
+        .unwrap();
+        let resubmission_policy = ResubmissionPolicy {
+            cooldown: 10u32.into(),
+            max_attempts: 1,
+        };
+
+        #[extrinsic_call]
+        set_project_policies(
+            RawOrigin::Root,
+            project_key,
+            resubmission_policy.clone(),
+            VotingExpiryPolicy::OptimisticApproval,
+        );
+        assert_last_event::<T>(
+            Event::<T>::ProjectPoliciesSet {
+                project_key,
+                resubmission_policy,
+                voting_expiry_policy: VotingExpiryPolicy::OptimisticApproval,
+            }
+            .into(),
+        );
+    }
+
     impl_benchmark_test_suite!(
         Proposals,
         crate::mock::build_test_externality(),
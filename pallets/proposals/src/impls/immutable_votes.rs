@@ -11,6 +11,7 @@ impl<T: Config> ImmutableIndividualVotes<T> {
             BoundedBTreeMap<AccountIdOf<T>, bool, T::MaximumContributorsPerProject>,
             T::MaxMilestonesPerProject,
         > = BoundedBTreeMap::new();
+        let mut delegated_votes: DelegatedVotes<T> = BoundedBTreeMap::new();
 
         for milestone_key in milestone_keys.iter() {
             let inner_votes: BoundedBTreeMap<
@@ -22,9 +23,17 @@ impl<T: Config> ImmutableIndividualVotes<T> {
             outer_votes
                 .try_insert(milestone_key.to_owned(), inner_votes)
                 .expect("milestone_keys and outer_votes have been bound by the same binding; qed");
+            delegated_votes
+                .try_insert(milestone_key.to_owned(), BoundedBTreeMap::new())
+                .expect(
+                    "milestone_keys and delegated_votes have been bound by the same binding; qed",
+                );
         }
 
-        Self { votes: outer_votes }
+        Self {
+            votes: outer_votes,
+            delegated_votes,
+        }
     }
 
     /// Insert the vote from an individual on a milestone.
@@ -49,6 +58,38 @@ impl<T: Config> ImmutableIndividualVotes<T> {
         Ok(())
     }
 
+    /// Insert the vote a delegate has cast on behalf of a delegator on a milestone.
+    /// The vote counts as the delegator's own so it is just as immutable.
+    pub(crate) fn insert_delegated_vote(
+        &mut self,
+        milestone_key: MilestoneKey,
+        delegator: &AccountIdOf<T>,
+        delegate: &AccountIdOf<T>,
+        vote: bool,
+    ) -> Result<(), DispatchError> {
+        self.insert_individual_vote(milestone_key, delegator, vote)?;
+        if let Some(delegated) = self.delegated_votes.get_mut(&milestone_key) {
+            delegated
+                .try_insert(delegator.clone(), delegate.clone())
+                .map_err(|_| Error::<T>::TooManyContributions)?;
+        } else {
+            return Err(Error::<T>::IndividualVoteNotFound.into());
+        }
+
+        Ok(())
+    }
+
+    /// Whether an individual has voted on a milestone, either themselves or through a delegate.
+    pub(crate) fn has_voted(
+        &self,
+        milestone_key: MilestoneKey,
+        account_id: &AccountIdOf<T>,
+    ) -> bool {
+        self.votes
+            .get(&milestone_key)
+            .map_or(false, |votes| votes.contains_key(account_id))
+    }
+
     /// Clear the votes for a given milestone.
     /// Used when a milestone is submitted.
     /// Skips if the milestone is not found.
@@ -56,6 +97,14 @@ impl<T: Config> ImmutableIndividualVotes<T> {
         if let Some(btree) = self.votes.get_mut(&milestone_key) {
             *btree = Default::default()
         }
+        if let Some(btree) = self.delegated_votes.get_mut(&milestone_key) {
+            *btree = Default::default()
+        }
+    }
+
+    /// The delegates that have voted on behalf of others, keyed by milestone and then delegator.
+    pub(crate) fn delegated_votes(&self) -> &DelegatedVotes<T> {
+        &self.delegated_votes
     }
 
     /// Take a mutable reference to the inner individual votes item.
@@ -1,5 +1,7 @@
 pub mod immutable_votes;
 pub mod pallet_impls;
+pub mod runtime_api;
 
 pub use immutable_votes::*;
 pub use pallet_impls::*;
+pub use runtime_api::*;
@@ -1,5 +1,9 @@
 use crate::*;
-use pallet_disputes::{traits::DisputeHooks, DisputeResult};
+use pallet_disputes::{
+    traits::{DisputeHooks, DisputeParties},
+    DisputeResult,
+};
+use pallet_fellowship::traits::{FellowshipHooks, SelectJury};
 use scale_info::prelude::format;
 use sp_runtime::traits::{Saturating, Zero};
 
@@ -12,23 +16,89 @@ impl<T: Config> Pallet<T> {
         T::PalletId::get().into_sub_account_truncating(format!("//{key}"))
     }
 
+    /// The funds of a milestone that are refunded once it can be refunded.
+    pub fn milestone_refund_amount(
+        milestone: &Milestone<BlockNumberFor<T>>,
+        raised_funds: BalanceOf<T>,
+    ) -> BalanceOf<T> {
+        milestone
+            .refund_share
+            .mul_floor(milestone.percentage_to_unlock.mul_floor(raised_funds))
+    }
+
+    /// The funds of a partly refunded milestone that the initiator can withdraw.
+    /// Zero unless the milestone can be refunded.
+    pub fn milestone_split_amount(
+        milestone: &Milestone<BlockNumberFor<T>>,
+        raised_funds: BalanceOf<T>,
+    ) -> BalanceOf<T> {
+        if !milestone.can_refund {
+            return Zero::zero();
+        }
+        milestone
+            .percentage_to_unlock
+            .mul_floor(raised_funds)
+            .saturating_sub(Self::milestone_refund_amount(milestone, raised_funds))
+    }
+
+    /// The median of the refund shares proposed by the jury, the lower of the two middle shares
+    /// is used for an even number of proposals. Without proposals the milestone is refunded in full.
+    pub(crate) fn median_refund_share(mut proposals: Vec<Percent>) -> Percent {
+        proposals.sort();
+        proposals
+            .get(proposals.len().saturating_sub(1) / 2)
+            .copied()
+            .unwrap_or(Percent::from_percent(100))
+    }
+
     // Take a project and submit an associated milestone.
     pub(crate) fn new_milestone_submission(
         who: T::AccountId,
         project_key: ProjectKey,
         milestone_key: MilestoneKey,
+        evidence_hash: Option<ContentHash>,
     ) -> DispatchResultWithPostInfo {
         let project = Projects::<T>::get(project_key).ok_or(Error::<T>::ProjectDoesNotExist)?;
 
         ensure!(project.initiator == who, Error::<T>::UserIsNotInitiator);
+        ensure!(!project.cancelled, Error::<T>::ProjectWithdrawn);
+        ensure!(
+            project.funding_state.is_funded(),
+            Error::<T>::ProjectNotFunded
+        );
         let milestone = project
             .milestones
             .get(&milestone_key)
             .ok_or(Error::<T>::MilestoneDoesNotExist)?;
         ensure!(!milestone.is_approved, Error::<T>::MilestoneAlreadyApproved);
+        ensure!(
+            !Rounds::<T>::contains_key((project_key, milestone_key), RoundType::VotingRound),
+            Error::<T>::MilestoneAlreadyInVoting
+        );
+        ensure!(
+            !MilestoneDisputes::<T>::contains_key(project_key, milestone_key),
+            Error::<T>::MilestonesAlreadyInDispute
+        );
+
+        let now = frame_system::Pallet::<T>::block_number();
+        let policy = &project.resubmission_policy;
+        ensure!(
+            (milestone.attempts.len() as u32) < policy.attempts_allowed(),
+            Error::<T>::NoAttemptsRemaining
+        );
+        if let Some(rejected_on) = milestone
+            .attempts
+            .last()
+            .and_then(|attempt| attempt.rejected_on)
+        {
+            ensure!(
+                now >= rejected_on.saturating_add(policy.cooldown),
+                Error::<T>::ResubmissionCooldown
+            );
+        }
+        ensure!(!milestone.can_refund, Error::<T>::MilestoneIsRefundable);
 
-        let expiry_block =
-            <T as Config>::MilestoneVotingWindow::get() + frame_system::Pallet::<T>::block_number();
+        let expiry_block = <T as Config>::MilestoneVotingWindow::get() + now;
         Rounds::<T>::insert(
             (project_key, milestone_key),
             RoundType::VotingRound,
@@ -59,6 +129,20 @@ impl<T: Config> Pallet<T> {
 
         ProjectInVoting::<T>::insert(project_key, milestone_key, ());
 
+        // Keep the evidence of the latest submission for the voters.
+        Projects::<T>::mutate(project_key, |maybe_project| {
+            if let Some(project) = maybe_project {
+                if let Some(milestone) = project.milestones.get_mut(&milestone_key) {
+                    milestone.evidence_hash = evidence_hash;
+                    // The attempts have been checked against the bound above.
+                    let _ = milestone.attempts.try_push(MilestoneAttempt {
+                        submitted_on: now,
+                        rejected_on: None,
+                    });
+                }
+            }
+        });
+
         Self::deposit_event(Event::MilestoneSubmitted(who, project_key, milestone_key));
         Self::deposit_event(Event::VotingRoundCreated(project_key));
         Ok(().into())
@@ -76,20 +160,45 @@ impl<T: Config> Pallet<T> {
             Error::<T>::VotingRoundNotStarted
         );
 
-        let contribution_amount = project
-            .contributions
-            .get(&who)
-            .ok_or(Error::<T>::OnlyContributorsCanVote)?
-            .value;
+        let own_contribution = project.contributions.get(&who).map(|c| c.value);
+        let delegators = Self::vote_delegators(project_key, &project, &who);
+        ensure!(
+            own_contribution.is_some() || !delegators.is_empty(),
+            Error::<T>::OnlyContributorsCanVote
+        );
         let now = frame_system::Pallet::<T>::block_number();
         let user_has_voted_key = (project_key, RoundType::VotingRound, milestone_key);
 
-        IndividualVoteStore::<T>::try_mutate(project_key, |maybe_individual_votes| {
-            if let Some(individual_votes) = maybe_individual_votes {
-                individual_votes.insert_individual_vote(milestone_key, &who, approve_milestone)?;
-            }
-            Ok::<(), DispatchError>(())
-        })?;
+        let contribution_amount =
+            IndividualVoteStore::<T>::try_mutate(project_key, |maybe_individual_votes| {
+                let mut contribution_amount = own_contribution.unwrap_or_else(Zero::zero);
+                if let Some(individual_votes) = maybe_individual_votes {
+                    if own_contribution.is_some() {
+                        individual_votes.insert_individual_vote(
+                            milestone_key,
+                            &who,
+                            approve_milestone,
+                        )?;
+                    }
+                    // Delegators that have already voted keep their own vote.
+                    for (delegator, value) in delegators.iter() {
+                        if !individual_votes.has_voted(milestone_key, delegator) {
+                            individual_votes.insert_delegated_vote(
+                                milestone_key,
+                                delegator,
+                                &who,
+                                approve_milestone,
+                            )?;
+                            contribution_amount = contribution_amount.saturating_add(*value);
+                        }
+                    }
+                    ensure!(
+                        !contribution_amount.is_zero(),
+                        Error::<T>::VotesAreImmutable
+                    );
+                }
+                Ok::<BalanceOf<T>, DispatchError>(contribution_amount)
+            })?;
 
         let funding_threshold: BalanceOf<T> =
             T::PercentRequiredForVoteToPass::get().mul_floor(project.raised_funds);
@@ -128,6 +237,27 @@ impl<T: Config> Pallet<T> {
         Ok(().into())
     }
 
+    /// The contributors of a project that have delegated their milestone votes to the delegate, with their contribution.
+    /// A delegation for the project takes precedence over one for all projects.
+    pub(crate) fn vote_delegators(
+        project_key: ProjectKey,
+        project: &Project<T>,
+        delegate: &AccountIdOf<T>,
+    ) -> Vec<(AccountIdOf<T>, BalanceOf<T>)> {
+        project
+            .contributions
+            .iter()
+            .filter(|(contributor, _)| {
+                VoteDelegations::<T>::get(contributor, DelegationScope::Project(project_key))
+                    .or_else(|| {
+                        VoteDelegations::<T>::get(contributor, DelegationScope::AllProjects)
+                    })
+                    .map_or(false, |d| &d == delegate)
+            })
+            .map(|(contributor, contribution)| (contributor.clone(), contribution.value))
+            .collect()
+    }
+
     pub(crate) fn new_withdrawal(
         who: T::AccountId,
         project_key: ProjectKey,
@@ -136,9 +266,16 @@ impl<T: Config> Pallet<T> {
 
         ensure!(!project.cancelled, Error::<T>::ProjectWithdrawn);
         ensure!(who == project.initiator, Error::<T>::UserIsNotInitiator);
+        ensure!(
+            project.funding_state.is_funded(),
+            Error::<T>::ProjectNotFunded
+        );
 
         let withdrawable = Projects::<T>::try_mutate_exists(project_key, |maybe_project| {
             if let Some(project) = maybe_project {
+                let raised_funds = project.raised_funds;
+                // The initiator's share of the milestones the jury only partly refunded.
+                let mut split_withdrawable: BalanceOf<T> = Zero::zero();
                 let withdrawable_percent: Percent = project
                     .milestones
                     .iter_mut()
@@ -149,17 +286,26 @@ impl<T: Config> Pallet<T> {
                             });
                             ms.percentage_to_unlock
                         } else {
+                            let split_amount = Self::milestone_split_amount(ms, raised_funds);
+                            if ms.split_withdrawn_on.is_none() && !split_amount.is_zero() {
+                                ms.split_withdrawn_on =
+                                    Some(frame_system::Pallet::<T>::block_number());
+                                split_withdrawable =
+                                    split_withdrawable.saturating_add(split_amount);
+                            }
                             <Percent as Zero>::zero()
                         }
                     })
                     .fold(<Percent as Zero>::zero(), |acc, item| acc + item);
 
                 ensure!(
-                    withdrawable_percent != Zero::zero(),
+                    withdrawable_percent != Zero::zero() || split_withdrawable != Zero::zero(),
                     Error::<T>::NoAvailableFundsToWithdraw
                 );
 
-                let withdrawable = withdrawable_percent.mul_floor(project.raised_funds);
+                let withdrawable = withdrawable_percent
+                    .mul_floor(project.raised_funds)
+                    .saturating_add(split_withdrawable);
                 let fee = <T as Config>::ImbueFee::get().mul_floor(withdrawable);
                 let initiator_payment = withdrawable.saturating_sub(fee);
                 let project_account = Self::project_account_id(project_key);
@@ -197,6 +343,7 @@ impl<T: Config> Pallet<T> {
                             Ok(())
                         },
                     )?;
+                    Self::remove_jury_projects(project_key, &project.jury);
                     *maybe_project = None;
                 }
                 Ok::<BalanceOf<T>, DispatchError>(withdrawable)
@@ -244,20 +391,58 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Compare the balance of the project account with the raised funds and update the funding state.
+    /// Once the project account holds the raised funds the project is marked as funded
+    /// and is no longer tracked in ProjectsAwaitingFunding.
+    /// Returns Ok(true) if the project is now funded.
+    pub(crate) fn try_update_funding_state(project_key: ProjectKey) -> Result<bool, DispatchError> {
+        Projects::<T>::try_mutate(project_key, |maybe_project| {
+            let project = maybe_project
+                .as_mut()
+                .ok_or(Error::<T>::ProjectDoesNotExist)?;
+            ensure!(
+                !project.funding_state.is_funded(),
+                Error::<T>::ProjectAlreadyFunded
+            );
+
+            let project_account = Self::project_account_id(project_key);
+            let received = T::MultiCurrency::free_balance(project.currency_id, &project_account);
+
+            if received >= project.raised_funds {
+                project.funding_state = FundingState::Funded;
+                ProjectsAwaitingFunding::<T>::remove(&project_account);
+                Self::deposit_event(Event::<T>::ProjectFunded {
+                    project_key,
+                    amount: received,
+                });
+                return Ok(true);
+            }
+
+            if received != Zero::zero() {
+                let new_state = FundingState::PartiallyFunded { received };
+                if project.funding_state != new_state {
+                    project.funding_state = new_state;
+                    Self::deposit_event(Event::<T>::ProjectFundingReceived {
+                        project_key,
+                        received,
+                        required: project.raised_funds,
+                    });
+                }
+            }
+            Ok(false)
+        })
+    }
+
     /// Try and convert some proposed milestones to milestones.
     /// Will never fail so long as proposed_milestones and BoundedBTreeMilestones<T> have the same bound.
     pub(crate) fn try_convert_to_milestones(
-        proposed_milestones: BoundedVec<ProposedMilestone, T::MaxMilestonesPerProject>,
+        proposed_milestones: BoundedProposedMilestones<T>,
         project_key: ProjectKey,
     ) -> Result<BoundedBTreeMilestones<T>, DispatchError> {
         let mut milestone_key: u32 = 0;
         let mut milestones: BoundedBTreeMilestones<T> = BoundedBTreeMap::new();
         for proposed_milestone in proposed_milestones {
-            let milestone = Milestone::new(
-                project_key,
-                milestone_key,
-                proposed_milestone.percentage_to_unlock,
-            );
+            let milestone = Milestone::new(project_key, milestone_key, proposed_milestone);
 
             milestones
                 .try_insert(milestone_key, milestone)
@@ -303,10 +488,112 @@ impl<T: Config> Pallet<T> {
                 user_has_voted_key.0,
                 user_has_voted_key.2,
             ));
+            Self::reject_milestone_attempt(project_key, user_has_voted_key.2);
         }
         Ok(())
     }
 
+    /// Record the rejection of the latest attempt at a milestone.
+    /// Once the last attempt allowed by the resubmission policy is rejected the milestone can be refunded.
+    pub(crate) fn reject_milestone_attempt(project_key: ProjectKey, milestone_key: MilestoneKey) {
+        let now = frame_system::Pallet::<T>::block_number();
+        let exhausted = Projects::<T>::mutate(project_key, |maybe_project| {
+            let mut exhausted = false;
+            if let Some(project) = maybe_project {
+                let attempts_allowed = project.resubmission_policy.attempts_allowed();
+                if let Some(milestone) = project.milestones.get_mut(&milestone_key) {
+                    if let Some(attempt) = milestone.attempts.last_mut() {
+                        attempt.rejected_on = Some(now);
+                    }
+                    if milestone.attempts.len() as u32 >= attempts_allowed
+                        && milestone.transfer_status.is_none()
+                    {
+                        milestone.can_refund = true;
+                        exhausted = true;
+                    }
+                }
+            }
+            exhausted
+        });
+
+        if exhausted {
+            Self::deposit_event(Event::MilestoneAttemptsExhausted {
+                project_key,
+                milestone_key,
+            });
+        }
+    }
+
+    /// Decide a milestone vote that was not finalised before its voting round expired.
+    /// The voting expiry policy of the project approves or rejects the milestone using the tally so far.
+    pub(crate) fn expire_voting_round(
+        project_key: ProjectKey,
+        milestone_key: MilestoneKey,
+    ) -> Weight {
+        let mut weight = T::DbWeight::get().reads_writes(4, 3);
+        let maybe_vote = MilestoneVotes::<T>::mutate(project_key, |vote_btree| {
+            vote_btree.remove(&milestone_key)
+        });
+
+        IndividualVoteStore::<T>::mutate(project_key, |m_votes| {
+            if let Some(individual_votes) = m_votes {
+                individual_votes.clear_milestone_votes(milestone_key);
+            }
+        });
+
+        ProjectInVoting::<T>::remove(project_key, milestone_key);
+
+        let (vote, project) = match (maybe_vote, Projects::<T>::get(project_key)) {
+            (Some(vote), Some(project)) => (vote, project),
+            _ => return weight,
+        };
+        let is_pending = project
+            .milestones
+            .get(&milestone_key)
+            .map(|ms| !ms.is_approved)
+            .unwrap_or(false);
+        if project.cancelled || !is_pending {
+            return weight;
+        }
+
+        let threshold = T::PercentRequiredForVoteToPass::get();
+        let approved = match project.voting_expiry_policy {
+            VotingExpiryPolicy::OptimisticApproval => {
+                vote.nay < threshold.mul_floor(project.raised_funds)
+            }
+            VotingExpiryPolicy::TurnoutRelative { min_turnout } => {
+                let turnout = vote.yay.saturating_add(vote.nay);
+                !vote.yay.is_zero()
+                    && turnout >= min_turnout.mul_floor(project.raised_funds)
+                    && vote.yay >= threshold.mul_floor(turnout)
+            }
+            VotingExpiryPolicy::Reject => false,
+        };
+
+        Self::deposit_event(Event::<T>::MilestoneVotingExpired {
+            project_key,
+            milestone_key,
+            yay: vote.yay,
+            nay: vote.nay,
+            approved,
+        });
+
+        if approved {
+            Projects::<T>::mutate(project_key, |maybe_project| {
+                if let Some(p) = maybe_project {
+                    if let Some(ms) = p.milestones.get_mut(&milestone_key) {
+                        ms.is_approved = true
+                    }
+                }
+            });
+        } else {
+            Self::reject_milestone_attempt(project_key, milestone_key);
+        }
+        weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+        weight
+    }
+
     pub(crate) fn close_voting_round(
         _project_key: ProjectKey,
         user_has_voted_key: (ProjectKey, RoundType, MilestoneKey),
@@ -315,9 +602,10 @@ impl<T: Config> Pallet<T> {
         // Prevent further voting.
         let exp_block = Rounds::<T>::take((project_key, milestone_key), RoundType::VotingRound)
             .ok_or(Error::<T>::VotingRoundNotStarted)?;
-        // Prevent hook from calling.
-        // TODO: only remove project key????
-        RoundsExpiring::<T>::remove(exp_block);
+        // Prevent the hook from expiring the round, other rounds expiring on this block are kept.
+        RoundsExpiring::<T>::mutate(exp_block, |keys| {
+            keys.retain(|k| *k != (project_key, RoundType::VotingRound, milestone_key))
+        });
 
         MilestoneVotes::<T>::mutate(project_key, |btree_votes| {
             let _val = btree_votes.remove(&milestone_key);
@@ -335,44 +623,551 @@ impl<T: Config> Pallet<T> {
         ProjectInVoting::<T>::remove(project_key, milestone_key);
         Ok(())
     }
+
+    /// Open a vote of no confidence on a project, the raiser's contribution counts against the project.
+    /// A vote of no confidence is against the whole project so the milestone key is always 0.
+    pub(crate) fn raise_no_confidence_round(
+        who: T::AccountId,
+        project_key: ProjectKey,
+    ) -> DispatchResult {
+        let project = Projects::<T>::get(project_key).ok_or(Error::<T>::ProjectDoesNotExist)?;
+        ensure!(!project.cancelled, Error::<T>::ProjectWithdrawn);
+        ensure!(
+            project.funding_state.is_funded(),
+            Error::<T>::ProjectNotFunded
+        );
+        let contribution = project
+            .contributions
+            .get(&who)
+            .ok_or(Error::<T>::OnlyContributorsCanVote)?;
+        ensure!(
+            !NoConfidenceVotes::<T>::contains_key(project_key),
+            Error::<T>::NoConfidenceRoundAlreadyExists
+        );
+        ensure!(
+            MilestoneDisputes::<T>::iter_prefix(project_key)
+                .next()
+                .is_none(),
+            Error::<T>::ProjectInDispute
+        );
+
+        let vote = Vote {
+            yay: Zero::zero(),
+            nay: contribution.value,
+            is_approved: false,
+        };
+
+        let expiry_block = frame_system::Pallet::<T>::block_number()
+            .saturating_add(<T as Config>::NoConfidenceVotingWindow::get());
+        Rounds::<T>::insert(
+            (project_key, 0),
+            RoundType::VoteOfNoConfidence,
+            expiry_block,
+        );
+        RoundsExpiring::<T>::try_mutate(expiry_block, |keys| {
+            keys.try_push((project_key, RoundType::VoteOfNoConfidence, 0))
+                .map_err(|_| Error::<T>::Overflow)?;
+            Ok::<(), DispatchError>(())
+        })?;
+        UserHasVoted::<T>::try_mutate((project_key, RoundType::VoteOfNoConfidence, 0), |votes| {
+            votes
+                .try_insert(who.clone(), false)
+                .map_err(|_| Error::<T>::TooManyContributions)?;
+            Ok::<(), DispatchError>(())
+        })?;
+        NoConfidenceVotes::<T>::insert(project_key, vote.clone());
+
+        Self::deposit_event(Event::NoConfidenceRoundCreated { who, project_key });
+
+        Self::try_auto_finalise_no_confidence_round(project_key, &vote, project.raised_funds)
+    }
+
+    /// Add a vote to a vote of no confidence, weighted by the contribution of the voter.
+    pub(crate) fn add_vote_no_confidence(
+        who: T::AccountId,
+        project_key: ProjectKey,
+        is_yay: bool,
+    ) -> DispatchResult {
+        ensure!(
+            Rounds::<T>::contains_key((project_key, 0), RoundType::VoteOfNoConfidence),
+            Error::<T>::NoConfidenceRoundNotStarted
+        );
+        let project = Projects::<T>::get(project_key).ok_or(Error::<T>::ProjectDoesNotExist)?;
+        let contribution = project
+            .contributions
+            .get(&who)
+            .ok_or(Error::<T>::OnlyContributorsCanVote)?;
+
+        UserHasVoted::<T>::try_mutate((project_key, RoundType::VoteOfNoConfidence, 0), |votes| {
+            ensure!(!votes.contains_key(&who), Error::<T>::VotesAreImmutable);
+            votes
+                .try_insert(who.clone(), is_yay)
+                .map_err(|_| Error::<T>::TooManyContributions)?;
+            Ok::<(), DispatchError>(())
+        })?;
+
+        let vote = NoConfidenceVotes::<T>::try_mutate(project_key, |maybe_vote| {
+            if let Some(vote) = maybe_vote {
+                if is_yay {
+                    vote.yay = vote.yay.saturating_add(contribution.value);
+                } else {
+                    vote.nay = vote.nay.saturating_add(contribution.value);
+                }
+                Ok::<Vote<BalanceOf<T>>, DispatchError>(vote.clone())
+            } else {
+                Err(Error::<T>::NoConfidenceRoundNotStarted.into())
+            }
+        })?;
+
+        Self::deposit_event(Event::NoConfidenceRoundVotedUpon {
+            who,
+            project_key,
+            is_yay,
+        });
+
+        Self::try_auto_finalise_no_confidence_round(project_key, &vote, project.raised_funds)
+    }
+
+    /// Finalise the vote of no confidence if the votes against the project have met the threshold.
+    pub(crate) fn try_auto_finalise_no_confidence_round(
+        project_key: ProjectKey,
+        vote: &Vote<BalanceOf<T>>,
+        raised_funds: BalanceOf<T>,
+    ) -> DispatchResult {
+        let threshold_votes: BalanceOf<T> =
+            T::PercentRequiredForVoteNoConfidenceToPass::get().mul_floor(raised_funds);
+        if vote.nay >= threshold_votes {
+            Self::close_no_confidence_round(project_key)?;
+        }
+        Ok(())
+    }
+
+    /// Generate the id of a new dispute on the milestones of a project and index it.
+    pub(crate) fn register_dispute(
+        project_key: ProjectKey,
+        milestone_keys: &[MilestoneKey],
+    ) -> DisputeId {
+        let dispute_id = DisputeCount::<T>::get().saturating_add(1);
+        DisputeCount::<T>::put(dispute_id);
+        DisputeProjects::<T>::insert(dispute_id, project_key);
+        for milestone_key in milestone_keys.iter() {
+            MilestoneDisputes::<T>::insert(project_key, milestone_key, dispute_id);
+        }
+        dispute_id
+    }
+
+    /// Get the ids of the open disputes on a project.
+    pub fn project_disputes(project_key: ProjectKey) -> Vec<DisputeId> {
+        let mut dispute_ids =
+            MilestoneDisputes::<T>::iter_prefix_values(project_key).collect::<Vec<DisputeId>>();
+        dispute_ids.sort();
+        dispute_ids.dedup();
+        dispute_ids
+    }
+
+    /// Send a dispute to the ArbitrationOrigin, it expires if it is not decided within the ArbitrationWindow.
+    /// The expiry is tracked as a round on the first milestone in dispute.
+    pub(crate) fn open_arbitration(
+        dispute_id: DisputeId,
+        project_key: ProjectKey,
+        milestone_keys: BoundedVec<MilestoneKey, T::MaxMilestonesPerProject>,
+        bond: DepositIdOf<T>,
+    ) -> DispatchResult {
+        let first_milestone_key = *milestone_keys.first().ok_or(Error::<T>::InvalidParam)?;
+        let expiry_block = <T as Config>::ArbitrationWindow::get()
+            .saturating_add(frame_system::Pallet::<T>::block_number());
+        Rounds::<T>::insert(
+            (project_key, first_milestone_key),
+            RoundType::Arbitration,
+            expiry_block,
+        );
+        RoundsExpiring::<T>::try_mutate(expiry_block, |keys| {
+            keys.try_push((project_key, RoundType::Arbitration, first_milestone_key))
+                .map_err(|_| Error::<T>::Overflow)?;
+            Ok::<(), DispatchError>(())
+        })?;
+        Arbitrations::<T>::insert(
+            dispute_id,
+            Arbitration {
+                project_key,
+                milestone_keys,
+                bond,
+                expires: expiry_block,
+            },
+        );
+
+        Self::deposit_event(Event::<T>::DisputeSentToArbitration {
+            dispute_id,
+            project_key,
+        });
+        Ok(())
+    }
+
+    /// Hand the verdicts of the ArbitrationOrigin to the DisputeHooks as if the jury had decided them.
+    pub(crate) fn decide_arbitration(
+        dispute_id: DisputeId,
+        verdicts: BoundedVec<(MilestoneKey, DisputeResult), T::MaxMilestonesPerProject>,
+    ) -> DispatchResult {
+        let arbitration =
+            Arbitrations::<T>::get(dispute_id).ok_or(Error::<T>::ArbitrationNotFound)?;
+        ensure!(
+            verdicts.len() == arbitration.milestone_keys.len()
+                && arbitration.milestone_keys.iter().all(|milestone_key| {
+                    verdicts
+                        .iter()
+                        .any(|(decided_key, _)| decided_key == milestone_key)
+                }),
+            Error::<T>::InvalidArbitrationVerdicts
+        );
+
+        Arbitrations::<T>::remove(dispute_id);
+        if let Some(first_milestone_key) = arbitration.milestone_keys.first() {
+            let key = (arbitration.project_key, first_milestone_key);
+            if let Some(exp_block) = Rounds::<T>::take(key, RoundType::Arbitration) {
+                RoundsExpiring::<T>::mutate(exp_block, |keys| {
+                    keys.retain(|(p_key, round_type, m_key)| {
+                        !(*p_key == arbitration.project_key
+                            && *round_type == RoundType::Arbitration
+                            && m_key == first_milestone_key)
+                    })
+                });
+            }
+        }
+
+        Self::deposit_event(Event::<T>::ArbitrationDecided { dispute_id });
+        // The hook is weighed in arbitrate_dispute and reports a bond it cannot settle itself.
+        <Self as DisputeHooks<DisputeId, MilestoneKey, DepositIdOf<T>>>::on_dispute_complete(
+            dispute_id,
+            verdicts.into_inner(),
+            Some(arbitration.bond),
+        );
+        Ok(())
+    }
+
+    /// The ArbitrationOrigin did not decide in time, the milestones are released without a refund
+    /// and the bond is returned as the raiser is not at fault.
+    pub(crate) fn expire_arbitration(dispute_id: DisputeId) -> Weight {
+        let mut weight = T::DbWeight::get().reads(1);
+        if let Some(arbitration) = Arbitrations::<T>::take(dispute_id) {
+            if let Err(error) = <T as Config>::DepositHandler::return_deposit(arbitration.bond) {
+                Self::deposit_event(Event::<T>::DisputeBondFailed { dispute_id, error });
+            }
+            DisputeProjects::<T>::remove(dispute_id);
+            for milestone_key in arbitration.milestone_keys.iter() {
+                MilestoneDisputes::<T>::remove(arbitration.project_key, milestone_key);
+                RefundShareProposals::<T>::remove(arbitration.project_key, milestone_key);
+            }
+            Self::deposit_event(Event::<T>::ArbitrationExpired { dispute_id });
+            weight = weight.saturating_add(
+                T::DbWeight::get().reads_writes(
+                    1,
+                    (arbitration.milestone_keys.len() as u64)
+                        .saturating_mul(2)
+                        .saturating_add(3),
+                ),
+            );
+        }
+        weight
+    }
+
+    /// Swap a juror that has left the fellowship for a freshly selected one on the jury of a project.
+    /// Returns the replacement, if no one can be selected the juror is only removed.
+    /// Nothing is selected if the juror does not sit on the jury of the project.
+    pub(crate) fn replace_project_juror(
+        project_key: ProjectKey,
+        juror: &AccountIdOf<T>,
+    ) -> Option<AccountIdOf<T>> {
+        Projects::<T>::mutate(project_key, |maybe_project| {
+            let project = maybe_project.as_mut()?;
+            let index = project.jury.iter().position(|member| member == juror)?;
+
+            // The initiator, contributors and the current jury cannot be the replacement.
+            let mut excluded = project
+                .contributions
+                .keys()
+                .cloned()
+                .collect::<Vec<AccountIdOf<T>>>();
+            excluded.push(project.initiator.clone());
+            excluded.extend(project.jury.iter().cloned());
+            let mut replacement =
+                <T::JurySelector as SelectJury<AccountIdOf<T>>>::select_jury_excluding(
+                    1, &excluded,
+                )
+                .into_inner()
+                .pop();
+
+            Self::remove_juror_project(juror, project_key);
+            // A fellow already on MaxJuriesPerAccount juries cannot be the replacement.
+            if let Some(new_juror) = replacement.as_ref() {
+                if JurorProjects::<T>::try_mutate(new_juror, |project_keys| {
+                    project_keys.try_push(project_key)
+                })
+                .is_err()
+                {
+                    replacement = None;
+                }
+            }
+            match replacement.as_ref() {
+                Some(new_juror) => project.jury[index] = new_juror.clone(),
+                None => {
+                    project.jury.remove(index);
+                }
+            }
+            replacement
+        })
+    }
+
+    /// The weight of replace_project_juror, excluding the selection of the replacement.
+    pub(crate) fn replace_project_juror_weight() -> Weight {
+        // Projects::mutate
+        // JurorProjects::mutate for the juror and the replacement
+        // Selecting the replacement excludes the contributors, the initiator and the jury
+        T::DbWeight::get()
+            .reads_writes(3, 3)
+            .saturating_add(
+                <T::JurySelector as SelectJury<AccountIdOf<T>>>::select_jury_weight(
+                    <T as Config>::MaximumContributorsPerProject::get()
+                        .saturating_add(MaxJuryOf::<T>::get())
+                        .saturating_add(1),
+                ),
+            )
+    }
+
+    /// Stop tracking that `juror` sits on the jury of a project.
+    pub(crate) fn remove_juror_project(juror: &AccountIdOf<T>, project_key: ProjectKey) {
+        JurorProjects::<T>::mutate_exists(juror, |maybe_project_keys| {
+            if let Some(project_keys) = maybe_project_keys {
+                project_keys.retain(|key| *key != project_key);
+                if project_keys.is_empty() {
+                    *maybe_project_keys = None;
+                }
+            }
+        });
+    }
+
+    /// Stop tracking the jury of a project once it has been completed.
+    pub(crate) fn remove_jury_projects(project_key: ProjectKey, jury: &[AccountIdOf<T>]) {
+        for juror in jury.iter() {
+            Self::remove_juror_project(juror, project_key);
+        }
+    }
+
+    /// The vote of no confidence has passed, cancel the project and allow all milestones
+    /// that have not been withdrawn to be refunded via the refund extrinsic.
+    pub(crate) fn close_no_confidence_round(project_key: ProjectKey) -> DispatchResult {
+        let exp_block = Rounds::<T>::take((project_key, 0), RoundType::VoteOfNoConfidence)
+            .ok_or(Error::<T>::NoConfidenceRoundNotStarted)?;
+        // Prevent the hook from expiring the round.
+        RoundsExpiring::<T>::mutate(exp_block, |keys| {
+            keys.retain(|(p_key, round_type, _)| {
+                !(*p_key == project_key && *round_type == RoundType::VoteOfNoConfidence)
+            })
+        });
+        NoConfidenceVotes::<T>::remove(project_key);
+        UserHasVoted::<T>::remove((project_key, RoundType::VoteOfNoConfidence, 0));
+
+        Projects::<T>::try_mutate(project_key, |maybe_project| {
+            let project = maybe_project
+                .as_mut()
+                .ok_or(Error::<T>::ProjectDoesNotExist)?;
+            project.cancelled = true;
+            for (_ms_key, ms) in project.milestones.iter_mut() {
+                if ms.transfer_status.is_none() {
+                    ms.can_refund = true;
+                }
+            }
+            Ok::<(), DispatchError>(())
+        })?;
+
+        Self::deposit_event(Event::NoConfidenceRoundFinalised { project_key });
+        Ok(())
+    }
 }
 
-impl<T: Config> DisputeHooks<ProjectKey, MilestoneKey> for Pallet<T> {
+/// The initiator of a project defends the disputes raised on it.
+impl<T: Config> DisputeParties<AccountIdOf<T>, DisputeId> for Pallet<T> {
+    fn defendant(dispute_id: DisputeId) -> Option<AccountIdOf<T>> {
+        DisputeProjects::<T>::get(dispute_id)
+            .and_then(Projects::<T>::get)
+            .map(|project| project.initiator)
+    }
+}
+
+impl<T: Config> DisputeHooks<DisputeId, MilestoneKey, DepositIdOf<T>> for Pallet<T> {
     fn on_dispute_complete(
-        project_key: ProjectKey,
-        specifics: Vec<MilestoneKey>,
-        dispute_result: pallet_disputes::pallet::DisputeResult,
+        dispute_id: DisputeId,
+        verdicts: Vec<(MilestoneKey, pallet_disputes::pallet::DisputeResult)>,
+        bond: Option<DepositIdOf<T>>,
     ) -> Weight {
-        ProjectsInDispute::<T>::remove(project_key);
-        Projects::<T>::mutate(project_key, |maybe_project| {
-            match maybe_project {
-                Some(project) => {
-                    match dispute_result {
-                        DisputeResult::Success => {
-                            for milestone_key in specifics.iter() {
-                                if let Some(milestone) = project.milestones.get_mut(milestone_key) {
-                                    // Shouldnt be needed but nice to have this check.
-                                    // Will prevent someone calling both refund and withdraw on the same milestone.
-                                    if milestone.transfer_status.is_none() {
-                                        milestone.can_refund = true;
+        // The bond was reserved when the dispute was raised.
+        // The raiser gets it back if any milestone was upheld, otherwise it is sent to the slash account.
+        if let Some(bond) = bond {
+            let upheld = verdicts
+                .iter()
+                .any(|(_, result)| *result == DisputeResult::Success);
+            let settled = if upheld {
+                <T as Config>::DepositHandler::return_deposit(bond)
+            } else {
+                <T as Config>::DepositHandler::slash_reserve_deposit(bond)
+            };
+            // The hook cannot fail, so a bond that cannot be settled is reported instead.
+            if let Err(error) = settled {
+                Self::deposit_event(Event::<T>::DisputeBondFailed { dispute_id, error });
+            }
+        }
+        if let Some(project_key) = DisputeProjects::<T>::take(dispute_id) {
+            let mut refund_share_proposals = BTreeMap::new();
+            for (milestone_key, _) in verdicts.iter() {
+                MilestoneDisputes::<T>::remove(project_key, milestone_key);
+                refund_share_proposals.insert(
+                    *milestone_key,
+                    RefundShareProposals::<T>::take(project_key, milestone_key),
+                );
+            }
+            Projects::<T>::mutate(project_key, |maybe_project| {
+                match maybe_project {
+                    Some(project) => {
+                        for (milestone_key, result) in verdicts.iter() {
+                            match result {
+                                DisputeResult::Success => {
+                                    // Only proposals from jurors still on the jury are counted.
+                                    let refund_share = Self::median_refund_share(
+                                        refund_share_proposals
+                                            .remove(milestone_key)
+                                            .unwrap_or_default()
+                                            .into_iter()
+                                            .filter(|(juror, _)| project.jury.contains(juror))
+                                            .map(|(_, refund_share)| refund_share)
+                                            .collect(),
+                                    );
+                                    if let Some(milestone) =
+                                        project.milestones.get_mut(milestone_key)
+                                    {
+                                        // Shouldnt be needed but nice to have this check.
+                                        // Will prevent someone calling both refund and withdraw on the same milestone.
+                                        if milestone.transfer_status.is_none() {
+                                            milestone.can_refund = true;
+                                            milestone.refund_share = refund_share;
+                                        }
                                     }
                                 }
-                            }
-                        }
-                        DisputeResult::Failure => {
-                            // I Guess do nothing.. ProjectsInDispute gets cleared regardless allowing further disputes.
+                                DisputeResult::Failure => {
+                                    // I Guess do nothing.. MilestoneDisputes gets cleared regardless allowing further disputes.
+                                }
+                            };
                         }
-                    };
+                    }
+                    // Looks like the project was deleted somehow during the dispute.
+                    // The only way this is possible is through a refund or final withdraw.
+                    // Not a massive issue as either way the project has been finalised.
+                    // Just ignore and return weight.
+                    None => {}
                 }
-                // Looks like the project was deleted somehow during the dispute.
-                // The only way this is possible is through a refund or final withdraw.
-                // Not a massive issue as either way the project has been finalised.
-                // Just ignore and return weight.
-                None => {}
-            }
-        });
-        // ProjectsInDispute::remove
+            });
+        }
+        // DisputeProjects::take
+        // MilestoneDisputes::remove per milestone
+        // RefundShareProposals::take per milestone
         // Projects::mutate
-        T::DbWeight::get().reads_writes(2, 2)
+        // DepositHandler::return_deposit / slash_reserve_deposit
+        T::DbWeight::get().reads_writes(
+            4u64.saturating_add(verdicts.len() as u64),
+            4u64.saturating_add(verdicts.len().saturating_mul(2) as u64),
+        )
+    }
+
+    fn on_dispute_cancel(
+        dispute_id: DisputeId,
+        milestone_keys: Vec<MilestoneKey>,
+        bond: Option<DepositIdOf<T>>,
+    ) -> Result<Weight, DispatchError> {
+        // The raiser withdrew the dispute before the jury decided it so the bond is returned.
+        if let Some(bond) = bond {
+            <T as Config>::DepositHandler::return_deposit(bond)?;
+        }
+        // The milestones can be disputed again.
+        if let Some(project_key) = DisputeProjects::<T>::take(dispute_id) {
+            for milestone_key in milestone_keys.iter() {
+                MilestoneDisputes::<T>::remove(project_key, milestone_key);
+                RefundShareProposals::<T>::remove(project_key, milestone_key);
+            }
+        }
+        Ok(Self::on_dispute_cancel_weight(milestone_keys.len() as u32))
+    }
+
+    fn on_dispute_cancel_weight(milestones: u32) -> Weight {
+        T::DbWeight::get().reads_writes(3, 3u64.saturating_add(2 * milestones as u64))
+    }
+}
+
+/// Jurors cannot leave the fellowship while they sit on an open dispute.
+/// Once they are removed they are replaced on the jury of each of their projects, and on each open dispute,
+/// by a freshly selected juror. If no one can be selected the jury shrinks instead.
+impl<T: Config> FellowshipHooks<AccountIdOf<T>> for Pallet<T> {
+    fn can_leave_fellowship(who: &AccountIdOf<T>) -> Result<(), DispatchError> {
+        ensure!(
+            <T as Config>::DisputeRaiser::disputes_of_juror(who).is_empty(),
+            Error::<T>::JurorInOpenDispute
+        );
+        Ok(())
+    }
+
+    fn on_fellowship_revoked(who: &AccountIdOf<T>) -> Weight {
+        let project_keys = JurorProjects::<T>::get(who);
+        let dispute_ids = <T as Config>::DisputeRaiser::disputes_of_juror(who);
+        let mut weight = T::DbWeight::get().reads(1u64.saturating_add(dispute_ids.len() as u64));
+        // A project gets a single replacement, it is used on each of its open disputes as well.
+        let mut replacements: BTreeMap<ProjectKey, Option<AccountIdOf<T>>> = BTreeMap::new();
+        for project_key in project_keys {
+            weight = weight.saturating_add(Self::replace_project_juror_weight());
+            let replacement = Self::replace_project_juror(project_key, who);
+            Self::deposit_event(Event::<T>::ProjectJurorReplaced {
+                project_key,
+                juror: who.clone(),
+                replacement: replacement.clone(),
+            });
+            replacements.insert(project_key, replacement);
+        }
+        for dispute_id in dispute_ids {
+            // DisputeProjects::get
+            // DisputeRaiser::replace_juror
+            weight = weight.saturating_add(T::DbWeight::get().reads_writes(5, 5));
+            if let Some(project_key) = DisputeProjects::<T>::get(dispute_id) {
+                let replacement = replacements
+                    .entry(project_key)
+                    .or_insert_with(|| {
+                        weight = weight.saturating_add(Self::replace_project_juror_weight());
+                        Self::replace_project_juror(project_key, who)
+                    })
+                    .clone();
+                if <T as Config>::DisputeRaiser::replace_juror(dispute_id, who, replacement.clone())
+                    .is_ok()
+                {
+                    Self::deposit_event(Event::<T>::JurorReplaced {
+                        project_key,
+                        dispute_id,
+                        juror: who.clone(),
+                        replacement,
+                    });
+                }
+            }
+        }
+        weight
+    }
+
+    fn max_hooks_weight() -> Weight {
+        let max_projects = <T as Config>::MaxJuriesPerAccount::get() as u64;
+        let max_disputes =
+            <T::DisputeRaiser as DisputeRaiser<AccountIdOf<T>>>::MaxDisputesPerJuror::get() as u64;
+        // Each dispute can be on a project the juror is no longer tracked on.
+        T::DbWeight::get()
+            .reads(2u64.saturating_add(max_disputes))
+            .saturating_add(Self::replace_project_juror_weight().saturating_mul(max_projects))
+            .saturating_add(
+                T::DbWeight::get()
+                    .reads_writes(5, 5)
+                    .saturating_add(Self::replace_project_juror_weight())
+                    .saturating_mul(max_disputes),
+            )
     }
 }
@@ -0,0 +1,191 @@
+use crate::*;
+use pallet_proposals_rpc_runtime_api::{
+    ContributionInfo, FundingStatus, MilestoneInfo, MilestoneStatus, ProjectInfo,
+};
+use sp_runtime::traits::{Saturating, Zero};
+
+/// The maximum number of items returned by a single paginated runtime api call.
+pub const MAX_PROJECTS_PER_PAGE: u32 = 50;
+
+pub type ProjectInfoOf<T> = ProjectInfo<AccountIdOf<T>, BalanceOf<T>, BlockNumberFor<T>>;
+
+impl<T: Config> Pallet<T> {
+    /// Get a decoded view of a project, used by the runtime api.
+    pub fn project_info(project_key: ProjectKey) -> Option<ProjectInfoOf<T>> {
+        Projects::<T>::get(project_key).map(|project| Self::to_project_info(project_key, project))
+    }
+
+    /// Get a page of the projects created by an initiator, ordered by project key.
+    /// The limit is capped at MAX_PROJECTS_PER_PAGE.
+    pub fn projects_by_initiator(
+        initiator: &AccountIdOf<T>,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<ProjectInfoOf<T>> {
+        let mut project_keys =
+            InitiatorProjects::<T>::iter_key_prefix(initiator).collect::<Vec<ProjectKey>>();
+        project_keys.sort();
+
+        project_keys
+            .into_iter()
+            .skip(offset as usize)
+            .take(limit.min(MAX_PROJECTS_PER_PAGE) as usize)
+            .filter_map(Self::project_info)
+            .collect()
+    }
+
+    /// Get a page of the projects that have milestones in a voting round, ordered by project key.
+    /// The limit is capped at MAX_PROJECTS_PER_PAGE.
+    pub fn projects_in_voting(offset: u32, limit: u32) -> Vec<(ProjectKey, Vec<MilestoneKey>)> {
+        let mut in_voting: BTreeMap<ProjectKey, Vec<MilestoneKey>> = BTreeMap::new();
+        ProjectInVoting::<T>::iter_keys().for_each(|(project_key, milestone_key)| {
+            in_voting
+                .entry(project_key)
+                .or_default()
+                .push(milestone_key)
+        });
+
+        in_voting
+            .into_iter()
+            .skip(offset as usize)
+            .take(limit.min(MAX_PROJECTS_PER_PAGE) as usize)
+            .map(|(project_key, mut milestone_keys)| {
+                milestone_keys.sort();
+                (project_key, milestone_keys)
+            })
+            .collect()
+    }
+
+    /// The amount the initiator can currently withdraw from a project, before fees.
+    /// Includes the initiator's share of the milestones that were only partly refunded.
+    pub fn withdrawable_funds(project: &Project<T>) -> BalanceOf<T> {
+        let split_funds = project
+            .milestones
+            .values()
+            .filter(|ms| ms.split_withdrawn_on.is_none())
+            .fold(Zero::zero(), |acc: BalanceOf<T>, ms| {
+                acc.saturating_add(Self::milestone_split_amount(ms, project.raised_funds))
+            });
+        Self::unlocked_funds(project, |ms| ms.is_approved).saturating_add(split_funds)
+    }
+
+    /// The amount that can currently be refunded from a project, before fees.
+    pub fn refundable_funds(project: &Project<T>) -> BalanceOf<T> {
+        project
+            .milestones
+            .values()
+            .filter(|ms| ms.can_refund && ms.transfer_status.is_none())
+            .fold(Zero::zero(), |acc: BalanceOf<T>, ms| {
+                acc.saturating_add(Self::milestone_refund_amount(ms, project.raised_funds))
+            })
+    }
+
+    /// Sum the funds of the untransferred milestones matching the filter.
+    fn unlocked_funds(
+        project: &Project<T>,
+        filter: impl Fn(&Milestone<BlockNumberFor<T>>) -> bool,
+    ) -> BalanceOf<T> {
+        let percent = project
+            .milestones
+            .values()
+            .filter(|ms| ms.transfer_status.is_none() && filter(ms))
+            .fold(<Percent as Zero>::zero(), |acc, ms| {
+                acc.saturating_add(ms.percentage_to_unlock)
+            });
+        percent.mul_floor(project.raised_funds)
+    }
+
+    fn to_project_info(project_key: ProjectKey, project: Project<T>) -> ProjectInfoOf<T> {
+        let milestone_votes = MilestoneVotes::<T>::get(project_key);
+        let individual_votes = IndividualVoteStore::<T>::get(project_key);
+        let withdrawable_funds = Self::withdrawable_funds(&project);
+        let refundable_funds = Self::refundable_funds(&project);
+
+        let milestones = project
+            .milestones
+            .into_iter()
+            .map(|(milestone_key, ms)| {
+                let status = match ms.transfer_status {
+                    Some(TransferStatus::Withdrawn { on }) => MilestoneStatus::Withdrawn { on },
+                    Some(TransferStatus::Refunded { on }) => MilestoneStatus::Refunded { on },
+                    None if ms.can_refund => MilestoneStatus::Refundable,
+                    None if ms.is_approved => MilestoneStatus::Approved,
+                    None if MilestoneDisputes::<T>::contains_key(project_key, milestone_key) => {
+                        MilestoneStatus::InDispute
+                    }
+                    None if ProjectInVoting::<T>::contains_key(project_key, milestone_key) => {
+                        MilestoneStatus::InVoting
+                    }
+                    None => MilestoneStatus::Pending,
+                };
+                let vote = milestone_votes
+                    .get(&milestone_key)
+                    .cloned()
+                    .unwrap_or_default();
+                let votes = individual_votes
+                    .as_ref()
+                    .and_then(|i| i.votes.get(&milestone_key))
+                    .map(|v| v.iter().map(|(who, vote)| (who.clone(), *vote)).collect())
+                    .unwrap_or_default();
+                let delegated_votes = individual_votes
+                    .as_ref()
+                    .and_then(|i| i.delegated_votes().get(&milestone_key))
+                    .map(|v| {
+                        v.iter()
+                            .map(|(delegator, delegate)| (delegator.clone(), delegate.clone()))
+                            .collect()
+                    })
+                    .unwrap_or_default();
+
+                MilestoneInfo {
+                    milestone_key,
+                    percentage_to_unlock: ms.percentage_to_unlock.deconstruct(),
+                    status,
+                    content_hash: ms.content_hash.into_inner(),
+                    due_block: ms.due_block,
+                    evidence_hash: ms.evidence_hash.map(|h| h.into_inner()),
+                    yay: vote.yay,
+                    nay: vote.nay,
+                    votes,
+                    delegated_votes,
+                }
+            })
+            .collect();
+
+        let contributions = project
+            .contributions
+            .into_iter()
+            .map(|(who, c)| ContributionInfo {
+                who,
+                value: c.value,
+                timestamp: c.timestamp,
+            })
+            .collect();
+
+        let funding_state = match project.funding_state {
+            FundingState::AwaitingFunding => FundingStatus::AwaitingFunding,
+            FundingState::PartiallyFunded { received } => {
+                FundingStatus::PartiallyFunded { received }
+            }
+            FundingState::Funded => FundingStatus::Funded,
+        };
+
+        ProjectInfo {
+            project_key,
+            initiator: project.initiator,
+            agreement_hash: project.agreement_hash,
+            currency_id: project.currency_id,
+            raised_funds: project.raised_funds,
+            withdrawn_funds: project.withdrawn_funds,
+            refunded_funds: project.refunded_funds,
+            withdrawable_funds,
+            refundable_funds,
+            funding_state,
+            created_on: project.created_on,
+            cancelled: project.cancelled,
+            milestones,
+            contributions,
+            jury: project.jury.into_inner(),
+        }
+    }
+}
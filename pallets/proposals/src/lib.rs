@@ -43,11 +43,18 @@ pub mod impls;
 pub use impls::*;
 pub type ProjectKey = u32;
 pub type MilestoneKey = u32;
+pub type DisputeId = u32;
 pub type IndividualVotes<T> = BoundedBTreeMap<
     MilestoneKey,
     BoundedBTreeMap<AccountIdOf<T>, bool, <T as Config>::MaximumContributorsPerProject>,
     <T as Config>::MaxMilestonesPerProject,
 >;
+/// The delegate that voted on behalf of each delegator, per milestone.
+pub type DelegatedVotes<T> = BoundedBTreeMap<
+    MilestoneKey,
+    BoundedBTreeMap<AccountIdOf<T>, AccountIdOf<T>, <T as Config>::MaximumContributorsPerProject>,
+    <T as Config>::MaxMilestonesPerProject,
+>;
 
 pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 pub type BalanceOf<T> = <<T as Config>::MultiCurrency as MultiCurrency<AccountIdOf<T>>>::Balance;
@@ -66,8 +73,17 @@ type BoundedBTreeMilestones<T> = BoundedBTreeMap<
     <T as Config>::MaxMilestonesPerProject,
 >;
 pub type BoundedProposedMilestones<T> =
-    BoundedVec<ProposedMilestone, <T as Config>::MaxMilestonesPerProject>;
+    BoundedVec<ProposedMilestone<BlockNumberFor<T>>, <T as Config>::MaxMilestonesPerProject>;
 pub type AgreementHash = H256;
+/// The maximum length of a content hash, large enough to hold an ipfs cid.
+pub const MAX_CONTENT_HASH_LEN: u32 = 64;
+/// A hash linking on-chain data to its off-chain content.
+pub type ContentHash = BoundedVec<u8, ConstU32<MAX_CONTENT_HASH_LEN>>;
+/// The most times a milestone can be submitted, caps the max_attempts of a ResubmissionPolicy.
+pub const MAX_MILESTONE_ATTEMPTS: u32 = 10;
+/// The submissions of a milestone, the latest last.
+pub type MilestoneAttempts<BlockNumber> =
+    BoundedVec<MilestoneAttempt<BlockNumber>, ConstU32<MAX_MILESTONE_ATTEMPTS>>;
 type BoundedProjectKeysPerBlock<T> =
     BoundedVec<(ProjectKey, RoundType, MilestoneKey), <T as Config>::ExpiringProjectRoundsPerBlock>;
 type ContributionsFor<T> = BoundedBTreeMap<
@@ -96,6 +112,10 @@ pub mod pallet {
         type MilestoneVotingWindow: Get<BlockNumberFor<Self>>;
         /// The minimum percentage of votes, inclusive, that is required for a vote to pass.  
         type PercentRequiredForVoteToPass: Get<Percent>;
+        /// The amount of time given, up to point of decision, when a vote of no confidence is held.
+        type NoConfidenceVotingWindow: Get<BlockNumberFor<Self>>;
+        /// The minimum percentage of votes, inclusive, that is required for a vote of no confidence to pass.
+        type PercentRequiredForVoteNoConfidenceToPass: Get<Percent>;
         /// Maximum number of contributors per project.
         type MaximumContributorsPerProject: Get<u32>;
         /// Maximum milestones allowed in a project.
@@ -118,21 +138,38 @@ pub mod pallet {
         type DepositHandler: DepositHandler<BalanceOf<Self>, AccountIdOf<Self>>;
         /// The type that will be used to calculate the deposit of a project.
         type ProjectStorageItem: Get<StorageItemOf<Self>>;
+        /// The type that will be used to calculate the bond taken when raising a dispute.
+        type DisputeStorageItem: Get<StorageItemOf<Self>>;
         /// The trait that handler the raising of a dispute.
         type DisputeRaiser: DisputeRaiser<
             AccountIdOf<Self>,
-            DisputeKey = ProjectKey,
+            DisputeKey = DisputeId,
             SpecificId = MilestoneKey,
             MaxSpecifics = Self::MaxMilestonesPerProject,
             MaxJurySize = MaxJuryOf<Self>,
+            BondId = DepositIdOf<Self>,
         >;
+        /// Disputes raised on a project with a jury smaller than this are decided by the ArbitrationOrigin.
+        type MinimumJurySize: Get<u32>;
+        /// The origin that decides the disputes of projects with too small a jury, i.e the council.
+        type ArbitrationOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// The amount of time the ArbitrationOrigin has to decide a dispute before it expires.
+        type ArbitrationWindow: Get<BlockNumberFor<Self>>;
         /// The jury selector type which is defining the max jury size.
         type JurySelector: pallet_fellowship::traits::SelectJury<AccountIdOf<Self>>;
+        /// The maximum number of live projects an account can sit on the jury of.
+        type MaxJuriesPerAccount: Get<u32>;
         /// The origin responsible for setting the address responsible for minting tokens.
         type AssetSignerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// The resubmission policy given to new projects, it limits how often a rejected milestone can be submitted again.
+        type DefaultResubmissionPolicy: Get<ResubmissionPolicy<BlockNumberFor<Self>>>;
+        /// The voting expiry policy given to new projects, it decides a milestone vote nobody finalised in time.
+        type DefaultVotingExpiryPolicy: Get<VotingExpiryPolicy>;
+        /// The origin that can replace the resubmission and voting expiry policies of a project.
+        type ProjectPolicyOrigin: EnsureOrigin<Self::RuntimeOrigin>;
     }
 
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(7);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(16);
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -184,10 +221,29 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// The live projects an account sits on the jury of, so they can be replaced if they leave the fellowship.
+    /// Key: AccountId
+    /// Value: BoundedVec<ProjectKey>
+    #[pallet::storage]
+    pub type JurorProjects<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        AccountIdOf<T>,
+        BoundedVec<ProjectKey, <T as Config>::MaxJuriesPerAccount>,
+        ValueQuery,
+    >;
+
     #[pallet::storage]
     #[pallet::getter(fn project_count)]
     pub type ProjectCount<T> = StorageValue<_, ProjectKey, ValueQuery>;
 
+    /// The running total of a vote of no confidence on a project.
+    /// Yay is for the project continuing, nay is against.
+    #[pallet::storage]
+    #[pallet::getter(fn no_confidence_votes)]
+    pub type NoConfidenceVotes<T: Config> =
+        StorageMap<_, Identity, ProjectKey, Vote<BalanceOf<T>>, OptionQuery>;
+
     /// Stores the ending block of the project key and round.
     #[pallet::storage]
     pub type Rounds<T> = StorageDoubleMap<
@@ -210,14 +266,41 @@ pub mod pallet {
         ValueQuery,
     >;
 
-    // TODO: Check if this is in use.
-    /// A helper to find what projects / milestones are in a dispute.
+    /// The id of the last dispute raised, the next dispute is given the following id.
+    #[pallet::storage]
+    pub type DisputeCount<T> = StorageValue<_, DisputeId, ValueQuery>;
+
+    /// The open dispute of each milestone, a milestone can only be in one dispute at a time.
+    #[pallet::storage]
+    pub type MilestoneDisputes<T> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ProjectKey,
+        Blake2_128Concat,
+        MilestoneKey,
+        DisputeId,
+        OptionQuery,
+    >;
+
+    /// The project each open dispute was raised on.
+    #[pallet::storage]
+    pub type DisputeProjects<T> =
+        StorageMap<_, Blake2_128Concat, DisputeId, ProjectKey, OptionQuery>;
+
+    /// Disputes waiting on the ArbitrationOrigin as the jury of the project is too small to vote.
     #[pallet::storage]
-    pub type ProjectsInDispute<T> = StorageMap<
+    pub type Arbitrations<T: Config> =
+        StorageMap<_, Blake2_128Concat, DisputeId, Arbitration<T>, OptionQuery>;
+
+    /// The share of a disputed milestone each juror proposes to refund if the dispute succeeds.
+    #[pallet::storage]
+    pub type RefundShareProposals<T: Config> = StorageDoubleMap<
         _,
         Blake2_128Concat,
         ProjectKey,
-        BoundedVec<MilestoneKey, <T as Config>::MaxMilestonesPerProject>,
+        Blake2_128Concat,
+        MilestoneKey,
+        BoundedBTreeMap<AccountIdOf<T>, Percent, MaxJuryOf<T>>,
         ValueQuery,
     >;
 
@@ -234,6 +317,38 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// The projects created by each initiator.
+    /// A helper for the runtime api so we dont have to iterate over every project.
+    #[pallet::storage]
+    pub type InitiatorProjects<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AccountIdOf<T>,
+        Blake2_128Concat,
+        ProjectKey,
+        (),
+        ValueQuery,
+    >;
+
+    /// Projects that are waiting for their funds to arrive, keyed by the project account.
+    /// Lets the treasury tooling find the projects to call check_project_funding for.
+    #[pallet::storage]
+    pub type ProjectsAwaitingFunding<T: Config> =
+        StorageMap<_, Blake2_128Concat, AccountIdOf<T>, ProjectKey, OptionQuery>;
+
+    /// The account a contributor has delegated their milestone votes to.
+    /// A delegation for a single project takes precedence over one for all projects.
+    #[pallet::storage]
+    pub type VoteDelegations<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AccountIdOf<T>,
+        Blake2_128Concat,
+        DelegationScope,
+        AccountIdOf<T>,
+        OptionQuery,
+    >;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -275,6 +390,98 @@ pub mod pallet {
         ForeignAssetSignerChanged(T::AccountId),
         /// Foreign Asset Signer Changed
         ForeignAssetMinted(T::AccountId, T::AccountId, CurrencyId, BalanceOf<T>),
+        /// Funds have arrived for a project but it is not yet fully funded.
+        ProjectFundingReceived {
+            project_key: ProjectKey,
+            received: BalanceOf<T>,
+            required: BalanceOf<T>,
+        },
+        /// A project has received all of its funds and can now submit milestones.
+        ProjectFunded {
+            project_key: ProjectKey,
+            amount: BalanceOf<T>,
+        },
+        /// A vote of no confidence has been raised against a project.
+        NoConfidenceRoundCreated {
+            who: T::AccountId,
+            project_key: ProjectKey,
+        },
+        /// A vote has been made on a vote of no confidence, is_yay is for the project continuing.
+        NoConfidenceRoundVotedUpon {
+            who: T::AccountId,
+            project_key: ProjectKey,
+            is_yay: bool,
+        },
+        /// A vote of no confidence has passed, the remaining milestones can now be refunded.
+        NoConfidenceRoundFinalised { project_key: ProjectKey },
+        /// A vote of no confidence has expired without reaching the threshold.
+        NoConfidenceRoundExpired { project_key: ProjectKey },
+        /// The jury of the project is too small so the dispute has been sent to the ArbitrationOrigin.
+        DisputeSentToArbitration {
+            dispute_id: DisputeId,
+            project_key: ProjectKey,
+        },
+        /// The ArbitrationOrigin has decided a dispute.
+        ArbitrationDecided { dispute_id: DisputeId },
+        /// The ArbitrationOrigin did not decide a dispute in time, no milestones are refunded.
+        ArbitrationExpired { dispute_id: DisputeId },
+        /// The bond of a dispute could not be returned or slashed once the dispute was settled.
+        DisputeBondFailed {
+            dispute_id: DisputeId,
+            error: DispatchError,
+        },
+        /// A juror has left the fellowship and has been replaced on an open dispute.
+        /// Without a replacement the jury has shrunk.
+        JurorReplaced {
+            project_key: ProjectKey,
+            dispute_id: DisputeId,
+            juror: AccountIdOf<T>,
+            replacement: Option<AccountIdOf<T>>,
+        },
+        /// A juror has left the fellowship and has been replaced on the jury of a project.
+        /// Without a replacement the jury has shrunk.
+        ProjectJurorReplaced {
+            project_key: ProjectKey,
+            juror: AccountIdOf<T>,
+            replacement: Option<AccountIdOf<T>>,
+        },
+        /// A juror has proposed the share of a disputed milestone to refund.
+        RefundShareProposed {
+            who: AccountIdOf<T>,
+            project_key: ProjectKey,
+            milestone_key: MilestoneKey,
+            refund_share: Percent,
+        },
+        /// The last attempt at a milestone has been rejected, it can now be refunded.
+        MilestoneAttemptsExhausted {
+            project_key: ProjectKey,
+            milestone_key: MilestoneKey,
+        },
+        /// A milestone voting round has expired and has been decided by the voting expiry policy of the project.
+        MilestoneVotingExpired {
+            project_key: ProjectKey,
+            milestone_key: MilestoneKey,
+            yay: BalanceOf<T>,
+            nay: BalanceOf<T>,
+            approved: bool,
+        },
+        /// A contributor has delegated their milestone votes.
+        VoteDelegated {
+            who: AccountIdOf<T>,
+            delegate: AccountIdOf<T>,
+            scope: DelegationScope,
+        },
+        /// A contributor has revoked a delegation of their milestone votes.
+        DelegationRevoked {
+            who: AccountIdOf<T>,
+            scope: DelegationScope,
+        },
+        /// The resubmission and voting expiry policies of a project have been replaced.
+        ProjectPoliciesSet {
+            project_key: ProjectKey,
+            resubmission_policy: ResubmissionPolicy<BlockNumberFor<T>>,
+            voting_expiry_policy: VotingExpiryPolicy,
+        },
     }
 
     // Errors inform users that something went wrong.
@@ -356,6 +563,50 @@ pub mod pallet {
         RequireForeignAssetSigner,
         /// A Jury is required to create a project.
         JuryRequired,
+        /// The project has not yet received its funding.
+        ProjectNotFunded,
+        /// The project has already received its funding.
+        ProjectAlreadyFunded,
+        /// A vote of no confidence is already in progress for this project.
+        NoConfidenceRoundAlreadyExists,
+        /// There is no vote of no confidence in progress for this project.
+        NoConfidenceRoundNotStarted,
+        /// This dispute is not awaiting arbitration.
+        ArbitrationNotFound,
+        /// The verdicts must cover exactly the milestones in dispute.
+        InvalidArbitrationVerdicts,
+        /// A juror cannot leave the fellowship while they sit on an open dispute.
+        JurorInOpenDispute,
+        /// A juror already sits on the jury of the maximum number of projects.
+        JurorOnTooManyProjects,
+        /// Only a member of the jury can propose a refund share.
+        OnlyJuryCanProposeRefundShare,
+        /// The milestone is not in dispute.
+        MilestoneNotInDispute,
+        /// A refund share must refund something, vote against the dispute instead.
+        InvalidRefundShare,
+        /// The milestone was rejected too recently to be submitted again.
+        ResubmissionCooldown,
+        /// The milestone has already been submitted the maximum number of times.
+        NoAttemptsRemaining,
+        /// You cannot delegate your votes to yourself.
+        CannotDelegateToSelf,
+        /// Only a contributor can delegate their votes on a project.
+        OnlyContributorsCanDelegate,
+        /// There is no delegation to revoke.
+        DelegationNotFound,
+        /// A vote of no confidence cannot be raised while a dispute is open on the project.
+        ProjectInDispute,
+        /// A dispute cannot be raised while a vote of no confidence is open on the project.
+        ProjectInNoConfidenceRound,
+        /// A dispute must be raised on at least one milestone.
+        NoMilestonesToDispute,
+        /// A milestone can only be given once when raising a dispute.
+        DuplicateMilestoneKeys,
+        /// The milestone is already being voted on.
+        MilestoneAlreadyInVoting,
+        /// A milestone that can be refunded cannot be submitted.
+        MilestoneIsRefundable,
     }
 
     #[pallet::hooks]
@@ -369,30 +620,42 @@ pub mod pallet {
                 let (project_key, round_type, milestone_key) = item;
                 weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
 
+                // The round has since been closed or restarted with a new expiry, leave it be.
+                if Rounds::<T>::get((project_key, milestone_key), round_type) != Some(n) {
+                    return;
+                }
                 // Remove the round prevents further voting.
                 Rounds::<T>::remove((project_key, milestone_key), round_type);
                 match round_type {
                     // Voting rounds automatically finalise if its reached its threshold.
-                    // Therefore we can remove it on round end.
+                    // Therefore if it has expired the voting expiry policy of the project decides it.
                     RoundType::VotingRound => {
+                        weight = weight.saturating_add(Self::expire_voting_round(
+                            *project_key,
+                            *milestone_key,
+                        ));
+                    }
+                    // Votes of no confidence are finalised as soon as they reach the threshold.
+                    // Therefore if it has expired then it has failed.
+                    RoundType::VoteOfNoConfidence => {
                         weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 2));
 
-                        MilestoneVotes::<T>::mutate(project_key, |vote_btree| {
-                            vote_btree.remove(milestone_key);
+                        NoConfidenceVotes::<T>::remove(project_key);
+                        UserHasVoted::<T>::remove((project_key, round_type, milestone_key));
+                        Self::deposit_event(Event::<T>::NoConfidenceRoundExpired {
+                            project_key: *project_key,
                         });
-
-                        IndividualVoteStore::<T>::mutate(project_key, |m_votes| {
-                            if let Some(individual_votes) = m_votes {
-                                individual_votes.clear_milestone_votes(*milestone_key);
-                            }
-                        });
-
-                        ProjectInVoting::<T>::remove(project_key, milestone_key);
                     }
-                    // Votes of no confidence do not finaliese automatically
-                    RoundType::VoteOfNoConfidence => {
-                        // for now keep the round in tact and let them finalise.
-                        // todo, this should be handled in pallet-dispute.
+                    // Arbitrations are removed once decided.
+                    // Therefore if it has expired then the ArbitrationOrigin did not decide in time.
+                    RoundType::Arbitration => {
+                        weight = weight.saturating_add(T::DbWeight::get().reads(1));
+
+                        if let Some(dispute_id) =
+                            MilestoneDisputes::<T>::get(project_key, milestone_key)
+                        {
+                            weight = weight.saturating_add(Self::expire_arbitration(dispute_id));
+                        }
                     }
                 }
             });
@@ -404,20 +667,26 @@ pub mod pallet {
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Submit a milestones to be voted on.
+        /// The evidence_hash should point to the off-chain proof of the delivered work.
+        /// A milestone cannot be submitted while it is being voted on, in dispute or can be refunded.
         #[pallet::call_index(8)]
         #[pallet::weight(<T as Config>::WeightInfo::submit_milestone())]
         pub fn submit_milestone(
             origin: OriginFor<T>,
             project_key: ProjectKey,
             milestone_key: MilestoneKey,
+            evidence_hash: Option<ContentHash>,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
-            Self::new_milestone_submission(who, project_key, milestone_key)
+            Self::new_milestone_submission(who, project_key, milestone_key, evidence_hash)
         }
 
         /// The contributors call this to vote on a milestone submission.
+        /// The votes of the contributors that have delegated to the caller, and have not voted themselves, are cast with it.
         #[pallet::call_index(9)]
-        #[pallet::weight(<T as Config>::WeightInfo::vote_on_milestone())]
+        #[pallet::weight(<T as Config>::WeightInfo::vote_on_milestone().saturating_add(
+            T::DbWeight::get().reads(2 * <T as Config>::MaximumContributorsPerProject::get() as u64)
+        ))]
         pub fn vote_on_milestone(
             origin: OriginFor<T>,
             project_key: ProjectKey,
@@ -428,6 +697,33 @@ pub mod pallet {
             Self::new_milestone_vote(who, project_key, milestone_key, approve_milestone)
         }
 
+        /// Raise a vote of no confidence against a project, only a contributor can do this.
+        /// The project must be funded and have no open disputes.
+        /// The caller's contribution is counted as a vote against the project.
+        #[pallet::call_index(23)]
+        #[pallet::weight(<T as Config>::WeightInfo::raise_vote_of_no_confidence())]
+        pub fn raise_vote_of_no_confidence(
+            origin: OriginFor<T>,
+            project_key: ProjectKey,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::raise_no_confidence_round(who, project_key)
+        }
+
+        /// Vote on an existing vote of no confidence, votes are weighted by contribution.
+        /// is_yay is FOR the project's continuation, so is_yay == false == against the project.
+        /// The round is finalised automatically when the threshold is met.
+        #[pallet::call_index(24)]
+        #[pallet::weight(<T as Config>::WeightInfo::vote_on_no_confidence_round())]
+        pub fn vote_on_no_confidence_round(
+            origin: OriginFor<T>,
+            project_key: ProjectKey,
+            is_yay: bool,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::add_vote_no_confidence(who, project_key, is_yay)
+        }
+
         /// Withdraw some avaliable funds from the project.
         #[pallet::call_index(11)]
         #[pallet::weight(<T as Config>::WeightInfo::withdraw())]
@@ -440,14 +736,36 @@ pub mod pallet {
         }
 
         /// Raise a dispute using the handle DisputeRaiser in the Config.
+        /// Each dispute is given a new id, milestones that are not already in dispute can be disputed in parallel.
+        /// If the jury is smaller than MinimumJurySize the dispute is decided by the ArbitrationOrigin instead.
+        /// A bond is reserved from the raiser, it is returned if the dispute succeeds
+        /// and slashed if it fails.
         #[pallet::call_index(14)]
-        #[pallet::weight(<T as Config>::WeightInfo::raise_dispute())]
+        // On top of the base weight each milestone is read and written to MilestoneDisputes,
+        // and each juror has their dispute count read and written and the dispute indexed.
+        #[pallet::weight(<T as Config>::WeightInfo::raise_dispute().saturating_add(
+            T::DbWeight::get().reads_writes(
+                (milestone_keys.len() as u64).saturating_add(MaxJuryOf::<T>::get() as u64),
+                (milestone_keys.len() as u64).saturating_add(2 * MaxJuryOf::<T>::get() as u64),
+            )
+        ))]
         pub fn raise_dispute(
             origin: OriginFor<T>,
             project_key: ProjectKey,
             milestone_keys: BoundedVec<MilestoneKey, T::MaxMilestonesPerProject>,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
+            ensure!(
+                !milestone_keys.is_empty(),
+                Error::<T>::NoMilestonesToDispute
+            );
+            ensure!(
+                milestone_keys
+                    .iter()
+                    .enumerate()
+                    .all(|(index, milestone_key)| !milestone_keys[..index].contains(milestone_key)),
+                Error::<T>::DuplicateMilestoneKeys
+            );
             let project = Projects::<T>::get(project_key).ok_or(Error::<T>::ProjectDoesNotExist)?;
             ensure!(
                 milestone_keys
@@ -460,7 +778,13 @@ pub mod pallet {
                 Error::<T>::OnlyContributorsCanRaiseDispute
             );
             ensure!(
-                !ProjectsInDispute::<T>::contains_key(project_key),
+                !NoConfidenceVotes::<T>::contains_key(project_key),
+                Error::<T>::ProjectInNoConfidenceRound
+            );
+            ensure!(
+                !milestone_keys.iter().any(|milestone_key| {
+                    MilestoneDisputes::<T>::contains_key(project_key, milestone_key)
+                }),
                 Error::<T>::MilestonesAlreadyInDispute
             );
             ensure!(
@@ -470,24 +794,30 @@ pub mod pallet {
                 Error::<T>::CannotRaiseDisputeOnApprovedMilestone
             );
 
-            if project.jury.len() == 1 {
-                // https://github.com/ImbueNetwork/imbue/issues/270
-                let _ = <Self as pallet_disputes::traits::DisputeHooks<ProjectKey, MilestoneKey>>::on_dispute_complete(project_key, milestone_keys.to_vec(), pallet_disputes::DisputeResult::Success);
+            let bond = <T as Config>::DepositHandler::take_deposit(
+                who.clone(),
+                <T as Config>::DisputeStorageItem::get(),
+                CurrencyId::Native,
+            )?;
+            let dispute_id = Self::register_dispute(project_key, &milestone_keys);
+            if (project.jury.len() as u32) < <T as Config>::MinimumJurySize::get() {
+                Self::open_arbitration(dispute_id, project_key, milestone_keys, bond)?;
             } else {
                 <T as Config>::DisputeRaiser::raise_dispute(
-                    project_key,
+                    dispute_id,
                     who,
                     project.jury,
-                    milestone_keys.clone(),
+                    milestone_keys,
+                    Some(bond),
                 )?;
-                ProjectsInDispute::<T>::insert(project_key, milestone_keys);
             }
 
             Ok(())
         }
 
         /// Attempt a refund of milestones.
-        /// Will only refund milestones that have can_refund set to true.
+        /// Will only refund milestones that have can_refund set to true,
+        /// only the refund share decided by the jury is refunded.
         #[pallet::call_index(15)]
         #[pallet::weight(<T as Config>::WeightInfo::refund())]
         pub fn refund(origin: OriginFor<T>, project_key: ProjectKey) -> DispatchResult {
@@ -505,10 +835,10 @@ pub mod pallet {
 
                     for (_ms_key, ms) in project.milestones.iter_mut() {
                         if ms.can_refund && ms.transfer_status.is_none() {
-                            let milestone_amount =
-                                ms.percentage_to_unlock.mul_floor(project.raised_funds);
+                            let refund_amount =
+                                Self::milestone_refund_amount(ms, project.raised_funds);
                             total_to_refund_including_fee =
-                                total_to_refund_including_fee.saturating_add(milestone_amount);
+                                total_to_refund_including_fee.saturating_add(refund_amount);
                             ms.transfer_status = Some(TransferStatus::Refunded {
                                 on: frame_system::Pallet::<T>::block_number(),
                             });
@@ -563,6 +893,7 @@ pub mod pallet {
                         .saturating_add(project.withdrawn_funds)
                         == project.raised_funds
                     {
+                        Self::remove_jury_projects(project_key, &project.jury);
                         *maybe_project = None;
                     }
 
@@ -623,6 +954,149 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Check the balance of a project awaiting funding and update its funding state.
+        /// Deposits are not observed as they happen, as that would put an unweighed read on every
+        /// transfer in the chain, so this must be called once funds have arrived, including via XCM.
+        #[pallet::call_index(18)]
+        #[pallet::weight(<T as Config>::WeightInfo::check_project_funding())]
+        pub fn check_project_funding(
+            origin: OriginFor<T>,
+            project_key: ProjectKey,
+        ) -> DispatchResult {
+            let _ = ensure_signed(origin)?;
+            Self::try_update_funding_state(project_key)?;
+            Ok(())
+        }
+
+        /// Decide a dispute that was sent to arbitration because the jury of the project is too small.
+        /// The verdicts must cover exactly the disputed milestones and are handled as a jury's would be.
+        /// Must be called by T::ArbitrationOrigin.
+        #[pallet::call_index(19)]
+        #[pallet::weight(<T as Config>::WeightInfo::arbitrate_dispute())]
+        pub fn arbitrate_dispute(
+            origin: OriginFor<T>,
+            dispute_id: DisputeId,
+            verdicts: BoundedVec<
+                (MilestoneKey, pallet_disputes::DisputeResult),
+                T::MaxMilestonesPerProject,
+            >,
+        ) -> DispatchResult {
+            T::ArbitrationOrigin::ensure_origin(origin)?;
+            Self::decide_arbitration(dispute_id, verdicts)
+        }
+
+        /// Propose the share of a disputed milestone that is refunded if the dispute succeeds,
+        /// the initiator can withdraw the rest. A later proposal replaces an earlier one.
+        /// The median proposal of the jury is used, without proposals the milestone is refunded in full.
+        /// Must be called by a member of the jury while the milestone is in dispute.
+        #[pallet::call_index(20)]
+        #[pallet::weight(<T as Config>::WeightInfo::propose_refund_share())]
+        pub fn propose_refund_share(
+            origin: OriginFor<T>,
+            project_key: ProjectKey,
+            milestone_key: MilestoneKey,
+            refund_share: Percent,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let project = Projects::<T>::get(project_key).ok_or(Error::<T>::ProjectDoesNotExist)?;
+            ensure!(
+                project.jury.contains(&who),
+                Error::<T>::OnlyJuryCanProposeRefundShare
+            );
+            ensure!(
+                MilestoneDisputes::<T>::contains_key(project_key, milestone_key),
+                Error::<T>::MilestoneNotInDispute
+            );
+            ensure!(!refund_share.is_zero(), Error::<T>::InvalidRefundShare);
+            RefundShareProposals::<T>::try_mutate(project_key, milestone_key, |proposals| {
+                proposals
+                    .try_insert(who.clone(), refund_share)
+                    .map_err(|_| Error::<T>::TooManyJuryMembers)
+            })?;
+
+            Self::deposit_event(Event::<T>::RefundShareProposed {
+                who,
+                project_key,
+                milestone_key,
+                refund_share,
+            });
+            Ok(())
+        }
+
+        /// Delegate your milestone votes on a project, or on all of your projects, to another account.
+        /// The delegate votes with your contribution until you vote yourself or revoke the delegation.
+        /// Delegating again for the same scope replaces the delegate.
+        #[pallet::call_index(21)]
+        #[pallet::weight(<T as Config>::WeightInfo::delegate_vote())]
+        pub fn delegate_vote(
+            origin: OriginFor<T>,
+            scope: DelegationScope,
+            delegate: AccountIdOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(who != delegate, Error::<T>::CannotDelegateToSelf);
+            if let DelegationScope::Project(project_key) = scope {
+                let project =
+                    Projects::<T>::get(project_key).ok_or(Error::<T>::ProjectDoesNotExist)?;
+                ensure!(
+                    project.contributions.contains_key(&who),
+                    Error::<T>::OnlyContributorsCanDelegate
+                );
+            }
+            VoteDelegations::<T>::insert(&who, scope, &delegate);
+
+            Self::deposit_event(Event::<T>::VoteDelegated {
+                who,
+                delegate,
+                scope,
+            });
+            Ok(())
+        }
+
+        /// Revoke a delegation of your milestone votes, votes already cast by the delegate remain.
+        #[pallet::call_index(22)]
+        #[pallet::weight(<T as Config>::WeightInfo::revoke_delegation())]
+        pub fn revoke_delegation(origin: OriginFor<T>, scope: DelegationScope) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                VoteDelegations::<T>::take(&who, scope).is_some(),
+                Error::<T>::DelegationNotFound
+            );
+
+            Self::deposit_event(Event::<T>::DelegationRevoked { who, scope });
+            Ok(())
+        }
+
+        /// Replace the resubmission and voting expiry policies a project was given when it was created.
+        /// Voting rounds that are already open are decided by the new voting expiry policy.
+        /// Must be called by T::ProjectPolicyOrigin.
+        #[pallet::call_index(23)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_project_policies())]
+        pub fn set_project_policies(
+            origin: OriginFor<T>,
+            project_key: ProjectKey,
+            resubmission_policy: ResubmissionPolicy<BlockNumberFor<T>>,
+            voting_expiry_policy: VotingExpiryPolicy,
+        ) -> DispatchResult {
+            T::ProjectPolicyOrigin::ensure_origin(origin)?;
+            Projects::<T>::try_mutate(project_key, |maybe_project| {
+                let project = maybe_project
+                    .as_mut()
+                    .ok_or(Error::<T>::ProjectDoesNotExist)?;
+                ensure!(!project.cancelled, Error::<T>::ProjectWithdrawn);
+                project.resubmission_policy = resubmission_policy.clone();
+                project.voting_expiry_policy = voting_expiry_policy;
+                Ok::<(), DispatchError>(())
+            })?;
+
+            Self::deposit_event(Event::<T>::ProjectPoliciesSet {
+                project_key,
+                resubmission_policy,
+                voting_expiry_policy,
+            });
+            Ok(())
+        }
     }
 
     impl<T: crate::Config> IntoProposal<AccountIdOf<T>, BalanceOf<T>, BlockNumberFor<T>>
@@ -640,7 +1114,10 @@ pub mod pallet {
             contributions: ContributionsFor<T>,
             agreement_hash: H256,
             benificiary: AccountIdOf<T>,
-            proposed_milestones: BoundedVec<ProposedMilestone, Self::MaxMilestonesPerProject>,
+            proposed_milestones: BoundedVec<
+                ProposedMilestone<BlockNumberFor<T>>,
+                Self::MaxMilestonesPerProject,
+            >,
             refund_locations: BoundedVec<
                 (Locality<AccountIdOf<T>>, Percent),
                 Self::MaximumContributorsPerProject,
@@ -651,6 +1128,13 @@ pub mod pallet {
         ) -> Result<(), DispatchError> {
             ensure!(jury.len() > 0, Error::<T>::JuryRequired);
             let project_key = crate::ProjectCount::<T>::get().saturating_add(1);
+            for juror in jury.iter() {
+                JurorProjects::<T>::try_mutate(juror, |project_keys| {
+                    project_keys
+                        .try_push(project_key)
+                        .map_err(|_| Error::<T>::JurorOnTooManyProjects)
+                })?;
+            }
 
             // Take storage deposit only for a Project.
             let deposit_id = <T as Config>::DepositHandler::take_deposit(
@@ -661,7 +1145,7 @@ pub mod pallet {
 
             let project_account_id = crate::Pallet::<T>::project_account_id(project_key);
             // todo: Error handling here can be improved.
-            let _is_funded = Self::fund_project(
+            let is_funded = Self::fund_project(
                 &on_creation_funding,
                 &contributions,
                 &project_account_id,
@@ -706,11 +1190,19 @@ pub mod pallet {
                 on_creation_funding,
                 refunded_funds: Zero::zero(),
                 external_owned_address: eoa,
+                funding_state: if is_funded {
+                    FundingState::Funded
+                } else {
+                    FundingState::AwaitingFunding
+                },
+                resubmission_policy: <T as Config>::DefaultResubmissionPolicy::get(),
+                voting_expiry_policy: <T as Config>::DefaultVotingExpiryPolicy::get(),
             };
 
             let individual_votes = ImmutableIndividualVotes::new(bounded_milestone_keys);
             IndividualVoteStore::<T>::insert(project_key, individual_votes);
 
+            InitiatorProjects::<T>::insert(&benificiary, project_key, ());
             Projects::<T>::insert(project_key, project);
             ProjectCount::<T>::put(project_key);
 
@@ -720,8 +1212,14 @@ pub mod pallet {
                 project_key,
                 sum_of_contributions,
                 currency_id,
-                project_account_id,
+                project_account_id.clone(),
             ));
+
+            if !is_funded {
+                ProjectsAwaitingFunding::<T>::insert(&project_account_id, project_key);
+                // The funds may have arrived before the project was created.
+                let _ = Self::try_update_funding_state(project_key);
+            }
             Ok(())
         }
 
@@ -775,22 +1273,24 @@ pub mod pallet {
 pub enum RoundType {
     VotingRound,
     VoteOfNoConfidence,
+    Arbitration,
 }
 
 /// The milestones provided by the user to define the milestones of a project.
-/// TODO: add ipfs hash like in the grants pallet and
 /// TODO: move these to a common repo (common_types will do)
 #[derive(Encode, Decode, PartialEq, Eq, Clone, Debug, TypeInfo, MaxEncodedLen)]
-pub struct ProposedMilestone {
+pub struct ProposedMilestone<BlockNumber> {
     pub percentage_to_unlock: Percent,
+    /// The hash of the off-chain milestone content, the title, description and deliverables.
+    pub content_hash: ContentHash,
+    /// The block the milestone is due to be delivered by.
+    pub due_block: Option<BlockNumber>,
 }
 
 /// The contribution users made to a project project.
 /// TODO: move these to a common repo (common_types will do)
 /// TODO: add ipfs hash like in the grants pallet and
 
-// TODO: MIGRATION FOR MILESTONES
-//can_refund
 #[derive(Encode, Decode, PartialEq, Eq, Clone, Debug, TypeInfo, MaxEncodedLen)]
 pub struct Milestone<BlockNumber> {
     pub project_key: ProjectKey,
@@ -799,21 +1299,40 @@ pub struct Milestone<BlockNumber> {
     pub is_approved: bool,
     pub can_refund: bool,
     pub transfer_status: Option<TransferStatus<BlockNumber>>,
+    /// The hash of the off-chain milestone content, taken from the proposed milestone.
+    pub content_hash: ContentHash,
+    /// The block the milestone is due to be delivered by.
+    pub due_block: Option<BlockNumber>,
+    /// The hash of the evidence given on the latest submission of the milestone.
+    pub evidence_hash: Option<ContentHash>,
+    /// The share of the milestone refunded once it can be refunded, decided by the jury of a dispute.
+    /// The initiator can withdraw the rest.
+    pub refund_share: Percent,
+    /// When the initiator withdrew their share of a milestone that was only partly refunded.
+    pub split_withdrawn_on: Option<BlockNumber>,
+    /// The submissions of the milestone, the latest last.
+    pub attempts: MilestoneAttempts<BlockNumber>,
 }
 
 impl<B> Milestone<B> {
     fn new(
         project_key: ProjectKey,
         milestone_key: MilestoneKey,
-        percentage_to_unlock: Percent,
+        proposed_milestone: ProposedMilestone<B>,
     ) -> Self {
         Self {
             project_key,
             milestone_key,
-            percentage_to_unlock,
+            percentage_to_unlock: proposed_milestone.percentage_to_unlock,
             is_approved: false,
             can_refund: false,
             transfer_status: None,
+            content_hash: proposed_milestone.content_hash,
+            due_block: proposed_milestone.due_block,
+            evidence_hash: None,
+            refund_share: Percent::from_percent(100),
+            split_withdrawn_on: None,
+            attempts: Default::default(),
         }
     }
 }
@@ -870,6 +1389,12 @@ pub struct Project<T: Config> {
     pub refunded_funds: BalanceOf<T>,
     /// The payment address used when the currency_id is of type foreign.
     pub external_owned_address: Option<common_types::ForeignOwnedAccount>,
+    /// Whether the raised funds have arrived in the project account.
+    pub funding_state: FundingState<BalanceOf<T>>,
+    /// Limits how often a rejected milestone can be submitted again.
+    pub resubmission_policy: ResubmissionPolicy<BlockNumberFor<T>>,
+    /// Decides a milestone vote that has not been finalised before the voting round expires.
+    pub voting_expiry_policy: VotingExpiryPolicy,
 }
 
 /// For deriving the location of an account.
@@ -916,6 +1441,24 @@ pub enum FundingPath {
     WaitForFunding,
 }
 
+/// Tracks the arrival of funds into the project account.
+/// Projects using FundingPath::TakeFromReserved are funded on creation.
+#[derive(Encode, Decode, PartialEq, Eq, Clone, Debug, TypeInfo, MaxEncodedLen)]
+pub enum FundingState<Balance> {
+    /// Nothing has arrived in the project account yet.
+    AwaitingFunding,
+    /// Some of the raised funds have arrived in the project account.
+    PartiallyFunded { received: Balance },
+    /// The project account holds the raised funds, milestones can now be submitted.
+    Funded,
+}
+
+impl<Balance> FundingState<Balance> {
+    pub fn is_funded(&self) -> bool {
+        matches!(self, FundingState::Funded)
+    }
+}
+
 /// Defines how the funds were taken out of a specific milestone.
 /// Contians the block number for possible further investigation.
 #[derive(Encode, Decode, PartialEq, Eq, Clone, Debug, TypeInfo, MaxEncodedLen)]
@@ -924,11 +1467,75 @@ pub enum TransferStatus<BlockNumber> {
     Withdrawn { on: BlockNumber },
 }
 
+/// Limits how often the initiator can submit a milestone once it has been rejected.
+#[derive(Encode, Decode, PartialEq, Eq, Clone, Debug, TypeInfo, MaxEncodedLen)]
+pub struct ResubmissionPolicy<BlockNumber> {
+    /// The time the initiator must wait after a rejection before submitting the milestone again.
+    pub cooldown: BlockNumber,
+    /// The number of times a milestone can be submitted, capped at MAX_MILESTONE_ATTEMPTS.
+    /// Once the last attempt is rejected the milestone can be refunded.
+    pub max_attempts: u32,
+}
+
+impl<BlockNumber> ResubmissionPolicy<BlockNumber> {
+    /// The number of times a milestone can be submitted.
+    pub fn attempts_allowed(&self) -> u32 {
+        self.max_attempts.min(MAX_MILESTONE_ATTEMPTS)
+    }
+}
+
+/// Decides the outcome of a milestone vote that has not reached the threshold before the round expired.
+#[derive(Encode, Decode, PartialEq, Eq, Clone, Copy, Debug, TypeInfo, MaxEncodedLen, Default)]
+pub enum VotingExpiryPolicy {
+    /// Approve the milestone unless the votes against it meet PercentRequiredForVoteToPass of the raised funds.
+    OptimisticApproval,
+    /// Approve the milestone if at least min_turnout of the raised funds voted
+    /// and the votes for it meet PercentRequiredForVoteToPass of the funds that voted.
+    TurnoutRelative { min_turnout: Percent },
+    /// Reject the milestone, the initiator can submit it again.
+    #[default]
+    Reject,
+}
+
+/// A single submission of a milestone.
+#[derive(Encode, Decode, PartialEq, Eq, Clone, Debug, TypeInfo, MaxEncodedLen)]
+pub struct MilestoneAttempt<BlockNumber> {
+    /// The block the milestone was submitted.
+    pub submitted_on: BlockNumber,
+    /// The block the contributors rejected the submission, if they did.
+    pub rejected_on: Option<BlockNumber>,
+}
+
 /// Stores the btree for each individual vote.
 #[derive(Encode, Decode, PartialEq, Eq, Clone, Debug, TypeInfo, MaxEncodedLen)]
 #[scale_info(skip_type_params(T))]
 pub struct ImmutableIndividualVotes<T: Config> {
     votes: IndividualVotes<T>,
+    /// Who voted on behalf of each delegator, their vote is also held in votes.
+    delegated_votes: DelegatedVotes<T>,
+}
+
+/// The projects a delegation of milestone votes applies to.
+#[derive(Encode, Decode, PartialEq, Eq, Clone, Copy, Debug, TypeInfo, MaxEncodedLen)]
+pub enum DelegationScope {
+    /// Every project the delegator has contributed to.
+    AllProjects,
+    /// A single project.
+    Project(ProjectKey),
+}
+
+/// A dispute decided by the ArbitrationOrigin as the jury of the project is too small to vote.
+#[derive(Encode, Decode, PartialEq, Eq, Clone, Debug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct Arbitration<T: Config> {
+    /// The project the dispute was raised on.
+    pub project_key: ProjectKey,
+    /// The milestones in dispute.
+    pub milestone_keys: BoundedVec<MilestoneKey, T::MaxMilestonesPerProject>,
+    /// The bond taken from the raiser.
+    pub bond: DepositIdOf<T>,
+    /// The block the arbitration expires if it has not been decided.
+    pub expires: BlockNumberFor<T>,
 }
 
 pub trait WeightInfoT {
@@ -938,4 +1545,12 @@ pub trait WeightInfoT {
     fn on_initialize() -> Weight;
     fn raise_dispute() -> Weight;
     fn refund() -> Weight;
+    fn check_project_funding() -> Weight;
+    fn raise_vote_of_no_confidence() -> Weight;
+    fn vote_on_no_confidence_round() -> Weight;
+    fn arbitrate_dispute() -> Weight;
+    fn propose_refund_share() -> Weight;
+    fn delegate_vote() -> Weight;
+    fn revoke_delegation() -> Weight;
+    fn set_project_policies() -> Weight;
 }
@@ -723,6 +723,47 @@ pub mod v6 {
 pub mod v7 {
     use super::*;
 
+    #[derive(Encode, Decode, PartialEq, Eq, Clone, Debug, TypeInfo, MaxEncodedLen)]
+    pub struct MilestoneV7<BlockNumber> {
+        pub project_key: ProjectKey,
+        pub milestone_key: MilestoneKey,
+        pub percentage_to_unlock: Percent,
+        pub is_approved: bool,
+        pub can_refund: bool,
+        pub transfer_status: Option<TransferStatus<BlockNumber>>,
+    }
+
+    pub type V7BoundedBTreeMilestones<T> = BoundedBTreeMap<
+        MilestoneKey,
+        MilestoneV7<BlockNumberFor<T>>,
+        <T as Config>::MaxMilestonesPerProject,
+    >;
+
+    #[derive(Encode, Decode, PartialEq, Eq, Clone, Debug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct ProjectV7<T: Config> {
+        pub agreement_hash: H256,
+        pub milestones: V7BoundedBTreeMilestones<T>,
+        pub contributions: ContributionsFor<T>,
+        pub currency_id: common_types::CurrencyId,
+        pub withdrawn_funds: BalanceOf<T>,
+        pub raised_funds: BalanceOf<T>,
+        pub initiator: AccountIdOf<T>,
+        pub created_on: BlockNumberFor<T>,
+        pub cancelled: bool,
+        pub deposit_id: DepositIdOf<T>,
+        pub refund_locations:
+            BoundedVec<(Locality<AccountIdOf<T>>, Percent), T::MaximumContributorsPerProject>,
+        pub jury: BoundedVec<AccountIdOf<T>, MaxJuryOf<T>>,
+        pub on_creation_funding: FundingPath,
+        pub refunded_funds: BalanceOf<T>,
+        pub external_owned_address: Option<common_types::ForeignOwnedAccount>,
+    }
+
+    #[storage_alias]
+    pub type Projects<T: Config> =
+        StorageMap<Pallet<T>, Identity, ProjectKey, ProjectV7<T>, OptionQuery>;
+
     pub struct MigrateToV7<T: Config>(T);
 
     impl<T: Config> OnRuntimeUpgrade for MigrateToV7<T>
@@ -771,7 +812,7 @@ pub mod v7 {
         #[cfg(feature = "try-runtime")]
         fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
             log::warn!( target:  "pallet-proposals", "Running post_upgrade()");
-            Projects::<T>::iter().for_each(|(_k, project)| {
+            v7::Projects::<T>::iter().for_each(|(_k, project)| {
                 assert!(project.jury.len() > 0, "jury size must be > 0");
             });
 
@@ -816,11 +857,11 @@ pub mod v7 {
 
             let jury = match project.funding_type {
                 v5::FundingType::Grant(_) => project.contributions.keys().cloned().collect::<Vec<AccountIdOf<T>>>(),
-                v5::FundingType::Brief => <T::JurySelector as SelectJury<AccountIdOf<T>>>::select_jury().to_vec(),
-                _ => <T::JurySelector as SelectJury<AccountIdOf<T>>>::select_jury().to_vec(),
+                v5::FundingType::Brief => <T::JurySelector as SelectJury<AccountIdOf<T>>>::select_jury(<T::JurySelector as SelectJury<AccountIdOf<T>>>::JurySize::get()).to_vec(),
+                _ => <T::JurySelector as SelectJury<AccountIdOf<T>>>::select_jury(<T::JurySelector as SelectJury<AccountIdOf<T>>>::JurySize::get()).to_vec(),
             };
 
-            let mut new_milestones: BoundedBTreeMilestones<T> = BoundedBTreeMap::new();
+            let mut new_milestones: V7BoundedBTreeMilestones<T> = BoundedBTreeMap::new();
             project.milestones.iter().for_each(|(_ms_key, ms): (&MilestoneKey, &v6::V6Milestone)| {
                 // assume that if its approved then its been withdrawn.
                 let mut transfer_status: Option<TransferStatus<BlockNumberFor<T>>> = None;
@@ -828,7 +869,7 @@ pub mod v7 {
                     transfer_status = Some(TransferStatus::Withdrawn{on: frame_system::Pallet::<T>::block_number()});
                 }
 
-                let new_ms = crate::Milestone {
+                let new_ms = MilestoneV7 {
                     project_key: ms.project_key,
                     milestone_key: ms.milestone_key,
                     percentage_to_unlock: ms.percentage_to_unlock,
@@ -840,7 +881,7 @@ pub mod v7 {
                 new_milestones.try_insert(ms.milestone_key, new_ms).expect("If this fails in try_runtime we have an issue. Dont reduce bound of milestones.");
             });
 
-            let migrated_project = crate::Project {
+            let migrated_project = ProjectV7 {
                 agreement_hash: project.agreement_hash,
                 milestones: new_milestones,
                 contributions: project.contributions,
@@ -859,185 +900,1158 @@ pub mod v7 {
             };
 
             *weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 2));
-            crate::Projects::<T>::insert(key, migrated_project);
+            v7::Projects::<T>::insert(key, migrated_project);
         });
     }
 }
 
-#[cfg(test)]
-mod test {
+pub mod v8 {
     use super::*;
-    use mock::*;
-    use test_utils::*;
 
-    use v0::{ContributionV0, MilestoneV0, ProjectV0};
+    #[derive(Encode, Decode, PartialEq, Eq, Clone, Debug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct ProjectV8<T: Config> {
+        pub agreement_hash: H256,
+        pub milestones: v7::V7BoundedBTreeMilestones<T>,
+        pub contributions: ContributionsFor<T>,
+        pub currency_id: common_types::CurrencyId,
+        pub withdrawn_funds: BalanceOf<T>,
+        pub raised_funds: BalanceOf<T>,
+        pub initiator: AccountIdOf<T>,
+        pub created_on: BlockNumberFor<T>,
+        pub cancelled: bool,
+        pub deposit_id: DepositIdOf<T>,
+        pub refund_locations:
+            BoundedVec<(Locality<AccountIdOf<T>>, Percent), T::MaximumContributorsPerProject>,
+        pub jury: BoundedVec<AccountIdOf<T>, MaxJuryOf<T>>,
+        pub on_creation_funding: FundingPath,
+        pub refunded_funds: BalanceOf<T>,
+        pub external_owned_address: Option<common_types::ForeignOwnedAccount>,
+        pub funding_state: FundingState<BalanceOf<T>>,
+    }
 
-    #[test]
-    fn migrate_v0_to_v1() {
-        let contribution_value = 1_000_000_u128;
+    #[storage_alias]
+    pub type Projects<T: Config> =
+        StorageMap<Pallet<T>, Identity, ProjectKey, ProjectV8<T>, OptionQuery>;
 
-        build_test_externality().execute_with(|| {
-            let project_key = 1;
-            let old_milestones = vec![
-                MilestoneV0 {
-                    project_key,
-                    name: b"milestone 1".to_vec(),
-                    milestone_key: 0,
-                    percentage_to_unlock: 40,
-                    is_approved: true,
-                },
-                MilestoneV0 {
-                    project_key,
-                    name: b"milestone 2".to_vec(),
-                    milestone_key: 1,
-                    percentage_to_unlock: 60,
-                    is_approved: true,
-                },
-            ];
+    pub struct MigrateToV8<T: Config>(T);
 
-            let old_contributions = vec![
-                ContributionV0 {
-                    account_id: ALICE,
-                    value: contribution_value,
-                },
-                ContributionV0 {
-                    account_id: BOB,
-                    value: contribution_value,
-                },
-            ];
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV8<T> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            log::warn!( target: "pallet-proposals", "Running pre_upgrade()");
+            let current = <Pallet<T> as GetStorageVersion>::current_storage_version();
+            let onchain = <Pallet<T> as GetStorageVersion>::on_chain_storage_version();
 
-            let old_project = ProjectV0 {
-                name: b"Project Pre-migrations".to_vec(),
-                logo: b"logo".to_vec(),
-                description: b"description".to_vec(),
-                website: b"https://imbue.network".to_vec(),
-                milestones: old_milestones,
-                contributions: old_contributions,
-                currency_id: CurrencyId::KSM,
-                required_funds: (100_000_000u32).into(),
-                withdrawn_funds: (0u32).into(),
-                initiator: ALICE,
-                create_block_number: 100u64,
-                approved_for_funding: true,
-                funding_threshold_met: true,
-                cancelled: false,
-            };
+            ensure!(
+                current == 8 && onchain == 7,
+                "Current version must be set to v8 and onchain to v7"
+            );
+            Ok((v7::Projects::<T>::iter_keys().count() as u32).encode())
+        }
 
-            v0::Projects::<Test>::insert(project_key, &old_project);
-            let _ = v1::migrate::<Test>();
-            let migrated_project =
-                v1::Projects::<Test>::get(project_key).expect("project should exist");
+        fn on_runtime_upgrade() -> Weight {
+            let mut weight = T::DbWeight::get().reads_writes(1, 1);
+            log::warn!("****** STARTING MIGRATION *****");
 
-            assert_eq!(old_project.name, migrated_project.name);
+            let current = <Pallet<T> as GetStorageVersion>::current_storage_version();
+            let onchain = <Pallet<T> as GetStorageVersion>::on_chain_storage_version();
+            if current == 8 && onchain == 7 {
+                migrate_funding_state::<T>(&mut weight);
+                current.put::<Pallet<T>>();
+                log::warn!("v8 has been successfully applied");
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 1));
+            } else {
+                log::warn!("Skipping v8 due to mismatched version, this be removed from Executive");
+                weight = weight.saturating_add(T::DbWeight::get().reads(1));
+            }
 
-            assert_eq!(
-                old_project.milestones[0].percentage_to_unlock,
-                migrated_project
-                    .milestones
-                    .get(&0)
-                    .unwrap()
-                    .percentage_to_unlock
-            );
+            log::warn!("****** ENDING MIGRATION *****");
+            weight
+        }
 
-            assert_eq!(
-                old_project.milestones[0].name,
-                migrated_project.milestones.get(&0).unwrap().name
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            log::warn!( target:  "pallet-proposals", "Running post_upgrade()");
+            let project_count: u32 = Decode::decode(&mut state.as_slice())
+                .map_err(|_| "Failed to decode the pre upgrade state")?;
+            ensure!(
+                v8::Projects::<T>::iter_keys().count() as u32 == project_count,
+                "All projects must be migrated"
             );
-
-            assert_eq!(
-                &old_project.contributions[0].value,
-                &migrated_project.contributions.get(&ALICE).unwrap().value
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 8,
+                "Storage version should be v8 after the migration"
             );
 
-            assert_eq!(
-                contribution_value.saturating_mul(2),
-                migrated_project.raised_funds
-            );
-        })
+            Ok(())
+        }
     }
 
-    #[test]
-    fn migrate_v1_to_v2() {
-        build_test_externality().execute_with(|| {
-            let project_key = 1;
-            let contribution_value = 1_000_000_u128;
-            let mut contributions: BTreeMap<
-                AccountIdOf<Test>,
-                Contribution<BalanceOf<Test>, TimestampOf<Test>>,
-            > = BTreeMap::new();
+    /// Projects funded from reserved contributions already hold their funds.
+    /// Projects waiting for funding are checked against the balance of their project account.
+    pub(super) fn migrate_funding_state<T: Config>(weight: &mut Weight) {
+        v8::Projects::<T>::translate(|project_key, project: v7::ProjectV7<T>| {
+            *weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
 
-            contributions.insert(
-                ALICE,
-                Contribution {
-                    value: contribution_value,
-                    timestamp: TimestampOf::<Test>::default(),
-                },
-            );
+            let funding_state = match project.on_creation_funding {
+                FundingPath::TakeFromReserved => FundingState::Funded,
+                FundingPath::WaitForFunding => {
+                    *weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+                    let project_account = crate::Pallet::<T>::project_account_id(project_key);
+                    let received =
+                        T::MultiCurrency::free_balance(project.currency_id, &project_account);
+                    // Funds may have been withdrawn already, in which case the project was funded.
+                    if received.saturating_add(project.withdrawn_funds) >= project.raised_funds {
+                        FundingState::Funded
+                    } else {
+                        ProjectsAwaitingFunding::<T>::insert(&project_account, project_key);
+                        if received == Zero::zero() {
+                            FundingState::AwaitingFunding
+                        } else {
+                            FundingState::PartiallyFunded { received }
+                        }
+                    }
+                }
+            };
 
-            contributions.insert(
-                BOB,
-                Contribution {
-                    value: contribution_value,
-                    timestamp: TimestampOf::<Test>::default(),
-                },
-            );
+            Some(ProjectV8 {
+                agreement_hash: project.agreement_hash,
+                milestones: project.milestones,
+                contributions: project.contributions,
+                currency_id: project.currency_id,
+                withdrawn_funds: project.withdrawn_funds,
+                raised_funds: project.raised_funds,
+                initiator: project.initiator,
+                created_on: project.created_on,
+                cancelled: project.cancelled,
+                deposit_id: project.deposit_id,
+                refund_locations: project.refund_locations,
+                jury: project.jury,
+                on_creation_funding: project.on_creation_funding,
+                refunded_funds: project.refunded_funds,
+                external_owned_address: project.external_owned_address,
+                funding_state,
+            })
+        });
+    }
+}
 
-            let old_project = v1::ProjectV1 {
-                name: b"Project Pre-migrations".to_vec(),
-                logo: b"logo".to_vec(),
-                description: b"description".to_vec(),
-                website: b"https://imbue.network".to_vec(),
-                milestones: BTreeMap::new(),
-                contributions,
-                currency_id: CurrencyId::KSM,
-                required_funds: (100_000_000u32).into(),
-                raised_funds: (100_000_000u32).into(),
-                withdrawn_funds: (0u32).into(),
-                initiator: ALICE,
-                create_block_number: 100u64,
-                approved_for_funding: true,
-                funding_threshold_met: true,
-                cancelled: false,
-            };
-            v1::Projects::<Test>::insert(project_key, &old_project);
-            let _ = v2::migrate::<Test>();
-            dbg!(&v1::Projects::<Test>::iter_keys().collect::<Vec<u32>>());
-            let migrated_project = v2::Projects::<Test>::get(1).unwrap();
+pub mod v9 {
+    use super::*;
 
-            assert_eq!(old_project.create_block_number, migrated_project.created_on);
+    #[derive(Encode, Decode, PartialEq, Eq, Clone, Debug, TypeInfo, MaxEncodedLen)]
+    pub struct MilestoneV9<BlockNumber> {
+        pub project_key: ProjectKey,
+        pub milestone_key: MilestoneKey,
+        pub percentage_to_unlock: Percent,
+        pub is_approved: bool,
+        pub can_refund: bool,
+        pub transfer_status: Option<TransferStatus<BlockNumber>>,
+        pub content_hash: ContentHash,
+        pub due_block: Option<BlockNumber>,
+        pub evidence_hash: Option<ContentHash>,
+    }
 
-            assert_eq!(
-                &old_project.contributions.get(&ALICE).unwrap().value,
-                &migrated_project.contributions.get(&ALICE).unwrap().value
-            );
+    pub type V9BoundedBTreeMilestones<T> = BoundedBTreeMap<
+        MilestoneKey,
+        MilestoneV9<BlockNumberFor<T>>,
+        <T as Config>::MaxMilestonesPerProject,
+    >;
 
-            assert_eq!(H256::default(), migrated_project.agreement_hash);
-            assert_eq!(v5::FundingType::Proposal, migrated_project.funding_type);
-        })
+    #[derive(Encode, Decode, PartialEq, Eq, Clone, Debug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct ProjectV9<T: Config> {
+        pub agreement_hash: H256,
+        pub milestones: V9BoundedBTreeMilestones<T>,
+        pub contributions: ContributionsFor<T>,
+        pub currency_id: common_types::CurrencyId,
+        pub withdrawn_funds: BalanceOf<T>,
+        pub raised_funds: BalanceOf<T>,
+        pub initiator: AccountIdOf<T>,
+        pub created_on: BlockNumberFor<T>,
+        pub cancelled: bool,
+        pub deposit_id: DepositIdOf<T>,
+        pub refund_locations:
+            BoundedVec<(Locality<AccountIdOf<T>>, Percent), T::MaximumContributorsPerProject>,
+        pub jury: BoundedVec<AccountIdOf<T>, MaxJuryOf<T>>,
+        pub on_creation_funding: FundingPath,
+        pub refunded_funds: BalanceOf<T>,
+        pub external_owned_address: Option<common_types::ForeignOwnedAccount>,
+        pub funding_state: FundingState<BalanceOf<T>>,
     }
 
-    #[test]
-    fn migrate_v2_to_v3() {
-        build_test_externality().execute_with(|| {
-            use v3::*;
+    #[storage_alias]
+    pub type Projects<T: Config> =
+        StorageMap<Pallet<T>, Identity, ProjectKey, ProjectV9<T>, OptionQuery>;
 
-            // 1. -- TESTED - Use blocknumber instead of timestamp for contribution.
-            // 2. -- TESTED - Project has had required_funds, approved_for_funding and funding_threshold_met removed.
-            // 3. -- TESTED - UserVotes storage map needs removing + placing into UserHasVoted
-            // 4. -- TESTED - Milestone votes is now a double map (project_key, milestone_key) +
-            // 5. -- TESTED - Rounds is also a DoubleMap
-            // 6. -- UNTESTED: Round type has had contribution_round removed. Cannot test as the contribution rounds dont exist anymore.
-            // 7. -- TESTED - percent_to_unlock changed from u32 to Percent. (cuteolaf)
-            // 8. -- TESTED - Project new field deposit_id
-            // 9. -- // - milestones and contributions have been bound, on overflow the project is removed.
-            let mut old_milestones = BTreeMap::new();
-            old_milestones.insert(
-                0,
-                v2::MilestoneV1 {
-                    project_key: 0,
-                    milestone_key: 0,
-                    percentage_to_unlock: 40u32,
-                    is_approved: true,
+    pub struct MigrateToV9<T: Config>(T);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV9<T> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            log::warn!( target: "pallet-proposals", "Running pre_upgrade()");
+            let current = <Pallet<T> as GetStorageVersion>::current_storage_version();
+            let onchain = <Pallet<T> as GetStorageVersion>::on_chain_storage_version();
+
+            ensure!(
+                current == 9 && onchain == 8,
+                "Current version must be set to v9 and onchain to v8"
+            );
+            Ok((v8::Projects::<T>::iter_keys().count() as u32).encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let mut weight = T::DbWeight::get().reads_writes(1, 1);
+            log::warn!("****** STARTING MIGRATION *****");
+
+            let current = <Pallet<T> as GetStorageVersion>::current_storage_version();
+            let onchain = <Pallet<T> as GetStorageVersion>::on_chain_storage_version();
+            if current == 9 && onchain == 8 {
+                migrate_milestone_metadata::<T>(&mut weight);
+                current.put::<Pallet<T>>();
+                log::warn!("v9 has been successfully applied");
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 1));
+            } else {
+                log::warn!("Skipping v9 due to mismatched version, this be removed from Executive");
+                weight = weight.saturating_add(T::DbWeight::get().reads(1));
+            }
+
+            log::warn!("****** ENDING MIGRATION *****");
+            weight
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            log::warn!( target:  "pallet-proposals", "Running post_upgrade()");
+            let project_count: u32 = Decode::decode(&mut state.as_slice())
+                .map_err(|_| "Failed to decode the pre upgrade state")?;
+            ensure!(
+                v9::Projects::<T>::iter_keys().count() as u32 == project_count,
+                "All projects must be migrated"
+            );
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 9,
+                "Storage version should be v9 after the migration"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Existing milestones have no metadata, so they are given an empty content hash.
+    pub(super) fn migrate_milestone_metadata<T: Config>(weight: &mut Weight) {
+        v9::Projects::<T>::translate(|_project_key, project: v8::ProjectV8<T>| {
+            *weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+            let mut milestones: V9BoundedBTreeMilestones<T> = BoundedBTreeMap::new();
+            project.milestones.into_iter().for_each(|(ms_key, ms)| {
+                let migrated_ms = MilestoneV9 {
+                    project_key: ms.project_key,
+                    milestone_key: ms.milestone_key,
+                    percentage_to_unlock: ms.percentage_to_unlock,
+                    is_approved: ms.is_approved,
+                    can_refund: ms.can_refund,
+                    transfer_status: ms.transfer_status,
+                    content_hash: Default::default(),
+                    due_block: None,
+                    evidence_hash: None,
+                };
+                // The bound has not changed so this cannot fail.
+                let _ = milestones.try_insert(ms_key, migrated_ms);
+            });
+
+            Some(ProjectV9 {
+                agreement_hash: project.agreement_hash,
+                milestones,
+                contributions: project.contributions,
+                currency_id: project.currency_id,
+                withdrawn_funds: project.withdrawn_funds,
+                raised_funds: project.raised_funds,
+                initiator: project.initiator,
+                created_on: project.created_on,
+                cancelled: project.cancelled,
+                deposit_id: project.deposit_id,
+                refund_locations: project.refund_locations,
+                jury: project.jury,
+                on_creation_funding: project.on_creation_funding,
+                refunded_funds: project.refunded_funds,
+                external_owned_address: project.external_owned_address,
+                funding_state: project.funding_state,
+            })
+        });
+    }
+}
+
+pub mod v10 {
+    use super::*;
+
+    #[storage_alias]
+    pub type ProjectsInDispute<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        ProjectKey,
+        BoundedVec<MilestoneKey, <T as Config>::MaxMilestonesPerProject>,
+        ValueQuery,
+    >;
+
+    pub struct MigrateToV10<T: Config>(T);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV10<T> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            log::warn!( target: "pallet-proposals", "Running pre_upgrade()");
+            let current = <Pallet<T> as GetStorageVersion>::current_storage_version();
+            let onchain = <Pallet<T> as GetStorageVersion>::on_chain_storage_version();
+
+            ensure!(
+                current == 10 && onchain == 9,
+                "Current version must be set to v10 and onchain to v9"
+            );
+            Ok((ProjectsInDispute::<T>::iter_keys().count() as u32).encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let mut weight = T::DbWeight::get().reads_writes(1, 1);
+            log::warn!("****** STARTING MIGRATION *****");
+
+            let current = <Pallet<T> as GetStorageVersion>::current_storage_version();
+            let onchain = <Pallet<T> as GetStorageVersion>::on_chain_storage_version();
+            if current == 10 && onchain == 9 {
+                migrate_dispute_ids::<T>(&mut weight);
+                current.put::<Pallet<T>>();
+                log::warn!("v10 has been successfully applied");
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 1));
+            } else {
+                log::warn!(
+                    "Skipping v10 due to mismatched version, this be removed from Executive"
+                );
+                weight = weight.saturating_add(T::DbWeight::get().reads(1));
+            }
+
+            log::warn!("****** ENDING MIGRATION *****");
+            weight
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            log::warn!( target:  "pallet-proposals", "Running post_upgrade()");
+            let dispute_count: u32 = Decode::decode(&mut state.as_slice())
+                .map_err(|_| "Failed to decode the pre upgrade state")?;
+            ensure!(
+                crate::DisputeProjects::<T>::iter_keys().count() as u32 == dispute_count,
+                "All disputes must be migrated"
+            );
+            ensure!(
+                ProjectsInDispute::<T>::iter_keys().count() == 0,
+                "The old dispute storage must be empty"
+            );
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 10,
+                "Storage version should be v10 after the migration"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Disputes used to be keyed by project so open disputes keep the project key as their id.
+    /// The dispute count starts after the last project key so new ids cannot collide with them.
+    pub(super) fn migrate_dispute_ids<T: Config>(weight: &mut Weight) {
+        ProjectsInDispute::<T>::drain().for_each(|(project_key, milestone_keys)| {
+            *weight = weight.saturating_add(
+                T::DbWeight::get().reads_writes(1, (milestone_keys.len() as u64).saturating_add(2)),
+            );
+            crate::DisputeProjects::<T>::insert(project_key, project_key);
+            milestone_keys.iter().for_each(|milestone_key| {
+                crate::MilestoneDisputes::<T>::insert(project_key, milestone_key, project_key);
+            });
+        });
+        *weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+        crate::DisputeCount::<T>::put(crate::ProjectCount::<T>::get());
+    }
+}
+
+pub mod v11 {
+    use super::*;
+
+    #[derive(Encode, Decode, PartialEq, Eq, Clone, Debug, TypeInfo, MaxEncodedLen)]
+    pub struct MilestoneV11<BlockNumber> {
+        pub project_key: ProjectKey,
+        pub milestone_key: MilestoneKey,
+        pub percentage_to_unlock: Percent,
+        pub is_approved: bool,
+        pub can_refund: bool,
+        pub transfer_status: Option<TransferStatus<BlockNumber>>,
+        pub content_hash: ContentHash,
+        pub due_block: Option<BlockNumber>,
+        pub evidence_hash: Option<ContentHash>,
+        pub refund_share: Percent,
+        pub split_withdrawn_on: Option<BlockNumber>,
+    }
+
+    pub type V11BoundedBTreeMilestones<T> = BoundedBTreeMap<
+        MilestoneKey,
+        MilestoneV11<BlockNumberFor<T>>,
+        <T as Config>::MaxMilestonesPerProject,
+    >;
+
+    #[derive(Encode, Decode, PartialEq, Eq, Clone, Debug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct ProjectV11<T: Config> {
+        pub agreement_hash: H256,
+        pub milestones: V11BoundedBTreeMilestones<T>,
+        pub contributions: ContributionsFor<T>,
+        pub currency_id: common_types::CurrencyId,
+        pub withdrawn_funds: BalanceOf<T>,
+        pub raised_funds: BalanceOf<T>,
+        pub initiator: AccountIdOf<T>,
+        pub created_on: BlockNumberFor<T>,
+        pub cancelled: bool,
+        pub deposit_id: DepositIdOf<T>,
+        pub refund_locations:
+            BoundedVec<(Locality<AccountIdOf<T>>, Percent), T::MaximumContributorsPerProject>,
+        pub jury: BoundedVec<AccountIdOf<T>, MaxJuryOf<T>>,
+        pub on_creation_funding: FundingPath,
+        pub refunded_funds: BalanceOf<T>,
+        pub external_owned_address: Option<common_types::ForeignOwnedAccount>,
+        pub funding_state: FundingState<BalanceOf<T>>,
+    }
+
+    #[storage_alias]
+    pub type Projects<T: Config> =
+        StorageMap<Pallet<T>, Identity, ProjectKey, ProjectV11<T>, OptionQuery>;
+
+    pub struct MigrateToV11<T: Config>(T);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV11<T> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            log::warn!( target: "pallet-proposals", "Running pre_upgrade()");
+            let current = <Pallet<T> as GetStorageVersion>::current_storage_version();
+            let onchain = <Pallet<T> as GetStorageVersion>::on_chain_storage_version();
+
+            ensure!(
+                current == 11 && onchain == 10,
+                "Current version must be set to v11 and onchain to v10"
+            );
+            Ok((v9::Projects::<T>::iter_keys().count() as u32).encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let mut weight = T::DbWeight::get().reads_writes(1, 1);
+            log::warn!("****** STARTING MIGRATION *****");
+
+            let current = <Pallet<T> as GetStorageVersion>::current_storage_version();
+            let onchain = <Pallet<T> as GetStorageVersion>::on_chain_storage_version();
+            if current == 11 && onchain == 10 {
+                migrate_refund_shares::<T>(&mut weight);
+                current.put::<Pallet<T>>();
+                log::warn!("v11 has been successfully applied");
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 1));
+            } else {
+                log::warn!(
+                    "Skipping v11 due to mismatched version, this be removed from Executive"
+                );
+                weight = weight.saturating_add(T::DbWeight::get().reads(1));
+            }
+
+            log::warn!("****** ENDING MIGRATION *****");
+            weight
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            log::warn!( target:  "pallet-proposals", "Running post_upgrade()");
+            let project_count: u32 = Decode::decode(&mut state.as_slice())
+                .map_err(|_| "Failed to decode the pre upgrade state")?;
+            ensure!(
+                v11::Projects::<T>::iter_values().count() as u32 == project_count,
+                "All projects must be migrated"
+            );
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 11,
+                "Storage version should be v11 after the migration"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Milestones refunded before refund shares existed were refunded in full.
+    pub(super) fn migrate_refund_shares<T: Config>(weight: &mut Weight) {
+        v11::Projects::<T>::translate(|_project_key, project: v9::ProjectV9<T>| {
+            *weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+            let mut milestones: V11BoundedBTreeMilestones<T> = BoundedBTreeMap::new();
+            project.milestones.into_iter().for_each(|(ms_key, ms)| {
+                let migrated_ms = MilestoneV11 {
+                    project_key: ms.project_key,
+                    milestone_key: ms.milestone_key,
+                    percentage_to_unlock: ms.percentage_to_unlock,
+                    is_approved: ms.is_approved,
+                    can_refund: ms.can_refund,
+                    transfer_status: ms.transfer_status,
+                    content_hash: ms.content_hash,
+                    due_block: ms.due_block,
+                    evidence_hash: ms.evidence_hash,
+                    refund_share: Percent::from_percent(100),
+                    split_withdrawn_on: None,
+                };
+                // The bound has not changed so this cannot fail.
+                let _ = milestones.try_insert(ms_key, migrated_ms);
+            });
+
+            Some(ProjectV11 {
+                agreement_hash: project.agreement_hash,
+                milestones,
+                contributions: project.contributions,
+                currency_id: project.currency_id,
+                withdrawn_funds: project.withdrawn_funds,
+                raised_funds: project.raised_funds,
+                initiator: project.initiator,
+                created_on: project.created_on,
+                cancelled: project.cancelled,
+                deposit_id: project.deposit_id,
+                refund_locations: project.refund_locations,
+                jury: project.jury,
+                on_creation_funding: project.on_creation_funding,
+                refunded_funds: project.refunded_funds,
+                external_owned_address: project.external_owned_address,
+                funding_state: project.funding_state,
+            })
+        });
+    }
+}
+
+pub mod v12 {
+    use super::*;
+
+    #[derive(Encode, Decode, PartialEq, Eq, Clone, Debug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct ProjectV12<T: Config> {
+        pub agreement_hash: H256,
+        pub milestones: BoundedBTreeMilestones<T>,
+        pub contributions: ContributionsFor<T>,
+        pub currency_id: common_types::CurrencyId,
+        pub withdrawn_funds: BalanceOf<T>,
+        pub raised_funds: BalanceOf<T>,
+        pub initiator: AccountIdOf<T>,
+        pub created_on: BlockNumberFor<T>,
+        pub cancelled: bool,
+        pub deposit_id: DepositIdOf<T>,
+        pub refund_locations:
+            BoundedVec<(Locality<AccountIdOf<T>>, Percent), T::MaximumContributorsPerProject>,
+        pub jury: BoundedVec<AccountIdOf<T>, MaxJuryOf<T>>,
+        pub on_creation_funding: FundingPath,
+        pub refunded_funds: BalanceOf<T>,
+        pub external_owned_address: Option<common_types::ForeignOwnedAccount>,
+        pub funding_state: FundingState<BalanceOf<T>>,
+        pub resubmission_policy: ResubmissionPolicy<BlockNumberFor<T>>,
+    }
+
+    #[storage_alias]
+    pub type Projects<T: Config> =
+        StorageMap<Pallet<T>, Identity, ProjectKey, ProjectV12<T>, OptionQuery>;
+
+    pub struct MigrateToV12<T: Config>(T);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV12<T> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            log::warn!( target: "pallet-proposals", "Running pre_upgrade()");
+            let current = <Pallet<T> as GetStorageVersion>::current_storage_version();
+            let onchain = <Pallet<T> as GetStorageVersion>::on_chain_storage_version();
+
+            ensure!(
+                current == 12 && onchain == 11,
+                "Current version must be set to v12 and onchain to v11"
+            );
+            Ok((v11::Projects::<T>::iter_keys().count() as u32).encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let mut weight = T::DbWeight::get().reads_writes(1, 1);
+            log::warn!("****** STARTING MIGRATION *****");
+
+            let current = <Pallet<T> as GetStorageVersion>::current_storage_version();
+            let onchain = <Pallet<T> as GetStorageVersion>::on_chain_storage_version();
+            if current == 12 && onchain == 11 {
+                migrate_resubmission_policies::<T>(&mut weight);
+                current.put::<Pallet<T>>();
+                log::warn!("v12 has been successfully applied");
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 1));
+            } else {
+                log::warn!(
+                    "Skipping v12 due to mismatched version, this be removed from Executive"
+                );
+                weight = weight.saturating_add(T::DbWeight::get().reads(1));
+            }
+
+            log::warn!("****** ENDING MIGRATION *****");
+            weight
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            log::warn!( target:  "pallet-proposals", "Running post_upgrade()");
+            let project_count: u32 = Decode::decode(&mut state.as_slice())
+                .map_err(|_| "Failed to decode the pre upgrade state")?;
+            ensure!(
+                v12::Projects::<T>::iter_values().count() as u32 == project_count,
+                "All projects must be migrated"
+            );
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 12,
+                "Storage version should be v12 after the migration"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Existing projects are given the default resubmission policy,
+    /// submissions made before attempts were tracked do not count towards it.
+    pub(super) fn migrate_resubmission_policies<T: Config>(weight: &mut Weight) {
+        v12::Projects::<T>::translate(|_project_key, project: v11::ProjectV11<T>| {
+            *weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+            let mut milestones: BoundedBTreeMilestones<T> = BoundedBTreeMap::new();
+            project.milestones.into_iter().for_each(|(ms_key, ms)| {
+                let migrated_ms = crate::Milestone {
+                    project_key: ms.project_key,
+                    milestone_key: ms.milestone_key,
+                    percentage_to_unlock: ms.percentage_to_unlock,
+                    is_approved: ms.is_approved,
+                    can_refund: ms.can_refund,
+                    transfer_status: ms.transfer_status,
+                    content_hash: ms.content_hash,
+                    due_block: ms.due_block,
+                    evidence_hash: ms.evidence_hash,
+                    refund_share: ms.refund_share,
+                    split_withdrawn_on: ms.split_withdrawn_on,
+                    attempts: Default::default(),
+                };
+                // The bound has not changed so this cannot fail.
+                let _ = milestones.try_insert(ms_key, migrated_ms);
+            });
+
+            Some(v12::ProjectV12 {
+                agreement_hash: project.agreement_hash,
+                milestones,
+                contributions: project.contributions,
+                currency_id: project.currency_id,
+                withdrawn_funds: project.withdrawn_funds,
+                raised_funds: project.raised_funds,
+                initiator: project.initiator,
+                created_on: project.created_on,
+                cancelled: project.cancelled,
+                deposit_id: project.deposit_id,
+                refund_locations: project.refund_locations,
+                jury: project.jury,
+                on_creation_funding: project.on_creation_funding,
+                refunded_funds: project.refunded_funds,
+                external_owned_address: project.external_owned_address,
+                funding_state: project.funding_state,
+                resubmission_policy: <T as Config>::DefaultResubmissionPolicy::get(),
+            })
+        });
+    }
+}
+
+pub mod v13 {
+    use super::*;
+
+    pub struct MigrateToV13<T: Config>(T);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV13<T> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            log::warn!( target: "pallet-proposals", "Running pre_upgrade()");
+            let current = <Pallet<T> as GetStorageVersion>::current_storage_version();
+            let onchain = <Pallet<T> as GetStorageVersion>::on_chain_storage_version();
+
+            ensure!(
+                current == 13 && onchain == 12,
+                "Current version must be set to v13 and onchain to v12"
+            );
+            Ok((v12::Projects::<T>::iter_keys().count() as u32).encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let mut weight = T::DbWeight::get().reads_writes(1, 1);
+            log::warn!("****** STARTING MIGRATION *****");
+
+            let current = <Pallet<T> as GetStorageVersion>::current_storage_version();
+            let onchain = <Pallet<T> as GetStorageVersion>::on_chain_storage_version();
+            if current == 13 && onchain == 12 {
+                migrate_voting_expiry_policies::<T>(&mut weight);
+                current.put::<Pallet<T>>();
+                log::warn!("v13 has been successfully applied");
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 1));
+            } else {
+                log::warn!(
+                    "Skipping v13 due to mismatched version, this be removed from Executive"
+                );
+                weight = weight.saturating_add(T::DbWeight::get().reads(1));
+            }
+
+            log::warn!("****** ENDING MIGRATION *****");
+            weight
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            log::warn!( target:  "pallet-proposals", "Running post_upgrade()");
+            let project_count: u32 = Decode::decode(&mut state.as_slice())
+                .map_err(|_| "Failed to decode the pre upgrade state")?;
+            ensure!(
+                crate::Projects::<T>::iter_values().count() as u32 == project_count,
+                "All projects must be migrated"
+            );
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 13,
+                "Storage version should be v13 after the migration"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Existing projects are given the default voting expiry policy.
+    pub(super) fn migrate_voting_expiry_policies<T: Config>(weight: &mut Weight) {
+        crate::Projects::<T>::translate(|_project_key, project: v12::ProjectV12<T>| {
+            *weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+            Some(crate::Project {
+                agreement_hash: project.agreement_hash,
+                milestones: project.milestones,
+                contributions: project.contributions,
+                currency_id: project.currency_id,
+                withdrawn_funds: project.withdrawn_funds,
+                raised_funds: project.raised_funds,
+                initiator: project.initiator,
+                created_on: project.created_on,
+                cancelled: project.cancelled,
+                deposit_id: project.deposit_id,
+                refund_locations: project.refund_locations,
+                jury: project.jury,
+                on_creation_funding: project.on_creation_funding,
+                refunded_funds: project.refunded_funds,
+                external_owned_address: project.external_owned_address,
+                funding_state: project.funding_state,
+                resubmission_policy: project.resubmission_policy,
+                voting_expiry_policy: <T as Config>::DefaultVotingExpiryPolicy::get(),
+            })
+        });
+    }
+}
+
+pub mod v14 {
+    use super::*;
+
+    #[derive(Encode, Decode, PartialEq, Eq, Clone, Debug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct ImmutableIndividualVotesV13<T: Config> {
+        pub votes: IndividualVotes<T>,
+    }
+
+    #[storage_alias]
+    pub type IndividualVoteStore<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        ProjectKey,
+        ImmutableIndividualVotesV13<T>,
+        OptionQuery,
+    >;
+
+    pub struct MigrateToV14<T: Config>(T);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV14<T> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            log::warn!( target: "pallet-proposals", "Running pre_upgrade()");
+            let current = <Pallet<T> as GetStorageVersion>::current_storage_version();
+            let onchain = <Pallet<T> as GetStorageVersion>::on_chain_storage_version();
+
+            ensure!(
+                current == 14 && onchain == 13,
+                "Current version must be set to v14 and onchain to v13"
+            );
+            Ok((v14::IndividualVoteStore::<T>::iter_keys().count() as u32).encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let mut weight = T::DbWeight::get().reads_writes(1, 1);
+            log::warn!("****** STARTING MIGRATION *****");
+
+            let current = <Pallet<T> as GetStorageVersion>::current_storage_version();
+            let onchain = <Pallet<T> as GetStorageVersion>::on_chain_storage_version();
+            if current == 14 && onchain == 13 {
+                migrate_delegated_votes::<T>(&mut weight);
+                current.put::<Pallet<T>>();
+                log::warn!("v14 has been successfully applied");
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 1));
+            } else {
+                log::warn!(
+                    "Skipping v14 due to mismatched version, this be removed from Executive"
+                );
+                weight = weight.saturating_add(T::DbWeight::get().reads(1));
+            }
+
+            log::warn!("****** ENDING MIGRATION *****");
+            weight
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            log::warn!( target:  "pallet-proposals", "Running post_upgrade()");
+            let vote_store_count: u32 = Decode::decode(&mut state.as_slice())
+                .map_err(|_| "Failed to decode the pre upgrade state")?;
+            ensure!(
+                crate::IndividualVoteStore::<T>::iter_values().count() as u32 == vote_store_count,
+                "All individual votes must be migrated"
+            );
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 14,
+                "Storage version should be v14 after the migration"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// The individual votes of existing projects are given an empty record of delegated votes for each milestone.
+    pub(super) fn migrate_delegated_votes<T: Config>(weight: &mut Weight) {
+        crate::IndividualVoteStore::<T>::translate(
+            |_project_key, individual_votes: ImmutableIndividualVotesV13<T>| {
+                *weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+                let mut delegated_votes: DelegatedVotes<T> = BoundedBTreeMap::new();
+                individual_votes.votes.keys().for_each(|milestone_key| {
+                    // The bound has not changed so this cannot fail.
+                    let _ = delegated_votes.try_insert(*milestone_key, BoundedBTreeMap::new());
+                });
+
+                Some(crate::ImmutableIndividualVotes {
+                    votes: individual_votes.votes,
+                    delegated_votes,
+                })
+            },
+        );
+    }
+}
+
+pub mod v15 {
+    use super::*;
+
+    pub struct MigrateToV15<T: Config>(T);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV15<T> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            log::warn!( target: "pallet-proposals", "Running pre_upgrade()");
+            let current = <Pallet<T> as GetStorageVersion>::current_storage_version();
+            let onchain = <Pallet<T> as GetStorageVersion>::on_chain_storage_version();
+
+            ensure!(
+                current == 15 && onchain == 14,
+                "Current version must be set to v15 and onchain to v14"
+            );
+            Ok(().encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let mut weight = T::DbWeight::get().reads_writes(1, 1);
+            log::warn!("****** STARTING MIGRATION *****");
+
+            let current = <Pallet<T> as GetStorageVersion>::current_storage_version();
+            let onchain = <Pallet<T> as GetStorageVersion>::on_chain_storage_version();
+            if current == 15 && onchain == 14 {
+                migrate_juror_projects::<T>(&mut weight);
+                current.put::<Pallet<T>>();
+                log::warn!("v15 has been successfully applied");
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 1));
+            } else {
+                log::warn!(
+                    "Skipping v15 due to mismatched version, this be removed from Executive"
+                );
+                weight = weight.saturating_add(T::DbWeight::get().reads(1));
+            }
+
+            log::warn!("****** ENDING MIGRATION *****");
+            weight
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            log::warn!( target:  "pallet-proposals", "Running post_upgrade()");
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 15,
+                "Storage version should be v15 after the migration"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Track the jury of every live project.
+    /// A juror already on MaxJuriesPerAccount projects is not tracked on the rest,
+    /// they stay on those juries if they leave the fellowship.
+    pub(super) fn migrate_juror_projects<T: Config>(weight: &mut Weight) {
+        crate::Projects::<T>::iter().for_each(|(project_key, project)| {
+            *weight = weight.saturating_add(T::DbWeight::get().reads(1));
+            for juror in project.jury.iter() {
+                *weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+                crate::JurorProjects::<T>::mutate(juror, |project_keys| {
+                    if project_keys.try_push(project_key).is_err() {
+                        log::warn!(
+                            "Juror is on too many projects, project {} is not tracked",
+                            project_key
+                        );
+                    }
+                });
+            }
+        });
+    }
+}
+
+pub mod v16 {
+    use super::*;
+
+    pub struct MigrateToV16<T: Config>(T);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV16<T> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            log::warn!( target: "pallet-proposals", "Running pre_upgrade()");
+            let current = <Pallet<T> as GetStorageVersion>::current_storage_version();
+            let onchain = <Pallet<T> as GetStorageVersion>::on_chain_storage_version();
+
+            ensure!(
+                current == 16 && onchain == 15,
+                "Current version must be set to v16 and onchain to v15"
+            );
+            Ok(().encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let mut weight = T::DbWeight::get().reads_writes(1, 1);
+            log::warn!("****** STARTING MIGRATION *****");
+
+            let current = <Pallet<T> as GetStorageVersion>::current_storage_version();
+            let onchain = <Pallet<T> as GetStorageVersion>::on_chain_storage_version();
+            if current == 16 && onchain == 15 {
+                migrate_initiator_projects::<T>(&mut weight);
+                current.put::<Pallet<T>>();
+                log::warn!("v16 has been successfully applied");
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 1));
+            } else {
+                log::warn!(
+                    "Skipping v16 due to mismatched version, this be removed from Executive"
+                );
+                weight = weight.saturating_add(T::DbWeight::get().reads(1));
+            }
+
+            log::warn!("****** ENDING MIGRATION *****");
+            weight
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            log::warn!( target:  "pallet-proposals", "Running post_upgrade()");
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 16,
+                "Storage version should be v16 after the migration"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Index every project by its initiator.
+    pub(super) fn migrate_initiator_projects<T: Config>(weight: &mut Weight) {
+        crate::Projects::<T>::iter().for_each(|(project_key, project)| {
+            *weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+            crate::InitiatorProjects::<T>::insert(project.initiator, project_key, ());
+        });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use mock::*;
+    use test_utils::*;
+
+    use v0::{ContributionV0, MilestoneV0, ProjectV0};
+
+    #[test]
+    fn migrate_v0_to_v1() {
+        let contribution_value = 1_000_000_u128;
+
+        build_test_externality().execute_with(|| {
+            let project_key = 1;
+            let old_milestones = vec![
+                MilestoneV0 {
+                    project_key,
+                    name: b"milestone 1".to_vec(),
+                    milestone_key: 0,
+                    percentage_to_unlock: 40,
+                    is_approved: true,
+                },
+                MilestoneV0 {
+                    project_key,
+                    name: b"milestone 2".to_vec(),
+                    milestone_key: 1,
+                    percentage_to_unlock: 60,
+                    is_approved: true,
+                },
+            ];
+
+            let old_contributions = vec![
+                ContributionV0 {
+                    account_id: ALICE,
+                    value: contribution_value,
+                },
+                ContributionV0 {
+                    account_id: BOB,
+                    value: contribution_value,
+                },
+            ];
+
+            let old_project = ProjectV0 {
+                name: b"Project Pre-migrations".to_vec(),
+                logo: b"logo".to_vec(),
+                description: b"description".to_vec(),
+                website: b"https://imbue.network".to_vec(),
+                milestones: old_milestones,
+                contributions: old_contributions,
+                currency_id: CurrencyId::KSM,
+                required_funds: (100_000_000u32).into(),
+                withdrawn_funds: (0u32).into(),
+                initiator: ALICE,
+                create_block_number: 100u64,
+                approved_for_funding: true,
+                funding_threshold_met: true,
+                cancelled: false,
+            };
+
+            v0::Projects::<Test>::insert(project_key, &old_project);
+            let _ = v1::migrate::<Test>();
+            let migrated_project =
+                v1::Projects::<Test>::get(project_key).expect("project should exist");
+
+            assert_eq!(old_project.name, migrated_project.name);
+
+            assert_eq!(
+                old_project.milestones[0].percentage_to_unlock,
+                migrated_project
+                    .milestones
+                    .get(&0)
+                    .unwrap()
+                    .percentage_to_unlock
+            );
+
+            assert_eq!(
+                old_project.milestones[0].name,
+                migrated_project.milestones.get(&0).unwrap().name
+            );
+
+            assert_eq!(
+                &old_project.contributions[0].value,
+                &migrated_project.contributions.get(&ALICE).unwrap().value
+            );
+
+            assert_eq!(
+                contribution_value.saturating_mul(2),
+                migrated_project.raised_funds
+            );
+        })
+    }
+
+    #[test]
+    fn migrate_v1_to_v2() {
+        build_test_externality().execute_with(|| {
+            let project_key = 1;
+            let contribution_value = 1_000_000_u128;
+            let mut contributions: BTreeMap<
+                AccountIdOf<Test>,
+                Contribution<BalanceOf<Test>, TimestampOf<Test>>,
+            > = BTreeMap::new();
+
+            contributions.insert(
+                ALICE,
+                Contribution {
+                    value: contribution_value,
+                    timestamp: TimestampOf::<Test>::default(),
+                },
+            );
+
+            contributions.insert(
+                BOB,
+                Contribution {
+                    value: contribution_value,
+                    timestamp: TimestampOf::<Test>::default(),
+                },
+            );
+
+            let old_project = v1::ProjectV1 {
+                name: b"Project Pre-migrations".to_vec(),
+                logo: b"logo".to_vec(),
+                description: b"description".to_vec(),
+                website: b"https://imbue.network".to_vec(),
+                milestones: BTreeMap::new(),
+                contributions,
+                currency_id: CurrencyId::KSM,
+                required_funds: (100_000_000u32).into(),
+                raised_funds: (100_000_000u32).into(),
+                withdrawn_funds: (0u32).into(),
+                initiator: ALICE,
+                create_block_number: 100u64,
+                approved_for_funding: true,
+                funding_threshold_met: true,
+                cancelled: false,
+            };
+            v1::Projects::<Test>::insert(project_key, &old_project);
+            let _ = v2::migrate::<Test>();
+            dbg!(&v1::Projects::<Test>::iter_keys().collect::<Vec<u32>>());
+            let migrated_project = v2::Projects::<Test>::get(1).unwrap();
+
+            assert_eq!(old_project.create_block_number, migrated_project.created_on);
+
+            assert_eq!(
+                &old_project.contributions.get(&ALICE).unwrap().value,
+                &migrated_project.contributions.get(&ALICE).unwrap().value
+            );
+
+            assert_eq!(H256::default(), migrated_project.agreement_hash);
+            assert_eq!(v5::FundingType::Proposal, migrated_project.funding_type);
+        })
+    }
+
+    #[test]
+    fn migrate_v2_to_v3() {
+        build_test_externality().execute_with(|| {
+            use v3::*;
+
+            // 1. -- TESTED - Use blocknumber instead of timestamp for contribution.
+            // 2. -- TESTED - Project has had required_funds, approved_for_funding and funding_threshold_met removed.
+            // 3. -- TESTED - UserVotes storage map needs removing + placing into UserHasVoted
+            // 4. -- TESTED - Milestone votes is now a double map (project_key, milestone_key) +
+            // 5. -- TESTED - Rounds is also a DoubleMap
+            // 6. -- UNTESTED: Round type has had contribution_round removed. Cannot test as the contribution rounds dont exist anymore.
+            // 7. -- TESTED - percent_to_unlock changed from u32 to Percent. (cuteolaf)
+            // 8. -- TESTED - Project new field deposit_id
+            // 9. -- // - milestones and contributions have been bound, on overflow the project is removed.
+            let mut old_milestones = BTreeMap::new();
+            old_milestones.insert(
+                0,
+                v2::MilestoneV1 {
+                    project_key: 0,
+                    milestone_key: 0,
+                    percentage_to_unlock: 40u32,
+                    is_approved: true,
                 },
             );
             old_milestones.insert(
@@ -1099,66 +2113,544 @@ mod test {
                 round_type: v3::RoundType::VotingRound,
                 is_canceled: false,
             };
-            v3::OldRounds::<Test>::insert(0, Some(old_round));
+            v3::OldRounds::<Test>::insert(0, Some(old_round));
+
+            let _w = v3::migrate_all::<Test>();
+
+            let project_apres = v6::Projects::<Test>::get(0).unwrap();
+            // #1, 2, 7 & 8
+            assert_eq!(project.agreement_hash, project_apres.agreement_hash);
+            assert_eq!(
+                project.contributions[&CHARLIE].value,
+                project_apres.contributions[&CHARLIE].value
+            );
+            assert_eq!(
+                project.contributions[&BOB].value,
+                project_apres.contributions[&BOB].value
+            );
+            assert_eq!(project_apres.contributions.iter().len(), 2usize);
+            assert_eq!(
+                project.milestones[&0].milestone_key,
+                project_apres.milestones[&0].milestone_key
+            );
+            assert_eq!(
+                project.milestones[&0].project_key,
+                project_apres.milestones[&0].project_key
+            );
+            assert_eq!(
+                Percent::from_percent(project.milestones[&0].percentage_to_unlock as u8),
+                project_apres.milestones[&0].percentage_to_unlock
+            );
+            assert_eq!(project_apres.deposit_id, u32::MAX as u64);
+
+            // #3
+            let new_votes =
+                UserHasVoted::<Test>::get((10u32, crate::RoundType::VotingRound, 10u32));
+            assert!(new_votes[&ALICE]);
+            let new_votes =
+                UserHasVoted::<Test>::get((10u32, crate::RoundType::VoteOfNoConfidence, 10u32));
+            assert!(new_votes[&ALICE]);
+
+            // #4
+            assert_eq!(
+                v3::OldMilestoneVotes::<Test>::get((10, 10)),
+                Default::default()
+            );
+            assert!(v6::MilestoneVotes::<Test>::contains_key(10, 10));
+            let v = v6::MilestoneVotes::<Test>::get(10, 10).unwrap();
+            assert_eq!(v.yay, 100_000);
+            assert_eq!(v.nay, 50_000);
+            assert!(!v.is_approved);
+
+            // #5
+            assert!(OldRounds::<Test>::get(0).is_none());
+            [1, 2, 3].iter().for_each(|k| {
+                let end = v4::V4Rounds::<Test>::get(k, crate::RoundType::VotingRound).unwrap();
+                assert_eq!(end, end_block_number);
+            });
+        })
+    }
+
+    #[test]
+    fn migrate_v3_to_v4() {
+        build_test_externality().execute_with(|| {
+            let cont = get_contributions::<Test>(vec![BOB, DAVE], 100_000);
+            let prop_milestones = get_milestones(10);
+            let jury = vec![JURY_1, JURY_2];
+            let project_key = create_and_fund_project::<Test>(
+                ALICE,
+                cont,
+                prop_milestones,
+                CurrencyId::Native,
+                jury,
+            )
+            .expect("project wasnt created!");
+            let milestone_key: MilestoneKey = 0;
+            let expiry_block: BlockNumber = 10;
+            let rounds_expiring: BoundedProjectKeysPerBlock<Test> =
+                vec![(project_key, RoundType::VotingRound, milestone_key)]
+                    .try_into()
+                    .unwrap();
+
+            // insert a fake round to be mutated.
+            v4::V4Rounds::<Test>::insert(project_key, crate::RoundType::VotingRound, expiry_block);
+            crate::RoundsExpiring::<Test>::insert(expiry_block, rounds_expiring);
+            v6::MilestoneVotes::<Test>::insert(project_key, milestone_key, Vote::default());
+            v6::MilestoneVotes::<Test>::insert(project_key, milestone_key + 1, Vote::default());
+
+            let mut weight = <Weight as Default>::default();
+            v4::migrate_votes::<Test>(&mut weight);
+            // assert that:
+            // 1: the round has been removed (to allow resubmission)
+            // 2: milestone votes have been reset (although resubmission resets this)
+            // 3: the round doesnt try and autocomplete and remove itself inadvertantly. (RoundsExpiring)
+            assert!(!v4::V4Rounds::<Test>::contains_key(
+                project_key,
+                crate::RoundType::VotingRound
+            ));
+            assert!(crate::RoundsExpiring::<Test>::get(expiry_block).is_empty());
+            assert!(v6::MilestoneVotes::<Test>::get(project_key, milestone_key).is_none());
+            assert!(v6::MilestoneVotes::<Test>::get(project_key, milestone_key + 1).is_none());
+        })
+    }
+
+    fn v7_milestones(
+        milestones: BoundedBTreeMilestones<Test>,
+    ) -> v7::V7BoundedBTreeMilestones<Test> {
+        milestones
+            .into_iter()
+            .map(|(ms_key, ms)| {
+                (
+                    ms_key,
+                    v7::MilestoneV7 {
+                        project_key: ms.project_key,
+                        milestone_key: ms.milestone_key,
+                        percentage_to_unlock: ms.percentage_to_unlock,
+                        is_approved: ms.is_approved,
+                        can_refund: ms.can_refund,
+                        transfer_status: ms.transfer_status,
+                    },
+                )
+            })
+            .collect::<BTreeMap<_, _>>()
+            .try_into()
+            .expect("bound is the same")
+    }
+
+    fn v7_project(
+        project: crate::Project<Test>,
+        on_creation_funding: FundingPath,
+    ) -> v7::ProjectV7<Test> {
+        v7::ProjectV7 {
+            agreement_hash: project.agreement_hash,
+            milestones: v7_milestones(project.milestones),
+            contributions: project.contributions,
+            currency_id: project.currency_id,
+            withdrawn_funds: project.withdrawn_funds,
+            raised_funds: project.raised_funds,
+            initiator: project.initiator,
+            created_on: project.created_on,
+            cancelled: project.cancelled,
+            deposit_id: project.deposit_id,
+            refund_locations: project.refund_locations,
+            jury: project.jury,
+            on_creation_funding,
+            refunded_funds: project.refunded_funds,
+            external_owned_address: project.external_owned_address,
+        }
+    }
+
+    #[test]
+    fn migrate_v7_to_v8() {
+        build_test_externality().execute_with(|| {
+            let cont = get_contributions::<Test>(vec![BOB, DAVE], 100_000);
+            let prop_milestones = get_milestones(10);
+            let jury = vec![JURY_1, JURY_2];
+            let funded_key = create_and_fund_project::<Test>(
+                ALICE,
+                cont.clone(),
+                prop_milestones.clone(),
+                CurrencyId::Native,
+                jury.clone(),
+            )
+            .expect("project wasnt created!");
+            let awaiting_key = create_and_fund_project::<Test>(
+                ALICE,
+                cont,
+                prop_milestones,
+                CurrencyId::Native,
+                jury,
+            )
+            .expect("project wasnt created!");
+
+            let funded = crate::Projects::<Test>::get(funded_key).unwrap();
+            v7::Projects::<Test>::insert(
+                funded_key,
+                v7_project(funded, FundingPath::TakeFromReserved),
+            );
+
+            // Only part of the funds have arrived in the project account.
+            let awaiting_account = Pallet::<Test>::project_account_id(awaiting_key);
+            let awaiting = crate::Projects::<Test>::get(awaiting_key).unwrap();
+            assert_ok!(<Tokens as MultiCurrency<AccountId>>::withdraw(
+                CurrencyId::Native,
+                &awaiting_account,
+                150_000
+            ));
+            v7::Projects::<Test>::insert(
+                awaiting_key,
+                v7_project(awaiting, FundingPath::WaitForFunding),
+            );
+
+            let mut weight = <Weight as Default>::default();
+            v8::migrate_funding_state::<Test>(&mut weight);
+
+            let funded = v8::Projects::<Test>::get(funded_key).unwrap();
+            assert_eq!(funded.funding_state, FundingState::Funded);
+            let awaiting = v8::Projects::<Test>::get(awaiting_key).unwrap();
+            assert_eq!(
+                awaiting.funding_state,
+                FundingState::PartiallyFunded { received: 50_000 }
+            );
+            assert_eq!(
+                ProjectsAwaitingFunding::<Test>::get(awaiting_account),
+                Some(awaiting_key)
+            );
+        })
+    }
+
+    #[test]
+    fn migrate_v8_to_v9() {
+        build_test_externality().execute_with(|| {
+            let cont = get_contributions::<Test>(vec![BOB, DAVE], 100_000);
+            let prop_milestones = get_milestones(10);
+            let jury = vec![JURY_1, JURY_2];
+            let project_key = create_and_fund_project::<Test>(
+                ALICE,
+                cont,
+                prop_milestones,
+                CurrencyId::Native,
+                jury,
+            )
+            .expect("project wasnt created!");
+
+            let project = crate::Projects::<Test>::get(project_key).unwrap();
+            let mut old_milestones = v7_milestones(project.milestones.clone());
+            if let Some(ms) = old_milestones.get_mut(&0) {
+                ms.is_approved = true;
+            }
+            let old_project = v8::ProjectV8 {
+                agreement_hash: project.agreement_hash,
+                milestones: old_milestones,
+                contributions: project.contributions.clone(),
+                currency_id: project.currency_id,
+                withdrawn_funds: project.withdrawn_funds,
+                raised_funds: project.raised_funds,
+                initiator: project.initiator,
+                created_on: project.created_on,
+                cancelled: project.cancelled,
+                deposit_id: project.deposit_id,
+                refund_locations: project.refund_locations.clone(),
+                jury: project.jury.clone(),
+                on_creation_funding: project.on_creation_funding,
+                refunded_funds: project.refunded_funds,
+                external_owned_address: project.external_owned_address,
+                funding_state: project.funding_state.clone(),
+            };
+            v8::Projects::<Test>::insert(project_key, old_project);
 
-            let _w = v3::migrate_all::<Test>();
+            let mut weight = <Weight as Default>::default();
+            v9::migrate_milestone_metadata::<Test>(&mut weight);
+
+            let migrated = v9::Projects::<Test>::get(project_key).unwrap();
+            assert_eq!(migrated.milestones.len(), 10);
+            assert!(migrated.milestones.get(&0).unwrap().is_approved);
+            migrated.milestones.iter().for_each(|(ms_key, ms)| {
+                assert_eq!(ms.milestone_key, *ms_key);
+                assert_eq!(ms.percentage_to_unlock, Percent::from_percent(10u8));
+                assert!(ms.content_hash.is_empty());
+                assert!(ms.due_block.is_none());
+                assert!(ms.evidence_hash.is_none());
+            });
+            assert_eq!(migrated.contributions, project.contributions);
+            assert_eq!(migrated.funding_state, project.funding_state);
+        })
+    }
 
-            let project_apres = v6::Projects::<Test>::get(0).unwrap();
-            // #1, 2, 7 & 8
-            assert_eq!(project.agreement_hash, project_apres.agreement_hash);
-            assert_eq!(
-                project.contributions[&CHARLIE].value,
-                project_apres.contributions[&CHARLIE].value
-            );
+    #[test]
+    fn migrate_v9_to_v10() {
+        build_test_externality().execute_with(|| {
+            let cont = get_contributions::<Test>(vec![BOB, DAVE], 100_000);
+            let prop_milestones = get_milestones(10);
+            let jury = vec![JURY_1, JURY_2];
+            let disputed_key = create_and_fund_project::<Test>(
+                ALICE,
+                cont.clone(),
+                prop_milestones.clone(),
+                CurrencyId::Native,
+                jury.clone(),
+            )
+            .expect("project wasnt created!");
+            let other_key = create_and_fund_project::<Test>(
+                ALICE,
+                cont,
+                prop_milestones,
+                CurrencyId::Native,
+                jury,
+            )
+            .expect("project wasnt created!");
+
+            let disputed_milestones: BoundedVec<MilestoneKey, MaxMilestonesPerProject> =
+                vec![1u32, 2u32].try_into().unwrap();
+            v10::ProjectsInDispute::<Test>::insert(disputed_key, disputed_milestones);
+
+            let mut weight = <Weight as Default>::default();
+            v10::migrate_dispute_ids::<Test>(&mut weight);
+
+            assert_eq!(v10::ProjectsInDispute::<Test>::iter_keys().count(), 0);
             assert_eq!(
-                project.contributions[&BOB].value,
-                project_apres.contributions[&BOB].value
+                crate::DisputeProjects::<Test>::get(disputed_key),
+                Some(disputed_key)
             );
-            assert_eq!(project_apres.contributions.iter().len(), 2usize);
             assert_eq!(
-                project.milestones[&0].milestone_key,
-                project_apres.milestones[&0].milestone_key
+                crate::MilestoneDisputes::<Test>::get(disputed_key, 1),
+                Some(disputed_key)
             );
             assert_eq!(
-                project.milestones[&0].project_key,
-                project_apres.milestones[&0].project_key
+                crate::MilestoneDisputes::<Test>::get(disputed_key, 2),
+                Some(disputed_key)
             );
+            assert!(!crate::MilestoneDisputes::<Test>::contains_key(
+                disputed_key,
+                0
+            ));
+            assert!(!crate::DisputeProjects::<Test>::contains_key(other_key));
+            assert_eq!(crate::DisputeCount::<Test>::get(), other_key);
+        })
+    }
+
+    #[test]
+    fn migrate_v10_to_v11() {
+        build_test_externality().execute_with(|| {
+            let cont = get_contributions::<Test>(vec![BOB, DAVE], 100_000);
+            let prop_milestones = get_milestones(10);
+            let jury = vec![JURY_1, JURY_2];
+            let project_key = create_and_fund_project::<Test>(
+                ALICE,
+                cont,
+                prop_milestones,
+                CurrencyId::Native,
+                jury,
+            )
+            .expect("project wasnt created!");
+
+            let project = crate::Projects::<Test>::get(project_key).unwrap();
+            let old_milestones: v9::V9BoundedBTreeMilestones<Test> = project
+                .milestones
+                .clone()
+                .into_iter()
+                .map(|(ms_key, ms)| {
+                    (
+                        ms_key,
+                        v9::MilestoneV9 {
+                            project_key: ms.project_key,
+                            milestone_key: ms.milestone_key,
+                            percentage_to_unlock: ms.percentage_to_unlock,
+                            is_approved: ms.is_approved,
+                            can_refund: ms_key == 0,
+                            transfer_status: ms.transfer_status,
+                            content_hash: ms.content_hash,
+                            due_block: ms.due_block,
+                            evidence_hash: ms.evidence_hash,
+                        },
+                    )
+                })
+                .collect::<BTreeMap<_, _>>()
+                .try_into()
+                .expect("bound is the same");
+            let old_project = v9::ProjectV9 {
+                agreement_hash: project.agreement_hash,
+                milestones: old_milestones,
+                contributions: project.contributions.clone(),
+                currency_id: project.currency_id,
+                withdrawn_funds: project.withdrawn_funds,
+                raised_funds: project.raised_funds,
+                initiator: project.initiator,
+                created_on: project.created_on,
+                cancelled: project.cancelled,
+                deposit_id: project.deposit_id,
+                refund_locations: project.refund_locations.clone(),
+                jury: project.jury.clone(),
+                on_creation_funding: project.on_creation_funding,
+                refunded_funds: project.refunded_funds,
+                external_owned_address: project.external_owned_address,
+                funding_state: project.funding_state.clone(),
+            };
+            v9::Projects::<Test>::insert(project_key, old_project);
+
+            let mut weight = <Weight as Default>::default();
+            v11::migrate_refund_shares::<Test>(&mut weight);
+
+            let migrated = v11::Projects::<Test>::get(project_key).unwrap();
+            assert_eq!(migrated.milestones.len(), 10);
+            assert!(migrated.milestones.get(&0).unwrap().can_refund);
+            migrated.milestones.iter().for_each(|(ms_key, ms)| {
+                assert_eq!(ms.milestone_key, *ms_key);
+                assert_eq!(ms.refund_share, Percent::from_percent(100));
+                assert!(ms.split_withdrawn_on.is_none());
+            });
+            assert_eq!(migrated.contributions, project.contributions);
+            assert_eq!(migrated.jury, project.jury);
+        })
+    }
+
+    #[test]
+    fn migrate_v11_to_v12() {
+        build_test_externality().execute_with(|| {
+            let cont = get_contributions::<Test>(vec![BOB, DAVE], 100_000);
+            let prop_milestones = get_milestones(10);
+            let jury = vec![JURY_1, JURY_2];
+            let project_key = create_and_fund_project::<Test>(
+                ALICE,
+                cont,
+                prop_milestones,
+                CurrencyId::Native,
+                jury,
+            )
+            .expect("project wasnt created!");
+
+            let project = crate::Projects::<Test>::get(project_key).unwrap();
+            let old_milestones: v11::V11BoundedBTreeMilestones<Test> = project
+                .milestones
+                .clone()
+                .into_iter()
+                .map(|(ms_key, ms)| {
+                    (
+                        ms_key,
+                        v11::MilestoneV11 {
+                            project_key: ms.project_key,
+                            milestone_key: ms.milestone_key,
+                            percentage_to_unlock: ms.percentage_to_unlock,
+                            is_approved: ms.is_approved,
+                            can_refund: ms_key == 0,
+                            transfer_status: ms.transfer_status,
+                            content_hash: ms.content_hash,
+                            due_block: ms.due_block,
+                            evidence_hash: ms.evidence_hash,
+                            refund_share: if ms_key == 0 {
+                                Percent::from_percent(40)
+                            } else {
+                                ms.refund_share
+                            },
+                            split_withdrawn_on: ms.split_withdrawn_on,
+                        },
+                    )
+                })
+                .collect::<BTreeMap<_, _>>()
+                .try_into()
+                .expect("bound is the same");
+            let old_project = v11::ProjectV11 {
+                agreement_hash: project.agreement_hash,
+                milestones: old_milestones,
+                contributions: project.contributions.clone(),
+                currency_id: project.currency_id,
+                withdrawn_funds: project.withdrawn_funds,
+                raised_funds: project.raised_funds,
+                initiator: project.initiator,
+                created_on: project.created_on,
+                cancelled: project.cancelled,
+                deposit_id: project.deposit_id,
+                refund_locations: project.refund_locations.clone(),
+                jury: project.jury.clone(),
+                on_creation_funding: project.on_creation_funding,
+                refunded_funds: project.refunded_funds,
+                external_owned_address: project.external_owned_address,
+                funding_state: project.funding_state.clone(),
+            };
+            v11::Projects::<Test>::insert(project_key, old_project);
+
+            let mut weight = <Weight as Default>::default();
+            v12::migrate_resubmission_policies::<Test>(&mut weight);
+
+            let migrated = v12::Projects::<Test>::get(project_key).unwrap();
+            assert_eq!(migrated.milestones.len(), 10);
+            let first = migrated.milestones.get(&0).unwrap();
+            assert!(first.can_refund);
+            assert_eq!(first.refund_share, Percent::from_percent(40));
+            migrated.milestones.iter().for_each(|(ms_key, ms)| {
+                assert_eq!(ms.milestone_key, *ms_key);
+                assert!(ms.attempts.is_empty());
+            });
             assert_eq!(
-                Percent::from_percent(project.milestones[&0].percentage_to_unlock as u8),
-                project_apres.milestones[&0].percentage_to_unlock
+                migrated.resubmission_policy,
+                <Test as Config>::DefaultResubmissionPolicy::get()
             );
-            assert_eq!(project_apres.deposit_id, u32::MAX as u64);
+            assert_eq!(migrated.contributions, project.contributions);
+        })
+    }
 
-            // #3
-            let new_votes =
-                UserHasVoted::<Test>::get((10u32, crate::RoundType::VotingRound, 10u32));
-            assert!(new_votes[&ALICE]);
-            let new_votes =
-                UserHasVoted::<Test>::get((10u32, crate::RoundType::VoteOfNoConfidence, 10u32));
-            assert!(new_votes[&ALICE]);
+    #[test]
+    fn migrate_v12_to_v13() {
+        build_test_externality().execute_with(|| {
+            let cont = get_contributions::<Test>(vec![BOB, DAVE], 100_000);
+            let prop_milestones = get_milestones(10);
+            let jury = vec![JURY_1, JURY_2];
+            let project_key = create_and_fund_project::<Test>(
+                ALICE,
+                cont,
+                prop_milestones,
+                CurrencyId::Native,
+                jury,
+            )
+            .expect("project wasnt created!");
 
-            // #4
+            let project = crate::Projects::<Test>::get(project_key).unwrap();
+            let old_project = v12::ProjectV12 {
+                agreement_hash: project.agreement_hash,
+                milestones: project.milestones.clone(),
+                contributions: project.contributions.clone(),
+                currency_id: project.currency_id,
+                withdrawn_funds: project.withdrawn_funds,
+                raised_funds: project.raised_funds,
+                initiator: project.initiator,
+                created_on: project.created_on,
+                cancelled: project.cancelled,
+                deposit_id: project.deposit_id,
+                refund_locations: project.refund_locations.clone(),
+                jury: project.jury.clone(),
+                on_creation_funding: project.on_creation_funding,
+                refunded_funds: project.refunded_funds,
+                external_owned_address: project.external_owned_address,
+                funding_state: project.funding_state.clone(),
+                resubmission_policy: ResubmissionPolicy {
+                    cooldown: 5,
+                    max_attempts: 2,
+                },
+            };
+            v12::Projects::<Test>::insert(project_key, old_project);
+            DefaultVotingExpiryPolicy::set(VotingExpiryPolicy::OptimisticApproval);
+
+            let mut weight = <Weight as Default>::default();
+            v13::migrate_voting_expiry_policies::<Test>(&mut weight);
+
+            let migrated = crate::Projects::<Test>::get(project_key).unwrap();
+            assert_eq!(migrated.milestones, project.milestones);
             assert_eq!(
-                v3::OldMilestoneVotes::<Test>::get((10, 10)),
-                Default::default()
+                migrated.resubmission_policy,
+                ResubmissionPolicy {
+                    cooldown: 5,
+                    max_attempts: 2,
+                }
+            );
+            assert_eq!(
+                migrated.voting_expiry_policy,
+                VotingExpiryPolicy::OptimisticApproval
             );
-            assert!(v6::MilestoneVotes::<Test>::contains_key(10, 10));
-            let v = v6::MilestoneVotes::<Test>::get(10, 10).unwrap();
-            assert_eq!(v.yay, 100_000);
-            assert_eq!(v.nay, 50_000);
-            assert!(!v.is_approved);
-
-            // #5
-            assert!(OldRounds::<Test>::get(0).is_none());
-            [1, 2, 3].iter().for_each(|k| {
-                let end = v4::V4Rounds::<Test>::get(k, crate::RoundType::VotingRound).unwrap();
-                assert_eq!(end, end_block_number);
-            });
         })
     }
 
     #[test]
-    fn migrate_v3_to_v4() {
+    fn migrate_v13_to_v14() {
         build_test_externality().execute_with(|| {
             let cont = get_contributions::<Test>(vec![BOB, DAVE], 100_000);
             let prop_milestones = get_milestones(10);
@@ -1171,32 +2663,103 @@ mod test {
                 jury,
             )
             .expect("project wasnt created!");
-            let milestone_key: MilestoneKey = 0;
-            let expiry_block: BlockNumber = 10;
-            let rounds_expiring: BoundedProjectKeysPerBlock<Test> =
-                vec![(project_key, RoundType::VotingRound, milestone_key)]
-                    .try_into()
-                    .unwrap();
 
-            // insert a fake round to be mutated.
-            v4::V4Rounds::<Test>::insert(project_key, crate::RoundType::VotingRound, expiry_block);
-            crate::RoundsExpiring::<Test>::insert(expiry_block, rounds_expiring);
-            v6::MilestoneVotes::<Test>::insert(project_key, milestone_key, Vote::default());
-            v6::MilestoneVotes::<Test>::insert(project_key, milestone_key + 1, Vote::default());
+            let mut votes = crate::IndividualVoteStore::<Test>::get(project_key)
+                .unwrap()
+                .as_ref()
+                .clone();
+            votes.get_mut(&0).unwrap().try_insert(BOB, true).unwrap();
+            v14::IndividualVoteStore::<Test>::insert(
+                project_key,
+                v14::ImmutableIndividualVotesV13 {
+                    votes: votes.clone(),
+                },
+            );
 
             let mut weight = <Weight as Default>::default();
-            v4::migrate_votes::<Test>(&mut weight);
-            // assert that:
-            // 1: the round has been removed (to allow resubmission)
-            // 2: milestone votes have been reset (although resubmission resets this)
-            // 3: the round doesnt try and autocomplete and remove itself inadvertantly. (RoundsExpiring)
-            assert!(!v4::V4Rounds::<Test>::contains_key(
-                project_key,
-                crate::RoundType::VotingRound
-            ));
-            assert!(crate::RoundsExpiring::<Test>::get(expiry_block).is_empty());
-            assert!(v6::MilestoneVotes::<Test>::get(project_key, milestone_key).is_none());
-            assert!(v6::MilestoneVotes::<Test>::get(project_key, milestone_key + 1).is_none());
+            v14::migrate_delegated_votes::<Test>(&mut weight);
+
+            let migrated = crate::IndividualVoteStore::<Test>::get(project_key).unwrap();
+            assert_eq!(migrated.as_ref(), &votes);
+            assert_eq!(migrated.delegated_votes().len(), 10);
+            migrated
+                .delegated_votes()
+                .values()
+                .for_each(|delegated| assert!(delegated.is_empty()));
+        })
+    }
+
+    #[test]
+    fn migrate_v14_to_v15() {
+        build_test_externality().execute_with(|| {
+            let cont = get_contributions::<Test>(vec![BOB, DAVE], 100_000);
+            let prop_milestones = get_milestones(10);
+            let project_key = create_and_fund_project::<Test>(
+                ALICE,
+                cont.clone(),
+                prop_milestones.clone(),
+                CurrencyId::Native,
+                vec![JURY_1, JURY_2],
+            )
+            .expect("project wasnt created!");
+            let other_project_key = create_and_fund_project::<Test>(
+                ALICE,
+                cont,
+                prop_milestones,
+                CurrencyId::Native,
+                vec![JURY_1],
+            )
+            .expect("project wasnt created!");
+            let _ = crate::JurorProjects::<Test>::clear(u32::MAX, None);
+
+            let mut weight = <Weight as Default>::default();
+            v15::migrate_juror_projects::<Test>(&mut weight);
+
+            assert_eq!(
+                crate::JurorProjects::<Test>::get(JURY_1).to_vec(),
+                vec![project_key, other_project_key]
+            );
+            assert_eq!(
+                crate::JurorProjects::<Test>::get(JURY_2).to_vec(),
+                vec![project_key]
+            );
+        })
+    }
+
+    #[test]
+    fn migrate_v15_to_v16() {
+        build_test_externality().execute_with(|| {
+            let cont = get_contributions::<Test>(vec![BOB, DAVE], 100_000);
+            let prop_milestones = get_milestones(10);
+            let project_key = create_and_fund_project::<Test>(
+                ALICE,
+                cont.clone(),
+                prop_milestones.clone(),
+                CurrencyId::Native,
+                vec![JURY_1],
+            )
+            .expect("project wasnt created!");
+            let other_project_key = create_and_fund_project::<Test>(
+                BOB,
+                cont,
+                prop_milestones,
+                CurrencyId::Native,
+                vec![JURY_1],
+            )
+            .expect("project wasnt created!");
+            let _ = crate::InitiatorProjects::<Test>::clear(u32::MAX, None);
+
+            let mut weight = <Weight as Default>::default();
+            v16::migrate_initiator_projects::<Test>(&mut weight);
+
+            assert_eq!(
+                crate::InitiatorProjects::<Test>::iter_key_prefix(ALICE).collect::<Vec<_>>(),
+                vec![project_key]
+            );
+            assert_eq!(
+                crate::InitiatorProjects::<Test>::iter_key_prefix(BOB).collect::<Vec<_>>(),
+                vec![other_project_key]
+            );
         })
     }
 }
@@ -156,13 +156,26 @@ parameter_types! {
     pub RefundsPerBlock: u8 = 2;
     pub IsIdentityRequired: bool = false;
     pub MilestoneVotingWindow: BlockNumber  =  100800u64;
+    pub NoConfidenceVotingWindow: BlockNumber = 100800u64;
+    pub PercentRequiredForVoteNoConfidenceToPass: Percent = Percent::from_percent(75u8);
     pub MaxMilestonesPerProject: u32 = 10;
     pub ImbueFee: Percent = Percent::from_percent(5u8);
     pub ExpiringProjectRoundsPerBlock: u32 = 10;
     pub ProjectStorageItem: StorageItems = StorageItems::Project;
+    pub DisputeStorageItem: StorageItems = StorageItems::Dispute;
     pub MaxProjectsPerAccount: u16 = 50;
     pub MaxJuryMembers: u32 = 100;
+    pub MaxDisputesPerJuror: u32 = 50;
     pub ImbueFeeAccount: AccountId = TREASURY;
+    pub MinimumJurySize: u32 = 2;
+    pub ArbitrationWindow: BlockNumber = 100;
+}
+
+parameter_types! {
+    pub static DefaultResubmissionPolicy: ResubmissionPolicy<BlockNumber> =
+        ResubmissionPolicy { cooldown: 0, max_attempts: MAX_MILESTONE_ATTEMPTS };
+    pub static DefaultVotingExpiryPolicy: VotingExpiryPolicy = VotingExpiryPolicy::Reject;
+    pub static MaxJuriesPerAccount: u32 = 50;
 }
 
 impl pallet_proposals::Config for Test {
@@ -173,6 +186,8 @@ impl pallet_proposals::Config for Test {
     type PercentRequiredForVoteToPass = PercentRequiredForVoteToPass;
     type MaximumContributorsPerProject = MaximumContributorsPerProject;
     type MilestoneVotingWindow = MilestoneVotingWindow;
+    type NoConfidenceVotingWindow = NoConfidenceVotingWindow;
+    type PercentRequiredForVoteNoConfidenceToPass = PercentRequiredForVoteNoConfidenceToPass;
     type ExternalRefundHandler = pallet_proposals::traits::MockRefundHandler<Test>;
     type MaxMilestonesPerProject = MaxMilestonesPerProject;
     type ImbueFee = ImbueFee;
@@ -180,10 +195,18 @@ impl pallet_proposals::Config for Test {
     type ExpiringProjectRoundsPerBlock = ExpiringProjectRoundsPerBlock;
     type DepositHandler = MockDepositHandler;
     type ProjectStorageItem = ProjectStorageItem;
+    type DisputeStorageItem = DisputeStorageItem;
     type MaxProjectsPerAccount = MaxProjectsPerAccount;
     type DisputeRaiser = MockDisputeRaiser;
     type JurySelector = MockJurySelector;
+    type MaxJuriesPerAccount = MaxJuriesPerAccount;
     type AssetSignerOrigin = EnsureRoot<AccountId>;
+    type MinimumJurySize = MinimumJurySize;
+    type ArbitrationOrigin = EnsureRoot<AccountId>;
+    type ArbitrationWindow = ArbitrationWindow;
+    type DefaultResubmissionPolicy = DefaultResubmissionPolicy;
+    type DefaultVotingExpiryPolicy = DefaultVotingExpiryPolicy;
+    type ProjectPolicyOrigin = EnsureRoot<AccountId>;
 }
 
 parameter_types! {
@@ -254,6 +277,17 @@ pub(crate) fn build_test_externality() -> sp_io::TestExternalities {
 #[derive(Encode, Decode, PartialEq, Eq, Clone, Debug, MaxEncodedLen, TypeInfo, Copy)]
 pub enum StorageItems {
     Project,
+    Dispute,
+}
+
+parameter_types! {
+    pub static TakenDeposits: Vec<(AccountId, StorageItems)> = vec![];
+    pub static ReturnedDeposits: Vec<u64> = vec![];
+    pub static SlashedDeposits: Vec<u64> = vec![];
+    pub static DepositsFail: bool = false;
+    pub static DisputeBonds: Vec<(DisputeId, Option<u64>)> = vec![];
+    pub static DisputeJuries: Vec<(DisputeId, Vec<AccountId>)> = vec![];
+    pub static JuryPool: Vec<AccountId> = vec![];
 }
 
 pub struct MockDepositHandler;
@@ -261,32 +295,68 @@ impl DepositHandler<Balance, AccountId> for MockDepositHandler {
     type DepositId = u64;
     type StorageItem = StorageItems;
     fn take_deposit(
-        _who: AccountId,
-        _storage_item: Self::StorageItem,
+        who: AccountId,
+        storage_item: Self::StorageItem,
         _currency_id: CurrencyId,
     ) -> Result<Self::DepositId, DispatchError> {
-        Ok(0u64)
+        TakenDeposits::mutate(|taken| taken.push((who, storage_item)));
+        Ok(TakenDeposits::get().len() as u64 - 1)
     }
-    fn return_deposit(_deposit_id: Self::DepositId) -> DispatchResult {
+    fn return_deposit(deposit_id: Self::DepositId) -> DispatchResult {
+        if DepositsFail::get() {
+            return Err(DispatchError::Other("deposit failed"));
+        }
+        ReturnedDeposits::mutate(|returned| returned.push(deposit_id));
         Ok(())
     }
-    fn slash_reserve_deposit(_deposit_id: Self::DepositId) -> DispatchResult {
+    fn slash_reserve_deposit(deposit_id: Self::DepositId) -> DispatchResult {
+        if DepositsFail::get() {
+            return Err(DispatchError::Other("deposit failed"));
+        }
+        SlashedDeposits::mutate(|slashed| slashed.push(deposit_id));
         Ok(())
     }
 }
 
 pub struct MockDisputeRaiser;
 impl DisputeRaiser<AccountId> for MockDisputeRaiser {
-    type DisputeKey = ProjectKey;
+    type DisputeKey = DisputeId;
     type SpecificId = MilestoneKey;
     type MaxJurySize = MaxJuryMembers;
+    type MaxDisputesPerJuror = MaxDisputesPerJuror;
     type MaxSpecifics = MaxMilestonesPerProject;
+    type BondId = u64;
     fn raise_dispute(
-        _dispute_key: Self::DisputeKey,
+        dispute_key: Self::DisputeKey,
         _raised_by: AccountId,
-        _jury: BoundedVec<AccountId, Self::MaxJurySize>,
+        jury: BoundedVec<AccountId, Self::MaxJurySize>,
         _specific_ids: BoundedVec<Self::SpecificId, Self::MaxSpecifics>,
+        bond: Option<Self::BondId>,
+    ) -> Result<(), DispatchError> {
+        DisputeBonds::mutate(|bonds| bonds.push((dispute_key, bond)));
+        DisputeJuries::mutate(|juries| juries.push((dispute_key, jury.into_inner())));
+        Ok(())
+    }
+    fn disputes_of_juror(juror: &AccountId) -> Vec<Self::DisputeKey> {
+        DisputeJuries::get()
+            .into_iter()
+            .filter(|(_, jury)| jury.contains(juror))
+            .map(|(dispute_key, _)| dispute_key)
+            .collect()
+    }
+    fn replace_juror(
+        dispute_key: Self::DisputeKey,
+        juror: &AccountId,
+        replacement: Option<AccountId>,
     ) -> Result<(), DispatchError> {
+        DisputeJuries::mutate(|juries| {
+            for (key, jury) in juries.iter_mut() {
+                if *key == dispute_key {
+                    jury.retain(|member| member != juror);
+                    jury.extend(replacement);
+                }
+            }
+        });
         Ok(())
     }
 }
@@ -294,7 +364,7 @@ impl DisputeRaiser<AccountId> for MockDisputeRaiser {
 pub struct MockJurySelector;
 impl pallet_fellowship::traits::SelectJury<AccountId> for MockJurySelector {
     type JurySize = MaxJuryMembers;
-    fn select_jury() -> BoundedVec<AccountId, Self::JurySize> {
-        BoundedVec::new()
+    fn select_jury(amount: u32) -> BoundedVec<AccountId, Self::JurySize> {
+        BoundedVec::truncate_from(JuryPool::get().into_iter().take(amount as usize).collect())
     }
 }
@@ -46,16 +46,18 @@ pub fn get_contributions<T: Config>(
     contributions
 }
 
-pub fn get_milestones(n: u8) -> Vec<ProposedMilestone> {
+pub fn get_milestones<BlockNumber>(n: u8) -> Vec<ProposedMilestone<BlockNumber>> {
     (0..n)
         .map(|_| ProposedMilestone {
             percentage_to_unlock: Percent::from_percent(100u8 / n),
+            content_hash: Default::default(),
+            due_block: None,
         })
-        .collect::<Vec<ProposedMilestone>>()
+        .collect::<Vec<ProposedMilestone<BlockNumber>>>()
 }
 
 #[cfg(feature = "runtime-benchmarks")]
-pub fn get_max_milestones<T: Config>() -> Vec<ProposedMilestone> {
+pub fn get_max_milestones<T: Config>() -> Vec<ProposedMilestone<BlockNumberFor<T>>> {
     get_milestones(<T as Config>::MaxMilestonesPerProject::get() as u8)
 }
 
@@ -65,7 +67,7 @@ pub fn get_max_milestones<T: Config>() -> Vec<ProposedMilestone> {
 pub fn create_and_fund_project<T: Config>(
     beneficiary: AccountIdOf<T>,
     contributions: ContributionsFor<T>,
-    proposed_milestones: Vec<ProposedMilestone>,
+    proposed_milestones: Vec<ProposedMilestone<BlockNumberFor<T>>>,
     currency_id: CurrencyId,
     jury: Vec<AccountIdOf<T>>,
 ) -> Result<ProjectKey, DispatchError> {
@@ -97,12 +99,13 @@ pub fn create_and_fund_project<T: Config>(
 
 // For testing grants and errors pre funding
 // TODO: tests for these!
-pub fn _create_project_awaiting_funding<T: Config>(
+pub fn create_project_awaiting_funding<T: Config>(
     beneficiary: AccountIdOf<T>,
     contributions: ContributionsFor<T>,
-    proposed_milestones: Vec<ProposedMilestone>,
+    proposed_milestones: Vec<ProposedMilestone<BlockNumberFor<T>>>,
     currency_id: CurrencyId,
     treasury_account: MultiLocation,
+    jury: Vec<AccountIdOf<T>>,
 ) -> Result<ProjectKey, DispatchError> {
     let agreement_hash: H256 = Default::default();
     // Reserve the assets from the contributors used.
@@ -113,7 +116,7 @@ pub fn _create_project_awaiting_funding<T: Config>(
         beneficiary,
         proposed_milestones.try_into().map_err(|_|Error::<T>::TooManyMilestones)?,
         vec![(Locality::Foreign(treasury_account), Percent::from_parts(100u8))].try_into().map_err(|_|Error::<T>::TooManyRefundLocations)?,
-        BoundedVec::new(),
+        jury.try_into().map_err(|_|Error::<T>::TooManyJuryMembers)?,
         FundingPath::WaitForFunding,
         None,
     )?;
@@ -139,12 +142,22 @@ pub fn create_funded_user<T: Config>(
 }
 
 /// Manually call the hook OnDisputeCompleteWith a predefined result for testing>
+/// A dispute is registered for the milestones if they are not already in one.
 pub fn complete_dispute<T: Config>(
     project_key: ProjectKey,
     milestone_keys: Vec<MilestoneKey>,
     result: pallet_disputes::DisputeResult,
+    bond: Option<DepositIdOf<T>>,
 ) -> crate::Weight {
-    <crate::Pallet<T>>::on_dispute_complete(project_key, milestone_keys, result)
+    let dispute_id = milestone_keys
+        .first()
+        .and_then(|milestone_key| MilestoneDisputes::<T>::get(project_key, milestone_key))
+        .unwrap_or_else(|| crate::Pallet::<T>::register_dispute(project_key, &milestone_keys));
+    let verdicts = milestone_keys
+        .into_iter()
+        .map(|milestone_key| (milestone_key, result))
+        .collect();
+    <crate::Pallet<T>>::on_dispute_complete(dispute_id, verdicts, bond)
 }
 
 pub fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
@@ -0,0 +1,338 @@
+use crate::{mock::*, *};
+use frame_support::{assert_noop, assert_ok};
+use test_utils::*;
+
+fn create_project_in_voting() -> ProjectKey {
+    let contributions = get_contributions::<Test>(vec![BOB, CHARLIE, DAVE], 100_000u128);
+    let project_key = create_and_fund_project::<Test>(
+        ALICE,
+        contributions,
+        get_milestones(10),
+        CurrencyId::Native,
+        vec![JURY_1, JURY_2],
+    )
+    .unwrap();
+    assert_ok!(Proposals::submit_milestone(
+        RuntimeOrigin::signed(ALICE),
+        project_key,
+        0,
+        None
+    ));
+    project_key
+}
+
+fn milestone_yay(project_key: ProjectKey) -> Balance {
+    MilestoneVotes::<Test>::get(project_key)
+        .get(&0)
+        .expect("milestone is in voting")
+        .yay
+}
+
+#[test]
+fn delegate_vote_works() {
+    build_test_externality().execute_with(|| {
+        let project_key = create_project_in_voting();
+        let scope = DelegationScope::Project(project_key);
+        assert_ok!(Proposals::delegate_vote(
+            RuntimeOrigin::signed(BOB),
+            scope,
+            CHARLIE
+        ));
+        assert_eq!(VoteDelegations::<Test>::get(BOB, scope), Some(CHARLIE));
+        assert_last_event::<Test>(
+            Event::<Test>::VoteDelegated {
+                who: BOB,
+                delegate: CHARLIE,
+                scope,
+            }
+            .into(),
+        );
+    })
+}
+
+#[test]
+fn delegate_vote_to_self_fails() {
+    build_test_externality().execute_with(|| {
+        assert_noop!(
+            Proposals::delegate_vote(
+                RuntimeOrigin::signed(BOB),
+                DelegationScope::AllProjects,
+                BOB
+            ),
+            Error::<Test>::CannotDelegateToSelf
+        );
+    })
+}
+
+#[test]
+fn delegate_vote_on_a_project_requires_a_contribution() {
+    build_test_externality().execute_with(|| {
+        let project_key = create_project_in_voting();
+        assert_noop!(
+            Proposals::delegate_vote(
+                RuntimeOrigin::signed(JOHN),
+                DelegationScope::Project(project_key),
+                BOB
+            ),
+            Error::<Test>::OnlyContributorsCanDelegate
+        );
+        assert_noop!(
+            Proposals::delegate_vote(
+                RuntimeOrigin::signed(JOHN),
+                DelegationScope::Project(project_key + 1),
+                BOB
+            ),
+            Error::<Test>::ProjectDoesNotExist
+        );
+        assert_ok!(Proposals::delegate_vote(
+            RuntimeOrigin::signed(JOHN),
+            DelegationScope::AllProjects,
+            BOB
+        ));
+    })
+}
+
+#[test]
+fn revoke_delegation_works() {
+    build_test_externality().execute_with(|| {
+        let scope = DelegationScope::AllProjects;
+        assert_noop!(
+            Proposals::revoke_delegation(RuntimeOrigin::signed(BOB), scope),
+            Error::<Test>::DelegationNotFound
+        );
+        assert_ok!(Proposals::delegate_vote(
+            RuntimeOrigin::signed(BOB),
+            scope,
+            CHARLIE
+        ));
+        assert_ok!(Proposals::revoke_delegation(
+            RuntimeOrigin::signed(BOB),
+            scope
+        ));
+        assert!(VoteDelegations::<Test>::get(BOB, scope).is_none());
+        assert_last_event::<Test>(Event::<Test>::DelegationRevoked { who: BOB, scope }.into());
+    })
+}
+
+#[test]
+fn vote_on_milestone_counts_delegated_weight() {
+    build_test_externality().execute_with(|| {
+        let project_key = create_project_in_voting();
+        assert_ok!(Proposals::delegate_vote(
+            RuntimeOrigin::signed(BOB),
+            DelegationScope::Project(project_key),
+            CHARLIE
+        ));
+        assert_ok!(Proposals::vote_on_milestone(
+            RuntimeOrigin::signed(CHARLIE),
+            project_key,
+            0,
+            true
+        ));
+        assert_eq!(milestone_yay(project_key), 200_000);
+
+        let individual_votes = IndividualVoteStore::<Test>::get(project_key).unwrap();
+        let votes = individual_votes.as_ref().get(&0).unwrap();
+        assert_eq!(votes.get(&BOB), Some(&true));
+        assert_eq!(votes.get(&CHARLIE), Some(&true));
+        let delegated = individual_votes.delegated_votes().get(&0).unwrap();
+        assert_eq!(delegated.get(&BOB), Some(&CHARLIE));
+        assert_eq!(delegated.len(), 1);
+    })
+}
+
+#[test]
+fn vote_on_milestone_delegated_weight_can_approve_a_milestone() {
+    build_test_externality().execute_with(|| {
+        let project_key = create_project_in_voting();
+        for delegator in [BOB, DAVE] {
+            assert_ok!(Proposals::delegate_vote(
+                RuntimeOrigin::signed(delegator),
+                DelegationScope::AllProjects,
+                CHARLIE
+            ));
+        }
+        assert_ok!(Proposals::vote_on_milestone(
+            RuntimeOrigin::signed(CHARLIE),
+            project_key,
+            0,
+            true
+        ));
+        assert!(
+            Projects::<Test>::get(project_key)
+                .unwrap()
+                .milestones
+                .get(&0)
+                .unwrap()
+                .is_approved
+        );
+    })
+}
+
+#[test]
+fn vote_on_milestone_delegators_that_voted_keep_their_vote() {
+    build_test_externality().execute_with(|| {
+        let project_key = create_project_in_voting();
+        assert_ok!(Proposals::delegate_vote(
+            RuntimeOrigin::signed(BOB),
+            DelegationScope::AllProjects,
+            CHARLIE
+        ));
+        assert_ok!(Proposals::vote_on_milestone(
+            RuntimeOrigin::signed(BOB),
+            project_key,
+            0,
+            false
+        ));
+        assert_ok!(Proposals::vote_on_milestone(
+            RuntimeOrigin::signed(CHARLIE),
+            project_key,
+            0,
+            true
+        ));
+        assert_eq!(milestone_yay(project_key), 100_000);
+        let individual_votes = IndividualVoteStore::<Test>::get(project_key).unwrap();
+        assert_eq!(
+            individual_votes.as_ref().get(&0).unwrap().get(&BOB),
+            Some(&false)
+        );
+        assert!(individual_votes
+            .delegated_votes()
+            .get(&0)
+            .unwrap()
+            .is_empty());
+    })
+}
+
+#[test]
+fn vote_on_milestone_delegator_cannot_vote_after_their_delegate() {
+    build_test_externality().execute_with(|| {
+        let project_key = create_project_in_voting();
+        assert_ok!(Proposals::delegate_vote(
+            RuntimeOrigin::signed(BOB),
+            DelegationScope::AllProjects,
+            CHARLIE
+        ));
+        assert_ok!(Proposals::vote_on_milestone(
+            RuntimeOrigin::signed(CHARLIE),
+            project_key,
+            0,
+            true
+        ));
+        assert_noop!(
+            Proposals::vote_on_milestone(RuntimeOrigin::signed(BOB), project_key, 0, false),
+            Error::<Test>::VotesAreImmutable
+        );
+    })
+}
+
+#[test]
+fn vote_on_milestone_delegate_does_not_need_a_contribution() {
+    build_test_externality().execute_with(|| {
+        let project_key = create_project_in_voting();
+        assert_noop!(
+            Proposals::vote_on_milestone(RuntimeOrigin::signed(JOHN), project_key, 0, true),
+            Error::<Test>::OnlyContributorsCanVote
+        );
+        assert_ok!(Proposals::delegate_vote(
+            RuntimeOrigin::signed(BOB),
+            DelegationScope::Project(project_key),
+            JOHN
+        ));
+        assert_ok!(Proposals::vote_on_milestone(
+            RuntimeOrigin::signed(JOHN),
+            project_key,
+            0,
+            true
+        ));
+        assert_eq!(milestone_yay(project_key), 100_000);
+        let individual_votes = IndividualVoteStore::<Test>::get(project_key).unwrap();
+        assert!(!individual_votes
+            .as_ref()
+            .get(&0)
+            .unwrap()
+            .contains_key(&JOHN));
+
+        assert_noop!(
+            Proposals::vote_on_milestone(RuntimeOrigin::signed(JOHN), project_key, 0, true),
+            Error::<Test>::VotesAreImmutable
+        );
+    })
+}
+
+#[test]
+fn vote_on_milestone_project_delegation_takes_precedence() {
+    build_test_externality().execute_with(|| {
+        let project_key = create_project_in_voting();
+        assert_ok!(Proposals::delegate_vote(
+            RuntimeOrigin::signed(BOB),
+            DelegationScope::AllProjects,
+            DAVE
+        ));
+        assert_ok!(Proposals::delegate_vote(
+            RuntimeOrigin::signed(BOB),
+            DelegationScope::Project(project_key),
+            CHARLIE
+        ));
+        assert_ok!(Proposals::vote_on_milestone(
+            RuntimeOrigin::signed(DAVE),
+            project_key,
+            0,
+            true
+        ));
+        assert_eq!(milestone_yay(project_key), 100_000);
+
+        let project = Projects::<Test>::get(project_key).unwrap();
+        assert!(Proposals::vote_delegators(project_key, &project, &DAVE).is_empty());
+        assert_eq!(
+            Proposals::vote_delegators(project_key, &project, &CHARLIE),
+            vec![(BOB, 100_000)]
+        );
+    })
+}
+
+#[test]
+fn vote_on_milestone_revoked_delegation_is_not_counted() {
+    build_test_externality().execute_with(|| {
+        let project_key = create_project_in_voting();
+        assert_ok!(Proposals::delegate_vote(
+            RuntimeOrigin::signed(BOB),
+            DelegationScope::AllProjects,
+            CHARLIE
+        ));
+        assert_ok!(Proposals::revoke_delegation(
+            RuntimeOrigin::signed(BOB),
+            DelegationScope::AllProjects
+        ));
+        assert_ok!(Proposals::vote_on_milestone(
+            RuntimeOrigin::signed(CHARLIE),
+            project_key,
+            0,
+            true
+        ));
+        assert_eq!(milestone_yay(project_key), 100_000);
+    })
+}
+
+#[test]
+fn project_info_lists_delegated_votes() {
+    build_test_externality().execute_with(|| {
+        let project_key = create_project_in_voting();
+        assert_ok!(Proposals::delegate_vote(
+            RuntimeOrigin::signed(BOB),
+            DelegationScope::AllProjects,
+            CHARLIE
+        ));
+        assert_ok!(Proposals::vote_on_milestone(
+            RuntimeOrigin::signed(CHARLIE),
+            project_key,
+            0,
+            false
+        ));
+
+        let info = Proposals::project_info(project_key).unwrap();
+        let milestone = &info.milestones[0];
+        assert_eq!(milestone.votes, vec![(BOB, false), (CHARLIE, false)]);
+        assert_eq!(milestone.delegated_votes, vec![(BOB, CHARLIE)]);
+    })
+}
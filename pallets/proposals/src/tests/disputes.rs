@@ -1,9 +1,12 @@
 use crate::{mock::*, *};
 use common_types::CurrencyId;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_err, assert_noop, assert_ok, error::BadOrigin};
 use pallet_disputes::DisputeResult;
+use pallet_fellowship::traits::FellowshipHooks;
 use test_utils::*;
 
+use super::pallet::run_to_block;
+
 #[test]
 fn raise_dispute_not_contributor() {
     build_test_externality().execute_with(|| {
@@ -126,6 +129,39 @@ fn raise_dispute_invalid_milestone_key() {
     })
 }
 
+#[test]
+fn raise_dispute_rejects_empty_and_duplicate_milestone_keys() {
+    build_test_externality().execute_with(|| {
+        let contributions = get_contributions::<Test>(vec![BOB, CHARLIE], 1_000_000u128);
+        let milestones = get_milestones(10);
+        let project_key = create_and_fund_project::<Test>(
+            ALICE,
+            contributions,
+            milestones,
+            CurrencyId::Native,
+            vec![JURY_1, JURY_2],
+        )
+        .unwrap();
+
+        assert_noop!(
+            Proposals::raise_dispute(
+                RuntimeOrigin::signed(BOB),
+                project_key,
+                vec![].try_into().unwrap()
+            ),
+            Error::<Test>::NoMilestonesToDispute
+        );
+        assert_noop!(
+            Proposals::raise_dispute(
+                RuntimeOrigin::signed(BOB),
+                project_key,
+                vec![0u32, 1, 0].try_into().unwrap()
+            ),
+            Error::<Test>::DuplicateMilestoneKeys
+        );
+    })
+}
+
 #[test]
 fn raise_dispute_cant_raise_on_approved_milestone() {
     build_test_externality().execute_with(|| {
@@ -146,7 +182,8 @@ fn raise_dispute_cant_raise_on_approved_milestone() {
         assert_ok!(Proposals::submit_milestone(
             RuntimeOrigin::signed(ALICE),
             project_key,
-            submitted_milestone_key
+            submitted_milestone_key,
+            None
         ));
         assert_ok!(Proposals::vote_on_milestone(
             RuntimeOrigin::signed(BOB),
@@ -209,8 +246,13 @@ fn on_dispute_complete_success_removes_dispute_status() {
             project_key,
             milestone_keys.into_inner(),
             DisputeResult::Success,
+            None,
+        );
+        assert_eq!(
+            MilestoneDisputes::<Test>::iter_prefix(project_key).count(),
+            0
         );
-        assert!(!ProjectsInDispute::<Test>::contains_key(project_key));
+        assert!(Proposals::project_disputes(project_key).is_empty());
     })
 }
 
@@ -243,8 +285,13 @@ fn on_dispute_complete_failure_removes_dispute_status() {
             project_key,
             milestone_keys.into_inner(),
             DisputeResult::Failure,
+            None,
         );
-        assert!(!ProjectsInDispute::<Test>::contains_key(project_key));
+        assert_eq!(
+            MilestoneDisputes::<Test>::iter_prefix(project_key).count(),
+            0
+        );
+        assert!(Proposals::project_disputes(project_key).is_empty());
     })
 }
 
@@ -278,6 +325,7 @@ fn dispute_success_does_not_cancel_project() {
             project_key,
             milestone_keys.into_inner(),
             DisputeResult::Success,
+            None,
         );
 
         let project = Projects::<Test>::get(project_key).unwrap();
@@ -315,6 +363,7 @@ fn dispute_success_approves_milestone_for_refund_but_only_ones_specified() {
             project_key,
             milestone_keys.into_inner(),
             DisputeResult::Success,
+            None,
         );
     })
 }
@@ -339,7 +388,8 @@ fn raise_dispute_allows_milestone_voting() {
         assert_ok!(Proposals::submit_milestone(
             RuntimeOrigin::signed(ALICE),
             project_key,
-            submitted_milestone_key
+            submitted_milestone_key,
+            None
         ));
         assert_ok!(Proposals::vote_on_milestone(
             RuntimeOrigin::signed(BOB),
@@ -387,7 +437,8 @@ fn raise_dispute_allows_milestone_voting_on_non_disputed_milestones() {
         assert_ok!(Proposals::submit_milestone(
             RuntimeOrigin::signed(ALICE),
             project_key,
-            submitted_milestone_keys[0]
+            submitted_milestone_keys[0],
+            None
         ));
 
         let dispute_milestone_keys: BoundedVec<u32, <Test as Config>::MaxMilestonesPerProject> =
@@ -404,7 +455,8 @@ fn raise_dispute_allows_milestone_voting_on_non_disputed_milestones() {
         assert_ok!(Proposals::submit_milestone(
             RuntimeOrigin::signed(ALICE),
             project_key,
-            submitted_milestone_keys[1]
+            submitted_milestone_keys[1],
+            None
         ));
 
         assert_ok!(Proposals::vote_on_milestone(
@@ -452,7 +504,8 @@ fn raise_dispute_allows_submission() {
         assert_ok!(Proposals::submit_milestone(
             RuntimeOrigin::signed(ALICE),
             project_key,
-            milestone_key
+            milestone_key,
+            None
         ));
     })
 }
@@ -488,6 +541,7 @@ fn failed_dispute_tests() {
             project_key,
             dispute_milestone_keys.into_inner(),
             DisputeResult::Failure,
+            None,
         );
 
         // just gonna assert that the milestones arnt approved for refund.
@@ -528,6 +582,7 @@ fn assert_can_recall_dispute_after_success() {
             project_key,
             milestone_keys.into_inner(),
             DisputeResult::Success,
+            None,
         );
         let milestone_keys: BoundedVec<u32, <Test as Config>::MaxMilestonesPerProject> =
             (5u32..10_u32).collect::<Vec<u32>>().try_into().unwrap();
@@ -567,6 +622,7 @@ fn assert_can_recall_dispute_after_failure() {
             project_key,
             milestone_keys.into_inner(),
             DisputeResult::Failure,
+            None,
         );
         let milestone_keys: BoundedVec<u32, <Test as Config>::MaxMilestonesPerProject> =
             (5u32..10_u32).collect::<Vec<u32>>().try_into().unwrap();
@@ -579,7 +635,7 @@ fn assert_can_recall_dispute_after_failure() {
 }
 
 #[test]
-fn raise_dispute_with_single_jury_auto_completes() {
+fn raise_dispute_with_single_jury_goes_to_arbitration() {
     build_test_externality().execute_with(|| {
         let contributions = get_contributions::<Test>(vec![BOB, CHARLIE], 1_000_000u128);
         let milestones = get_milestones(10);
@@ -606,24 +662,956 @@ fn raise_dispute_with_single_jury_auto_completes() {
             milestone_keys.clone()
         ));
 
+        let dispute_id = MilestoneDisputes::<Test>::get(project_key, 0).unwrap();
+        let arbitration = Arbitrations::<Test>::get(dispute_id).unwrap();
+        assert_eq!(arbitration.project_key, project_key);
+        assert_eq!(arbitration.milestone_keys, milestone_keys);
+        assert!(Rounds::<Test>::contains_key(
+            (project_key, 0),
+            RoundType::Arbitration
+        ));
+        System::assert_last_event(RuntimeEvent::Proposals(
+            Event::<Test>::DisputeSentToArbitration {
+                dispute_id,
+                project_key,
+            },
+        ));
+
         let project = Projects::<Test>::get(project_key).unwrap();
+        project.milestones.iter().for_each(|(_key, ms)| {
+            assert!(
+                !ms.can_refund,
+                "Nothing should be refundable until the arbitration is decided."
+            );
+            assert_eq!(ms.transfer_status, None)
+        });
+    })
+}
+
+#[test]
+fn raise_dispute_takes_bond_from_raiser() {
+    build_test_externality().execute_with(|| {
+        let contributions = get_contributions::<Test>(vec![BOB, CHARLIE], 1_000_000u128);
+        let milestones = get_milestones(10);
+        let jury = vec![JURY_1, JURY_2];
+        let project_key = create_and_fund_project::<Test>(
+            ALICE,
+            contributions,
+            milestones,
+            CurrencyId::Native,
+            jury,
+        )
+        .unwrap();
+        let milestone_keys: BoundedVec<u32, <Test as Config>::MaxMilestonesPerProject> =
+            vec![0u32].try_into().unwrap();
+        assert_ok!(Proposals::raise_dispute(
+            RuntimeOrigin::signed(BOB),
+            project_key,
+            milestone_keys
+        ));
+        let (who, storage_item) = *TakenDeposits::get().last().unwrap();
+        assert_eq!(who, BOB);
+        assert_eq!(storage_item, StorageItems::Dispute);
+        let bond_id = TakenDeposits::get().len() as u64 - 1;
+        assert_eq!(
+            DisputeBonds::get().last(),
+            Some(&(DisputeCount::<Test>::get(), Some(bond_id)))
+        );
+    })
+}
+
+#[test]
+fn raise_dispute_with_single_jury_takes_bond_for_arbitration() {
+    build_test_externality().execute_with(|| {
+        let contributions = get_contributions::<Test>(vec![BOB, CHARLIE], 1_000_000u128);
+        let milestones = get_milestones(10);
+        let project_key = create_and_fund_project::<Test>(
+            ALICE,
+            contributions,
+            milestones,
+            CurrencyId::Native,
+            vec![JURY_1],
+        )
+        .unwrap();
+        let milestone_keys: BoundedVec<u32, <Test as Config>::MaxMilestonesPerProject> =
+            vec![0u32].try_into().unwrap();
+        assert_ok!(Proposals::raise_dispute(
+            RuntimeOrigin::signed(BOB),
+            project_key,
+            milestone_keys
+        ));
+        let (who, storage_item) = *TakenDeposits::get().last().unwrap();
+        assert_eq!(who, BOB);
+        assert_eq!(storage_item, StorageItems::Dispute);
+        let dispute_id = MilestoneDisputes::<Test>::get(project_key, 0).unwrap();
+        assert_eq!(
+            Arbitrations::<Test>::get(dispute_id).unwrap().bond,
+            TakenDeposits::get().len() as u64 - 1
+        );
+        // The dispute pallet is never involved.
+        assert!(DisputeBonds::get().is_empty());
+    })
+}
+
+#[test]
+fn dispute_success_returns_bond() {
+    build_test_externality().execute_with(|| {
+        let contributions = get_contributions::<Test>(vec![BOB, CHARLIE], 1_000_000u128);
+        let milestones = get_milestones(10);
+        let project_key = create_and_fund_project::<Test>(
+            ALICE,
+            contributions,
+            milestones,
+            CurrencyId::Native,
+            vec![JURY_1, JURY_2],
+        )
+        .unwrap();
+        let _ = complete_dispute::<Test>(project_key, vec![0], DisputeResult::Success, Some(7));
+        assert_eq!(ReturnedDeposits::get(), vec![7]);
+        assert!(SlashedDeposits::get().is_empty());
+    })
+}
+
+#[test]
+fn dispute_failure_slashes_bond() {
+    build_test_externality().execute_with(|| {
+        let contributions = get_contributions::<Test>(vec![BOB, CHARLIE], 1_000_000u128);
+        let milestones = get_milestones(10);
+        let project_key = create_and_fund_project::<Test>(
+            ALICE,
+            contributions,
+            milestones,
+            CurrencyId::Native,
+            vec![JURY_1, JURY_2],
+        )
+        .unwrap();
+        let _ = complete_dispute::<Test>(project_key, vec![0], DisputeResult::Failure, Some(7));
+        assert_eq!(SlashedDeposits::get(), vec![7]);
+        assert!(ReturnedDeposits::get().is_empty());
+    })
+}
+
+#[test]
+fn unsettled_dispute_bond_is_reported() {
+    build_test_externality().execute_with(|| {
+        let contributions = get_contributions::<Test>(vec![BOB, CHARLIE], 1_000_000u128);
+        let milestones = get_milestones(10);
+        let project_key = create_and_fund_project::<Test>(
+            ALICE,
+            contributions,
+            milestones,
+            CurrencyId::Native,
+            vec![JURY_1, JURY_2],
+        )
+        .unwrap();
+        let dispute_id = Proposals::register_dispute(project_key, &[0]);
+        DepositsFail::set(true);
+        let _ = complete_dispute::<Test>(project_key, vec![0], DisputeResult::Failure, Some(7));
+        assert!(SlashedDeposits::get().is_empty());
+        System::assert_has_event(RuntimeEvent::Proposals(Event::<Test>::DisputeBondFailed {
+            dispute_id,
+            error: DispatchError::Other("deposit failed"),
+        }));
+        // The verdicts are still applied.
+        assert!(MilestoneDisputes::<Test>::get(project_key, 0).is_none());
+    })
+}
+
+#[test]
+fn mixed_verdicts_only_refund_upheld_milestones() {
+    build_test_externality().execute_with(|| {
+        let contributions = get_contributions::<Test>(vec![BOB, CHARLIE], 1_000_000u128);
+        let milestones = get_milestones(10);
+        let project_key = create_and_fund_project::<Test>(
+            ALICE,
+            contributions,
+            milestones,
+            CurrencyId::Native,
+            vec![JURY_1, JURY_2],
+        )
+        .unwrap();
+        let milestone_keys: BoundedVec<u32, <Test as Config>::MaxMilestonesPerProject> =
+            vec![2u32, 3u32].try_into().unwrap();
+        assert_ok!(Proposals::raise_dispute(
+            RuntimeOrigin::signed(BOB),
+            project_key,
+            milestone_keys
+        ));
+        let dispute_id = MilestoneDisputes::<Test>::get(project_key, 2).unwrap();
+        let _ = <Proposals as pallet_disputes::traits::DisputeHooks<
+            DisputeId,
+            MilestoneKey,
+            u64,
+        >>::on_dispute_complete(
+            dispute_id,
+            vec![(2, DisputeResult::Success), (3, DisputeResult::Failure)],
+            Some(7),
+        );
 
+        let project = Projects::<Test>::get(project_key).unwrap();
         project.milestones.iter().for_each(|(key, ms)| {
-            // if its meant to be approved for refund then check
-            if milestone_keys.contains(key) {
-                assert!(
-                    ms.can_refund,
-                    "the dispute should have bypassed dispute pallet and autofinalised."
-                );
-            } else {
-                assert!(
-                    !ms.can_refund,
-                    "This milestone should not have been approved for refund."
-                );
-            }
+            assert_eq!(ms.can_refund, *key == 2);
+        });
+        assert!(!MilestoneDisputes::<Test>::contains_key(project_key, 2));
+        assert!(!MilestoneDisputes::<Test>::contains_key(project_key, 3));
+        assert!(!DisputeProjects::<Test>::contains_key(dispute_id));
+        // A milestone was upheld so the bond is returned.
+        assert_eq!(ReturnedDeposits::get(), vec![7]);
+    })
+}
 
-            // They havent been transferred yet
-            assert_eq!(ms.transfer_status, None)
+#[test]
+fn separate_milestones_can_be_disputed_in_parallel() {
+    build_test_externality().execute_with(|| {
+        let contributions = get_contributions::<Test>(vec![BOB, CHARLIE], 1_000_000u128);
+        let milestones = get_milestones(10);
+        let project_key = create_and_fund_project::<Test>(
+            ALICE,
+            contributions,
+            milestones,
+            CurrencyId::Native,
+            vec![JURY_1, JURY_2],
+        )
+        .unwrap();
+        assert_ok!(Proposals::raise_dispute(
+            RuntimeOrigin::signed(BOB),
+            project_key,
+            vec![0u32, 1u32].try_into().unwrap()
+        ));
+        assert_ok!(Proposals::raise_dispute(
+            RuntimeOrigin::signed(CHARLIE),
+            project_key,
+            vec![2u32].try_into().unwrap()
+        ));
+        let first = MilestoneDisputes::<Test>::get(project_key, 0).unwrap();
+        let second = MilestoneDisputes::<Test>::get(project_key, 2).unwrap();
+        assert_ne!(first, second);
+        assert_eq!(MilestoneDisputes::<Test>::get(project_key, 1), Some(first));
+        assert_eq!(DisputeProjects::<Test>::get(first), Some(project_key));
+        assert_eq!(DisputeProjects::<Test>::get(second), Some(project_key));
+        assert_eq!(
+            Proposals::project_disputes(project_key),
+            vec![first, second]
+        );
+    })
+}
+
+#[test]
+fn raise_dispute_overlapping_milestones_fails() {
+    build_test_externality().execute_with(|| {
+        let contributions = get_contributions::<Test>(vec![BOB, CHARLIE], 1_000_000u128);
+        let milestones = get_milestones(10);
+        let project_key = create_and_fund_project::<Test>(
+            ALICE,
+            contributions,
+            milestones,
+            CurrencyId::Native,
+            vec![JURY_1, JURY_2],
+        )
+        .unwrap();
+        assert_ok!(Proposals::raise_dispute(
+            RuntimeOrigin::signed(BOB),
+            project_key,
+            vec![0u32, 1u32].try_into().unwrap()
+        ));
+        assert_noop!(
+            Proposals::raise_dispute(
+                RuntimeOrigin::signed(CHARLIE),
+                project_key,
+                vec![1u32, 2u32].try_into().unwrap()
+            ),
+            Error::<Test>::MilestonesAlreadyInDispute
+        );
+    })
+}
+
+#[test]
+fn completing_a_dispute_only_frees_its_milestones() {
+    build_test_externality().execute_with(|| {
+        let contributions = get_contributions::<Test>(vec![BOB, CHARLIE], 1_000_000u128);
+        let milestones = get_milestones(10);
+        let project_key = create_and_fund_project::<Test>(
+            ALICE,
+            contributions,
+            milestones,
+            CurrencyId::Native,
+            vec![JURY_1, JURY_2],
+        )
+        .unwrap();
+        assert_ok!(Proposals::raise_dispute(
+            RuntimeOrigin::signed(BOB),
+            project_key,
+            vec![0u32].try_into().unwrap()
+        ));
+        assert_ok!(Proposals::raise_dispute(
+            RuntimeOrigin::signed(CHARLIE),
+            project_key,
+            vec![1u32].try_into().unwrap()
+        ));
+        let second = MilestoneDisputes::<Test>::get(project_key, 1).unwrap();
+        let _ = complete_dispute::<Test>(project_key, vec![0], DisputeResult::Success, None);
+
+        assert!(!MilestoneDisputes::<Test>::contains_key(project_key, 0));
+        assert_eq!(MilestoneDisputes::<Test>::get(project_key, 1), Some(second));
+        assert_eq!(Proposals::project_disputes(project_key), vec![second]);
+        // The freed milestone can be disputed again.
+        assert_ok!(Proposals::raise_dispute(
+            RuntimeOrigin::signed(BOB),
+            project_key,
+            vec![0u32].try_into().unwrap()
+        ));
+    })
+}
+
+fn raise_arbitrated_dispute(milestone_keys: Vec<MilestoneKey>) -> (ProjectKey, DisputeId) {
+    let contributions = get_contributions::<Test>(vec![BOB, CHARLIE], 1_000_000u128);
+    let milestones = get_milestones(10);
+    let project_key = create_and_fund_project::<Test>(
+        ALICE,
+        contributions,
+        milestones,
+        CurrencyId::Native,
+        vec![JURY_1],
+    )
+    .unwrap();
+    assert_ok!(Proposals::raise_dispute(
+        RuntimeOrigin::signed(BOB),
+        project_key,
+        milestone_keys.clone().try_into().unwrap()
+    ));
+    let dispute_id = MilestoneDisputes::<Test>::get(project_key, milestone_keys[0]).unwrap();
+    (project_key, dispute_id)
+}
+
+#[test]
+fn arbitrate_dispute_applies_verdicts() {
+    build_test_externality().execute_with(|| {
+        let (project_key, dispute_id) = raise_arbitrated_dispute(vec![0, 1]);
+        let bond = Arbitrations::<Test>::get(dispute_id).unwrap().bond;
+        let expiry_block = Rounds::<Test>::get((project_key, 0), RoundType::Arbitration).unwrap();
+
+        assert_ok!(Proposals::arbitrate_dispute(
+            RuntimeOrigin::root(),
+            dispute_id,
+            vec![(0, DisputeResult::Success), (1, DisputeResult::Failure)]
+                .try_into()
+                .unwrap()
+        ));
+
+        let project = Projects::<Test>::get(project_key).unwrap();
+        project.milestones.iter().for_each(|(key, ms)| {
+            assert_eq!(ms.can_refund, *key == 0);
         });
+        assert!(!Arbitrations::<Test>::contains_key(dispute_id));
+        assert!(!Rounds::<Test>::contains_key(
+            (project_key, 0),
+            RoundType::Arbitration
+        ));
+        assert!(RoundsExpiring::<Test>::get(expiry_block).is_empty());
+        assert!(!MilestoneDisputes::<Test>::contains_key(project_key, 0));
+        assert!(!MilestoneDisputes::<Test>::contains_key(project_key, 1));
+        // A milestone was upheld so the bond is returned.
+        assert_eq!(ReturnedDeposits::get(), vec![bond]);
+        System::assert_has_event(RuntimeEvent::Proposals(Event::<Test>::ArbitrationDecided {
+            dispute_id,
+        }));
+    })
+}
+
+#[test]
+fn arbitrate_dispute_failure_slashes_bond() {
+    build_test_externality().execute_with(|| {
+        let (_project_key, dispute_id) = raise_arbitrated_dispute(vec![0]);
+        let bond = Arbitrations::<Test>::get(dispute_id).unwrap().bond;
+        assert_ok!(Proposals::arbitrate_dispute(
+            RuntimeOrigin::root(),
+            dispute_id,
+            vec![(0, DisputeResult::Failure)].try_into().unwrap()
+        ));
+        assert_eq!(SlashedDeposits::get(), vec![bond]);
+        assert!(ReturnedDeposits::get().is_empty());
+    })
+}
+
+#[test]
+fn arbitrate_dispute_requires_arbitration_origin() {
+    build_test_externality().execute_with(|| {
+        let (_project_key, dispute_id) = raise_arbitrated_dispute(vec![0]);
+        assert_noop!(
+            Proposals::arbitrate_dispute(
+                RuntimeOrigin::signed(JURY_1),
+                dispute_id,
+                vec![(0, DisputeResult::Success)].try_into().unwrap()
+            ),
+            BadOrigin
+        );
+    })
+}
+
+#[test]
+fn arbitrate_dispute_not_found() {
+    build_test_externality().execute_with(|| {
+        assert_noop!(
+            Proposals::arbitrate_dispute(
+                RuntimeOrigin::root(),
+                0,
+                vec![(0, DisputeResult::Success)].try_into().unwrap()
+            ),
+            Error::<Test>::ArbitrationNotFound
+        );
+    })
+}
+
+#[test]
+fn arbitrate_dispute_verdicts_must_match_milestones() {
+    build_test_externality().execute_with(|| {
+        let (_project_key, dispute_id) = raise_arbitrated_dispute(vec![0, 1]);
+        assert_noop!(
+            Proposals::arbitrate_dispute(
+                RuntimeOrigin::root(),
+                dispute_id,
+                vec![(0, DisputeResult::Success)].try_into().unwrap()
+            ),
+            Error::<Test>::InvalidArbitrationVerdicts
+        );
+        assert_noop!(
+            Proposals::arbitrate_dispute(
+                RuntimeOrigin::root(),
+                dispute_id,
+                vec![(0, DisputeResult::Success), (2, DisputeResult::Success)]
+                    .try_into()
+                    .unwrap()
+            ),
+            Error::<Test>::InvalidArbitrationVerdicts
+        );
+    })
+}
+
+#[test]
+fn arbitration_expires_without_refund() {
+    build_test_externality().execute_with(|| {
+        let (project_key, dispute_id) = raise_arbitrated_dispute(vec![0, 1]);
+        let bond = Arbitrations::<Test>::get(dispute_id).unwrap().bond;
+        let expiry_block = Rounds::<Test>::get((project_key, 0), RoundType::Arbitration).unwrap();
+
+        run_to_block(expiry_block);
+
+        assert!(!Arbitrations::<Test>::contains_key(dispute_id));
+        assert!(!DisputeProjects::<Test>::contains_key(dispute_id));
+        assert!(!MilestoneDisputes::<Test>::contains_key(project_key, 0));
+        assert!(!MilestoneDisputes::<Test>::contains_key(project_key, 1));
+        let project = Projects::<Test>::get(project_key).unwrap();
+        project.milestones.iter().for_each(|(_key, ms)| {
+            assert!(!ms.can_refund);
+        });
+        // The raiser is not at fault so the bond is returned.
+        assert_eq!(ReturnedDeposits::get(), vec![bond]);
+        System::assert_has_event(RuntimeEvent::Proposals(Event::<Test>::ArbitrationExpired {
+            dispute_id,
+        }));
+        assert_noop!(
+            Proposals::arbitrate_dispute(
+                RuntimeOrigin::root(),
+                dispute_id,
+                vec![(0, DisputeResult::Success), (1, DisputeResult::Success)]
+                    .try_into()
+                    .unwrap()
+            ),
+            Error::<Test>::ArbitrationNotFound
+        );
+    })
+}
+
+#[test]
+fn arbitration_expires_when_a_voting_round_on_the_same_block_closes_early() {
+    build_test_externality().execute_with(|| {
+        let voting_project_key = create_and_fund_project::<Test>(
+            ALICE,
+            get_contributions::<Test>(vec![DAVE], 1_000_000u128),
+            get_milestones(10),
+            CurrencyId::Native,
+            vec![JURY_1, JURY_2],
+        )
+        .unwrap();
+        assert_ok!(Proposals::submit_milestone(
+            RuntimeOrigin::signed(ALICE),
+            voting_project_key,
+            0,
+            None
+        ));
+        let voting_expiry =
+            Rounds::<Test>::get((voting_project_key, 0), RoundType::VotingRound).unwrap();
+
+        run_to_block(voting_expiry - <Test as Config>::ArbitrationWindow::get());
+        let (project_key, dispute_id) = raise_arbitrated_dispute(vec![0, 1]);
+        let arbitration_expiry =
+            Rounds::<Test>::get((project_key, 0), RoundType::Arbitration).unwrap();
+        assert_eq!(arbitration_expiry, voting_expiry);
+
+        // The voting round is finalised early, the arbitration must still expire.
+        assert_ok!(Proposals::vote_on_milestone(
+            RuntimeOrigin::signed(DAVE),
+            voting_project_key,
+            0,
+            true
+        ));
+        run_to_block(arbitration_expiry);
+
+        assert!(!Arbitrations::<Test>::contains_key(dispute_id));
+        assert!(!MilestoneDisputes::<Test>::contains_key(project_key, 0));
+        assert!(!MilestoneDisputes::<Test>::contains_key(project_key, 1));
+        System::assert_has_event(RuntimeEvent::Proposals(Event::<Test>::ArbitrationExpired {
+            dispute_id,
+        }));
+    })
+}
+
+#[test]
+fn juror_on_open_dispute_cannot_leave_fellowship() {
+    build_test_externality().execute_with(|| {
+        let contributions = get_contributions::<Test>(vec![BOB, CHARLIE], 1_000_000u128);
+        let milestones = get_milestones(10);
+        let project_key = create_and_fund_project::<Test>(
+            ALICE,
+            contributions,
+            milestones,
+            CurrencyId::Native,
+            vec![JURY_1, JURY_2],
+        )
+        .unwrap();
+        assert_ok!(<Proposals as FellowshipHooks<AccountId>>::can_leave_fellowship(&JURY_1));
+        assert_ok!(Proposals::raise_dispute(
+            RuntimeOrigin::signed(BOB),
+            project_key,
+            vec![0u32].try_into().unwrap()
+        ));
+        assert_noop!(
+            <Proposals as FellowshipHooks<AccountId>>::can_leave_fellowship(&JURY_1),
+            Error::<Test>::JurorInOpenDispute
+        );
+        assert_ok!(<Proposals as FellowshipHooks<AccountId>>::can_leave_fellowship(&DAVE));
+    })
+}
+
+#[test]
+fn revoked_juror_is_replaced_on_open_disputes_and_project() {
+    build_test_externality().execute_with(|| {
+        let contributions = get_contributions::<Test>(vec![BOB, CHARLIE], 1_000_000u128);
+        let milestones = get_milestones(10);
+        let project_key = create_and_fund_project::<Test>(
+            ALICE,
+            contributions,
+            milestones,
+            CurrencyId::Native,
+            vec![JURY_1, JURY_2],
+        )
+        .unwrap();
+        assert_ok!(Proposals::raise_dispute(
+            RuntimeOrigin::signed(BOB),
+            project_key,
+            vec![0u32].try_into().unwrap()
+        ));
+        assert_ok!(Proposals::raise_dispute(
+            RuntimeOrigin::signed(CHARLIE),
+            project_key,
+            vec![1u32].try_into().unwrap()
+        ));
+        // The current jury is never selected as a replacement.
+        JuryPool::set(vec![JURY_2, DAVE]);
+
+        let _ = <Proposals as FellowshipHooks<AccountId>>::on_fellowship_revoked(&JURY_1);
+
+        let project = Projects::<Test>::get(project_key).unwrap();
+        assert_eq!(project.jury.to_vec(), vec![DAVE, JURY_2]);
+        for (dispute_id, jury) in DisputeJuries::get() {
+            assert!(jury.contains(&DAVE));
+            assert!(!jury.contains(&JURY_1));
+            System::assert_has_event(RuntimeEvent::Proposals(Event::<Test>::JurorReplaced {
+                project_key,
+                dispute_id,
+                juror: JURY_1,
+                replacement: Some(DAVE),
+            }));
+        }
+    })
+}
+
+#[test]
+fn revoked_juror_is_not_replaced_by_the_initiator_or_a_contributor() {
+    build_test_externality().execute_with(|| {
+        let contributions = get_contributions::<Test>(vec![BOB, CHARLIE], 1_000_000u128);
+        let project_key = create_and_fund_project::<Test>(
+            ALICE,
+            contributions,
+            get_milestones(10),
+            CurrencyId::Native,
+            vec![JURY_1, JURY_2],
+        )
+        .unwrap();
+        JuryPool::set(vec![ALICE, BOB, CHARLIE, DAVE]);
+
+        let _ = <Proposals as FellowshipHooks<AccountId>>::on_fellowship_revoked(&JURY_1);
+
+        assert_eq!(
+            Projects::<Test>::get(project_key).unwrap().jury.to_vec(),
+            vec![DAVE, JURY_2]
+        );
+    })
+}
+
+#[test]
+fn juror_not_on_the_project_jury_is_not_replaced() {
+    build_test_externality().execute_with(|| {
+        let contributions = get_contributions::<Test>(vec![BOB, CHARLIE], 1_000_000u128);
+        let project_key = create_and_fund_project::<Test>(
+            ALICE,
+            contributions,
+            get_milestones(10),
+            CurrencyId::Native,
+            vec![JURY_1, JURY_2],
+        )
+        .unwrap();
+        JuryPool::set(vec![DAVE]);
+
+        assert_eq!(Proposals::replace_project_juror(project_key, &JOHN), None);
+        assert_eq!(
+            Projects::<Test>::get(project_key).unwrap().jury.to_vec(),
+            vec![JURY_1, JURY_2]
+        );
+        assert!(JurorProjects::<Test>::get(DAVE).is_empty());
+    })
+}
+
+#[test]
+fn revoked_juror_without_replacement_shrinks_the_jury() {
+    build_test_externality().execute_with(|| {
+        let contributions = get_contributions::<Test>(vec![BOB, CHARLIE], 1_000_000u128);
+        let milestones = get_milestones(10);
+        let project_key = create_and_fund_project::<Test>(
+            ALICE,
+            contributions,
+            milestones,
+            CurrencyId::Native,
+            vec![JURY_1, JURY_2],
+        )
+        .unwrap();
+        assert_ok!(Proposals::raise_dispute(
+            RuntimeOrigin::signed(BOB),
+            project_key,
+            vec![0u32].try_into().unwrap()
+        ));
+        let dispute_id = MilestoneDisputes::<Test>::get(project_key, 0).unwrap();
+
+        let _ = <Proposals as FellowshipHooks<AccountId>>::on_fellowship_revoked(&JURY_1);
+
+        let project = Projects::<Test>::get(project_key).unwrap();
+        assert_eq!(project.jury.to_vec(), vec![JURY_2]);
+        assert_eq!(DisputeJuries::get(), vec![(dispute_id, vec![JURY_2])]);
+        System::assert_last_event(RuntimeEvent::Proposals(Event::<Test>::JurorReplaced {
+            project_key,
+            dispute_id,
+            juror: JURY_1,
+            replacement: None,
+        }));
+    })
+}
+
+#[test]
+fn revoked_juror_is_replaced_on_projects_without_a_dispute() {
+    build_test_externality().execute_with(|| {
+        let contributions = get_contributions::<Test>(vec![BOB, CHARLIE], 1_000_000u128);
+        let project_key = create_and_fund_project::<Test>(
+            ALICE,
+            contributions.clone(),
+            get_milestones(10),
+            CurrencyId::Native,
+            vec![JURY_1, JURY_2],
+        )
+        .unwrap();
+        let other_project_key = create_and_fund_project::<Test>(
+            ALICE,
+            contributions,
+            get_milestones(10),
+            CurrencyId::Native,
+            vec![JURY_1],
+        )
+        .unwrap();
+        assert_eq!(
+            JurorProjects::<Test>::get(JURY_1).to_vec(),
+            vec![project_key, other_project_key]
+        );
+        JuryPool::set(vec![DAVE]);
+
+        let _ = <Proposals as FellowshipHooks<AccountId>>::on_fellowship_revoked(&JURY_1);
+
+        assert_eq!(
+            Projects::<Test>::get(project_key).unwrap().jury.to_vec(),
+            vec![DAVE, JURY_2]
+        );
+        assert_eq!(
+            Projects::<Test>::get(other_project_key)
+                .unwrap()
+                .jury
+                .to_vec(),
+            vec![DAVE]
+        );
+        assert!(JurorProjects::<Test>::get(JURY_1).is_empty());
+        assert_eq!(
+            JurorProjects::<Test>::get(DAVE).to_vec(),
+            vec![project_key, other_project_key]
+        );
+        System::assert_last_event(RuntimeEvent::Proposals(
+            Event::<Test>::ProjectJurorReplaced {
+                project_key: other_project_key,
+                juror: JURY_1,
+                replacement: Some(DAVE),
+            },
+        ));
+    })
+}
+
+#[test]
+fn juror_sits_on_at_most_max_juries_per_account() {
+    build_test_externality().execute_with(|| {
+        MaxJuriesPerAccount::set(1);
+        let contributions = get_contributions::<Test>(vec![BOB, CHARLIE], 1_000_000u128);
+        let project_key = create_and_fund_project::<Test>(
+            ALICE,
+            contributions.clone(),
+            get_milestones(10),
+            CurrencyId::Native,
+            vec![JURY_1, JURY_2],
+        )
+        .unwrap();
+        assert_err!(
+            create_and_fund_project::<Test>(
+                ALICE,
+                contributions.clone(),
+                get_milestones(10),
+                CurrencyId::Native,
+                vec![JURY_1],
+            ),
+            Error::<Test>::JurorOnTooManyProjects
+        );
+        let other_project_key = create_and_fund_project::<Test>(
+            ALICE,
+            contributions,
+            get_milestones(10),
+            CurrencyId::Native,
+            vec![DAVE],
+        )
+        .unwrap();
+
+        // A fellow on too many juries is not used as a replacement.
+        JuryPool::set(vec![DAVE]);
+        let _ = <Proposals as FellowshipHooks<AccountId>>::on_fellowship_revoked(&JURY_1);
+        assert_eq!(
+            Projects::<Test>::get(project_key).unwrap().jury.to_vec(),
+            vec![JURY_2]
+        );
+        assert_eq!(
+            JurorProjects::<Test>::get(DAVE).to_vec(),
+            vec![other_project_key]
+        );
+    })
+}
+
+#[test]
+fn jury_proposes_refund_shares_and_the_median_is_recorded() {
+    build_test_externality().execute_with(|| {
+        let contributions = get_contributions::<Test>(vec![BOB, CHARLIE], 1_000_000u128);
+        let project_key = create_and_fund_project::<Test>(
+            ALICE,
+            contributions,
+            get_milestones(10),
+            CurrencyId::Native,
+            vec![JURY_1, JURY_2],
+        )
+        .unwrap();
+        assert_ok!(Proposals::raise_dispute(
+            RuntimeOrigin::signed(BOB),
+            project_key,
+            vec![0u32, 1u32].try_into().unwrap()
+        ));
+        assert_ok!(Proposals::propose_refund_share(
+            RuntimeOrigin::signed(JURY_1),
+            project_key,
+            0,
+            Percent::from_percent(60)
+        ));
+        assert_last_event::<Test>(
+            Event::<Test>::RefundShareProposed {
+                who: JURY_1,
+                project_key,
+                milestone_key: 0,
+                refund_share: Percent::from_percent(60),
+            }
+            .into(),
+        );
+        assert_ok!(Proposals::propose_refund_share(
+            RuntimeOrigin::signed(JURY_2),
+            project_key,
+            0,
+            Percent::from_percent(40)
+        ));
+        assert_ok!(Proposals::propose_refund_share(
+            RuntimeOrigin::signed(JURY_1),
+            project_key,
+            1,
+            Percent::from_percent(30)
+        ));
+
+        let _ = complete_dispute::<Test>(project_key, vec![0, 1], DisputeResult::Success, None);
+        let project = Projects::<Test>::get(project_key).unwrap();
+        // The lower of the two middle shares is used.
+        assert_eq!(
+            project.milestones[&0].refund_share,
+            Percent::from_percent(40)
+        );
+        assert_eq!(
+            project.milestones[&1].refund_share,
+            Percent::from_percent(30)
+        );
+        assert!(project.milestones[&0].can_refund);
+        assert!(!RefundShareProposals::<Test>::contains_key(project_key, 0));
+        assert!(!RefundShareProposals::<Test>::contains_key(project_key, 1));
+    })
+}
+
+#[test]
+fn refund_shares_are_ignored_when_the_dispute_fails() {
+    build_test_externality().execute_with(|| {
+        let contributions = get_contributions::<Test>(vec![BOB, CHARLIE], 1_000_000u128);
+        let project_key = create_and_fund_project::<Test>(
+            ALICE,
+            contributions,
+            get_milestones(10),
+            CurrencyId::Native,
+            vec![JURY_1, JURY_2],
+        )
+        .unwrap();
+        assert_ok!(Proposals::raise_dispute(
+            RuntimeOrigin::signed(BOB),
+            project_key,
+            vec![0u32].try_into().unwrap()
+        ));
+        assert_ok!(Proposals::propose_refund_share(
+            RuntimeOrigin::signed(JURY_1),
+            project_key,
+            0,
+            Percent::from_percent(60)
+        ));
+        let _ = complete_dispute::<Test>(project_key, vec![0], DisputeResult::Failure, None);
+        let milestone = Projects::<Test>::get(project_key).unwrap().milestones[&0].clone();
+        assert!(!milestone.can_refund);
+        assert_eq!(milestone.refund_share, Percent::from_percent(100));
+        assert!(!RefundShareProposals::<Test>::contains_key(project_key, 0));
+    })
+}
+
+#[test]
+fn propose_refund_share_invalid_proposals() {
+    build_test_externality().execute_with(|| {
+        let contributions = get_contributions::<Test>(vec![BOB, CHARLIE], 1_000_000u128);
+        let project_key = create_and_fund_project::<Test>(
+            ALICE,
+            contributions,
+            get_milestones(10),
+            CurrencyId::Native,
+            vec![JURY_1, JURY_2],
+        )
+        .unwrap();
+        assert_noop!(
+            Proposals::propose_refund_share(
+                RuntimeOrigin::signed(JURY_1),
+                project_key,
+                0,
+                Percent::from_percent(60)
+            ),
+            Error::<Test>::MilestoneNotInDispute
+        );
+        assert_ok!(Proposals::raise_dispute(
+            RuntimeOrigin::signed(BOB),
+            project_key,
+            vec![0u32].try_into().unwrap()
+        ));
+        assert_noop!(
+            Proposals::propose_refund_share(
+                RuntimeOrigin::signed(BOB),
+                project_key,
+                0,
+                Percent::from_percent(60)
+            ),
+            Error::<Test>::OnlyJuryCanProposeRefundShare
+        );
+        assert_noop!(
+            Proposals::propose_refund_share(
+                RuntimeOrigin::signed(JURY_1),
+                project_key,
+                0,
+                Percent::zero()
+            ),
+            Error::<Test>::InvalidRefundShare
+        );
+        assert_noop!(
+            Proposals::propose_refund_share(
+                RuntimeOrigin::signed(JURY_1),
+                project_key + 1,
+                0,
+                Percent::from_percent(60)
+            ),
+            Error::<Test>::ProjectDoesNotExist
+        );
+    })
+}
+
+#[test]
+fn cancelled_dispute_frees_the_milestones_and_returns_the_bond() {
+    build_test_externality().execute_with(|| {
+        let contributions = get_contributions::<Test>(vec![BOB, CHARLIE], 1_000_000u128);
+        let project_key = create_and_fund_project::<Test>(
+            ALICE,
+            contributions,
+            get_milestones(10),
+            CurrencyId::Native,
+            vec![JURY_1, JURY_2],
+        )
+        .unwrap();
+        assert_ok!(Proposals::raise_dispute(
+            RuntimeOrigin::signed(BOB),
+            project_key,
+            vec![0u32, 1u32].try_into().unwrap()
+        ));
+        assert_ok!(Proposals::propose_refund_share(
+            RuntimeOrigin::signed(JURY_1),
+            project_key,
+            0,
+            Percent::from_percent(60)
+        ));
+        let dispute_id = MilestoneDisputes::<Test>::get(project_key, 0).unwrap();
+        assert_ok!(<Proposals as pallet_disputes::traits::DisputeHooks<
+            DisputeId,
+            MilestoneKey,
+            u64,
+        >>::on_dispute_cancel(
+            dispute_id, vec![0, 1], Some(7)
+        ));
+
+        assert_eq!(
+            MilestoneDisputes::<Test>::iter_prefix(project_key).count(),
+            0
+        );
+        assert!(!DisputeProjects::<Test>::contains_key(dispute_id));
+        assert!(RefundShareProposals::<Test>::get(project_key, 0).is_empty());
+        assert_eq!(ReturnedDeposits::get(), vec![7]);
+        let project = Projects::<Test>::get(project_key).unwrap();
+        assert!(project.milestones.values().all(|ms| !ms.can_refund));
+
+        // The milestones can be disputed again.
+        assert_ok!(Proposals::raise_dispute(
+            RuntimeOrigin::signed(BOB),
+            project_key,
+            vec![0u32].try_into().unwrap()
+        ));
     })
 }
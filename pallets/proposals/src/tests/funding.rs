@@ -0,0 +1,216 @@
+use crate::{mock::*, *};
+use frame_support::{assert_noop, assert_ok};
+use test_utils::*;
+use xcm::latest::MultiLocation;
+
+fn create_awaiting_project(contribution: u128) -> ProjectKey {
+    let contributions = get_contributions::<Test>(vec![BOB, CHARLIE], contribution);
+    let milestones = get_milestones(10);
+    create_project_awaiting_funding::<Test>(
+        ALICE,
+        contributions,
+        milestones,
+        CurrencyId::Native,
+        MultiLocation::here(),
+        vec![JURY_1, JURY_2],
+    )
+    .unwrap()
+}
+
+#[test]
+fn wait_for_funding_project_starts_awaiting_funding() {
+    build_test_externality().execute_with(|| {
+        let project_key = create_awaiting_project(1_000_000u128);
+        let project = Projects::<Test>::get(project_key).unwrap();
+        let project_account = Proposals::project_account_id(project_key);
+
+        assert_eq!(project.funding_state, FundingState::AwaitingFunding);
+        assert_eq!(
+            ProjectsAwaitingFunding::<Test>::get(project_account),
+            Some(project_key)
+        );
+    })
+}
+
+#[test]
+fn submit_milestone_fails_when_project_not_funded() {
+    build_test_externality().execute_with(|| {
+        let project_key = create_awaiting_project(1_000_000u128);
+        assert_noop!(
+            Proposals::submit_milestone(RuntimeOrigin::signed(ALICE), project_key, 0, None),
+            Error::<Test>::ProjectNotFunded
+        );
+    })
+}
+
+#[test]
+fn withdraw_fails_when_project_not_funded() {
+    build_test_externality().execute_with(|| {
+        let project_key = create_awaiting_project(1_000_000u128);
+        assert_noop!(
+            Proposals::withdraw(RuntimeOrigin::signed(ALICE), project_key),
+            Error::<Test>::ProjectNotFunded
+        );
+    })
+}
+
+#[test]
+fn checking_a_partial_deposit_marks_project_partially_funded() {
+    build_test_externality().execute_with(|| {
+        let project_key = create_awaiting_project(1_000_000u128);
+        let project_account = Proposals::project_account_id(project_key);
+
+        assert_ok!(Tokens::transfer(
+            RuntimeOrigin::signed(TREASURY),
+            project_account,
+            CurrencyId::Native,
+            500_000u128
+        ));
+        assert_ok!(Proposals::check_project_funding(
+            RuntimeOrigin::signed(BOB),
+            project_key
+        ));
+
+        let project = Projects::<Test>::get(project_key).unwrap();
+        assert_eq!(
+            project.funding_state,
+            FundingState::PartiallyFunded {
+                received: 500_000u128
+            }
+        );
+        System::assert_last_event(RuntimeEvent::Proposals(
+            Event::<Test>::ProjectFundingReceived {
+                project_key,
+                received: 500_000u128,
+                required: 2_000_000u128,
+            },
+        ));
+        assert_noop!(
+            Proposals::submit_milestone(RuntimeOrigin::signed(ALICE), project_key, 0, None),
+            Error::<Test>::ProjectNotFunded
+        );
+    })
+}
+
+#[test]
+fn checking_a_full_deposit_marks_project_funded() {
+    build_test_externality().execute_with(|| {
+        let project_key = create_awaiting_project(1_000_000u128);
+        let project_account = Proposals::project_account_id(project_key);
+
+        assert_ok!(<Tokens as MultiCurrency<AccountId>>::deposit(
+            CurrencyId::Native,
+            &project_account,
+            2_000_000u128
+        ));
+        assert_ok!(Proposals::check_project_funding(
+            RuntimeOrigin::signed(BOB),
+            project_key
+        ));
+
+        let project = Projects::<Test>::get(project_key).unwrap();
+        assert_eq!(project.funding_state, FundingState::Funded);
+        assert!(ProjectsAwaitingFunding::<Test>::get(project_account).is_none());
+        System::assert_has_event(RuntimeEvent::Proposals(Event::<Test>::ProjectFunded {
+            project_key,
+            amount: 2_000_000u128,
+        }));
+        assert_ok!(Proposals::submit_milestone(
+            RuntimeOrigin::signed(ALICE),
+            project_key,
+            0,
+            None
+        ));
+    })
+}
+
+#[test]
+fn deposits_in_another_currency_are_ignored() {
+    build_test_externality().execute_with(|| {
+        let project_key = create_awaiting_project(1_000_000u128);
+        let project_account = Proposals::project_account_id(project_key);
+
+        assert_ok!(<Tokens as MultiCurrency<AccountId>>::deposit(
+            CurrencyId::KSM,
+            &project_account,
+            2_000_000u128
+        ));
+        assert_ok!(Proposals::check_project_funding(
+            RuntimeOrigin::signed(BOB),
+            project_key
+        ));
+
+        let project = Projects::<Test>::get(project_key).unwrap();
+        assert_eq!(project.funding_state, FundingState::AwaitingFunding);
+    })
+}
+
+#[test]
+fn funds_arriving_before_creation_mark_project_funded() {
+    build_test_externality().execute_with(|| {
+        let project_key = ProjectCount::<Test>::get().saturating_add(1);
+        let project_account = Proposals::project_account_id(project_key);
+        assert_ok!(<Tokens as MultiCurrency<AccountId>>::deposit(
+            CurrencyId::Native,
+            &project_account,
+            2_000_000u128
+        ));
+
+        let created = create_awaiting_project(1_000_000u128);
+        assert_eq!(created, project_key);
+        let project = Projects::<Test>::get(project_key).unwrap();
+        assert_eq!(project.funding_state, FundingState::Funded);
+        assert!(ProjectsAwaitingFunding::<Test>::get(project_account).is_none());
+    })
+}
+
+#[test]
+fn deposits_are_not_observed_until_checked() {
+    build_test_externality().execute_with(|| {
+        let project_key = create_awaiting_project(1_000_000u128);
+        let project_account = Proposals::project_account_id(project_key);
+
+        assert_ok!(<Tokens as MultiCurrency<AccountId>>::deposit(
+            CurrencyId::Native,
+            &project_account,
+            2_000_000u128
+        ));
+
+        let project = Projects::<Test>::get(project_key).unwrap();
+        assert_eq!(project.funding_state, FundingState::AwaitingFunding);
+        assert_eq!(
+            ProjectsAwaitingFunding::<Test>::get(project_account),
+            Some(project_key)
+        );
+    })
+}
+
+#[test]
+fn check_project_funding_fails_for_funded_project() {
+    build_test_externality().execute_with(|| {
+        let contributions = get_contributions::<Test>(vec![BOB], 1_000_000u128);
+        let milestones = get_milestones(10);
+        let project_key = create_and_fund_project::<Test>(
+            ALICE,
+            contributions,
+            milestones,
+            CurrencyId::Native,
+            vec![JURY_1],
+        )
+        .unwrap();
+        assert_noop!(
+            Proposals::check_project_funding(RuntimeOrigin::signed(BOB), project_key),
+            Error::<Test>::ProjectAlreadyFunded
+        );
+    })
+}
+
+#[test]
+fn check_project_funding_fails_for_unknown_project() {
+    build_test_externality().execute_with(|| {
+        assert_noop!(
+            Proposals::check_project_funding(RuntimeOrigin::signed(BOB), 100),
+            Error::<Test>::ProjectDoesNotExist
+        );
+    })
+}
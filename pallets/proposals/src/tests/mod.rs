@@ -1,5 +1,9 @@
+pub mod delegation;
 pub mod disputes;
 pub mod foreign_asset;
+pub mod funding;
 pub mod immutable_votes;
+pub mod no_confidence;
 pub mod pallet;
 pub mod refunds;
+pub mod runtime_api;
@@ -0,0 +1,391 @@
+use crate::{mock::*, *};
+use frame_support::{assert_noop, assert_ok};
+use test_utils::*;
+use xcm::latest::MultiLocation;
+
+use super::pallet::run_to_block;
+
+fn create_project_with_contributors() -> ProjectKey {
+    let contributions = get_contributions::<Test>(vec![BOB, CHARLIE, DAVE, JOHN], 1_000_000u128);
+    let milestones = get_milestones(10);
+    create_and_fund_project::<Test>(
+        ALICE,
+        contributions,
+        milestones,
+        CurrencyId::Native,
+        vec![JURY_1, JURY_2],
+    )
+    .unwrap()
+}
+
+#[test]
+fn raise_no_confidence_round_not_contributor() {
+    build_test_externality().execute_with(|| {
+        let project_key = create_project_with_contributors();
+        assert_noop!(
+            Proposals::raise_vote_of_no_confidence(RuntimeOrigin::signed(ALICE), project_key),
+            Error::<Test>::OnlyContributorsCanVote
+        );
+    })
+}
+
+#[test]
+fn raise_no_confidence_round_project_does_not_exist() {
+    build_test_externality().execute_with(|| {
+        assert_noop!(
+            Proposals::raise_vote_of_no_confidence(RuntimeOrigin::signed(BOB), 10),
+            Error::<Test>::ProjectDoesNotExist
+        );
+    })
+}
+
+#[test]
+fn raise_no_confidence_round_project_not_funded() {
+    build_test_externality().execute_with(|| {
+        let project_key = create_project_awaiting_funding::<Test>(
+            ALICE,
+            get_contributions::<Test>(vec![BOB, CHARLIE], 1_000_000u128),
+            get_milestones(10),
+            CurrencyId::Native,
+            MultiLocation::here(),
+            vec![JURY_1, JURY_2],
+        )
+        .unwrap();
+        assert_noop!(
+            Proposals::raise_vote_of_no_confidence(RuntimeOrigin::signed(BOB), project_key),
+            Error::<Test>::ProjectNotFunded
+        );
+    })
+}
+
+#[test]
+fn raise_no_confidence_round_during_dispute() {
+    build_test_externality().execute_with(|| {
+        let project_key = create_project_with_contributors();
+        assert_ok!(Proposals::raise_dispute(
+            RuntimeOrigin::signed(BOB),
+            project_key,
+            vec![0].try_into().unwrap()
+        ));
+        assert_noop!(
+            Proposals::raise_vote_of_no_confidence(RuntimeOrigin::signed(CHARLIE), project_key),
+            Error::<Test>::ProjectInDispute
+        );
+    })
+}
+
+#[test]
+fn raise_dispute_during_no_confidence_round() {
+    build_test_externality().execute_with(|| {
+        let project_key = create_project_with_contributors();
+        assert_ok!(Proposals::raise_vote_of_no_confidence(
+            RuntimeOrigin::signed(BOB),
+            project_key
+        ));
+        assert_noop!(
+            Proposals::raise_dispute(
+                RuntimeOrigin::signed(CHARLIE),
+                project_key,
+                vec![0].try_into().unwrap()
+            ),
+            Error::<Test>::ProjectInNoConfidenceRound
+        );
+    })
+}
+
+#[test]
+fn raise_no_confidence_round_counts_raiser_as_nay() {
+    build_test_externality().execute_with(|| {
+        let project_key = create_project_with_contributors();
+        assert_ok!(Proposals::raise_vote_of_no_confidence(
+            RuntimeOrigin::signed(BOB),
+            project_key
+        ));
+
+        let vote = NoConfidenceVotes::<Test>::get(project_key).unwrap();
+        assert_eq!(vote.nay, 1_000_000u128);
+        assert_eq!(vote.yay, 0u128);
+        assert!(Rounds::<Test>::contains_key(
+            (project_key, 0),
+            RoundType::VoteOfNoConfidence
+        ));
+        System::assert_last_event(RuntimeEvent::Proposals(
+            Event::<Test>::NoConfidenceRoundCreated {
+                who: BOB,
+                project_key,
+            },
+        ));
+    })
+}
+
+#[test]
+fn raise_no_confidence_round_already_exists() {
+    build_test_externality().execute_with(|| {
+        let project_key = create_project_with_contributors();
+        assert_ok!(Proposals::raise_vote_of_no_confidence(
+            RuntimeOrigin::signed(BOB),
+            project_key
+        ));
+        assert_noop!(
+            Proposals::raise_vote_of_no_confidence(RuntimeOrigin::signed(CHARLIE), project_key),
+            Error::<Test>::NoConfidenceRoundAlreadyExists
+        );
+    })
+}
+
+#[test]
+fn vote_on_no_confidence_round_not_started() {
+    build_test_externality().execute_with(|| {
+        let project_key = create_project_with_contributors();
+        assert_noop!(
+            Proposals::vote_on_no_confidence_round(RuntimeOrigin::signed(BOB), project_key, false),
+            Error::<Test>::NoConfidenceRoundNotStarted
+        );
+    })
+}
+
+#[test]
+fn vote_on_no_confidence_round_not_contributor() {
+    build_test_externality().execute_with(|| {
+        let project_key = create_project_with_contributors();
+        assert_ok!(Proposals::raise_vote_of_no_confidence(
+            RuntimeOrigin::signed(BOB),
+            project_key
+        ));
+        assert_noop!(
+            Proposals::vote_on_no_confidence_round(
+                RuntimeOrigin::signed(ALICE),
+                project_key,
+                false
+            ),
+            Error::<Test>::OnlyContributorsCanVote
+        );
+    })
+}
+
+#[test]
+fn vote_on_no_confidence_round_votes_are_immutable() {
+    build_test_externality().execute_with(|| {
+        let project_key = create_project_with_contributors();
+        assert_ok!(Proposals::raise_vote_of_no_confidence(
+            RuntimeOrigin::signed(BOB),
+            project_key
+        ));
+        assert_noop!(
+            Proposals::vote_on_no_confidence_round(RuntimeOrigin::signed(BOB), project_key, true),
+            Error::<Test>::VotesAreImmutable
+        );
+        assert_ok!(Proposals::vote_on_no_confidence_round(
+            RuntimeOrigin::signed(CHARLIE),
+            project_key,
+            true
+        ));
+        assert_noop!(
+            Proposals::vote_on_no_confidence_round(
+                RuntimeOrigin::signed(CHARLIE),
+                project_key,
+                false
+            ),
+            Error::<Test>::VotesAreImmutable
+        );
+    })
+}
+
+#[test]
+fn vote_on_no_confidence_round_is_weighted_by_contribution() {
+    build_test_externality().execute_with(|| {
+        let project_key = create_project_with_contributors();
+        assert_ok!(Proposals::raise_vote_of_no_confidence(
+            RuntimeOrigin::signed(BOB),
+            project_key
+        ));
+        assert_ok!(Proposals::vote_on_no_confidence_round(
+            RuntimeOrigin::signed(CHARLIE),
+            project_key,
+            true
+        ));
+        assert_ok!(Proposals::vote_on_no_confidence_round(
+            RuntimeOrigin::signed(DAVE),
+            project_key,
+            false
+        ));
+
+        let vote = NoConfidenceVotes::<Test>::get(project_key).unwrap();
+        assert_eq!(vote.nay, 2_000_000u128);
+        assert_eq!(vote.yay, 1_000_000u128);
+        System::assert_last_event(RuntimeEvent::Proposals(
+            Event::<Test>::NoConfidenceRoundVotedUpon {
+                who: DAVE,
+                project_key,
+                is_yay: false,
+            },
+        ));
+    })
+}
+
+#[test]
+fn no_confidence_round_passing_allows_refund_of_all_milestones() {
+    build_test_externality().execute_with(|| {
+        let project_key = create_project_with_contributors();
+        assert_ok!(Proposals::raise_vote_of_no_confidence(
+            RuntimeOrigin::signed(BOB),
+            project_key
+        ));
+        assert_ok!(Proposals::vote_on_no_confidence_round(
+            RuntimeOrigin::signed(CHARLIE),
+            project_key,
+            false
+        ));
+        assert_ok!(Proposals::vote_on_no_confidence_round(
+            RuntimeOrigin::signed(DAVE),
+            project_key,
+            false
+        ));
+
+        System::assert_last_event(RuntimeEvent::Proposals(
+            Event::<Test>::NoConfidenceRoundFinalised { project_key },
+        ));
+        assert!(NoConfidenceVotes::<Test>::get(project_key).is_none());
+        assert!(!Rounds::<Test>::contains_key(
+            (project_key, 0),
+            RoundType::VoteOfNoConfidence
+        ));
+
+        let project = Projects::<Test>::get(project_key).unwrap();
+        assert!(project.cancelled);
+        assert!(project.milestones.values().all(|ms| ms.can_refund));
+
+        assert_ok!(Proposals::refund(RuntimeOrigin::signed(JOHN), project_key));
+        assert!(Projects::<Test>::get(project_key).is_none());
+    })
+}
+
+#[test]
+fn no_confidence_round_passing_does_not_refund_withdrawn_milestones() {
+    build_test_externality().execute_with(|| {
+        let project_key = create_project_with_contributors();
+        assert_ok!(Proposals::submit_milestone(
+            RuntimeOrigin::signed(ALICE),
+            project_key,
+            0,
+            None
+        ));
+        for who in [BOB, CHARLIE, DAVE] {
+            assert_ok!(Proposals::vote_on_milestone(
+                RuntimeOrigin::signed(who),
+                project_key,
+                0,
+                true
+            ));
+        }
+        assert_ok!(Proposals::withdraw(
+            RuntimeOrigin::signed(ALICE),
+            project_key
+        ));
+
+        assert_ok!(Proposals::raise_vote_of_no_confidence(
+            RuntimeOrigin::signed(BOB),
+            project_key
+        ));
+        assert_ok!(Proposals::vote_on_no_confidence_round(
+            RuntimeOrigin::signed(CHARLIE),
+            project_key,
+            false
+        ));
+        assert_ok!(Proposals::vote_on_no_confidence_round(
+            RuntimeOrigin::signed(DAVE),
+            project_key,
+            false
+        ));
+
+        let project = Projects::<Test>::get(project_key).unwrap();
+        project.milestones.iter().for_each(|(key, ms)| {
+            assert_eq!(ms.can_refund, *key != 0);
+        });
+        assert_noop!(
+            Proposals::submit_milestone(RuntimeOrigin::signed(ALICE), project_key, 1, None),
+            Error::<Test>::ProjectWithdrawn
+        );
+    })
+}
+
+#[test]
+fn no_confidence_round_expires_without_passing() {
+    build_test_externality().execute_with(|| {
+        let project_key = create_project_with_contributors();
+        assert_ok!(Proposals::raise_vote_of_no_confidence(
+            RuntimeOrigin::signed(BOB),
+            project_key
+        ));
+        let expiry_block =
+            frame_system::Pallet::<Test>::block_number() + NoConfidenceVotingWindow::get();
+        run_to_block(expiry_block + 1);
+
+        System::assert_has_event(RuntimeEvent::Proposals(
+            Event::<Test>::NoConfidenceRoundExpired { project_key },
+        ));
+        assert!(NoConfidenceVotes::<Test>::get(project_key).is_none());
+        assert_noop!(
+            Proposals::vote_on_no_confidence_round(
+                RuntimeOrigin::signed(CHARLIE),
+                project_key,
+                false
+            ),
+            Error::<Test>::NoConfidenceRoundNotStarted
+        );
+        let project = Projects::<Test>::get(project_key).unwrap();
+        assert!(!project.cancelled);
+        assert!(project.milestones.values().all(|ms| !ms.can_refund));
+
+        // A new round can be raised once the previous has expired.
+        assert_ok!(Proposals::raise_vote_of_no_confidence(
+            RuntimeOrigin::signed(BOB),
+            project_key
+        ));
+    })
+}
+
+#[test]
+fn no_confidence_round_expires_when_a_voting_round_on_the_same_block_closes_early() {
+    build_test_externality().execute_with(|| {
+        let project_key = create_project_with_contributors();
+        let voting_project_key = create_and_fund_project::<Test>(
+            ALICE,
+            get_contributions::<Test>(vec![BOB], 1_000_000u128),
+            get_milestones(10),
+            CurrencyId::Native,
+            vec![JURY_1, JURY_2],
+        )
+        .unwrap();
+        assert_ok!(Proposals::submit_milestone(
+            RuntimeOrigin::signed(ALICE),
+            voting_project_key,
+            0,
+            None
+        ));
+        assert_ok!(Proposals::raise_vote_of_no_confidence(
+            RuntimeOrigin::signed(BOB),
+            project_key
+        ));
+        let expiry_block =
+            Rounds::<Test>::get((project_key, 0), RoundType::VoteOfNoConfidence).unwrap();
+        assert_eq!(
+            Rounds::<Test>::get((voting_project_key, 0), RoundType::VotingRound),
+            Some(expiry_block)
+        );
+
+        // The voting round is finalised early, the vote of no confidence must still expire.
+        assert_ok!(Proposals::vote_on_milestone(
+            RuntimeOrigin::signed(BOB),
+            voting_project_key,
+            0,
+            true
+        ));
+        run_to_block(expiry_block);
+
+        System::assert_has_event(RuntimeEvent::Proposals(
+            Event::<Test>::NoConfidenceRoundExpired { project_key },
+        ));
+        assert!(NoConfidenceVotes::<Test>::get(project_key).is_none());
+    })
+}
@@ -27,7 +27,7 @@ fn submit_milestone_milestone_doesnt_exist() {
             create_and_fund_project::<Test>(ALICE, cont, prop_milestones, CurrencyId::Native, jury)
                 .unwrap();
         assert_noop!(
-            Proposals::submit_milestone(RuntimeOrigin::signed(ALICE), project_key, 11),
+            Proposals::submit_milestone(RuntimeOrigin::signed(ALICE), project_key, 11, None),
             Error::<Test>::MilestoneDoesNotExist
         );
     });
@@ -37,7 +37,7 @@ fn submit_milestone_milestone_doesnt_exist() {
 fn submit_milestone_no_project() {
     build_test_externality().execute_with(|| {
         assert_noop!(
-            Proposals::submit_milestone(RuntimeOrigin::signed(ALICE), 0, 1),
+            Proposals::submit_milestone(RuntimeOrigin::signed(ALICE), 0, 1, None),
             Error::<Test>::ProjectDoesNotExist
         );
     });
@@ -54,11 +54,11 @@ fn submit_milestone_not_initiator() {
             create_and_fund_project::<Test>(ALICE, cont, prop_milestones, CurrencyId::Native, jury)
                 .unwrap();
         assert_noop!(
-            Proposals::submit_milestone(RuntimeOrigin::signed(BOB), project_key, 1),
+            Proposals::submit_milestone(RuntimeOrigin::signed(BOB), project_key, 1, None),
             Error::<Test>::UserIsNotInitiator
         );
         assert_noop!(
-            Proposals::submit_milestone(RuntimeOrigin::signed(DAVE), project_key, 1),
+            Proposals::submit_milestone(RuntimeOrigin::signed(DAVE), project_key, 1, None),
             Error::<Test>::UserIsNotInitiator
         );
     });
@@ -85,11 +85,12 @@ fn submit_milestones_too_many_this_block() {
                 assert_ok!(Proposals::submit_milestone(
                     RuntimeOrigin::signed(ALICE),
                     project_key,
-                    1
+                    1,
+                    None
                 ));
             } else {
                 assert_noop!(
-                    Proposals::submit_milestone(RuntimeOrigin::signed(ALICE), project_key, 1),
+                    Proposals::submit_milestone(RuntimeOrigin::signed(ALICE), project_key, 1, None),
                     Error::<Test>::Overflow
                 );
             }
@@ -110,7 +111,8 @@ fn submit_milestone_creates_non_bias_vote() {
         assert_ok!(Proposals::submit_milestone(
             RuntimeOrigin::signed(ALICE),
             project_key,
-            1
+            1,
+            None
         ));
         let total_vote = MilestoneVotes::<Test>::get(project_key);
         let created_vote = total_vote.get(&1).expect("should exist");
@@ -121,7 +123,64 @@ fn submit_milestone_creates_non_bias_vote() {
 }
 
 #[test]
-fn submit_milestone_can_resubmit_during_voting_round() {
+fn submit_milestone_stores_evidence_hash() {
+    build_test_externality().execute_with(|| {
+        let cont = get_contributions::<Test>(vec![BOB, CHARLIE], 100_000);
+        let prop_milestones = get_milestones(10);
+        let project_key = create_and_fund_project::<Test>(
+            ALICE,
+            cont,
+            prop_milestones,
+            CurrencyId::Native,
+            vec![JURY_1],
+        )
+        .unwrap();
+        let evidence_hash: ContentHash = b"evidence".to_vec().try_into().unwrap();
+        assert_ok!(Proposals::submit_milestone(
+            RuntimeOrigin::signed(ALICE),
+            project_key,
+            0,
+            Some(evidence_hash.clone())
+        ));
+        let project = Projects::<Test>::get(project_key).unwrap();
+        assert_eq!(
+            project.milestones.get(&0).unwrap().evidence_hash,
+            Some(evidence_hash)
+        );
+        assert_eq!(project.milestones.get(&1).unwrap().evidence_hash, None);
+    });
+}
+
+#[test]
+fn milestone_metadata_is_copied_from_proposed_milestones() {
+    build_test_externality().execute_with(|| {
+        let cont = get_contributions::<Test>(vec![BOB], 100_000);
+        let mut prop_milestones = get_milestones(2);
+        prop_milestones[0].content_hash = b"spec".to_vec().try_into().unwrap();
+        prop_milestones[0].due_block = Some(100);
+        let project_key = create_and_fund_project::<Test>(
+            ALICE,
+            cont,
+            prop_milestones.clone(),
+            CurrencyId::Native,
+            vec![JURY_1],
+        )
+        .unwrap();
+        let project = Projects::<Test>::get(project_key).unwrap();
+        prop_milestones
+            .iter()
+            .enumerate()
+            .for_each(|(i, proposed)| {
+                let milestone = project.milestones.get(&(i as MilestoneKey)).unwrap();
+                assert_eq!(milestone.content_hash, proposed.content_hash);
+                assert_eq!(milestone.due_block, proposed.due_block);
+                assert_eq!(milestone.evidence_hash, None);
+            });
+    });
+}
+
+#[test]
+fn submit_milestone_cannot_resubmit_during_voting_round() {
     build_test_externality().execute_with(|| {
         let cont = get_contributions::<Test>(vec![BOB, CHARLIE], 100_000);
         let prop_milestones = get_milestones(10);
@@ -134,7 +193,8 @@ fn submit_milestone_can_resubmit_during_voting_round() {
         assert_ok!(Proposals::submit_milestone(
             RuntimeOrigin::signed(ALICE),
             project_key,
-            milestone_key
+            milestone_key,
+            None
         ));
         assert_ok!(Proposals::vote_on_milestone(
             RuntimeOrigin::signed(BOB),
@@ -142,29 +202,102 @@ fn submit_milestone_can_resubmit_during_voting_round() {
             milestone_key,
             true
         ));
-        assert_ok!(Proposals::submit_milestone(
-            RuntimeOrigin::signed(ALICE),
-            project_key,
-            milestone_key
-        ));
+        assert_noop!(
+            Proposals::submit_milestone(
+                RuntimeOrigin::signed(ALICE),
+                project_key,
+                milestone_key,
+                None
+            ),
+            Error::<Test>::MilestoneAlreadyInVoting
+        );
         let user_voted =
             UserHasVoted::<Test>::get((project_key, RoundType::VotingRound, milestone_key));
-        dbg!(&user_voted);
         assert_eq!(
             user_voted.len(),
-            0usize,
-            "User votes should be defaulted on resubmission."
+            1usize,
+            "User votes should be kept while the round is open."
         );
-        let total_vote = MilestoneVotes::<Test>::get(project_key);
+    });
+}
 
-        let group_vote = total_vote
-            .get(&milestone_key)
-            .expect("group vote should exist.");
+#[test]
+fn submit_milestone_in_dispute_fails() {
+    build_test_externality().execute_with(|| {
+        let cont = get_contributions::<Test>(vec![BOB, CHARLIE], 100_000);
+        let project_key = create_and_fund_project::<Test>(
+            ALICE,
+            cont,
+            get_milestones(10),
+            CurrencyId::Native,
+            vec![JURY_1, JURY_2],
+        )
+        .unwrap();
+        assert_ok!(Proposals::raise_dispute(
+            RuntimeOrigin::signed(BOB),
+            project_key,
+            vec![0u32].try_into().unwrap()
+        ));
+        assert_noop!(
+            Proposals::submit_milestone(RuntimeOrigin::signed(ALICE), project_key, 0, None),
+            Error::<Test>::MilestonesAlreadyInDispute
+        );
+    });
+}
+
+#[test]
+fn submit_milestone_that_can_be_refunded_fails() {
+    build_test_externality().execute_with(|| {
+        let cont = get_contributions::<Test>(vec![BOB, CHARLIE], 100_000);
+        let project_key = create_and_fund_project::<Test>(
+            ALICE,
+            cont,
+            get_milestones(10),
+            CurrencyId::Native,
+            vec![JURY_1, JURY_2],
+        )
+        .unwrap();
+        Projects::<Test>::mutate(project_key, |maybe_project| {
+            if let Some(project) = maybe_project {
+                project.milestones.get_mut(&0).unwrap().can_refund = true;
+            }
+        });
+        assert_noop!(
+            Proposals::submit_milestone(RuntimeOrigin::signed(ALICE), project_key, 0, None),
+            Error::<Test>::MilestoneIsRefundable
+        );
+    });
+}
+
+#[test]
+fn stale_round_expiry_is_skipped() {
+    build_test_externality().execute_with(|| {
+        let cont = get_contributions::<Test>(vec![BOB, CHARLIE], 100_000);
+        let project_key = create_and_fund_project::<Test>(
+            ALICE,
+            cont,
+            get_milestones(10),
+            CurrencyId::Native,
+            vec![JURY_1, JURY_2],
+        )
+        .unwrap();
+        assert_ok!(Proposals::submit_milestone(
+            RuntimeOrigin::signed(ALICE),
+            project_key,
+            0,
+            None
+        ));
+        let expiry_block = frame_system::Pallet::<Test>::block_number()
+            + <Test as Config>::MilestoneVotingWindow::get();
+        // A round restarted with a later expiry leaves its old entry behind.
+        Rounds::<Test>::insert((project_key, 0), RoundType::VotingRound, expiry_block + 1);
+
+        run_to_block(expiry_block + 1);
         assert_eq!(
-            group_vote,
-            &<Vote<Balance> as Default>::default(),
-            "Group vote should have defaulted on resubmission"
+            Rounds::<Test>::get((project_key, 0), RoundType::VotingRound),
+            Some(expiry_block + 1)
         );
+        assert!(MilestoneVotes::<Test>::get(project_key).contains_key(&0));
     });
 }
 
@@ -181,7 +314,8 @@ fn submit_milestone_can_submit_again_after_failed_vote() {
         assert_ok!(Proposals::submit_milestone(
             RuntimeOrigin::signed(ALICE),
             project_key,
-            1
+            1,
+            None
         ));
         let expiry_block = frame_system::Pallet::<Test>::block_number()
             + <Test as Config>::MilestoneVotingWindow::get();
@@ -189,7 +323,8 @@ fn submit_milestone_can_submit_again_after_failed_vote() {
         assert_ok!(Proposals::submit_milestone(
             RuntimeOrigin::signed(ALICE),
             project_key,
-            1
+            1,
+            None
         ));
     });
 }
@@ -208,7 +343,8 @@ fn submit_milestone_cannot_submit_again_after_success_vote() {
         assert_ok!(Proposals::submit_milestone(
             RuntimeOrigin::signed(ALICE),
             project_key,
-            milestone_key
+            milestone_key,
+            None
         ));
         assert_ok!(Proposals::vote_on_milestone(
             RuntimeOrigin::signed(BOB),
@@ -227,7 +363,12 @@ fn submit_milestone_cannot_submit_again_after_success_vote() {
             + <Test as Config>::MilestoneVotingWindow::get();
         run_to_block(expiry_block + 1);
         assert_noop!(
-            Proposals::submit_milestone(RuntimeOrigin::signed(ALICE), project_key, milestone_key),
+            Proposals::submit_milestone(
+                RuntimeOrigin::signed(ALICE),
+                project_key,
+                milestone_key,
+                None
+            ),
             Error::<Test>::MilestoneAlreadyApproved
         );
     });
@@ -247,7 +388,8 @@ fn ensure_milestone_vote_data_is_cleaned_after_autofinalisation_for() {
         assert_ok!(Proposals::submit_milestone(
             RuntimeOrigin::signed(ALICE),
             project_key,
-            milestone_key
+            milestone_key,
+            None
         ));
         assert_ok!(Proposals::vote_on_milestone(
             RuntimeOrigin::signed(BOB),
@@ -316,7 +458,8 @@ fn ensure_milestone_vote_data_is_cleaned_after_autofinalisation_against() {
         assert_ok!(Proposals::submit_milestone(
             RuntimeOrigin::signed(ALICE),
             project_key,
-            milestone_key
+            milestone_key,
+            None
         ));
         assert_ok!(Proposals::vote_on_milestone(
             RuntimeOrigin::signed(BOB),
@@ -385,7 +528,8 @@ fn users_can_submit_multiple_milestones_and_vote_independantly() {
         assert_ok!(Proposals::submit_milestone(
             RuntimeOrigin::signed(ALICE),
             project_key,
-            milestone_key_0
+            milestone_key_0,
+            None
         ));
         assert_ok!(Proposals::vote_on_milestone(
             RuntimeOrigin::signed(BOB),
@@ -396,7 +540,8 @@ fn users_can_submit_multiple_milestones_and_vote_independantly() {
         assert_ok!(Proposals::submit_milestone(
             RuntimeOrigin::signed(ALICE),
             project_key,
-            milestone_key_1
+            milestone_key_1,
+            None
         ));
         assert_ok!(Proposals::vote_on_milestone(
             RuntimeOrigin::signed(BOB),
@@ -470,7 +615,8 @@ fn vote_on_milestone_after_round_end_fails() {
         assert_ok!(Proposals::submit_milestone(
             RuntimeOrigin::signed(ALICE),
             project_key,
-            milestone_key
+            milestone_key,
+            None
         ));
         run_to_block(expiring_block);
         assert_noop!(
@@ -498,7 +644,8 @@ fn vote_on_milestone_where_voting_round_is_active_but_not_the_correct_milestone(
         assert_ok!(Proposals::submit_milestone(
             RuntimeOrigin::signed(ALICE),
             project_key,
-            0
+            0,
+            None
         ));
         assert_noop!(
             Proposals::vote_on_milestone(RuntimeOrigin::signed(BOB), project_key, 1, true),
@@ -522,13 +669,15 @@ fn if_double_submission_and_one_finalises_voting_on_the_second_can_vote() {
         assert_ok!(Proposals::submit_milestone(
             RuntimeOrigin::signed(ALICE),
             project_key,
-            0
+            0,
+            None
         ));
         run_to_block(frame_system::Pallet::<Test>::block_number() + 10);
         assert_ok!(Proposals::submit_milestone(
             RuntimeOrigin::signed(ALICE),
             project_key,
-            1
+            1,
+            None
         ));
         run_to_block(expiring_block);
         assert_ok!(Proposals::vote_on_milestone(
@@ -554,7 +703,8 @@ fn vote_on_milestone_not_contributor() {
         assert_ok!(Proposals::submit_milestone(
             RuntimeOrigin::signed(ALICE),
             project_key,
-            milestone_key
+            milestone_key,
+            None
         ));
         assert_noop!(
             Proposals::vote_on_milestone(
@@ -582,7 +732,8 @@ fn vote_on_milestone_actually_adds_to_vote() {
         assert_ok!(Proposals::submit_milestone(
             RuntimeOrigin::signed(ALICE),
             project_key,
-            milestone_key
+            milestone_key,
+            None
         ));
         assert_ok!(Proposals::vote_on_milestone(
             RuntimeOrigin::signed(BOB),
@@ -622,7 +773,8 @@ fn vote_on_milestone_autofinalises_on_all_voted_and_fail() {
         assert_ok!(Proposals::submit_milestone(
             RuntimeOrigin::signed(ALICE),
             project_key,
-            milestone_key
+            milestone_key,
+            None
         ));
         assert_ok!(Proposals::vote_on_milestone(
             RuntimeOrigin::signed(BOB),
@@ -662,7 +814,8 @@ fn vote_struct_removed_on_autofinalisation_success() {
         assert_ok!(Proposals::submit_milestone(
             RuntimeOrigin::signed(ALICE),
             project_key,
-            milestone_key
+            milestone_key,
+            None
         ));
         assert_ok!(Proposals::vote_on_milestone(
             RuntimeOrigin::signed(BOB),
@@ -699,7 +852,8 @@ fn vote_struct_removed_on_autofinalisation_failure() {
         assert_ok!(Proposals::submit_milestone(
             RuntimeOrigin::signed(ALICE),
             project_key,
-            milestone_key
+            milestone_key,
+            None
         ));
         assert_ok!(Proposals::vote_on_milestone(
             RuntimeOrigin::signed(BOB),
@@ -737,7 +891,8 @@ fn withdraw_not_initiator() {
         assert_ok!(Proposals::submit_milestone(
             RuntimeOrigin::signed(ALICE),
             project_key,
-            milestone_key
+            milestone_key,
+            None
         ));
         assert_ok!(Proposals::vote_on_milestone(
             RuntimeOrigin::signed(BOB),
@@ -776,9 +931,13 @@ fn withdraw_only_transfers_approved_milestones() {
                 .unwrap();
         let project_account = crate::Pallet::<Test>::project_account_id(project_key);
         let milestone_key = 0;
-        let _ =
-            Proposals::submit_milestone(RuntimeOrigin::signed(ALICE), project_key, milestone_key)
-                .unwrap();
+        let _ = Proposals::submit_milestone(
+            RuntimeOrigin::signed(ALICE),
+            project_key,
+            milestone_key,
+            None,
+        )
+        .unwrap();
         let _ = Proposals::vote_on_milestone(
             RuntimeOrigin::signed(BOB),
             project_key,
@@ -826,9 +985,13 @@ fn withdraw_removes_project_after_all_funds_taken() {
             create_and_fund_project::<Test>(ALICE, cont, prop_milestones, CurrencyId::Native, jury)
                 .unwrap();
         let milestone_key = 0;
-        let _ =
-            Proposals::submit_milestone(RuntimeOrigin::signed(ALICE), project_key, milestone_key)
-                .unwrap();
+        let _ = Proposals::submit_milestone(
+            RuntimeOrigin::signed(ALICE),
+            project_key,
+            milestone_key,
+            None,
+        )
+        .unwrap();
         let _ = Proposals::vote_on_milestone(
             RuntimeOrigin::signed(BOB),
             project_key,
@@ -845,7 +1008,9 @@ fn withdraw_removes_project_after_all_funds_taken() {
         assert!(
             Projects::<Test>::get(project_key).is_none(),
             "Project should have been removed after funds withdrawn."
-        )
+        );
+        assert!(JurorProjects::<Test>::get(JURY_1).is_empty());
+        assert!(JurorProjects::<Test>::get(JURY_2).is_empty());
     });
 }
 
@@ -860,9 +1025,13 @@ fn store_project_info_after_project_is_completed() {
             create_and_fund_project::<Test>(ALICE, cont, prop_milestones, CurrencyId::Native, jury)
                 .unwrap();
         let milestone_key = 0;
-        let _ =
-            Proposals::submit_milestone(RuntimeOrigin::signed(ALICE), project_key, milestone_key)
-                .unwrap();
+        let _ = Proposals::submit_milestone(
+            RuntimeOrigin::signed(ALICE),
+            project_key,
+            milestone_key,
+            None,
+        )
+        .unwrap();
         let _ = Proposals::vote_on_milestone(
             RuntimeOrigin::signed(BOB),
             project_key,
@@ -905,6 +1074,7 @@ fn store_too_many_projects_for_account() {
                 RuntimeOrigin::signed(ALICE),
                 project_key,
                 milestone_key,
+                None,
             )
             .unwrap();
             let _ = Proposals::vote_on_milestone(
@@ -945,9 +1115,13 @@ fn withdraw_takes_imbue_fee() {
         let fee_account_balance_before =
             <Test as Config>::MultiCurrency::free_balance(CurrencyId::Native, &fee_account);
 
-        let _ =
-            Proposals::submit_milestone(RuntimeOrigin::signed(ALICE), project_key, milestone_key)
-                .unwrap();
+        let _ = Proposals::submit_milestone(
+            RuntimeOrigin::signed(ALICE),
+            project_key,
+            milestone_key,
+            None,
+        )
+        .unwrap();
         let _ = Proposals::vote_on_milestone(
             RuntimeOrigin::signed(BOB),
             project_key,
@@ -982,9 +1156,13 @@ fn withdraw_cannot_double_withdraw() {
             create_and_fund_project::<Test>(ALICE, cont, prop_milestones, CurrencyId::Native, jury)
                 .unwrap();
         let milestone_key = 0;
-        let _ =
-            Proposals::submit_milestone(RuntimeOrigin::signed(ALICE), project_key, milestone_key)
-                .unwrap();
+        let _ = Proposals::submit_milestone(
+            RuntimeOrigin::signed(ALICE),
+            project_key,
+            milestone_key,
+            None,
+        )
+        .unwrap();
         let _ = Proposals::vote_on_milestone(
             RuntimeOrigin::signed(BOB),
             project_key,
@@ -1013,10 +1191,12 @@ fn withdraw_once_times_with_double_submissions() {
         let project_key =
             create_and_fund_project::<Test>(ALICE, cont, prop_milestones, CurrencyId::Native, jury)
                 .unwrap();
-        let _ = Proposals::submit_milestone(RuntimeOrigin::signed(ALICE), project_key, 0).unwrap();
+        let _ = Proposals::submit_milestone(RuntimeOrigin::signed(ALICE), project_key, 0, None)
+            .unwrap();
         let _ =
             Proposals::vote_on_milestone(RuntimeOrigin::signed(BOB), project_key, 0, true).unwrap();
-        let _ = Proposals::submit_milestone(RuntimeOrigin::signed(ALICE), project_key, 1).unwrap();
+        let _ = Proposals::submit_milestone(RuntimeOrigin::signed(ALICE), project_key, 1, None)
+            .unwrap();
         let _ =
             Proposals::vote_on_milestone(RuntimeOrigin::signed(BOB), project_key, 1, true).unwrap();
 
@@ -1049,7 +1229,8 @@ fn withdraw_twice_with_intermitent_submission() {
                 .unwrap();
 
         // The first submission and withdraw
-        let _ = Proposals::submit_milestone(RuntimeOrigin::signed(ALICE), project_key, 0).unwrap();
+        let _ = Proposals::submit_milestone(RuntimeOrigin::signed(ALICE), project_key, 0, None)
+            .unwrap();
         let _ =
             Proposals::vote_on_milestone(RuntimeOrigin::signed(BOB), project_key, 0, true).unwrap();
         let alice_before =
@@ -1067,7 +1248,8 @@ fn withdraw_twice_with_intermitent_submission() {
         );
 
         // The second submission and withdraw
-        let _ = Proposals::submit_milestone(RuntimeOrigin::signed(ALICE), project_key, 1).unwrap();
+        let _ = Proposals::submit_milestone(RuntimeOrigin::signed(ALICE), project_key, 1, None)
+            .unwrap();
         let _ =
             Proposals::vote_on_milestone(RuntimeOrigin::signed(BOB), project_key, 1, true).unwrap();
         let alice_before =
@@ -1093,9 +1275,13 @@ fn withdraw_with_variable_percentage() {
         let prop_milestones = vec![
             ProposedMilestone {
                 percentage_to_unlock: Percent::from_percent(70u8),
+                content_hash: Default::default(),
+                due_block: None,
             },
             ProposedMilestone {
                 percentage_to_unlock: Percent::from_percent(30u8),
+                content_hash: Default::default(),
+                due_block: None,
             },
         ];
         let jury = vec![JURY_1, JURY_2];
@@ -1103,7 +1289,8 @@ fn withdraw_with_variable_percentage() {
         let project_key =
             create_and_fund_project::<Test>(ALICE, cont, prop_milestones, CurrencyId::Native, jury)
                 .unwrap();
-        let _ = Proposals::submit_milestone(RuntimeOrigin::signed(ALICE), project_key, 0).unwrap();
+        let _ = Proposals::submit_milestone(RuntimeOrigin::signed(ALICE), project_key, 0, None)
+            .unwrap();
         let _ =
             Proposals::vote_on_milestone(RuntimeOrigin::signed(BOB), project_key, 0, true).unwrap();
         let alice_before =
@@ -1137,9 +1324,13 @@ fn withdraw_fails_before_approval() {
             Proposals::withdraw(RuntimeOrigin::signed(ALICE), project_key),
             Error::<Test>::NoAvailableFundsToWithdraw
         );
-        let _ =
-            Proposals::submit_milestone(RuntimeOrigin::signed(ALICE), project_key, milestone_key)
-                .unwrap();
+        let _ = Proposals::submit_milestone(
+            RuntimeOrigin::signed(ALICE),
+            project_key,
+            milestone_key,
+            None,
+        )
+        .unwrap();
         assert_noop!(
             Proposals::withdraw(RuntimeOrigin::signed(ALICE), project_key),
             Error::<Test>::NoAvailableFundsToWithdraw
@@ -1162,9 +1353,13 @@ fn withdraw_assert_milestone_state_change() {
             Proposals::withdraw(RuntimeOrigin::signed(ALICE), project_key),
             Error::<Test>::NoAvailableFundsToWithdraw
         );
-        let _ =
-            Proposals::submit_milestone(RuntimeOrigin::signed(ALICE), project_key, milestone_key)
-                .unwrap();
+        let _ = Proposals::submit_milestone(
+            RuntimeOrigin::signed(ALICE),
+            project_key,
+            milestone_key,
+            None,
+        )
+        .unwrap();
         assert_noop!(
             Proposals::withdraw(RuntimeOrigin::signed(ALICE), project_key),
             Error::<Test>::NoAvailableFundsToWithdraw
@@ -1221,12 +1416,14 @@ fn project_in_voting_is_saved_on_submission() {
         assert_ok!(Proposals::submit_milestone(
             RuntimeOrigin::signed(ALICE),
             project_key,
-            0
+            0,
+            None
         ));
         assert_ok!(Proposals::submit_milestone(
             RuntimeOrigin::signed(ALICE),
             project_key,
-            1
+            1,
+            None
         ));
 
         assert!(ProjectInVoting::<Test>::contains_key(project_key, 0));
@@ -1248,7 +1445,8 @@ fn project_in_voting_is_removed_on_init_hook() {
         assert_ok!(Proposals::submit_milestone(
             RuntimeOrigin::signed(ALICE),
             project_key,
-            0
+            0,
+            None
         ));
 
         assert!(ProjectInVoting::<Test>::contains_key(project_key, 0));
@@ -1276,7 +1474,8 @@ fn project_in_voting_is_removed_on_milestone_autofinalisation() {
         assert_ok!(Proposals::submit_milestone(
             RuntimeOrigin::signed(ALICE),
             project_key,
-            0
+            0,
+            None
         ));
         assert!(ProjectInVoting::<Test>::contains_key(project_key, 0));
         assert_ok!(Proposals::vote_on_milestone(
@@ -1288,3 +1487,494 @@ fn project_in_voting_is_removed_on_milestone_autofinalisation() {
         assert!(!ProjectInVoting::<Test>::contains_key(project_key, 0));
     })
 }
+
+fn submit_and_reject_milestone(project_key: ProjectKey, milestone_key: MilestoneKey) {
+    assert_ok!(Proposals::submit_milestone(
+        RuntimeOrigin::signed(ALICE),
+        project_key,
+        milestone_key,
+        None
+    ));
+    assert_ok!(Proposals::vote_on_milestone(
+        RuntimeOrigin::signed(BOB),
+        project_key,
+        milestone_key,
+        false
+    ));
+}
+
+#[test]
+fn submit_milestone_records_the_attempt() {
+    build_test_externality().execute_with(|| {
+        let cont = get_contributions::<Test>(vec![BOB], 100_000);
+        let project_key = create_and_fund_project::<Test>(
+            ALICE,
+            cont,
+            get_milestones(10),
+            CurrencyId::Native,
+            vec![JURY_1, JURY_2],
+        )
+        .unwrap();
+        let submitted_on = frame_system::Pallet::<Test>::block_number();
+        submit_and_reject_milestone(project_key, 0);
+        run_to_block(submitted_on + 5);
+        assert_ok!(Proposals::submit_milestone(
+            RuntimeOrigin::signed(ALICE),
+            project_key,
+            0,
+            None
+        ));
+
+        let attempts = Projects::<Test>::get(project_key)
+            .unwrap()
+            .milestones
+            .get(&0)
+            .unwrap()
+            .attempts
+            .clone()
+            .into_inner();
+        assert_eq!(
+            attempts,
+            vec![
+                MilestoneAttempt {
+                    submitted_on,
+                    rejected_on: Some(submitted_on),
+                },
+                MilestoneAttempt {
+                    submitted_on: submitted_on + 5,
+                    rejected_on: None,
+                },
+            ]
+        );
+    })
+}
+
+#[test]
+fn submit_milestone_during_the_resubmission_cooldown_fails() {
+    build_test_externality().execute_with(|| {
+        DefaultResubmissionPolicy::set(ResubmissionPolicy {
+            cooldown: 10,
+            max_attempts: 3,
+        });
+        let cont = get_contributions::<Test>(vec![BOB], 100_000);
+        let project_key = create_and_fund_project::<Test>(
+            ALICE,
+            cont,
+            get_milestones(10),
+            CurrencyId::Native,
+            vec![JURY_1, JURY_2],
+        )
+        .unwrap();
+        let rejected_on = frame_system::Pallet::<Test>::block_number();
+        submit_and_reject_milestone(project_key, 0);
+
+        assert_noop!(
+            Proposals::submit_milestone(RuntimeOrigin::signed(ALICE), project_key, 0, None),
+            Error::<Test>::ResubmissionCooldown
+        );
+        // Other milestones are not affected.
+        assert_ok!(Proposals::submit_milestone(
+            RuntimeOrigin::signed(ALICE),
+            project_key,
+            1,
+            None
+        ));
+
+        run_to_block(rejected_on + 10);
+        assert_ok!(Proposals::submit_milestone(
+            RuntimeOrigin::signed(ALICE),
+            project_key,
+            0,
+            None
+        ));
+    })
+}
+
+#[test]
+fn milestone_can_be_refunded_once_its_attempts_are_exhausted() {
+    build_test_externality().execute_with(|| {
+        DefaultResubmissionPolicy::set(ResubmissionPolicy {
+            cooldown: 0,
+            max_attempts: 2,
+        });
+        let cont = get_contributions::<Test>(vec![BOB], 100_000);
+        let project_key = create_and_fund_project::<Test>(
+            ALICE,
+            cont,
+            get_milestones(10),
+            CurrencyId::Native,
+            vec![JURY_1, JURY_2],
+        )
+        .unwrap();
+        submit_and_reject_milestone(project_key, 0);
+        assert!(
+            !Projects::<Test>::get(project_key)
+                .unwrap()
+                .milestones
+                .get(&0)
+                .unwrap()
+                .can_refund
+        );
+
+        submit_and_reject_milestone(project_key, 0);
+        assert_last_event::<Test>(
+            Event::<Test>::MilestoneAttemptsExhausted {
+                project_key,
+                milestone_key: 0,
+            }
+            .into(),
+        );
+        assert!(
+            Projects::<Test>::get(project_key)
+                .unwrap()
+                .milestones
+                .get(&0)
+                .unwrap()
+                .can_refund
+        );
+        assert_noop!(
+            Proposals::submit_milestone(RuntimeOrigin::signed(ALICE), project_key, 0, None),
+            Error::<Test>::NoAttemptsRemaining
+        );
+    })
+}
+
+#[test]
+fn resubmission_policy_is_fixed_when_the_project_is_created() {
+    build_test_externality().execute_with(|| {
+        let cont = get_contributions::<Test>(vec![BOB], 100_000);
+        let project_key = create_and_fund_project::<Test>(
+            ALICE,
+            cont,
+            get_milestones(10),
+            CurrencyId::Native,
+            vec![JURY_1, JURY_2],
+        )
+        .unwrap();
+        DefaultResubmissionPolicy::set(ResubmissionPolicy {
+            cooldown: 0,
+            max_attempts: 1,
+        });
+        submit_and_reject_milestone(project_key, 0);
+        assert_ok!(Proposals::submit_milestone(
+            RuntimeOrigin::signed(ALICE),
+            project_key,
+            0,
+            None
+        ));
+    })
+}
+
+#[test]
+fn set_project_policies_lets_projects_differ() {
+    build_test_externality().execute_with(|| {
+        DefaultResubmissionPolicy::set(ResubmissionPolicy {
+            cooldown: 0,
+            max_attempts: 3,
+        });
+        let cont = get_contributions::<Test>(vec![BOB], 100_000);
+        let project_key = create_and_fund_project::<Test>(
+            ALICE,
+            cont.clone(),
+            get_milestones(10),
+            CurrencyId::Native,
+            vec![JURY_1, JURY_2],
+        )
+        .unwrap();
+        let other_project_key = create_and_fund_project::<Test>(
+            ALICE,
+            cont,
+            get_milestones(10),
+            CurrencyId::Native,
+            vec![JURY_1, JURY_2],
+        )
+        .unwrap();
+        let resubmission_policy = ResubmissionPolicy {
+            cooldown: 0,
+            max_attempts: 1,
+        };
+        assert_noop!(
+            Proposals::set_project_policies(
+                RuntimeOrigin::signed(ALICE),
+                project_key,
+                resubmission_policy.clone(),
+                VotingExpiryPolicy::OptimisticApproval
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(Proposals::set_project_policies(
+            RuntimeOrigin::root(),
+            project_key,
+            resubmission_policy.clone(),
+            VotingExpiryPolicy::OptimisticApproval
+        ));
+        assert_last_event::<Test>(
+            Event::<Test>::ProjectPoliciesSet {
+                project_key,
+                resubmission_policy,
+                voting_expiry_policy: VotingExpiryPolicy::OptimisticApproval,
+            }
+            .into(),
+        );
+        let project = Projects::<Test>::get(project_key).unwrap();
+        let other_project = Projects::<Test>::get(other_project_key).unwrap();
+        assert_ne!(
+            project.resubmission_policy,
+            other_project.resubmission_policy
+        );
+        assert_eq!(
+            project.voting_expiry_policy,
+            VotingExpiryPolicy::OptimisticApproval
+        );
+        assert_eq!(
+            other_project.voting_expiry_policy,
+            VotingExpiryPolicy::Reject
+        );
+
+        submit_and_reject_milestone(project_key, 0);
+        assert_noop!(
+            Proposals::submit_milestone(RuntimeOrigin::signed(ALICE), project_key, 0, None),
+            Error::<Test>::NoAttemptsRemaining
+        );
+        submit_and_reject_milestone(other_project_key, 0);
+        assert_ok!(Proposals::submit_milestone(
+            RuntimeOrigin::signed(ALICE),
+            other_project_key,
+            0,
+            None
+        ));
+    })
+}
+
+#[test]
+fn set_project_policies_on_a_missing_project_fails() {
+    build_test_externality().execute_with(|| {
+        assert_noop!(
+            Proposals::set_project_policies(
+                RuntimeOrigin::root(),
+                0,
+                DefaultResubmissionPolicy::get(),
+                VotingExpiryPolicy::Reject
+            ),
+            Error::<Test>::ProjectDoesNotExist
+        );
+    })
+}
+
+fn expire_milestone_vote(policy: VotingExpiryPolicy, votes: Vec<(AccountId, bool)>) -> ProjectKey {
+    DefaultVotingExpiryPolicy::set(policy);
+    let cont = get_contributions::<Test>(vec![BOB, CHARLIE, DAVE], 100_000);
+    let project_key = create_and_fund_project::<Test>(
+        ALICE,
+        cont,
+        get_milestones(10),
+        CurrencyId::Native,
+        vec![JURY_1, JURY_2],
+    )
+    .unwrap();
+    assert_ok!(Proposals::submit_milestone(
+        RuntimeOrigin::signed(ALICE),
+        project_key,
+        0,
+        None
+    ));
+    let expiry_block = frame_system::Pallet::<Test>::block_number()
+        + <Test as Config>::MilestoneVotingWindow::get();
+    for (who, approve) in votes {
+        assert_ok!(Proposals::vote_on_milestone(
+            RuntimeOrigin::signed(who),
+            project_key,
+            0,
+            approve
+        ));
+    }
+    run_to_block(expiry_block);
+    assert!(!ProjectInVoting::<Test>::contains_key(project_key, 0));
+    assert!(MilestoneVotes::<Test>::get(project_key).get(&0).is_none());
+    project_key
+}
+
+fn turnout_relative(min_turnout: u8) -> VotingExpiryPolicy {
+    VotingExpiryPolicy::TurnoutRelative {
+        min_turnout: Percent::from_percent(min_turnout),
+    }
+}
+
+#[test]
+fn voting_round_expiry_rejects_by_default() {
+    build_test_externality().execute_with(|| {
+        let project_key = expire_milestone_vote(VotingExpiryPolicy::Reject, vec![(BOB, true)]);
+
+        assert_last_event::<Test>(
+            Event::<Test>::MilestoneVotingExpired {
+                project_key,
+                milestone_key: 0,
+                yay: 100_000,
+                nay: 0,
+                approved: false,
+            }
+            .into(),
+        );
+        let milestone = Projects::<Test>::get(project_key).unwrap().milestones[&0].clone();
+        assert!(!milestone.is_approved);
+        assert!(milestone.attempts[0].rejected_on.is_some());
+    })
+}
+
+#[test]
+fn voting_round_expiry_optimistic_approval_approves_without_enough_nays() {
+    build_test_externality().execute_with(|| {
+        let project_key =
+            expire_milestone_vote(VotingExpiryPolicy::OptimisticApproval, vec![(BOB, false)]);
+
+        assert_last_event::<Test>(
+            Event::<Test>::MilestoneVotingExpired {
+                project_key,
+                milestone_key: 0,
+                yay: 0,
+                nay: 100_000,
+                approved: true,
+            }
+            .into(),
+        );
+        assert!(Projects::<Test>::get(project_key).unwrap().milestones[&0].is_approved);
+        assert_ok!(Proposals::withdraw(
+            RuntimeOrigin::signed(ALICE),
+            project_key
+        ));
+    })
+}
+
+#[test]
+fn voting_round_expiry_turnout_relative_approves_with_enough_of_the_turnout() {
+    build_test_externality().execute_with(|| {
+        let project_key = expire_milestone_vote(turnout_relative(30), vec![(BOB, true)]);
+
+        assert_last_event::<Test>(
+            Event::<Test>::MilestoneVotingExpired {
+                project_key,
+                milestone_key: 0,
+                yay: 100_000,
+                nay: 0,
+                approved: true,
+            }
+            .into(),
+        );
+        assert!(Projects::<Test>::get(project_key).unwrap().milestones[&0].is_approved);
+    })
+}
+
+#[test]
+fn voting_round_expiry_turnout_relative_rejects_without_enough_of_the_turnout() {
+    build_test_externality().execute_with(|| {
+        let project_key =
+            expire_milestone_vote(turnout_relative(30), vec![(BOB, true), (CHARLIE, false)]);
+
+        assert_last_event::<Test>(
+            Event::<Test>::MilestoneVotingExpired {
+                project_key,
+                milestone_key: 0,
+                yay: 100_000,
+                nay: 100_000,
+                approved: false,
+            }
+            .into(),
+        );
+        assert!(!Projects::<Test>::get(project_key).unwrap().milestones[&0].is_approved);
+    })
+}
+
+#[test]
+fn voting_round_expiry_turnout_relative_rejects_below_the_minimum_turnout() {
+    build_test_externality().execute_with(|| {
+        // A third of the raised funds voted.
+        let project_key = expire_milestone_vote(turnout_relative(50), vec![(BOB, true)]);
+
+        assert_last_event::<Test>(
+            Event::<Test>::MilestoneVotingExpired {
+                project_key,
+                milestone_key: 0,
+                yay: 100_000,
+                nay: 0,
+                approved: false,
+            }
+            .into(),
+        );
+        assert!(!Projects::<Test>::get(project_key).unwrap().milestones[&0].is_approved);
+    })
+}
+
+#[test]
+fn voting_round_expiry_turnout_relative_rejects_without_votes() {
+    build_test_externality().execute_with(|| {
+        let project_key = expire_milestone_vote(turnout_relative(30), vec![]);
+
+        assert_last_event::<Test>(
+            Event::<Test>::MilestoneVotingExpired {
+                project_key,
+                milestone_key: 0,
+                yay: 0,
+                nay: 0,
+                approved: false,
+            }
+            .into(),
+        );
+        assert!(!Projects::<Test>::get(project_key).unwrap().milestones[&0].is_approved);
+    })
+}
+
+#[test]
+fn closing_a_voting_round_keeps_other_rounds_expiring_on_the_same_block() {
+    build_test_externality().execute_with(|| {
+        let project_key_1 = create_and_fund_project::<Test>(
+            ALICE,
+            get_contributions::<Test>(vec![BOB], 100_000),
+            get_milestones(10),
+            CurrencyId::Native,
+            vec![JURY_1, JURY_2],
+        )
+        .unwrap();
+        let project_key_2 = create_and_fund_project::<Test>(
+            ALICE,
+            get_contributions::<Test>(vec![CHARLIE], 100_000),
+            get_milestones(10),
+            CurrencyId::Native,
+            vec![JURY_1, JURY_2],
+        )
+        .unwrap();
+        let expiry_block = frame_system::Pallet::<Test>::block_number()
+            + <Test as Config>::MilestoneVotingWindow::get();
+        for project_key in [project_key_1, project_key_2] {
+            assert_ok!(Proposals::submit_milestone(
+                RuntimeOrigin::signed(ALICE),
+                project_key,
+                0,
+                None
+            ));
+        }
+
+        // The first round is finalised early.
+        assert_ok!(Proposals::vote_on_milestone(
+            RuntimeOrigin::signed(BOB),
+            project_key_1,
+            0,
+            true
+        ));
+        assert_eq!(
+            RoundsExpiring::<Test>::get(expiry_block).into_inner(),
+            vec![(project_key_2, RoundType::VotingRound, 0)]
+        );
+
+        run_to_block(expiry_block);
+        System::assert_has_event(RuntimeEvent::Proposals(
+            Event::<Test>::MilestoneVotingExpired {
+                project_key: project_key_2,
+                milestone_key: 0,
+                yay: 0,
+                nay: 0,
+                approved: false,
+            },
+        ));
+        assert!(!ProjectInVoting::<Test>::contains_key(project_key_2, 0));
+    })
+}
@@ -32,6 +32,7 @@ fn you_can_actually_refund_after_dispute_success() {
             project_key,
             milestone_keys.into_inner(),
             DisputeResult::Success,
+            None,
         );
         // All milestones should be good for refund
 
@@ -66,6 +67,7 @@ fn refund_assert_milestone_state_change() {
             project_key,
             milestone_keys.into_inner(),
             DisputeResult::Success,
+            None,
         );
         // All milestones should be good for refund
 
@@ -119,6 +121,7 @@ fn refund_not_contributor() {
             project_key,
             milestone_keys.into_inner(),
             DisputeResult::Success,
+            None,
         );
         assert_noop!(
             Proposals::refund(RuntimeOrigin::signed(DAVE), project_key),
@@ -156,6 +159,7 @@ fn refund_deletes_project_when_all_funds_are_refunded() {
             project_key,
             milestone_keys.into_inner(),
             DisputeResult::Success,
+            None,
         );
         // All milestones should be good for refund
 
@@ -201,10 +205,15 @@ fn withdraw_then_refund_no_double_spend() {
             project_key,
             milestone_keys.into_inner(),
             DisputeResult::Success,
+            None,
         );
-        let _ =
-            Proposals::submit_milestone(RuntimeOrigin::signed(ALICE), project_key, milestone_key)
-                .unwrap();
+        let _ = Proposals::submit_milestone(
+            RuntimeOrigin::signed(ALICE),
+            project_key,
+            milestone_key,
+            None,
+        )
+        .unwrap();
 
         let _ = Proposals::vote_on_milestone(
             RuntimeOrigin::signed(BOB),
@@ -271,10 +280,15 @@ fn refund_then_withdraw_no_double_spend() {
             project_key,
             milestone_keys.into_inner(),
             DisputeResult::Success,
+            None,
         );
-        let _ =
-            Proposals::submit_milestone(RuntimeOrigin::signed(ALICE), project_key, milestone_key)
-                .unwrap();
+        let _ = Proposals::submit_milestone(
+            RuntimeOrigin::signed(ALICE),
+            project_key,
+            milestone_key,
+            None,
+        )
+        .unwrap();
 
         let _ = Proposals::vote_on_milestone(
             RuntimeOrigin::signed(BOB),
@@ -325,6 +339,7 @@ fn refund_check_refund_amount() {
             project_key,
             milestone_keys.into_inner(),
             DisputeResult::Success,
+            None,
         );
         // All milestones should be good for refund
 
@@ -386,6 +401,7 @@ fn refund_takes_imbue_fee() {
             project_key,
             milestone_keys.into_inner(),
             DisputeResult::Success,
+            None,
         );
         // All milestones should be good for refund
 
@@ -419,3 +435,99 @@ fn refund_takes_imbue_fee() {
         );
     })
 }
+
+fn split_milestone(refund_share: Percent) -> ProjectKey {
+    let contributions = get_contributions::<Test>(vec![BOB, CHARLIE], 100_000u128);
+    let project_key = create_and_fund_project::<Test>(
+        ALICE,
+        contributions,
+        get_milestones(1),
+        CurrencyId::Native,
+        vec![JURY_1, JURY_2],
+    )
+    .unwrap();
+    assert_ok!(Proposals::raise_dispute(
+        RuntimeOrigin::signed(BOB),
+        project_key,
+        vec![0u32].try_into().unwrap()
+    ));
+    for juror in [JURY_1, JURY_2] {
+        assert_ok!(Proposals::propose_refund_share(
+            RuntimeOrigin::signed(juror),
+            project_key,
+            0,
+            refund_share
+        ));
+    }
+    let _ = complete_dispute::<Test>(project_key, vec![0], DisputeResult::Success, None);
+    project_key
+}
+
+#[test]
+fn refund_only_refunds_the_refund_share() {
+    build_test_externality().execute_with(|| {
+        let project_key = split_milestone(Percent::from_percent(60));
+        let alice_before =
+            <Test as Config>::MultiCurrency::free_balance(CurrencyId::Native, &ALICE);
+
+        assert_ok!(Proposals::refund(RuntimeOrigin::signed(BOB), project_key));
+        assert_last_event::<Test>(
+            Event::<Test>::ProjectRefunded {
+                project_key,
+                total_amount: 120_000,
+            }
+            .into(),
+        );
+        assert_noop!(
+            Proposals::refund(RuntimeOrigin::signed(BOB), project_key),
+            Error::<Test>::NoAvailableFundsToWithdraw
+        );
+
+        // The initiator withdraws the rest of the milestone.
+        assert_ok!(Proposals::withdraw(
+            RuntimeOrigin::signed(ALICE),
+            project_key
+        ));
+        let alice_after = <Test as Config>::MultiCurrency::free_balance(CurrencyId::Native, &ALICE);
+        let fee = <Test as Config>::ImbueFee::get().mul_floor(80_000u128);
+        assert_eq!(alice_after - alice_before, 80_000 - fee);
+        // Every fund has been taken out of the project.
+        assert!(Projects::<Test>::get(project_key).is_none());
+    })
+}
+
+#[test]
+fn withdraw_only_withdraws_the_initiator_share() {
+    build_test_externality().execute_with(|| {
+        let project_key = split_milestone(Percent::from_percent(60));
+
+        let project = Projects::<Test>::get(project_key).unwrap();
+        assert_eq!(Proposals::withdrawable_funds(&project), 80_000);
+        assert_eq!(Proposals::refundable_funds(&project), 120_000);
+        assert_ok!(Proposals::withdraw(
+            RuntimeOrigin::signed(ALICE),
+            project_key
+        ));
+        assert_last_event::<Test>(
+            Event::<Test>::ProjectFundsWithdrawn(ALICE, project_key, 80_000, CurrencyId::Native)
+                .into(),
+        );
+        assert_noop!(
+            Proposals::withdraw(RuntimeOrigin::signed(ALICE), project_key),
+            Error::<Test>::NoAvailableFundsToWithdraw
+        );
+        let project = Projects::<Test>::get(project_key).unwrap();
+        assert!(project.milestones[&0].split_withdrawn_on.is_some());
+        assert_eq!(Proposals::withdrawable_funds(&project), 0);
+
+        assert_ok!(Proposals::refund(RuntimeOrigin::signed(BOB), project_key));
+        assert_last_event::<Test>(
+            Event::<Test>::ProjectRefunded {
+                project_key,
+                total_amount: 120_000,
+            }
+            .into(),
+        );
+        assert!(Projects::<Test>::get(project_key).is_none());
+    })
+}
@@ -0,0 +1,202 @@
+use crate::{mock::*, *};
+use frame_support::assert_ok;
+use pallet_disputes::DisputeResult;
+use pallet_proposals_rpc_runtime_api::{FundingStatus, MilestoneStatus};
+use test_utils::*;
+
+#[test]
+fn project_info_none_for_missing_project() {
+    build_test_externality().execute_with(|| {
+        assert!(Proposals::project_info(0).is_none());
+    });
+}
+
+#[test]
+fn project_info_decodes_project() {
+    build_test_externality().execute_with(|| {
+        let cont = get_contributions::<Test>(vec![BOB, CHARLIE], 100_000);
+        let prop_milestones = get_milestones(10);
+        let jury = vec![JURY_1, JURY_2];
+        let project_key = create_and_fund_project::<Test>(
+            ALICE,
+            cont,
+            prop_milestones,
+            CurrencyId::Native,
+            jury.clone(),
+        )
+        .unwrap();
+
+        let info = Proposals::project_info(project_key).expect("project was just created; qed");
+        assert_eq!(info.project_key, project_key);
+        assert_eq!(info.initiator, ALICE);
+        assert_eq!(info.raised_funds, 200_000);
+        assert_eq!(info.funding_state, FundingStatus::Funded);
+        assert_eq!(info.jury, jury);
+        assert_eq!(info.milestones.len(), 10);
+        assert_eq!(info.contributions.len(), 2);
+        assert!(info
+            .milestones
+            .iter()
+            .all(|ms| ms.status == MilestoneStatus::Pending && ms.percentage_to_unlock == 10));
+        assert_eq!(info.withdrawable_funds, 0);
+        assert_eq!(info.refundable_funds, 0);
+    });
+}
+
+#[test]
+fn project_info_tracks_milestone_votes_and_withdrawals() {
+    build_test_externality().execute_with(|| {
+        let cont = get_contributions::<Test>(vec![BOB, CHARLIE], 100_000);
+        let prop_milestones = get_milestones(10);
+        let jury = vec![JURY_1, JURY_2];
+        let project_key =
+            create_and_fund_project::<Test>(ALICE, cont, prop_milestones, CurrencyId::Native, jury)
+                .unwrap();
+        let milestone_key = 0;
+        assert_ok!(Proposals::submit_milestone(
+            RuntimeOrigin::signed(ALICE),
+            project_key,
+            milestone_key,
+            None
+        ));
+        assert_ok!(Proposals::vote_on_milestone(
+            RuntimeOrigin::signed(BOB),
+            project_key,
+            milestone_key,
+            true
+        ));
+
+        let info = Proposals::project_info(project_key).unwrap();
+        let milestone = &info.milestones[milestone_key as usize];
+        assert_eq!(milestone.status, MilestoneStatus::InVoting);
+        assert_eq!(milestone.yay, 100_000);
+        assert_eq!(milestone.votes, vec![(BOB, true)]);
+
+        assert_ok!(Proposals::vote_on_milestone(
+            RuntimeOrigin::signed(CHARLIE),
+            project_key,
+            milestone_key,
+            true
+        ));
+        let info = Proposals::project_info(project_key).unwrap();
+        assert_eq!(
+            info.milestones[milestone_key as usize].status,
+            MilestoneStatus::Approved
+        );
+        assert_eq!(info.withdrawable_funds, 20_000);
+
+        assert_ok!(Proposals::withdraw(
+            RuntimeOrigin::signed(ALICE),
+            project_key
+        ));
+        let info = Proposals::project_info(project_key).unwrap();
+        assert_eq!(
+            info.milestones[milestone_key as usize].status,
+            MilestoneStatus::Withdrawn {
+                on: frame_system::Pallet::<Test>::block_number()
+            }
+        );
+        assert_eq!(info.withdrawable_funds, 0);
+        assert_eq!(info.withdrawn_funds, 20_000);
+    });
+}
+
+#[test]
+fn project_info_reports_refundable_funds() {
+    build_test_externality().execute_with(|| {
+        let cont = get_contributions::<Test>(vec![BOB], 100_000);
+        let prop_milestones = get_milestones(10);
+        let jury = vec![JURY_1, JURY_2];
+        let project_key =
+            create_and_fund_project::<Test>(ALICE, cont, prop_milestones, CurrencyId::Native, jury)
+                .unwrap();
+        let _ = complete_dispute::<Test>(project_key, vec![0, 1], DisputeResult::Success, None);
+
+        let info = Proposals::project_info(project_key).unwrap();
+        assert_eq!(info.milestones[0].status, MilestoneStatus::Refundable);
+        assert_eq!(info.milestones[1].status, MilestoneStatus::Refundable);
+        assert_eq!(info.milestones[2].status, MilestoneStatus::Pending);
+        assert_eq!(info.refundable_funds, 20_000);
+    });
+}
+
+#[test]
+fn projects_by_initiator_paginates() {
+    build_test_externality().execute_with(|| {
+        let keys = (0..3)
+            .map(|_| {
+                create_and_fund_project::<Test>(
+                    ALICE,
+                    get_contributions::<Test>(vec![BOB], 100_000),
+                    get_milestones(10),
+                    CurrencyId::Native,
+                    vec![JURY_1],
+                )
+                .unwrap()
+            })
+            .collect::<Vec<ProjectKey>>();
+        let bob_key = create_and_fund_project::<Test>(
+            BOB,
+            get_contributions::<Test>(vec![CHARLIE], 100_000),
+            get_milestones(10),
+            CurrencyId::Native,
+            vec![JURY_1],
+        )
+        .unwrap();
+
+        let page_keys = |who, offset, limit| {
+            Proposals::projects_by_initiator(&who, offset, limit)
+                .into_iter()
+                .map(|info| info.project_key)
+                .collect::<Vec<ProjectKey>>()
+        };
+        assert_eq!(page_keys(ALICE, 0, 2), keys[0..2].to_vec());
+        assert_eq!(page_keys(ALICE, 2, 2), keys[2..].to_vec());
+        assert!(page_keys(ALICE, 3, 2).is_empty());
+        assert_eq!(page_keys(BOB, 0, 10), vec![bob_key]);
+        assert!(page_keys(CHARLIE, 0, 10).is_empty());
+    });
+}
+
+#[test]
+fn projects_in_voting_groups_milestones_by_project() {
+    build_test_externality().execute_with(|| {
+        let first = create_and_fund_project::<Test>(
+            ALICE,
+            get_contributions::<Test>(vec![BOB], 100_000),
+            get_milestones(10),
+            CurrencyId::Native,
+            vec![JURY_1],
+        )
+        .unwrap();
+        let second = create_and_fund_project::<Test>(
+            ALICE,
+            get_contributions::<Test>(vec![BOB], 100_000),
+            get_milestones(10),
+            CurrencyId::Native,
+            vec![JURY_1],
+        )
+        .unwrap();
+        for (project_key, milestone_key) in [(first, 1), (first, 0), (second, 2)] {
+            assert_ok!(Proposals::submit_milestone(
+                RuntimeOrigin::signed(ALICE),
+                project_key,
+                milestone_key,
+                None
+            ));
+        }
+
+        assert_eq!(
+            Proposals::projects_in_voting(0, 10),
+            vec![(first, vec![0, 1]), (second, vec![2])]
+        );
+        assert_eq!(
+            Proposals::projects_in_voting(1, 10),
+            vec![(second, vec![2])]
+        );
+        assert_eq!(
+            Proposals::projects_in_voting(0, 1),
+            vec![(first, vec![0, 1])]
+        );
+    });
+}
@@ -23,7 +23,7 @@ pub trait IntoProposal<AccountId, Balance: AtLeast32BitUnsigned, BlockNumber> {
         >,
         brief_hash: H256,
         benificiary: AccountId,
-        milestones: BoundedVec<ProposedMilestone, Self::MaxMilestonesPerProject>,
+        milestones: BoundedVec<ProposedMilestone<BlockNumber>, Self::MaxMilestonesPerProject>,
         refund_locations: BoundedVec<
             (Locality<AccountId>, Percent),
             Self::MaximumContributorsPerProject,
@@ -39,7 +39,7 @@ use core::marker::PhantomData;
 /// Weight functions for `pallet_proposals`.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> crate::WeightInfoT for WeightInfo<T> {
-	/// Storage: `ImbueProposals::Projects` (r:1 w:0)
+	/// Storage: `ImbueProposals::Projects` (r:1 w:1)
 	/// Proof: `ImbueProposals::Projects` (`max_values`: None, `max_size`: Some(36350), added: 38825, mode: `MaxEncodedLen`)
 	/// Storage: `ImbueProposals::RoundsExpiring` (r:1 w:1)
 	/// Proof: `ImbueProposals::RoundsExpiring` (`max_values`: None, `max_size`: Some(111), added: 2586, mode: `MaxEncodedLen`)
@@ -47,17 +47,21 @@ impl<T: frame_system::Config> crate::WeightInfoT for WeightInfo<T> {
 	/// Proof: `ImbueProposals::IndividualVoteStore` (`max_values`: None, `max_size`: Some(16571), added: 19046, mode: `MaxEncodedLen`)
 	/// Storage: `ImbueProposals::MilestoneVotes` (r:1 w:1)
 	/// Proof: `ImbueProposals::MilestoneVotes` (`max_values`: None, `max_size`: Some(375), added: 2850, mode: `MaxEncodedLen`)
-	/// Storage: `ImbueProposals::Rounds` (r:0 w:1)
+	/// Storage: `ImbueProposals::Rounds` (r:1 w:1)
 	/// Proof: `ImbueProposals::Rounds` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueProposals::MilestoneDisputes` (r:1 w:0)
+	/// Proof: `ImbueProposals::MilestoneDisputes` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
 	fn submit_milestone() -> Weight {
+		// The storage accesses were adjusted by hand since the last run of `benchmark pallet`,
+		// the measurements below are out of date until it is run again.
 		// Proof Size summary in bytes:
 		//  Measured:  `568`
 		//  Estimated: `39815`
 		// Minimum execution time: 345_914_000 picoseconds.
 		Weight::from_parts(354_103_000, 0)
 			.saturating_add(Weight::from_parts(0, 39815))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `ImbueProposals::Projects` (r:1 w:1)
 	/// Proof: `ImbueProposals::Projects` (`max_values`: None, `max_size`: Some(36350), added: 38825, mode: `MaxEncodedLen`)
@@ -117,21 +121,25 @@ impl<T: frame_system::Config> crate::WeightInfoT for WeightInfo<T> {
 	}
 	/// Storage: `ImbueProposals::Projects` (r:1 w:0)
 	/// Proof: `ImbueProposals::Projects` (`max_values`: None, `max_size`: Some(36350), added: 38825, mode: `MaxEncodedLen`)
-	/// Storage: `ImbueProposals::ProjectsInDispute` (r:1 w:1)
-	/// Proof: `ImbueProposals::ProjectsInDispute` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueProposals::DisputeCount` (r:1 w:1)
+	/// Proof: `ImbueProposals::DisputeCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueProposals::DisputeProjects` (r:0 w:1)
+	/// Proof: `ImbueProposals::DisputeProjects` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	/// Storage: `ImbueDisputes::Disputes` (r:1 w:1)
 	/// Proof: `ImbueDisputes::Disputes` (`max_values`: None, `max_size`: Some(6602), added: 9077, mode: `MaxEncodedLen`)
 	/// Storage: `ImbueDisputes::DisputesFinaliseOn` (r:1 w:1)
 	/// Proof: `ImbueDisputes::DisputesFinaliseOn` (`max_values`: None, `max_size`: Some(221), added: 2696, mode: `MaxEncodedLen`)
 	fn raise_dispute() -> Weight {
+		// The storage accesses were adjusted by hand since the last run of `benchmark pallet`,
+		// the measurements below are out of date until it is run again.
 		// Proof Size summary in bytes:
 		//  Measured:  `4797`
 		//  Estimated: `39815`
 		// Minimum execution time: 346_461_000 picoseconds.
 		Weight::from_parts(356_015_000, 0)
 			.saturating_add(Weight::from_parts(0, 39815))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `ImbueProposals::Projects` (r:1 w:1)
 	/// Proof: `ImbueProposals::Projects` (`max_values`: None, `max_size`: Some(36350), added: 38825, mode: `MaxEncodedLen`)
@@ -147,4 +155,126 @@ impl<T: frame_system::Config> crate::WeightInfoT for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(53))
 			.saturating_add(T::DbWeight::get().writes(53))
 	}
+	/// Storage: `ImbueProposals::Projects` (r:1 w:1)
+	/// Proof: `ImbueProposals::Projects` (`max_values`: None, `max_size`: Some(36350), added: 38825, mode: `MaxEncodedLen`)
+	/// Storage: `OrmlTokens::Accounts` (r:1 w:0)
+	/// Proof: `OrmlTokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueProposals::ProjectsAwaitingFunding` (r:0 w:1)
+	/// Proof: `ImbueProposals::ProjectsAwaitingFunding` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn check_project_funding() -> Weight {
+		// Placeholder, not benchmarked: estimated from the storage accesses listed above.
+		// Replace with the output of `benchmark pallet` for this extrinsic.
+		Weight::from_parts(146_059_000, 0)
+			.saturating_add(Weight::from_parts(0, 39815))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `ImbueProposals::Projects` (r:1 w:0)
+	/// Proof: `ImbueProposals::Projects` (`max_values`: None, `max_size`: Some(36350), added: 38825, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueProposals::NoConfidenceVotes` (r:1 w:1)
+	/// Proof: `ImbueProposals::NoConfidenceVotes` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueProposals::MilestoneDisputes` (r:1 w:0)
+	/// Proof: `ImbueProposals::MilestoneDisputes` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueProposals::RoundsExpiring` (r:1 w:1)
+	/// Proof: `ImbueProposals::RoundsExpiring` (`max_values`: None, `max_size`: Some(111), added: 2586, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueProposals::UserHasVoted` (r:1 w:1)
+	/// Proof: `ImbueProposals::UserHasVoted` (`max_values`: None, `max_size`: Some(1703), added: 4178, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueProposals::Rounds` (r:0 w:1)
+	/// Proof: `ImbueProposals::Rounds` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	fn raise_vote_of_no_confidence() -> Weight {
+		// Placeholder, not benchmarked: estimated from the storage accesses listed above.
+		// Replace with the output of `benchmark pallet` for this extrinsic.
+		Weight::from_parts(289_634_000, 0)
+			.saturating_add(Weight::from_parts(0, 39815))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `ImbueProposals::Rounds` (r:1 w:1)
+	/// Proof: `ImbueProposals::Rounds` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueProposals::Projects` (r:1 w:1)
+	/// Proof: `ImbueProposals::Projects` (`max_values`: None, `max_size`: Some(36350), added: 38825, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueProposals::UserHasVoted` (r:1 w:1)
+	/// Proof: `ImbueProposals::UserHasVoted` (`max_values`: None, `max_size`: Some(1703), added: 4178, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueProposals::NoConfidenceVotes` (r:1 w:1)
+	/// Proof: `ImbueProposals::NoConfidenceVotes` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueProposals::RoundsExpiring` (r:1 w:1)
+	/// Proof: `ImbueProposals::RoundsExpiring` (`max_values`: None, `max_size`: Some(111), added: 2586, mode: `MaxEncodedLen`)
+	fn vote_on_no_confidence_round() -> Weight {
+		// Placeholder, not benchmarked: estimated from the storage accesses listed above.
+		// Replace with the output of `benchmark pallet` for this extrinsic.
+		Weight::from_parts(401_226_000, 0)
+			.saturating_add(Weight::from_parts(0, 39815))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `ImbueProposals::Arbitrations` (r:1 w:1)
+	/// Proof: `ImbueProposals::Arbitrations` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueProposals::Rounds` (r:1 w:1)
+	/// Proof: `ImbueProposals::Rounds` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueProposals::RoundsExpiring` (r:1 w:1)
+	/// Proof: `ImbueProposals::RoundsExpiring` (`max_values`: None, `max_size`: Some(111), added: 2586, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueProposals::DisputeProjects` (r:1 w:1)
+	/// Proof: `ImbueProposals::DisputeProjects` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Deposits::CurrentDeposits` (r:1 w:1)
+	/// Proof: `Deposits::CurrentDeposits` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueProposals::Projects` (r:1 w:1)
+	/// Proof: `ImbueProposals::Projects` (`max_values`: None, `max_size`: Some(36350), added: 38825, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueProposals::MilestoneDisputes` (r:0 w:10)
+	/// Proof: `ImbueProposals::MilestoneDisputes` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn arbitrate_dispute() -> Weight {
+		// Placeholder, not benchmarked: estimated from the storage accesses listed above.
+		// Replace with the output of `benchmark pallet` for this extrinsic.
+		Weight::from_parts(306_815_000, 0)
+			.saturating_add(Weight::from_parts(0, 39815))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(17))
+	}
+	/// Storage: `ImbueProposals::Projects` (r:1 w:0)
+	/// Proof: `ImbueProposals::Projects` (`max_values`: None, `max_size`: Some(36350), added: 38825, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueProposals::MilestoneDisputes` (r:1 w:0)
+	/// Proof: `ImbueProposals::MilestoneDisputes` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueProposals::RefundShareProposals` (r:1 w:1)
+	/// Proof: `ImbueProposals::RefundShareProposals` (`max_values`: None, `max_size`: Some(3245), added: 5720, mode: `MaxEncodedLen`)
+	fn propose_refund_share() -> Weight {
+		// Placeholder, not benchmarked: estimated from the storage accesses listed above.
+		// Replace with the output of `benchmark pallet` for this extrinsic.
+		Weight::from_parts(63_112_000, 0)
+			.saturating_add(Weight::from_parts(0, 39815))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `ImbueProposals::Projects` (r:1 w:0)
+	/// Proof: `ImbueProposals::Projects` (`max_values`: None, `max_size`: Some(36350), added: 38825, mode: `MaxEncodedLen`)
+	/// Storage: `ImbueProposals::VoteDelegations` (r:0 w:1)
+	/// Proof: `ImbueProposals::VoteDelegations` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	fn delegate_vote() -> Weight {
+		// Placeholder, not benchmarked: estimated from the storage accesses listed above.
+		// Replace with the output of `benchmark pallet` for this extrinsic.
+		Weight::from_parts(39_406_000, 0)
+			.saturating_add(Weight::from_parts(0, 39815))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `ImbueProposals::VoteDelegations` (r:1 w:1)
+	/// Proof: `ImbueProposals::VoteDelegations` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	fn revoke_delegation() -> Weight {
+		// Placeholder, not benchmarked: estimated from the storage accesses listed above.
+		// Replace with the output of `benchmark pallet` for this extrinsic.
+		Weight::from_parts(22_530_000, 0)
+			.saturating_add(Weight::from_parts(0, 3566))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `ImbueProposals::Projects` (r:1 w:1)
+	/// Proof: `ImbueProposals::Projects` (`max_values`: None, `max_size`: Some(36350), added: 38825, mode: `MaxEncodedLen`)
+	fn set_project_policies() -> Weight {
+		// Placeholder, not benchmarked: estimated from the storage accesses listed above.
+		// Replace with the output of `benchmark pallet` for this extrinsic.
+		Weight::from_parts(45_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 39815))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
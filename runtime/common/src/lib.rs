@@ -303,5 +303,7 @@ pub mod storage_deposits {
         CrowdFund,
         Grant,
         Brief,
+        Dispute,
+        Appeal,
     }
 }
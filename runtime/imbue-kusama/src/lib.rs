@@ -71,6 +71,8 @@ pub use common_runtime::{
 };
 pub use common_types::{CurrencyId, CustomMetadata};
 pub use pallet_balances::Call as BalancesCall;
+pub use pallet_briefs::BriefHash;
+pub use pallet_proposals::{DisputeId, MilestoneKey, ProjectKey};
 pub use pallet_timestamp::Call as TimestampCall;
 pub use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 
@@ -182,13 +184,35 @@ pub mod migrations {
     /// Unreleased migrations. Add new ones here:
     pub type Unreleased = (
         pallet_briefs::migrations::v3::MigrateToV3<Runtime>,
+        pallet_briefs::migrations::v4::MigrateToV4<Runtime>,
+        pallet_briefs::migrations::v5::MigrateToV5<Runtime>,
+        pallet_briefs::migrations::v6::MigrateToV6<Runtime>,
         pallet_fellowship::migration::v0::MigrateInitial<Runtime>,
+        pallet_fellowship::migration::v2::MigrateToV2<Runtime>,
+        pallet_fellowship::migration::v3::MigrateToV3<Runtime>,
         pallet_balances::migration::MigrateToTrackInactive<Runtime, xcm_config::CheckingAccount>,
         pallet_collator_selection::migration::v1::MigrateToV1<Runtime>,
         pallet_xcm::migration::v1::VersionUncheckedMigrateToV1<Runtime>,
         orml_unknown_tokens::Migration<Runtime>,
         // PROPOSALS MIGRATION MUST BE RUN AFTER FELLOWSHIP MIGRATION
         pallet_proposals::migration::v7::MigrateToV7<Runtime>,
+        pallet_proposals::migration::v8::MigrateToV8<Runtime>,
+        pallet_proposals::migration::v9::MigrateToV9<Runtime>,
+        pallet_proposals::migration::v10::MigrateToV10<Runtime>,
+        pallet_proposals::migration::v11::MigrateToV11<Runtime>,
+        pallet_proposals::migration::v12::MigrateToV12<Runtime>,
+        pallet_proposals::migration::v13::MigrateToV13<Runtime>,
+        pallet_proposals::migration::v14::MigrateToV14<Runtime>,
+        pallet_proposals::migration::v15::MigrateToV15<Runtime>,
+        pallet_proposals::migration::v16::MigrateToV16<Runtime>,
+        pallet_disputes::migration::v1::MigrateToV1<Runtime>,
+        pallet_disputes::migration::v2::MigrateToV2<Runtime>,
+        pallet_disputes::migration::v3::MigrateToV3<Runtime>,
+        pallet_disputes::migration::v4::MigrateToV4<Runtime>,
+        pallet_disputes::migration::v5::MigrateToV5<Runtime>,
+        pallet_disputes::migration::v6::MigrateToV6<Runtime>,
+        pallet_disputes::migration::v7::MigrateToV7<Runtime>,
+        pallet_disputes::migration::v8::MigrateToV8<Runtime, (), AppealBond>,
     );
 }
 
@@ -809,11 +833,23 @@ parameter_types! {
     pub const MaximumContributorsPerProject: u32 = 50;
     pub const IsIdentityRequired: bool = false;
     pub const MilestoneVotingWindow: BlockNumber = 100800;
+    pub const NoConfidenceVotingWindow: BlockNumber = 100800;
+    pub const PercentRequiredForVoteNoConfidenceToPass: Percent = Percent::from_percent(75u8);
     pub const ImbueFee: Percent = Percent::from_percent(5_u8);
     pub const ExpiringProjectRoundsPerBlock: u32 = 10;
     pub const ProjectStorageItem: StorageDepositItems = StorageDepositItems::Project;
+    pub const DisputeStorageItem: StorageDepositItems = StorageDepositItems::Dispute;
     pub const MaxMilestonesPerProject: u32 = 10;
     pub const MaxProjectsPerAccount: u16 = u16::MAX;
+    pub const MaxJuriesPerAccount: u32 = 100;
+    pub const MinimumJurySize: u32 = 2;
+    pub const ArbitrationWindow: BlockNumber = 7 * DAYS;
+    pub const MilestoneResubmissionPolicy: pallet_proposals::ResubmissionPolicy<BlockNumber> =
+        pallet_proposals::ResubmissionPolicy { cooldown: 3 * DAYS, max_attempts: 3 };
+    pub const MilestoneVotingExpiryPolicy: pallet_proposals::VotingExpiryPolicy =
+        pallet_proposals::VotingExpiryPolicy::TurnoutRelative {
+            min_turnout: Percent::from_percent(50),
+        };
 }
 
 impl pallet_proposals::Config for Runtime {
@@ -824,17 +860,27 @@ impl pallet_proposals::Config for Runtime {
     type MaximumContributorsPerProject = MaximumContributorsPerProject;
     type WeightInfo = pallet_proposals::weights::WeightInfo<Self>;
     type MilestoneVotingWindow = MilestoneVotingWindow;
+    type NoConfidenceVotingWindow = NoConfidenceVotingWindow;
+    type PercentRequiredForVoteNoConfidenceToPass = PercentRequiredForVoteNoConfidenceToPass;
     type ExternalRefundHandler = pallet_proposals::traits::XcmRefundHandler<Runtime, XTokens>;
     type MaxMilestonesPerProject = MaxMilestonesPerProject;
     type ImbueFee = ImbueFee;
     type ExpiringProjectRoundsPerBlock = ExpiringProjectRoundsPerBlock;
     type ProjectStorageItem = ProjectStorageItem;
+    type DisputeStorageItem = DisputeStorageItem;
     type DepositHandler = Deposits;
     type MaxProjectsPerAccount = MaxProjectsPerAccount;
-    type JurySelector = PointerBasedJurySelector<Runtime>;
+    type JurySelector = JurySelector;
+    type MaxJuriesPerAccount = MaxJuriesPerAccount;
     type ImbueFeeAccount = TreasuryAccount;
     type DisputeRaiser = pallet_disputes::Pallet<Runtime>;
     type AssetSignerOrigin = EnsureRoot<AccountId>;
+    type MinimumJurySize = MinimumJurySize;
+    type ArbitrationOrigin = EnsureRootOr<HalfOfCouncil>;
+    type ArbitrationWindow = ArbitrationWindow;
+    type DefaultResubmissionPolicy = MilestoneResubmissionPolicy;
+    type DefaultVotingExpiryPolicy = MilestoneVotingExpiryPolicy;
+    type ProjectPolicyOrigin = EnsureRootOr<HalfOfCouncil>;
 }
 
 parameter_types! {
@@ -868,10 +914,38 @@ impl pallet_briefs::Config for Runtime {
     type WeightInfo = pallet_briefs::weights::WeightInfo<Self>;
     type BriefStorageItem = BriefStorageItem;
     type DepositHandler = Deposits;
-    type JurySelector = PointerBasedJurySelector<Runtime>;
+    type JurySelector = JurySelector;
     type EnsureRole = pallet_fellowship::impls::EnsureFellowshipRole<Runtime>;
 }
 
+parameter_types! {
+    pub CrowdFundRoundExpiry: BlockNumber = 14 * DAYS;
+    pub MaxContributionRounds: u32 = 2;
+    pub MaxKeysPerRound: u32 = 50;
+    pub MaxWhitelistPerCrowdFund: u32 = 50;
+    pub CrowdFundStorageItem: StorageDepositItems = StorageDepositItems::CrowdFund;
+}
+
+// Crowdfunding is only part of the runtime when benchmarking, until it has benchmarked weights.
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_crowdfunding::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type MultiCurrency = Currencies;
+    type RoundExpiry = CrowdFundRoundExpiry;
+    type MaxContributionRounds = MaxContributionRounds;
+    type MaxKeysPerRound = MaxKeysPerRound;
+    type MaxContributionsPerCrowdFund = MaximumContributorsPerProject;
+    type MaxMilestonesPerCrowdFund = MaxMilestonesPerProject;
+    type MaxWhitelistPerCrowdFund = MaxWhitelistPerCrowdFund;
+    type IsIdentityRequired = IsIdentityRequired;
+    type AuthorityOrigin = EnsureRootOr<HalfOfCouncil>;
+    type IntoProposals = pallet_proposals::Pallet<Runtime>;
+    type CrowdFundStorageItem = CrowdFundStorageItem;
+    type DepositHandler = Deposits;
+    type JurySelector = JurySelector;
+    type WeightInfo = pallet_crowdfunding::weights::SubstrateWeight<Runtime>;
+}
+
 parameter_types! {
     pub MaxCandidatesPerShortlist: u32 = 50;
     pub ShortlistPeriod: BlockNumber = 14 * DAYS;
@@ -879,6 +953,14 @@ parameter_types! {
     pub DepositCurrencyId: CurrencyId = CurrencyId::Native;
 }
 
+parameter_types! {
+    pub JuryRoles: Vec<pallet_fellowship::Role> = vec![pallet_fellowship::Role::Vetter, pallet_fellowship::Role::Freelancer];
+    pub MinJuryRank: u16 = 0;
+}
+
+pub type JurySelector =
+    pallet_fellowship::impls::RandomJurySelector<Runtime, RelayChainRandomness, MaxJurySize>;
+
 impl pallet_fellowship::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type MultiCurrency = Currencies;
@@ -890,6 +972,10 @@ impl pallet_fellowship::Config for Runtime {
     // Send slashes to the treasury.
     type SlashAccount = TreasuryAccount;
     type Permissions = pallet_fellowship::impls::VetterAndFreelancerAllPermissions;
+    // Jurors cannot leave while on a dispute and are replaced when removed.
+    type FellowshipHooks = ImbueProposals;
+    type JuryRoles = JuryRoles;
+    type MinJuryRank = MinJuryRank;
     type WeightInfo = pallet_fellowship::weights::WeightInfo<Runtime>;
 }
 
@@ -909,6 +995,8 @@ impl DepositCalculator<Balance> for ImbueDepositCalculator {
             StorageDepositItems::CrowdFund => DOLLARS.saturating_mul(550),
             StorageDepositItems::Grant => DOLLARS.saturating_mul(400),
             StorageDepositItems::Brief => DOLLARS.saturating_mul(500),
+            StorageDepositItems::Dispute => DOLLARS.saturating_mul(100),
+            StorageDepositItems::Appeal => DOLLARS.saturating_mul(200),
         })
     }
 }
@@ -918,28 +1006,104 @@ impl pallet_deposits::Config for Runtime {
     type StorageItem = StorageDepositItems;
     type DepositId = DepositId;
     type DepositCalculator = ImbueDepositCalculator;
-    type DepositSlashAccount = TreasuryAccount;
+    // The only deposits slashed are the bonds of failed disputes and appeals, they fund the juror rewards.
+    type DepositSlashAccount = JurorRewardPot;
 }
 
 parameter_types! {
     pub MaxJurySize: u32 = 100;
     pub MaxDisputesPerBlock: u32 = 50;
     pub VotingTimeLimit: BlockNumber = DAYS * 14;
+    pub JurorRewardCurrencyId: CurrencyId = CurrencyId::Native;
+    pub JurorRewardPerDispute: Balance = DOLLARS.saturating_mul(50);
+    pub MaxMissedJuryVotes: u32 = 3;
+    pub AppealWindow: BlockNumber = DAYS * 3;
+    // The bond reserved from appellants before appeal bonds were taken as deposits.
+    pub AppealBond: Balance = DOLLARS.saturating_mul(200);
+    pub const AppealStorageItem: StorageDepositItems = StorageDepositItems::Appeal;
+    pub AppealDecisionPeriod: BlockNumber = DAYS * 14;
+    pub EvidencePeriod: BlockNumber = DAYS * 3;
+    pub RevealPeriod: BlockNumber = DAYS * 3;
+    pub MaxDisputeExtensions: u32 = 2;
+    pub MaxDisputesPerJuror: u32 = 20;
+    pub DisputeMinTurnout: Percent = Percent::from_percent(50);
+    pub DisputeSupermajority: Percent = Percent::from_percent(50);
+    pub DisputeTieBreak: pallet_disputes::DisputeResult = pallet_disputes::DisputeResult::Success;
+    pub MaxEvidence: u32 = 50;
+    pub const JurorRewardPotId: PalletId = PalletId(*b"imb/jury");
+    pub JurorRewardPot: AccountId = JurorRewardPotId::get().into_account_truncating();
 }
 
 impl pallet_disputes::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    type DisputeKey = pallet_proposals::ProjectKey;
-    type SpecificId = pallet_proposals::MilestoneKey;
+    type DisputeKey = DisputeId;
+    type SpecificId = MilestoneKey;
     type MaxJurySize = MaxJurySize;
     // TODO: this syntax for each max milestones per brief grant and projet.
     // it binds automatically.
     type MaxSpecifics = <Runtime as pallet_proposals::Config>::MaxMilestonesPerProject;
     type MaxDisputesPerBlock = MaxDisputesPerBlock;
     type VotingTimeLimit = VotingTimeLimit;
+    // Jurors commit to their votes during voting and reveal them afterwards.
+    // Open voting is disabled, so a dispute cannot finalise before the reveal period starts.
+    type RevealPeriod = RevealPeriod;
+    type MaxExtensions = MaxDisputeExtensions;
+    type MaxDisputesPerJuror = MaxDisputesPerJuror;
     type ForceOrigin = EnsureRootOr<HalfOfCouncil>;
+    type BondId = DepositId;
     type DisputeHooks = pallet_proposals::Pallet<Runtime>;
     type WeightInfo = pallet_disputes::weights::WeightInfo<Runtime>;
+    type MultiCurrency = Currencies;
+    type JurorRewardCurrencyId = JurorRewardCurrencyId;
+    // Rewards are paid from a dedicated pot funded by the bonds of failed disputes and slashed appeal bonds.
+    type JurorRewardPot = JurorRewardPot;
+    type JurorRewardPerDispute = JurorRewardPerDispute;
+    type JurorPenaltyHandler = RevokeFellowshipOnMissedVotes;
+    // A simple majority of at least half the jury, ties are still settled in favour of the raiser.
+    type DisputeOutcomePolicy = pallet_disputes::traits::SupermajorityPolicy<
+        DisputeMinTurnout,
+        DisputeSupermajority,
+        DisputeTieBreak,
+    >;
+    type EvidencePeriod = EvidencePeriod;
+    type MaxEvidence = MaxEvidence;
+    type AppealWindow = AppealWindow;
+    // Slashed appeal bonds top up the juror rewards through the DepositSlashAccount.
+    type DepositHandler = Deposits;
+    type AppealStorageItem = AppealStorageItem;
+    type AppealOrigin = EnsureRootOr<HalfOfCouncil>;
+    // Undecided appeals fall back to the verdicts of the jury.
+    type AppealDecisionPeriod = AppealDecisionPeriod;
+    type DisputeParties = pallet_proposals::Pallet<Runtime>;
+}
+
+/// Remove jurors from the fellowship and slash their deposit once they have failed to vote on
+/// MaxMissedJuryVotes disputes in a row.
+pub struct RevokeFellowshipOnMissedVotes;
+impl pallet_disputes::traits::JurorPenaltyHandler<AccountId> for RevokeFellowshipOnMissedVotes {
+    fn on_missed_vote(juror: &AccountId, missed_in_a_row: u32) -> Weight {
+        use pallet_fellowship::traits::{FellowshipHandle, WeightInfoT};
+
+        if missed_in_a_row < MaxMissedJuryVotes::get() {
+            return Weight::zero();
+        }
+        let hooks_weight =
+            <ImbueFellowship as FellowshipHandle<AccountId>>::revoke_fellowship(juror, true)
+                .unwrap_or_default();
+        <Runtime as pallet_fellowship::Config>::WeightInfo::force_remove_and_slash_fellowship()
+            .saturating_add(hooks_weight)
+    }
+
+    fn max_on_missed_vote_weight() -> Weight {
+        use pallet_fellowship::traits::{FellowshipHooks, WeightInfoT};
+
+        <Runtime as pallet_fellowship::Config>::WeightInfo::force_remove_and_slash_fellowship()
+            .saturating_add(
+                <<Runtime as pallet_fellowship::Config>::FellowshipHooks as FellowshipHooks<
+                    AccountId,
+                >>::max_hooks_weight(),
+            )
+    }
 }
 
 construct_runtime! {
@@ -997,6 +1161,8 @@ construct_runtime! {
         Deposits: pallet_deposits::{Pallet, Storage, Event<T>} = 103,
         ImbueFellowship: pallet_fellowship::{Pallet, Call, Storage, Event<T>} = 104,
         ImbueDisputes: pallet_disputes::{Pallet, Call, Storage, Event<T>} = 105,
+        #[cfg(feature = "runtime-benchmarks")]
+        ImbueCrowdFunding: pallet_crowdfunding::{Pallet, Call, Storage, Event<T>} = 106,
     }
 }
 
@@ -1036,6 +1202,7 @@ mod benches {
         [pallet_grants, ImbueGrants]
         [pallet_fellowship, ImbueFellowship]
         [pallet_disputes, ImbueDisputes]
+        [pallet_crowdfunding, ImbueCrowdFunding]
     );
 }
 
@@ -1206,6 +1373,7 @@ impl_runtime_apis! {
         }
     }
 
+    #[api_version(2)]
     impl pallet_proposals_rpc_runtime_api::ProposalsApi<Block, AccountId, Balance> for Runtime {
         fn get_project_account_by_id(project_id: u32) -> AccountId {
             ImbueProposals::project_account_id(project_id)
@@ -1220,7 +1388,9 @@ impl_runtime_apis! {
 
             let project_votes_encoded = IndividualVoteStore::<Runtime>::get(project_key).map(|i| <ImmutableIndividualVotes<Runtime> as Encode>::encode(&i));
 
-            let dispute_votes_encoded = match  Disputes::<Runtime>::get(project_key) {
+            // Only the earliest open dispute of the project is returned here.
+            let dispute = ImbueProposals::project_disputes(project_key).first().and_then(Disputes::<Runtime>::get);
+            let dispute_votes_encoded = match dispute {
                 Some(d) => {
                     let dispute_votes: DisputeVotes<BoundedVotes<Runtime>> = DisputeVotes {
                         votes: d.votes
@@ -1237,6 +1407,117 @@ impl_runtime_apis! {
 
             (project_encoded, project_votes_encoded, dispute_votes_encoded, milestones_in_voting)
         }
+
+        fn get_project_disputes(project_key: u32) -> Vec<(u32, Vec<u8>)> {
+            use pallet_disputes::{Disputes, DisputeVotes, BoundedVotes};
+
+            ImbueProposals::project_disputes(project_key)
+                .into_iter()
+                .filter_map(|dispute_key| {
+                    Disputes::<Runtime>::get(dispute_key).map(|d| {
+                        let dispute_votes: DisputeVotes<BoundedVotes<Runtime>> = DisputeVotes {
+                            votes: d.votes
+                        };
+                        (dispute_key, <DisputeVotes<BoundedVotes<Runtime>> as Encode>::encode(&dispute_votes))
+                    })
+                })
+                .collect()
+        }
+
+        fn get_project_info(project_key: u32) -> Option<pallet_proposals_rpc_runtime_api::ProjectInfo<AccountId, Balance, BlockNumber>> {
+            ImbueProposals::project_info(project_key)
+        }
+
+        fn get_projects_by_initiator(initiator: AccountId, offset: u32, limit: u32) -> Vec<pallet_proposals_rpc_runtime_api::ProjectInfo<AccountId, Balance, BlockNumber>> {
+            ImbueProposals::projects_by_initiator(&initiator, offset, limit)
+        }
+
+        fn get_projects_in_voting(offset: u32, limit: u32) -> Vec<(u32, Vec<u32>)> {
+            ImbueProposals::projects_in_voting(offset, limit)
+        }
+    }
+
+    impl pallet_disputes_rpc_runtime_api::DisputesApi<Block, AccountId, DisputeId, MilestoneKey, BlockNumber> for Runtime {
+        fn get_dispute(dispute_key: DisputeId) -> Option<pallet_disputes_rpc_runtime_api::DisputeInfo<AccountId, MilestoneKey, BlockNumber>> {
+            use pallet_disputes::EvidenceRole;
+            use pallet_disputes_rpc_runtime_api::EvidenceRole as EvidenceRoleInfo;
+
+            ImbueDisputes::disputes(dispute_key).map(|d| pallet_disputes_rpc_runtime_api::DisputeInfo {
+                raised_by: d.raised_by,
+                jury: d.jury.into_inner(),
+                votes: d
+                    .votes
+                    .into_iter()
+                    .map(|(juror, votes)| (juror, votes.into_iter().collect()))
+                    .collect(),
+                specifiers: d.specifiers.into_inner(),
+                extensions: d.extensions,
+                extension_votes: d.extension_votes.into_inner(),
+                expiration: d.expiration,
+                voting_starts: d.voting_starts,
+                evidence: d
+                    .evidence
+                    .into_iter()
+                    .map(|evidence| pallet_disputes_rpc_runtime_api::EvidenceInfo {
+                        submitted_by: evidence.submitted_by,
+                        role: match evidence.role {
+                            EvidenceRole::Raiser => EvidenceRoleInfo::Raiser,
+                            EvidenceRole::Defendant => EvidenceRoleInfo::Defendant,
+                            EvidenceRole::Juror => EvidenceRoleInfo::Juror,
+                        },
+                        hash: evidence.hash.into_inner(),
+                    })
+                    .collect(),
+            })
+        }
+
+        fn get_disputes_finalising_between(from: BlockNumber, to: BlockNumber) -> (Vec<(BlockNumber, Vec<DisputeId>)>, BlockNumber) {
+            ImbueDisputes::disputes_finalising_between(from, to)
+        }
+
+        fn get_disputes_awaiting_vote(juror: AccountId) -> Vec<DisputeId> {
+            ImbueDisputes::disputes_awaiting_vote(&juror)
+        }
+
+        fn get_projected_result(dispute_key: DisputeId) -> Option<Vec<(MilestoneKey, pallet_disputes_rpc_runtime_api::DisputeOutcome)>> {
+            use pallet_disputes::DisputeResult;
+            use pallet_disputes_rpc_runtime_api::DisputeOutcome;
+
+            ImbueDisputes::projected_result(dispute_key).map(|verdicts| {
+                verdicts
+                    .into_iter()
+                    .map(|(milestone_key, result)| {
+                        let outcome = match result {
+                            DisputeResult::Success => DisputeOutcome::Success,
+                            DisputeResult::Failure => DisputeOutcome::Failure,
+                        };
+                        (milestone_key, outcome)
+                    })
+                    .collect()
+            })
+        }
+    }
+
+    impl pallet_briefs_rpc_runtime_api::BriefsApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+        fn get_remaining_bounty(brief_id: BriefHash) -> Balance {
+            ImbueBriefs::get_remaining_bounty(brief_id)
+        }
+
+        fn get_brief(brief_id: BriefHash) -> Option<pallet_briefs_rpc_runtime_api::BriefInfo<AccountId, Balance, BlockNumber>> {
+            ImbueBriefs::brief_info(brief_id)
+        }
+
+        fn get_brief_contributions(brief_id: BriefHash) -> Vec<pallet_briefs_rpc_runtime_api::BriefContributionInfo<AccountId, Balance, BlockNumber>> {
+            ImbueBriefs::brief_contributions_info(brief_id)
+        }
+
+        fn get_briefs_by_owner(owner: AccountId) -> Vec<BriefHash> {
+            ImbueBriefs::briefs_by_owner(&owner)
+        }
+
+        fn get_briefs_by_applicant(applicant: AccountId) -> Vec<BriefHash> {
+            ImbueBriefs::briefs_by_applicant(&applicant)
+        }
     }
 
 
@@ -1323,37 +1604,52 @@ cumulus_pallet_parachain_system::register_validate_block! {
     CheckInherents = CheckInherents,
 }
 
-type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
-pub struct PointerBasedJurySelector<T: pallet_fellowship::Config>(T);
-impl<T: pallet_fellowship::Config> pallet_fellowship::traits::SelectJury<AccountIdOf<T>>
-    for PointerBasedJurySelector<T>
-{
-    type JurySize = MaxJurySize;
-    fn select_jury() -> frame_support::BoundedVec<AccountIdOf<T>, Self::JurySize> {
-        let mut out: frame_support::BoundedVec<AccountIdOf<T>, Self::JurySize> =
-            frame_support::BoundedVec::new();
-        let amount = Self::JurySize::get();
-        let keys = pallet_fellowship::Roles::<T>::iter_keys().collect::<Vec<AccountIdOf<T>>>();
-        let keys_len = keys.len();
-        let pointer = pallet_fellowship::JuryPointer::<T>::get();
-        let pointer_with_bound = pointer.saturating_add(amount.into());
-        for i in pointer..pointer_with_bound {
-            if keys_len > 0 {
-                let index = i as usize % keys_len;
-                // shouldnt be an issue due to mod.
-                if index < keys.len() {
-                    let key = &keys[index];
-                    // Here weve gone in a circle! break.
-                    if out.contains(key) {
-                        break;
-                    }
-                    if let Err(_) = out.try_push(key.clone()) {
-                        break;
-                    }
-                }
-            }
+/// Randomness from the BABE VRF output of the relay chain block this block is built on,
+/// read from the relay chain state proof given with the validation data.
+/// It is not known until the relay parent is, so the collator can only choose between the few
+/// relay parents it could build on rather than the outcome.
+/// The validation data is only set by the first inherent, before that, such as in `on_initialize`,
+/// this falls back to the ParentHashRandomness.
+pub struct RelayChainRandomness;
+impl Randomness<Hash, BlockNumber> for RelayChainRandomness {
+    fn random(subject: &[u8]) -> (Hash, BlockNumber) {
+        use cumulus_primitives_core::relay_chain::well_known_keys;
+        use sp_runtime::traits::Hash as HashT;
+
+        let relay_randomness = ParachainSystem::validation_data()
+            .zip(ParachainSystem::relay_state_proof())
+            .and_then(|(validation_data, proof)| {
+                cumulus_pallet_parachain_system::RelayChainStateProof::new(
+                    ParachainInfo::get(),
+                    validation_data.relay_parent_storage_root,
+                    proof,
+                )
+                .ok()?
+                .read_optional_entry::<Option<[u8; 32]>>(well_known_keys::CURRENT_BLOCK_RANDOMNESS)
+                .ok()
+                .flatten()
+                .flatten()
+            });
+
+        match relay_randomness {
+            Some(randomness) => (
+                BlakeTwo256::hash_of(&(subject, randomness)),
+                System::block_number(),
+            ),
+            None => ParentHashRandomness::random(subject),
         }
-        pallet_fellowship::JuryPointer::<T>::put(pointer_with_bound);
-        out
+    }
+}
+
+/// Randomness derived from the parent block hash.
+/// The collator of a block can predict this, so it is only used where the relay chain randomness is not available.
+pub struct ParentHashRandomness;
+impl Randomness<Hash, BlockNumber> for ParentHashRandomness {
+    fn random(subject: &[u8]) -> (Hash, BlockNumber) {
+        use sp_runtime::traits::Hash as HashT;
+        (
+            BlakeTwo256::hash_of(&(subject, System::parent_hash())),
+            System::block_number().saturating_sub(1),
+        )
     }
 }